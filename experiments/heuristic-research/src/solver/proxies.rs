@@ -109,6 +109,10 @@ where
         }))
     }
 
+    fn select_mates<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
+        self.inner.select_mates()
+    }
+
     fn ranked<'a>(&'a self) -> Box<dyn Iterator<Item = (&Self::Individual, usize)> + 'a> {
         self.inner.ranked()
     }
@@ -1,3 +1,7 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/math/statistics_test.rs"]
+mod statistics_test;
+
 use crate::utils::compare_floats;
 use std::cmp::Ordering;
 
@@ -57,6 +61,73 @@ pub fn get_stdev(values: &[f64]) -> f64 {
     get_variance_mean(values).0.sqrt()
 }
 
+/// Returns value of cumulative distribution function of standard normal distribution.
+/// Uses approximation of error function with maximum absolute error about 1.5E-7.
+pub fn get_normal_cdf(x: f64) -> f64 {
+    let z = x.abs() / std::f64::consts::SQRT_2;
+    let t = 1. / (1. + 0.3275911 * z);
+    let poly = t * (0.254829592 + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    let erf = 1. - poly * (-z * z).exp();
+
+    if x < 0. {
+        0.5 * (1. - erf)
+    } else {
+        0.5 * (1. + erf)
+    }
+}
+
+/// Returns quantile function (inverse of cumulative distribution function) value of standard normal
+/// distribution for given probability. Uses Acklam's rational approximation with relative error
+/// about 1.15E-9. Infinity values are returned for probabilities outside of (0, 1) range.
+pub fn get_normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e+01,
+        2.209460984245205e+02,
+        -2.759285104469687e+02,
+        1.38357751867269e+02,
+        -3.066479806614716e+01,
+        2.506628277459239e+00,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e+01,
+        1.615858368580409e+02,
+        -1.556989798598866e+02,
+        6.680131188771972e+01,
+        -1.328068155288572e+01,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-03,
+        -3.223964580411365e-01,
+        -2.400758277161838e+00,
+        -2.549732539343734e+00,
+        4.374664141464968e+00,
+        2.938163982698783e+00,
+    ];
+    const D: [f64; 4] = [7.784695709041462e-03, 3.224671290700398e-01, 2.445134137142996e+00, 3.754408661907416e+00];
+    const P_LOW: f64 = 0.02425;
+
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.)
+    };
+
+    if p <= 0. {
+        f64::NEG_INFINITY
+    } else if p >= 1. {
+        f64::INFINITY
+    } else if p < P_LOW {
+        tail((-2. * p.ln()).sqrt())
+    } else if p > 1. - P_LOW {
+        -tail((-2. * (1. - p).ln()).sqrt())
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.)
+    }
+}
+
 /// Returns variance and mean.
 fn get_variance_mean(values: &[f64]) -> (f64, f64) {
     let mean = get_mean_slice(values);
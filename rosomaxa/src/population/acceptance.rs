@@ -0,0 +1,127 @@
+#[cfg(test)]
+#[path = "../../tests/unit/population/acceptance_test.rs"]
+mod acceptance_test;
+
+use crate::prelude::*;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// A trait which specifies an acceptance criterion: it decides whether a candidate solution, which
+/// is not better than the best known one, should replace the current solution.
+pub trait Acceptance<S: HeuristicSolution> {
+    /// Returns true if the candidate solution is accepted as a new current solution.
+    fn is_accepted(&self, statistics: &HeuristicStatistics, current: &S, best: &S, candidate: &S) -> bool;
+}
+
+/// Implements simulated annealing acceptance criterion: a worse candidate is accepted with
+/// probability `exp(-delta / t)`, where delta is a relative difference between the candidate and
+/// the current solution. The temperature `t` is decreased exponentially from initial to final value
+/// based on termination progress.
+pub struct SimulatedAnnealing {
+    initial_temperature: f64,
+    final_temperature: f64,
+    environment: Arc<Environment>,
+}
+
+impl SimulatedAnnealing {
+    /// Creates a new instance of `SimulatedAnnealing`.
+    pub fn new(initial_temperature: f64, final_temperature: f64, environment: Arc<Environment>) -> Self {
+        assert!(initial_temperature >= final_temperature && final_temperature > 0.);
+
+        Self { initial_temperature, final_temperature, environment }
+    }
+
+    /// Returns temperature for given progress.
+    fn get_temperature(&self, progress: f64) -> f64 {
+        self.initial_temperature * (self.final_temperature / self.initial_temperature).powf(progress)
+    }
+}
+
+impl<S: HeuristicSolution> Acceptance<S> for SimulatedAnnealing {
+    fn is_accepted(&self, statistics: &HeuristicStatistics, current: &S, _: &S, candidate: &S) -> bool {
+        let delta = get_relative_distance(current, candidate);
+
+        if delta <= 0. {
+            return true;
+        }
+
+        let temperature = self.get_temperature(get_progress(statistics, self.environment.as_ref()));
+
+        self.environment.random.uniform_real(0., 1.) < (-delta / temperature).exp()
+    }
+}
+
+/// Implements threshold accepting criterion: a candidate is accepted if its relative difference
+/// from the current solution does not exceed threshold which is decreased linearly from initial
+/// to final value based on termination progress.
+pub struct ThresholdAccepting {
+    initial_threshold: f64,
+    final_threshold: f64,
+    environment: Arc<Environment>,
+}
+
+impl ThresholdAccepting {
+    /// Creates a new instance of `ThresholdAccepting`.
+    pub fn new(initial_threshold: f64, final_threshold: f64, environment: Arc<Environment>) -> Self {
+        assert!(initial_threshold >= final_threshold && final_threshold >= 0.);
+
+        Self { initial_threshold, final_threshold, environment }
+    }
+}
+
+impl<S: HeuristicSolution> Acceptance<S> for ThresholdAccepting {
+    fn is_accepted(&self, statistics: &HeuristicStatistics, current: &S, _: &S, candidate: &S) -> bool {
+        let progress = get_progress(statistics, self.environment.as_ref());
+        let threshold = get_linear_value(self.initial_threshold, self.final_threshold, progress);
+
+        get_relative_distance(current, candidate) <= threshold
+    }
+}
+
+/// Implements record-to-record travel criterion: a candidate is accepted if its relative difference
+/// from the best known solution (record) does not exceed deviation which is decreased linearly from
+/// initial to final value based on termination progress.
+pub struct RecordToRecordTravel {
+    initial_deviation: f64,
+    final_deviation: f64,
+    environment: Arc<Environment>,
+}
+
+impl RecordToRecordTravel {
+    /// Creates a new instance of `RecordToRecordTravel`.
+    pub fn new(initial_deviation: f64, final_deviation: f64, environment: Arc<Environment>) -> Self {
+        assert!(initial_deviation >= final_deviation && final_deviation >= 0.);
+
+        Self { initial_deviation, final_deviation, environment }
+    }
+}
+
+impl<S: HeuristicSolution> Acceptance<S> for RecordToRecordTravel {
+    fn is_accepted(&self, statistics: &HeuristicStatistics, _: &S, best: &S, candidate: &S) -> bool {
+        let progress = get_progress(statistics, self.environment.as_ref());
+        let deviation = get_linear_value(self.initial_deviation, self.final_deviation, progress);
+
+        get_relative_distance(best, candidate) <= deviation
+    }
+}
+
+/// Returns a relative difference between the candidate and the reference solution using the first
+/// fitness value which differs. Positive value means that the candidate is worse.
+fn get_relative_distance<S: HeuristicSolution>(reference: &S, candidate: &S) -> f64 {
+    reference
+        .get_fitness()
+        .zip(candidate.get_fitness())
+        .find(|(a, b)| compare_floats(*a, *b) != Ordering::Equal)
+        .map_or(0., |(a, b)| (b - a) / a.abs().max(1.))
+}
+
+/// Returns termination progress taking into account quota.
+fn get_progress(statistics: &HeuristicStatistics, environment: &Environment) -> f64 {
+    let quota = environment.quota.as_ref().map_or(0., |quota| quota.estimate());
+
+    statistics.termination_estimate.max(quota).clamp(0., 1.)
+}
+
+fn get_linear_value(initial: f64, last: f64, progress: f64) -> f64 {
+    initial + (last - initial) * progress
+}
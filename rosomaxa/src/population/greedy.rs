@@ -10,6 +10,8 @@ use std::sync::Arc;
 
 /// A population which keeps track of the best known individuals only.
 /// If solutions are equal, prefers to keep first discovered.
+/// Optionally, it keeps a current individual which is used for selection and which is replaced
+/// by new individuals according to acceptance criterion.
 pub struct Greedy<O, S>
 where
     O: HeuristicObjective<Solution = S>,
@@ -18,6 +20,9 @@ where
     objective: Arc<O>,
     selection_size: usize,
     best_known: Option<S>,
+    current: Option<S>,
+    acceptance: Option<Arc<dyn Acceptance<S> + Send + Sync>>,
+    statistics: HeuristicStatistics,
 }
 
 impl<O, S> HeuristicPopulation for Greedy<O, S>
@@ -36,24 +41,33 @@ where
     fn add(&mut self, individual: Self::Individual) -> bool {
         if let Some(best_known) = &self.best_known {
             if self.objective.total_order(best_known, &individual) != Ordering::Greater {
+                self.try_accept(individual);
                 return false;
             }
         }
 
+        if self.acceptance.is_some() {
+            self.current = Some(individual.deep_copy());
+        }
+
         self.best_known = Some(individual);
 
         true
     }
 
-    fn on_generation(&mut self, _: &HeuristicStatistics) {}
+    fn on_generation(&mut self, statistics: &HeuristicStatistics) {
+        if self.acceptance.is_some() {
+            self.statistics = statistics.clone();
+        }
+    }
 
     fn cmp(&self, a: &Self::Individual, b: &Self::Individual) -> Ordering {
         self.objective.total_order(a, b)
     }
 
     fn select<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
-        if let Some(best_known) = self.best_known.as_ref() {
-            Box::new(repeat(best_known).take(self.selection_size))
+        if let Some(selected) = self.current.as_ref().or(self.best_known.as_ref()) {
+            Box::new(repeat(selected).take(self.selection_size))
         } else {
             Box::new(empty())
         }
@@ -103,6 +117,36 @@ where
 {
     /// Creates a new instance of `Greedy`.
     pub fn new(objective: Arc<O>, selection_size: usize, best_known: Option<S>) -> Self {
-        Self { objective, selection_size, best_known }
+        Self {
+            objective,
+            selection_size,
+            best_known,
+            current: None,
+            acceptance: None,
+            statistics: HeuristicStatistics::default(),
+        }
+    }
+
+    /// Creates a new instance of `Greedy` which selects a current individual instead of the best known
+    /// one. The current individual is replaced by a new one if it is accepted by given criterion.
+    pub fn new_with_acceptance(
+        objective: Arc<O>,
+        selection_size: usize,
+        best_known: Option<S>,
+        acceptance: Arc<dyn Acceptance<S> + Send + Sync>,
+    ) -> Self {
+        let current = best_known.as_ref().map(|best_known| best_known.deep_copy());
+
+        Self { current, acceptance: Some(acceptance), ..Self::new(objective, selection_size, best_known) }
+    }
+
+    fn try_accept(&mut self, individual: S) {
+        if let (Some(acceptance), Some(current), Some(best_known)) =
+            (self.acceptance.as_ref(), self.current.as_ref(), self.best_known.as_ref())
+        {
+            if acceptance.is_accepted(&self.statistics, current, best_known, &individual) {
+                self.current = Some(individual);
+            }
+        }
     }
 }
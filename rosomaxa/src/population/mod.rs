@@ -1,5 +1,11 @@
 //! Specifies population types.
 
+mod acceptance;
+pub use self::acceptance::Acceptance;
+pub use self::acceptance::RecordToRecordTravel;
+pub use self::acceptance::SimulatedAnnealing;
+pub use self::acceptance::ThresholdAccepting;
+
 mod elitism;
 pub use self::elitism::DominanceOrder;
 pub use self::elitism::DominanceOrdered;
@@ -54,6 +60,12 @@ pub trait HeuristicPopulation: Display + Send + Sync {
     /// Selects parents from the population based on current selection phase.
     fn select<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Individual> + 'a>;
 
+    /// Selects mates to be recombined with one of the parents. Default implementation returns the
+    /// same individuals as `select` does.
+    fn select_mates<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
+        self.select()
+    }
+
     /// Returns subset of individuals within their rank sorted according their quality.
     fn ranked<'a>(&'a self) -> Box<dyn Iterator<Item = (&Self::Individual, usize)> + 'a>;
 
@@ -171,6 +171,29 @@ where
         }
     }
 
+    fn select_mates<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
+        match &self.phase {
+            RosomaxaPhases::Exploration { network, coordinates, .. } => {
+                // NOTE take the best individual from each node first to exploit network diversity
+                Box::new(
+                    coordinates
+                        .iter()
+                        .filter_map(move |(coordinate, _, _)| {
+                            network.find(coordinate).and_then(|node| {
+                                let node = node.read().unwrap();
+                                // NOTE see above
+                                unsafe { &*(&node.storage.population as *const Elitism<O, S>) as &Elitism<O, S> }
+                                    .select()
+                                    .next()
+                            })
+                        })
+                        .chain(self.elite.select()),
+                )
+            }
+            _ => self.select(),
+        }
+    }
+
     fn ranked<'a>(&'a self) -> Box<dyn Iterator<Item = (&Self::Individual, usize)> + 'a> {
         self.elite.ranked()
     }
@@ -11,6 +11,12 @@ pub type InfoLogger = Arc<dyn Fn(&str) + Send + Sync>;
 pub trait Quota: Send + Sync {
     /// Returns true when computation should be stopped.
     fn is_reached(&self) -> bool;
+
+    /// Returns a relative estimation till quota is reached. Value is in the `[0, 1]` range.
+    /// Default implementation returns zero as there is no way to estimate the progress.
+    fn estimate(&self) -> f64 {
+        0.
+    }
 }
 
 /// Keeps track of environment specific information which influences algorithm behavior.
@@ -82,6 +88,10 @@ impl Quota for TimeQuota {
     fn is_reached(&self) -> bool {
         self.start.elapsed_secs_as_f64() > self.limit_in_secs
     }
+
+    fn estimate(&self) -> f64 {
+        (self.start.elapsed_secs_as_f64() / self.limit_in_secs).min(1.)
+    }
 }
 
 /// Specifies data parallelism settings.
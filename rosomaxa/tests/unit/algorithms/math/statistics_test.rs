@@ -0,0 +1,24 @@
+use super::*;
+
+parameterized_test! {can_get_normal_cdf_and_quantile, (x, p), {
+    can_get_normal_cdf_and_quantile_impl(x, p);
+}}
+
+can_get_normal_cdf_and_quantile! {
+    case01_median: (0., 0.5),
+    case02_positive: (1.2815515655446004, 0.9),
+    case03_negative: (-1.6448536269514722, 0.05),
+    case04_lower_tail: (-2.3263478740408408, 0.01),
+    case05_upper_tail: (3.090232306167813, 0.999),
+}
+
+fn can_get_normal_cdf_and_quantile_impl(x: f64, p: f64) {
+    assert!((get_normal_cdf(x) - p).abs() < 1E-6);
+    assert!((get_normal_quantile(p) - x).abs() < 1E-6);
+}
+
+#[test]
+fn can_handle_quantile_bounds() {
+    assert_eq!(get_normal_quantile(0.), f64::NEG_INFINITY);
+    assert_eq!(get_normal_quantile(1.), f64::INFINITY);
+}
@@ -0,0 +1,91 @@
+use super::*;
+use crate::example::*;
+use crate::helpers::example::create_example_objective;
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+
+fn create_statistics(termination_estimate: f64) -> HeuristicStatistics {
+    HeuristicStatistics { termination_estimate, ..HeuristicStatistics::default() }
+}
+
+fn create_solutions(current: Vec<f64>, best: Vec<f64>, candidate: Vec<f64>) -> [VectorSolution; 3] {
+    let objective = create_example_objective();
+    [
+        VectorSolution::new(current, objective.clone()),
+        VectorSolution::new(best, objective.clone()),
+        VectorSolution::new(candidate, objective),
+    ]
+}
+
+parameterized_test! {can_accept_with_simulated_annealing, (candidate, progress, probability, expected), {
+    can_accept_with_simulated_annealing_impl(candidate, progress, probability, expected);
+}}
+
+can_accept_with_simulated_annealing! {
+    case01_improvement: (vec![1., 1.], 0., 1., true),
+    case02_worse_high_temperature: (vec![-1., -1.], 0., 0.4, true),
+    case03_worse_unlucky: (vec![-1., -1.], 0., 0.5, false),
+    case04_worse_low_temperature: (vec![-1., -1.], 1., 0.4, false),
+}
+
+fn can_accept_with_simulated_annealing_impl(candidate: Vec<f64>, progress: f64, probability: f64, expected: bool) {
+    let environment = create_test_environment_with_random(Arc::new(FakeRandom::new(vec![], vec![probability])));
+    let [current, best, candidate] = create_solutions(vec![2., 2.], vec![0., 0.], candidate);
+
+    let result = SimulatedAnnealing::new(0.01, 0.001, environment).is_accepted(
+        &create_statistics(progress),
+        &current,
+        &best,
+        &candidate,
+    );
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_accept_with_threshold, (candidate, progress, expected), {
+    can_accept_with_threshold_impl(candidate, progress, expected);
+}}
+
+can_accept_with_threshold! {
+    case01_improvement: (vec![1., 1.], 1., true),
+    case02_worse_within_threshold: (vec![-1., -1.], 0., true),
+    case03_worse_out_of_threshold: (vec![-1., -1.], 1., false),
+}
+
+fn can_accept_with_threshold_impl(candidate: Vec<f64>, progress: f64, expected: bool) {
+    let environment = Arc::new(Environment::default());
+    let [current, best, candidate] = create_solutions(vec![2., 2.], vec![0., 0.], candidate);
+
+    let result = ThresholdAccepting::new(0.01, 0., environment).is_accepted(
+        &create_statistics(progress),
+        &current,
+        &best,
+        &candidate,
+    );
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_accept_with_record_to_record_travel, (best, candidate, progress, expected), {
+    can_accept_with_record_to_record_travel_impl(best, candidate, progress, expected);
+}}
+
+can_accept_with_record_to_record_travel! {
+    case01_near_record: (vec![2., 2.], vec![-1., -1.], 0., true),
+    case02_near_record_late: (vec![2., 2.], vec![-1., -1.], 1., false),
+    case03_far_from_record: (vec![1., 1.], vec![-1., -1.], 0., false),
+}
+
+fn can_accept_with_record_to_record_travel_impl(best: Vec<f64>, candidate: Vec<f64>, progress: f64, expected: bool) {
+    let environment = Arc::new(Environment::default());
+    let [current, best, candidate] = create_solutions(vec![2., 2.], best, candidate);
+
+    let result = RecordToRecordTravel::new(0.01, 0., environment).is_accepted(
+        &create_statistics(progress),
+        &current,
+        &best,
+        &candidate,
+    );
+
+    assert_eq!(result, expected);
+}
@@ -2,10 +2,22 @@ use super::*;
 use crate::example::*;
 use crate::helpers::example::create_example_objective;
 
+struct FixedAcceptance(bool);
+
+impl Acceptance<VectorSolution> for FixedAcceptance {
+    fn is_accepted(&self, _: &HeuristicStatistics, _: &VectorSolution, _: &VectorSolution, _: &VectorSolution) -> bool {
+        self.0
+    }
+}
+
 fn get_best_fitness(population: &Greedy<VectorObjective, VectorSolution>) -> f64 {
     population.objective.fitness(population.ranked().next().unwrap().0)
 }
 
+fn get_selected_fitness(population: &Greedy<VectorObjective, VectorSolution>) -> f64 {
+    population.objective.fitness(population.select().next().unwrap())
+}
+
 #[test]
 fn can_keep_best_solution() {
     let objective = create_example_objective();
@@ -23,3 +35,36 @@ fn can_keep_best_solution() {
     assert_eq!(population.size(), 1);
     assert_eq!(get_best_fitness(&population), 401.);
 }
+
+#[test]
+fn can_select_current_solution_with_acceptance() {
+    let objective = create_example_objective();
+    let mut population =
+        Greedy::<_, _>::new_with_acceptance(objective.clone(), 1, None, Arc::new(FixedAcceptance(true)));
+
+    assert!(population.add(VectorSolution::new(vec![2., 2.], objective.clone())));
+    assert_eq!(get_selected_fitness(&population), 401.);
+
+    assert!(!population.add(VectorSolution::new(vec![-1., -1.], objective.clone())));
+    assert_eq!(get_best_fitness(&population), 401.);
+    assert_eq!(get_selected_fitness(&population), 404.);
+
+    assert!(population.add(VectorSolution::new(vec![1., 1.], objective)));
+    assert_eq!(get_best_fitness(&population), 0.);
+    assert_eq!(get_selected_fitness(&population), 0.);
+}
+
+#[test]
+fn can_discard_rejected_solution_with_acceptance() {
+    let objective = create_example_objective();
+    let mut population =
+        Greedy::<_, _>::new_with_acceptance(objective.clone(), 1, None, Arc::new(FixedAcceptance(false)));
+    assert!(population.add(VectorSolution::new(vec![2., 2.], objective.clone())));
+
+    assert!(!population.add(VectorSolution::new(vec![-1., -1.], objective)));
+
+    assert_eq!(population.size(), 1);
+    assert_eq!(get_best_fitness(&population), 401.);
+    assert_eq!(get_selected_fitness(&population), 401.);
+    assert!(population.all().all(|individual| population.objective.fitness(individual) == 401.));
+}
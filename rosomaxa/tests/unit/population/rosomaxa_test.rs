@@ -69,6 +69,28 @@ fn can_select_individuals_in_different_phases() {
     assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploitation);
 }
 
+#[test]
+fn can_select_mates_from_different_nodes() {
+    let (objective, mut rosomaxa) = create_rosomaxa(10);
+    (0..10).for_each(|idx| {
+        let value = idx as f64 - 5.;
+        rosomaxa.add_all(vec![VectorSolution::new(vec![value, value], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.75, idx))
+    });
+    let coordinates = match &rosomaxa.phase {
+        RosomaxaPhases::Exploration { coordinates, .. } => coordinates.len(),
+        _ => unreachable!(),
+    };
+
+    let mates = rosomaxa.select_mates().count();
+
+    assert!(coordinates > 1);
+    assert_eq!(mates, coordinates + rosomaxa.elite.select().count());
+
+    rosomaxa.update_phase(&create_statistics(0.95, 10));
+    assert_eq!(rosomaxa.select_mates().count(), rosomaxa.select().count());
+}
+
 #[test]
 fn can_optimize_network() {
     let termination_estimate = 0.75;
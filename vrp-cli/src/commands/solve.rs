@@ -537,6 +537,10 @@ pub fn create_interruption_quota(max_time: Option<usize>) -> Arc<dyn Quota + Sen
             self.inner.as_ref().map_or(false, |inner| inner.is_reached())
                 || self.should_interrupt.load(Ordering::Relaxed)
         }
+
+        fn estimate(&self) -> f64 {
+            self.inner.as_ref().map_or(0., |inner| inner.estimate())
+        }
     }
 
     let inner = max_time.map::<Arc<dyn Quota + Send + Sync>, _>(|time| Arc::new(TimeQuota::new(time as f64)));
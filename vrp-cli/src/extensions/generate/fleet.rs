@@ -33,11 +33,15 @@ pub(crate) fn generate_fleet(problem_proto: &Problem, vehicle_types_size: usize)
                 capacity: get_random_item(capacities.as_slice(), &rnd).expect("cannot find any capacity").clone(),
                 skills: get_random_item(skills.as_slice(), &rnd).expect("cannot find any skills").clone(),
                 limits: get_random_item(limits.as_slice(), &rnd).expect("cannot find any limits").clone(),
+                compartments: None,
+                battery: None,
+                emissions: None,
+                backhauls: None,
             }
         })
         .collect();
 
-    Fleet { vehicles, profiles }
+    Fleet { vehicles, drivers: None, profiles }
 }
 
 fn get_from_vehicle<F, T>(problem_proto: &Problem, func: F) -> Vec<T>
@@ -37,6 +37,8 @@ pub(crate) fn generate_plan(
                             duration: get_random_item(durations.as_slice(), &rnd).cloned().unwrap(),
                             times: get_random_item(time_windows.as_slice(), &rnd).cloned(),
                             tag: place.tag.clone(),
+                            lateness: None,
+                            durations: None,
                         })
                         .collect(),
                     demand: if keep_original_demand {
@@ -68,11 +70,26 @@ pub(crate) fn generate_plan(
                 value: job_proto.value,
                 group: job_proto.group.clone(),
                 compatibility: job_proto.compatibility.clone(),
+                product: job_proto.product.clone(),
+                max_ride_time: job_proto.max_ride_time,
+                days: None,
+                visits: None,
+                hub: job_proto.hub.clone(),
+                sync: job_proto.sync.clone(),
             }
         })
         .collect();
 
-    Ok(Plan { jobs, relations: None, areas: None, clustering: None })
+    Ok(Plan {
+        jobs,
+        relations: None,
+        areas: None,
+        clustering: None,
+        products: problem_proto.plan.products.clone(),
+        hubs: problem_proto.plan.hubs.clone(),
+        depots: problem_proto.plan.depots.clone(),
+        tours: None,
+    })
 }
 
 fn get_location_fn(
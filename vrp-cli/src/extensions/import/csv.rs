@@ -71,6 +71,8 @@ mod actual {
                 duration: job.duration as f64 * 60.,
                 times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
                 tag: None,
+                lateness: None,
+                durations: None,
             }],
             demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
             order: None,
@@ -102,6 +104,12 @@ mod actual {
                 value: None,
                 group: None,
                 compatibility: None,
+                product: None,
+                max_ride_time: None,
+                days: None,
+                visits: None,
+                hub: None,
+                sync: None,
             })
             .collect();
 
@@ -118,21 +126,26 @@ mod actual {
                     type_id: vehicle.id.clone(),
                     vehicle_ids: (1..=vehicle.amount).map(|seq| format!("{}_{}", vehicle.profile, seq)).collect(),
                     profile: VehicleProfile { matrix: vehicle.profile, scale: None },
-                    costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
+                    costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005, full_load_distance: None },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: vehicle.tw_start,
                             latest: None,
-                            location: depot_location.clone(),
+                            location: Some(depot_location.clone()),
                         },
-                        end: Some(ShiftEnd { earliest: None, latest: vehicle.tw_end, location: depot_location }),
+                        end: Some(ShiftEnd { earliest: None, latest: vehicle.tw_end, location: Some(depot_location) }),
                         dispatch: None,
                         breaks: None,
                         reloads: None,
+                        charging_stations: None,
                     }],
                     capacity: vec![vehicle.capacity],
                     skills: None,
                     limits: None,
+                    compartments: None,
+                    battery: None,
+                    emissions: None,
+                    backhauls: None,
                 }
             })
             .collect();
@@ -159,10 +172,23 @@ mod actual {
         let matrix_profile_names = vehicles.iter().map(|v| v.profile.matrix.clone()).collect::<HashSet<_>>();
 
         Ok(Problem {
-            plan: Plan { jobs, relations: None, areas: None, clustering: None },
+            plan: Plan {
+                jobs,
+                relations: None,
+                areas: None,
+                clustering: None,
+                products: None,
+                hubs: None,
+                depots: None,
+                tours: None,
+            },
             fleet: Fleet {
                 vehicles,
-                profiles: matrix_profile_names.into_iter().map(|name| MatrixProfile { name, speed: None }).collect(),
+                drivers: None,
+                profiles: matrix_profile_names
+                    .into_iter()
+                    .map(|name| MatrixProfile { name, speed: None, service_level: None })
+                    .collect(),
             },
             objectives: None,
         })
@@ -17,6 +17,7 @@ use vrp_core::models::problem::ProblemObjective;
 use vrp_core::prelude::*;
 use vrp_core::rosomaxa::evolution::{InitialOperator, TelemetryMode};
 use vrp_core::rosomaxa::get_default_selection_size;
+use vrp_core::rosomaxa::population::{Acceptance, RecordToRecordTravel, SimulatedAnnealing, ThresholdAccepting};
 use vrp_core::rosomaxa::prelude::*;
 use vrp_core::rosomaxa::utils::*;
 use vrp_core::solver::search::*;
@@ -56,6 +57,8 @@ pub enum PopulationType {
     Greedy {
         /// Selection size. Default is number of cpus.
         selection_size: Option<usize>,
+        /// Acceptance criterion which allows to select a current individual instead of the best known.
+        acceptance: Option<AcceptanceType>,
     },
 
     /// A basic population which sorts individuals based on their
@@ -97,6 +100,8 @@ pub enum PopulationType {
 /// An initial solution configuration.
 #[derive(Clone, Deserialize, Debug)]
 pub struct InitialConfig {
+    /// A main method used to build initial solution. Please note that `split` method assumes
+    /// homogeneous fleet: routes are estimated using the first available vehicle.
     pub method: RecreateMethod,
     pub alternatives: InitialAlternativesConfig,
 }
@@ -166,6 +171,15 @@ pub enum SearchOperatorType {
         operators: Vec<LocalOperatorType>,
     },
 
+    /// A crossover metaheuristic which recombines selected individual with a mate from population.
+    #[serde(rename(deserialize = "crossover"))]
+    Crossover {
+        /// Probability.
+        probability: OperatorProbabilityType,
+        /// Recreate methods used to repair an offspring.
+        recreates: Vec<RecreateMethod>,
+    },
+
     /// A ruin and recreate metaheuristic settings.
     #[serde(rename(deserialize = "ruin-recreate"))]
     RuinRecreate {
@@ -178,6 +192,26 @@ pub enum SearchOperatorType {
     },
 }
 
+/// An acceptance criterion configuration.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum AcceptanceType {
+    /// Simulated annealing with exponentially decreasing temperature.
+    #[serde(rename(deserialize = "simulated-annealing"))]
+    #[serde(rename_all = "camelCase")]
+    SimulatedAnnealing { initial_temperature: f64, final_temperature: f64 },
+
+    /// Threshold accepting with linearly decreasing threshold.
+    #[serde(rename(deserialize = "threshold-accepting"))]
+    #[serde(rename_all = "camelCase")]
+    ThresholdAccepting { initial_threshold: f64, final_threshold: f64 },
+
+    /// Record-to-record travel with linearly decreasing deviation.
+    #[serde(rename(deserialize = "record-to-record"))]
+    #[serde(rename_all = "camelCase")]
+    RecordToRecord { initial_deviation: f64, final_deviation: f64 },
+}
+
 /// A operator probability type
 #[derive(Clone, Deserialize, Debug)]
 #[serde(untagged)]
@@ -297,6 +331,9 @@ pub enum RecreateMethod {
     /// Insertion with slice method.
     #[serde(rename(deserialize = "slice"))]
     Slice { weight: usize },
+    /// Giant tour split method. Assumes homogeneous fleet.
+    #[serde(rename(deserialize = "split"))]
+    Split { weight: usize },
     /// Farthest insertion method.
     #[serde(rename(deserialize = "farthest"))]
     Farthest { weight: usize },
@@ -326,6 +363,19 @@ pub enum LocalOperatorType {
 
     #[serde(rename(deserialize = "sequence"))]
     Sequence { weight: usize },
+
+    #[serde(rename(deserialize = "two-opt"))]
+    TwoOpt { weight: usize },
+
+    #[serde(rename(deserialize = "or-opt"))]
+    OrOpt { weight: usize },
+
+    #[serde(rename(deserialize = "three-opt"))]
+    ThreeOpt { weight: usize },
+
+    #[serde(rename(deserialize = "cross"))]
+    #[serde(rename_all = "camelCase")]
+    Cross { weight: usize, max_segment_size: Option<usize> },
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -469,11 +519,18 @@ fn configure_from_evolution(
         if let Some(variation) = &config.population {
             let default_selection_size = get_default_selection_size(environment.as_ref());
             let population = match &variation {
-                PopulationType::Greedy { selection_size } => Box::new(GreedyPopulation::new(
-                    problem.objective.clone(),
-                    selection_size.unwrap_or(default_selection_size),
-                    None,
-                )),
+                PopulationType::Greedy { selection_size, acceptance } => {
+                    let selection_size = selection_size.unwrap_or(default_selection_size);
+                    Box::new(match acceptance {
+                        Some(acceptance) => GreedyPopulation::new_with_acceptance(
+                            problem.objective.clone(),
+                            selection_size,
+                            None,
+                            create_acceptance(acceptance, environment.clone()),
+                        ),
+                        None => GreedyPopulation::new(problem.objective.clone(), selection_size, None),
+                    })
+                }
                 PopulationType::Elitism { max_size, selection_size } => Box::new(ElitismPopulation::new(
                     problem.objective.clone(),
                     environment.random.clone(),
@@ -588,6 +645,7 @@ fn create_recreate_method(
             (Arc::new(RecreateWithSkipBest::new(*start, *end, random)), *weight)
         }
         RecreateMethod::Slice { weight } => (Arc::new(RecreateWithSlice::new(random)), *weight),
+        RecreateMethod::Split { weight } => (Arc::new(RecreateWithSplit::new_with_defaults(random)), *weight),
         RecreateMethod::Blinks { weight } => {
             (Arc::new(RecreateWithBlinks::<SingleDimLoad>::new_with_defaults(random.clone())), *weight)
         }
@@ -603,6 +661,23 @@ fn create_recreate_method(
     }
 }
 
+fn create_acceptance(
+    acceptance: &AcceptanceType,
+    environment: Arc<Environment>,
+) -> Arc<dyn Acceptance<InsertionContext> + Send + Sync> {
+    match acceptance {
+        AcceptanceType::SimulatedAnnealing { initial_temperature, final_temperature } => {
+            Arc::new(SimulatedAnnealing::new(*initial_temperature, *final_temperature, environment))
+        }
+        AcceptanceType::ThresholdAccepting { initial_threshold, final_threshold } => {
+            Arc::new(ThresholdAccepting::new(*initial_threshold, *final_threshold, environment))
+        }
+        AcceptanceType::RecordToRecord { initial_deviation, final_deviation } => {
+            Arc::new(RecordToRecordTravel::new(*initial_deviation, *final_deviation, environment))
+        }
+    }
+}
+
 fn create_operator(
     problem: Arc<Problem>,
     environment: Arc<Environment>,
@@ -625,6 +700,15 @@ fn create_operator(
             let operator = create_local_search(times, inners, environment.random.clone());
             (Arc::new(LocalSearch::new(operator)), create_operator_probability(probability, environment.random.clone()))
         }
+        SearchOperatorType::Crossover { probability, recreates } => {
+            let recreate = Arc::new(WeightedRecreate::new(
+                recreates.iter().map(|r| create_recreate_method(r, environment.clone())).collect(),
+            ));
+            (
+                Arc::new(CrossoverSearch::new(Arc::new(WeightedCrossover::default()), recreate)),
+                create_operator_probability(probability, environment.random.clone()),
+            )
+        }
         SearchOperatorType::Decomposition { routes, repeat, probability } => {
             if *repeat < 1 {
                 return Err(format!("repeat must be greater than 1. Specified: {}", repeat));
@@ -722,6 +806,15 @@ fn create_local_search(
                 (Arc::new(ExchangeIntraRouteRandom::new(noise.probability, noise.min, noise.max)), *weight)
             }
             LocalOperatorType::Sequence { weight } => (Arc::new(ExchangeSequence::default()), *weight),
+            LocalOperatorType::TwoOpt { weight } => (Arc::new(ExchangeTwoOpt::default()), *weight),
+            LocalOperatorType::OrOpt { weight } => (Arc::new(ExchangeOrOpt::default()), *weight),
+            LocalOperatorType::ThreeOpt { weight } => (Arc::new(ExchangeThreeOpt::default()), *weight),
+            LocalOperatorType::Cross { weight, max_segment_size } => {
+                let operator = max_segment_size
+                    .map(|max_segment_size| ExchangeCross::new(max_segment_size, 10, 4))
+                    .unwrap_or_default();
+                (Arc::new(operator), *weight)
+            }
         })
         .collect::<Vec<_>>();
 
@@ -12,6 +12,12 @@ pub fn create_empty_job() -> Job {
         value: None,
         group: None,
         compatibility: None,
+        product: None,
+        max_ride_time: None,
+        days: None,
+        visits: None,
+        hub: None,
+        sync: None,
     }
 }
 
@@ -20,11 +26,27 @@ pub fn create_empty_job_task() -> JobTask {
 }
 
 pub fn create_empty_job_place() -> JobPlace {
-    JobPlace { location: Location::Coordinate { lat: 0.0, lng: 0.0 }, duration: 0.0, times: None, tag: None }
+    JobPlace {
+        location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+        duration: 0.0,
+        times: None,
+        tag: None,
+        lateness: None,
+        durations: None,
+    }
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, areas: None, clustering: None }
+    Plan {
+        jobs: vec![],
+        relations: None,
+        areas: None,
+        clustering: None,
+        products: None,
+        hubs: None,
+        depots: None,
+        tours: None,
+    }
 }
 
 pub fn create_test_vehicle_type() -> VehicleType {
@@ -32,26 +54,31 @@ pub fn create_test_vehicle_type() -> VehicleType {
         type_id: "vehicle".to_string(),
         vehicle_ids: vec!["vehicle_1".to_string()],
         profile: VehicleProfile { matrix: "car".to_string(), scale: None },
-        costs: VehicleCosts { fixed: None, distance: 1., time: 0. },
+        costs: VehicleCosts { fixed: None, distance: 1., time: 0., full_load_distance: None },
         shifts: vec![VehicleShift {
             start: ShiftStart {
                 earliest: "2020-05-01T09:00:00.00Z".to_string(),
                 latest: None,
-                location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+                location: Some(Location::Coordinate { lat: 0.0, lng: 0.0 }),
             },
             end: None,
             dispatch: None,
             breaks: None,
             reloads: None,
+            charging_stations: None,
         }],
         capacity: vec![10],
         skills: None,
         limits: None,
+        compartments: None,
+        battery: None,
+        emissions: None,
+        backhauls: None,
     }
 }
 
 pub fn create_test_vehicle_profile() -> MatrixProfile {
-    MatrixProfile { name: "car".to_string(), speed: None }
+    MatrixProfile { name: "car".to_string(), speed: None, service_level: None }
 }
 
 pub fn create_test_time_window() -> Vec<String> {
@@ -26,7 +26,7 @@ fn can_read_full_config() {
         RecreateMethod::Cheapest { weight: 1 } => {}
         _ => unreachable!(),
     }
-    assert_eq!(initial.alternatives.methods.len(), 7);
+    assert_eq!(initial.alternatives.methods.len(), 8);
     assert_eq!(initial.alternatives.max_size, 4);
     assert_eq!(initial.alternatives.quota, 0.05);
 
@@ -59,7 +59,7 @@ fn can_read_full_config() {
     match hyper_config {
         HyperType::StaticSelective { operators } => {
             let operators = operators.expect("cannot get operators");
-            assert_eq!(operators.len(), 4);
+            assert_eq!(operators.len(), 5);
             match operators.first().unwrap() {
                 SearchOperatorType::Decomposition { routes, repeat, probability } => {
                     assert_eq!(*repeat, 4);
@@ -95,11 +95,22 @@ fn can_read_full_config() {
                 _ => unreachable!(),
             }
 
+            match operators.get(3).unwrap() {
+                SearchOperatorType::Crossover { probability, recreates } => {
+                    match probability {
+                        OperatorProbabilityType::Context { phases, .. } => assert_eq!(phases.len(), 1),
+                        _ => unreachable!(),
+                    }
+                    assert_eq!(recreates.len(), 2);
+                }
+                _ => unreachable!(),
+            }
+
             match operators.last().unwrap() {
                 SearchOperatorType::LocalSearch { probability, times, operators: inners } => {
                     assert_eq!(as_scalar_probability(probability), 0.01);
                     assert_eq!(*times, MinMaxConfig { min: 1, max: 2 });
-                    assert_eq!(inners.len(), 4);
+                    assert_eq!(inners.len(), 8);
                 }
                 _ => unreachable!(),
             }
@@ -123,6 +134,39 @@ fn can_read_full_config() {
     assert_eq!(logging.prefix, Some("[config.full]".to_string()));
 }
 
+#[test]
+fn can_read_alns_config() {
+    let file = File::open("../examples/data/config/config.alns.json").expect("cannot read config from file");
+    let config = read_config(BufReader::new(file)).unwrap();
+
+    let evolution_config = config.evolution.as_ref().expect("no evolution config");
+    match evolution_config.population.as_ref().expect("no population config") {
+        PopulationType::Greedy { selection_size, acceptance } => {
+            assert_eq!(*selection_size, Some(1));
+            match acceptance.as_ref().expect("no acceptance config") {
+                AcceptanceType::SimulatedAnnealing { initial_temperature, final_temperature } => {
+                    assert_eq!(*initial_temperature, 0.05);
+                    assert_eq!(*final_temperature, 0.001);
+                }
+                _ => unreachable!(),
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    let config = Config {
+        termination: Some(TerminationConfig { max_time: None, max_generations: Some(10), variation: None }),
+        ..config
+    };
+    let (solution, _, _) = create_builder_from_config(create_example_problem(), Vec::default(), &config)
+        .and_then(|config_builder| config_builder.build())
+        .map(|evolution_config| Solver::new(create_example_problem(), evolution_config))
+        .and_then(|solver| solver.solve())
+        .unwrap();
+
+    assert!(solution.unassigned.is_empty());
+}
+
 #[test]
 fn can_create_default_config() {
     let config = Config::default();
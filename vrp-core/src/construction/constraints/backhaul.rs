@@ -0,0 +1,146 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/backhaul_test.rs"]
+mod backhaul_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::*;
+use crate::models::common::{Demand, DemandDimension, LoadOps};
+use crate::models::problem::{Actor, Job};
+use crate::models::solution::Activity;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns true if actor should serve backhaul jobs only after linehaul ones.
+pub type BackhaulResolver = Arc<dyn Fn(&Actor) -> bool + Sync + Send>;
+
+/// Specifies a job type in linehaul/backhaul problem.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HaulType {
+    /// A job which delivers goods loaded at the start of the tour.
+    Linehaul,
+    /// A job which picks up goods brought back at the end of the tour.
+    Backhaul,
+}
+
+type HaulFunc = fn(&Activity) -> Option<HaulType>;
+
+/// A module which implements vehicle routing problem with backhauls (VRPB): for actors specified by
+/// resolver, backhaul jobs (with static pickup demand only) can be served only after all linehaul jobs
+/// (with static delivery demand only) of the tour. Other jobs, e.g. services or pickup and delivery
+/// jobs, can be served at any position in the tour.
+pub struct BackhaulModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+    haul_func: HaulFunc,
+}
+
+impl BackhaulModule {
+    /// Creates a new instance of `BackhaulModule`.
+    pub fn new<T: LoadOps>(resolver: BackhaulResolver, code: i32) -> Self {
+        let haul_func: HaulFunc = get_haul_type::<T>;
+
+        Self {
+            state_keys: vec![HAUL_INDICES_KEY],
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(BackhaulHardActivityConstraint {
+                code,
+                resolver,
+                haul_func,
+            }))],
+            haul_func,
+        }
+    }
+
+    fn update_haul_indices(&self, route_ctx: &mut RouteContext) {
+        let indices = route_ctx.route.tour.all_activities().enumerate().fold(
+            (None, None),
+            |(last_linehaul, first_backhaul): HaulIndices, (idx, activity)| match (self.haul_func)(activity) {
+                Some(HaulType::Linehaul) => (Some(idx), first_backhaul),
+                Some(HaulType::Backhaul) => (last_linehaul, first_backhaul.or(Some(idx))),
+                None => (last_linehaul, first_backhaul),
+            },
+        );
+
+        route_ctx.state_mut().put_route_state(HAUL_INDICES_KEY, indices);
+    }
+}
+
+impl ConstraintModule for BackhaulModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        self.update_haul_indices(ctx);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.update_haul_indices(route_ctx);
+        })
+    }
+
+    fn merge(&self, source: Job, _candidate: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Keeps index of the last linehaul and the first backhaul activities in the tour.
+type HaulIndices = (Option<usize>, Option<usize>);
+
+struct BackhaulHardActivityConstraint {
+    code: i32,
+    resolver: BackhaulResolver,
+    haul_func: HaulFunc,
+}
+
+impl HardActivityConstraint for BackhaulHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        if !(self.resolver)(route_ctx.route.actor.as_ref()) {
+            return None;
+        }
+
+        let (last_linehaul, first_backhaul) =
+            route_ctx.state.get_route_state::<HaulIndices>(HAUL_INDICES_KEY).cloned().unwrap_or_default();
+
+        // NOTE target is inserted after activity at given index
+        match (self.haul_func)(activity_ctx.target) {
+            // NOTE moving linehaul further in the tour cannot help
+            Some(HaulType::Linehaul) if first_backhaul.map_or(false, |idx| idx <= activity_ctx.index) => {
+                Some(ActivityConstraintViolation { code: self.code, stopped: true })
+            }
+            Some(HaulType::Backhaul) if last_linehaul.map_or(false, |idx| idx > activity_ctx.index) => {
+                Some(ActivityConstraintViolation { code: self.code, stopped: false })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Returns haul type of the activity using its job's static demand.
+fn get_haul_type<T: LoadOps>(activity: &Activity) -> Option<HaulType> {
+    let demand: &Demand<T> = activity.job.as_ref()?.dimens.get_demand()?;
+
+    // NOTE dynamic demand belongs to multi jobs which are not considered
+    if demand.pickup.1.is_not_empty() || demand.delivery.1.is_not_empty() {
+        return None;
+    }
+
+    match (demand.pickup.0.is_not_empty(), demand.delivery.0.is_not_empty()) {
+        (false, true) => Some(HaulType::Linehaul),
+        (true, false) => Some(HaulType::Backhaul),
+        _ => None,
+    }
+}
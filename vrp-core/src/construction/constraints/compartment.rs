@@ -0,0 +1,483 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/compartment_test.rs"]
+mod compartment_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::*;
+use crate::models::common::*;
+use crate::models::problem::{Job, Single};
+use crate::models::solution::{Activity, Route};
+use hashbrown::HashMap;
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A module which assigns demand of the jobs to vehicle compartments respecting compartment capacity
+/// and product compatibility rules. It is supposed to be used together with `CapacityConstraintModule`
+/// which keeps track of total vehicle capacity.
+pub struct CompartmentConstraintModule<T: LoadOps> {
+    code: i32,
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+    multi_trip: Option<Arc<dyn MultiTrip<T> + Send + Sync>>,
+}
+
+impl<T: LoadOps> CompartmentConstraintModule<T> {
+    /// Creates a new instance of `CompartmentConstraintModule` without multi trip (reload) functionality.
+    pub fn new(code: i32) -> Self {
+        Self::new_with_optional_multi_trip(code, None)
+    }
+
+    /// Creates a new instance of `CompartmentConstraintModule` with multi trip (reload) functionality.
+    pub fn new_with_multi_trip(code: i32, multi_trip: Arc<dyn MultiTrip<T> + Send + Sync>) -> Self {
+        Self::new_with_optional_multi_trip(code, Some(multi_trip))
+    }
+
+    fn new_with_optional_multi_trip(code: i32, multi_trip: Option<Arc<dyn MultiTrip<T> + Send + Sync>>) -> Self {
+        Self {
+            code,
+            state_keys: vec![COMPARTMENT_LOADS_KEY],
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(CompartmentHardRouteConstraint::<T> {
+                    code,
+                    phantom: Default::default(),
+                })),
+                ConstraintVariant::HardActivity(Arc::new(CompartmentHardActivityConstraint::<T> {
+                    code,
+                    multi_trip: multi_trip.clone(),
+                })),
+            ],
+            multi_trip,
+        }
+    }
+}
+
+impl<T: LoadOps> ConstraintModule for CompartmentConstraintModule<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let compartments: Option<&Vec<Compartment<T>>> = ctx.route.actor.vehicle.dimens.get_compartments();
+
+        if let Some(loads) = compartments
+            .and_then(|compartments| create_compartment_loads(&ctx.route, compartments, self.multi_trip.as_ref()))
+        {
+            ctx.state_mut().put_route_state(COMPARTMENT_LOADS_KEY, loads);
+        }
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        if self.multi_trip.as_ref().map_or(false, |multi_trip| multi_trip.is_reload_job(&candidate)) {
+            return Err(self.code);
+        }
+
+        match (&source, &candidate) {
+            (Job::Single(s_source), Job::Single(s_candidate)) => {
+                let get_product_id = |single: &Single| single.dimens.get_product().map(|product| product.id.clone());
+
+                if get_product_id(s_source) == get_product_id(s_candidate) {
+                    Ok(source)
+                } else {
+                    Err(self.code)
+                }
+            }
+            _ => Err(self.code),
+        }
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Assigns demand of given activities to the compartments using first fit strategy: demand which is
+/// loaded earlier is assigned first. Returns compartment index for each activity or `None` when demand
+/// cannot be assigned to compartments.
+pub fn assign_compartments<T: LoadOps>(
+    activities: &[&Activity],
+    compartments: &[Compartment<T>],
+    is_reload: &dyn Fn(&Activity) -> bool,
+) -> Option<Vec<Option<usize>>> {
+    assign_load_items(activities, compartments, is_reload).map(|(assignment, _)| assignment)
+}
+
+/// Keeps compartment loads of the route's demand assigned using first fit strategy.
+struct CompartmentLoads<T: LoadOps> {
+    /// Reload intervals of the route.
+    intervals: Vec<(usize, usize)>,
+    /// Load of each compartment at each activity.
+    loads: Vec<Vec<T>>,
+    /// Load of each compartment on departure from each activity.
+    departures: Vec<Vec<T>>,
+    /// Intervals when each compartment is occupied by given product.
+    occupied: Vec<Vec<OccupiedInterval>>,
+    /// Assigned compartment and load changes of dynamic demand keyed by job.
+    dynamic: HashMap<usize, (usize, Vec<(usize, T)>)>,
+}
+
+/// Represents an interval when compartment is occupied by given product.
+type OccupiedInterval = (usize, usize, Option<Arc<Product>>);
+
+/// Represents a dynamic demand as a job key, product and a list of load changes at given activity indices.
+type DynamicItem<T> = (usize, Option<Arc<Product>>, Vec<(usize, T)>);
+
+/// Represents a demand which is loaded into one compartment.
+struct LoadItem<T: LoadOps> {
+    product: Option<Arc<Product>>,
+    /// Index of the first activity when demand is on board.
+    start: usize,
+    /// Load on board starting from the first activity.
+    loads: Vec<T>,
+    /// Load on board on departure starting from the first activity.
+    departures: Vec<T>,
+    /// Indices of activities which serve the demand.
+    activities: Vec<usize>,
+    /// A job key and load changes of dynamic demand.
+    dynamic: Option<(usize, Vec<(usize, T)>)>,
+}
+
+fn create_compartment_loads<T: LoadOps>(
+    route: &Route,
+    compartments: &[Compartment<T>],
+    multi_trip: Option<&Arc<dyn MultiTrip<T> + Send + Sync>>,
+) -> Option<CompartmentLoads<T>> {
+    let is_reload =
+        |activity: &Activity| multi_trip.map_or(false, |multi_trip| multi_trip.get_reload(activity).is_some());
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+
+    assign_load_items(activities.as_slice(), compartments, &is_reload).map(|(_, loads)| loads)
+}
+
+fn assign_load_items<T: LoadOps>(
+    activities: &[&Activity],
+    compartments: &[Compartment<T>],
+    is_reload: &dyn Fn(&Activity) -> bool,
+) -> Option<(Vec<Option<usize>>, CompartmentLoads<T>)> {
+    let intervals = get_intervals(activities, is_reload);
+    let mut loads = vec![vec![T::default(); activities.len()]; compartments.len()];
+    let mut departures = vec![vec![T::default(); activities.len()]; compartments.len()];
+    let mut occupied: Vec<Vec<OccupiedInterval>> = vec![vec![]; compartments.len()];
+    let mut dynamic = HashMap::default();
+    let mut assignment = vec![None; activities.len()];
+
+    for item in get_load_items::<T>(activities, intervals.as_slice()) {
+        let end = item.start + item.loads.len() - 1;
+        let compartment_idx = compartments.iter().enumerate().position(|(idx, compartment)| {
+            compartment.can_load(item.product.as_deref())
+                && item
+                    .loads
+                    .iter()
+                    .zip(item.start..)
+                    .all(|(load, time)| compartment.capacity.can_fit(&(loads[idx][time] + *load)))
+                && occupied[idx].iter().all(|(other_start, other_end, other)| {
+                    *other_end < item.start
+                        || *other_start > end
+                        || is_compatible(item.product.as_ref(), other.as_ref())
+                })
+        })?;
+
+        item.loads.iter().zip(item.departures.iter()).zip(item.start..).for_each(|((load, departure), time)| {
+            loads[compartment_idx][time] = loads[compartment_idx][time] + *load;
+            departures[compartment_idx][time] = departures[compartment_idx][time] + *departure;
+        });
+        occupied[compartment_idx].push((item.start, end, item.product));
+        item.activities.iter().for_each(|idx| assignment[*idx] = Some(compartment_idx));
+
+        if let Some((key, changes)) = item.dynamic {
+            dynamic.insert(key, (compartment_idx, changes));
+        }
+    }
+
+    Some((assignment, CompartmentLoads { intervals, loads, departures, occupied, dynamic }))
+}
+
+fn get_intervals(activities: &[&Activity], is_reload: &dyn Fn(&Activity) -> bool) -> Vec<(usize, usize)> {
+    let last_idx = activities.len().max(1) - 1;
+
+    activities.iter().enumerate().fold(Vec::<(usize, usize)>::default(), |mut acc, (idx, activity)| {
+        if is_reload(activity) || idx == last_idx {
+            let start_idx = acc.last().map_or(0_usize, |item| item.1 + 1);
+            let end_idx = if idx == last_idx { last_idx } else { idx - 1 };
+
+            acc.push((start_idx, end_idx));
+        }
+
+        acc
+    })
+}
+
+fn get_interval(intervals: &[(usize, usize)], idx: usize) -> (usize, usize) {
+    intervals.iter().find(|(start, end)| *start <= idx && idx <= *end).cloned().unwrap_or((idx, idx))
+}
+
+fn get_load_items<T: LoadOps>(activities: &[&Activity], intervals: &[(usize, usize)]) -> Vec<LoadItem<T>> {
+    let mut items = vec![];
+    let mut dynamic_items: Vec<DynamicItem<T>> = vec![];
+    let mut dynamic_index = HashMap::<usize, usize>::default();
+
+    activities.iter().enumerate().for_each(|(idx, activity)| {
+        let single = if let Some(single) = activity.job.as_ref() { single } else { return };
+        let demand: &Demand<T> = if let Some(demand) = single.dimens.get_demand() { demand } else { return };
+        let product = single.dimens.get_product().cloned();
+        let (start, end) = get_interval(intervals, idx);
+
+        // NOTE static delivery is loaded at the beginning of the interval, static pickup is unloaded at its end
+        if has_load(&demand.delivery.0) {
+            let loads = vec![demand.delivery.0; idx + 1 - start];
+            let mut departures = loads.clone();
+            *departures.last_mut().unwrap() = T::default();
+
+            items.push(LoadItem {
+                product: product.clone(),
+                start,
+                loads,
+                departures,
+                activities: vec![idx],
+                dynamic: None,
+            })
+        }
+
+        if has_load(&demand.pickup.0) {
+            let loads = vec![demand.pickup.0; end.max(idx) + 1 - idx];
+            let departures = loads.clone();
+
+            items.push(LoadItem {
+                product: product.clone(),
+                start: idx,
+                loads,
+                departures,
+                activities: vec![idx],
+                dynamic: None,
+            })
+        }
+
+        if has_load(&demand.pickup.1) || has_load(&demand.delivery.1) {
+            let key = get_dynamic_key(activity, single);
+            let item_idx = *dynamic_index.entry(key).or_insert_with(|| {
+                dynamic_items.push((key, product, vec![]));
+                dynamic_items.len() - 1
+            });
+
+            dynamic_items[item_idx].2.push((idx, demand.pickup.1 - demand.delivery.1));
+        }
+    });
+
+    items.extend(dynamic_items.into_iter().map(|(key, product, changes)| {
+        let (start, loads, departures) = get_dynamic_loads(changes.as_slice());
+
+        LoadItem {
+            product,
+            start,
+            loads,
+            departures,
+            activities: changes.iter().map(|(idx, _)| *idx).collect(),
+            dynamic: Some((key, changes)),
+        }
+    }));
+
+    items.sort_by_key(|item| item.start);
+
+    items
+}
+
+/// Returns a key which identifies all activities of the job with dynamic demand.
+fn get_dynamic_key(activity: &Activity, single: &Arc<Single>) -> usize {
+    match activity.retrieve_job() {
+        Some(Job::Multi(multi)) => Arc::as_ptr(&multi) as *const () as usize,
+        _ => Arc::as_ptr(single) as *const () as usize,
+    }
+}
+
+/// Returns the first activity index, load and departure load of dynamic demand defined by load changes.
+fn get_dynamic_loads<T: LoadOps>(changes: &[(usize, T)]) -> (usize, Vec<T>, Vec<T>) {
+    let start = changes.first().unwrap().0;
+    let end = changes.last().unwrap().0;
+
+    let (loads, departures, _) =
+        (start..=end).fold((vec![], vec![], T::default()), |(mut loads, mut departures, current), time| {
+            let next = changes.iter().filter(|(idx, _)| *idx == time).fold(current, |acc, (_, change)| acc + *change);
+            loads.push(current.max_load(next));
+            departures.push(next);
+
+            (loads, departures, next)
+        });
+
+    (start, loads, departures)
+}
+
+fn has_load<T: LoadOps>(load: &T) -> bool {
+    *load != T::default()
+}
+
+fn is_compatible(left: Option<&Arc<Product>>, right: Option<&Arc<Product>>) -> bool {
+    match (left, right) {
+        (Some(left), Some(right)) => left.is_compatible(right),
+        _ => true,
+    }
+}
+
+struct CompartmentHardRouteConstraint<T: LoadOps> {
+    code: i32,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: LoadOps> HardRouteConstraint for CompartmentHardRouteConstraint<T> {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        let compartments: &Vec<Compartment<T>> = ctx.route.actor.vehicle.dimens.get_compartments()?;
+
+        let can_load = |single: &Single| {
+            let demand: Option<&Demand<T>> = single.dimens.get_demand();
+            let product = single.dimens.get_product().map(|product| product.as_ref());
+
+            demand.map_or(true, |demand| {
+                let loads = [demand.pickup.0, demand.pickup.1, demand.delivery.0, demand.delivery.1];
+                let loads = loads.iter().filter(|load| has_load(*load)).collect::<Vec<_>>();
+
+                loads.is_empty()
+                    || compartments.iter().any(|compartment| {
+                        compartment.can_load(product) && loads.iter().all(|load| compartment.capacity.can_fit(load))
+                    })
+            })
+        };
+
+        let is_loadable = match job {
+            Job::Single(single) => can_load(single),
+            Job::Multi(multi) => multi.jobs.iter().all(|single| can_load(single)),
+        };
+
+        if is_loadable {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+struct CompartmentHardActivityConstraint<T: LoadOps> {
+    code: i32,
+    multi_trip: Option<Arc<dyn MultiTrip<T> + Send + Sync>>,
+}
+
+impl<T: LoadOps> HardActivityConstraint for CompartmentHardActivityConstraint<T> {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let compartments: &Vec<Compartment<T>> = route_ctx.route.actor.vehicle.dimens.get_compartments()?;
+        let single = activity_ctx.target.job.as_ref()?;
+        let demand: &Demand<T> = single.dimens.get_demand()?;
+
+        // NOTE route state is not yet accepted for a new route
+        let computed;
+        let loads = match route_ctx.state.get_route_state::<CompartmentLoads<T>>(COMPARTMENT_LOADS_KEY) {
+            Some(loads) => Some(loads),
+            None => {
+                computed = create_compartment_loads(&route_ctx.route, compartments, self.multi_trip.as_ref());
+                computed.as_ref()
+            }
+        };
+
+        if loads.map_or(false, |loads| can_insert_demand(loads, compartments, activity_ctx, single, demand)) {
+            None
+        } else {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        }
+    }
+}
+
+/// Checks whether demand of the target activity can be assigned to compartments without changing
+/// assignment of the demand already in the route.
+fn can_insert_demand<T: LoadOps>(
+    loads: &CompartmentLoads<T>,
+    compartments: &[Compartment<T>],
+    activity_ctx: &ActivityContext,
+    single: &Arc<Single>,
+    demand: &Demand<T>,
+) -> bool {
+    let index = activity_ctx.index;
+    let target = index + 1;
+    let product = single.dimens.get_product();
+    let (start, end) = get_interval(loads.intervals.as_slice(), index);
+
+    // NOTE target is inserted after activity at `index`, so activities after it are shifted by one
+    let to_new = |idx: usize| if idx > index { idx + 1 } else { idx };
+    let get_base = |compartment_idx: usize, time: usize| match time.cmp(&target) {
+        Ordering::Less => loads.loads[compartment_idx][time],
+        Ordering::Equal => loads.departures[compartment_idx][index],
+        Ordering::Greater => loads.loads[compartment_idx][time - 1],
+    };
+    let is_free = |compartment_idx: usize, range: (usize, usize)| {
+        loads.occupied[compartment_idx].iter().all(|(other_start, other_end, other)| {
+            to_new(*other_end) < range.0 || to_new(*other_start) > range.1 || is_compatible(product, other.as_ref())
+        })
+    };
+
+    // keeps extra load at target activity in each compartment added by its static demand
+    let mut extra = vec![T::default(); compartments.len()];
+
+    let try_assign = |load: T, range: (usize, usize), extra: &mut Vec<T>| {
+        let compartment_idx = compartments.iter().enumerate().position(|(idx, compartment)| {
+            compartment.can_load(product.map(|product| product.as_ref()))
+                && (range.0..=range.1).all(|time| {
+                    let base = if time == target { get_base(idx, time) + extra[idx] } else { get_base(idx, time) };
+                    compartment.capacity.can_fit(&(base + load))
+                })
+                && is_free(idx, range)
+        });
+
+        compartment_idx.map(|idx| extra[idx] = extra[idx] + load).is_some()
+    };
+
+    if has_load(&demand.delivery.0) && !try_assign(demand.delivery.0, (start, target), &mut extra) {
+        return false;
+    }
+
+    if has_load(&demand.pickup.0) && !try_assign(demand.pickup.0, (target, to_new(end).max(target)), &mut extra) {
+        return false;
+    }
+
+    if has_load(&demand.pickup.1) || has_load(&demand.delivery.1) {
+        let change = demand.pickup.1 - demand.delivery.1;
+        let key = get_dynamic_key(activity_ctx.target, single);
+
+        return match loads.dynamic.get(&key) {
+            Some((compartment_idx, old_changes)) => {
+                let compartment = &compartments[*compartment_idx];
+                let (old_start, old_loads, old_departures) = get_dynamic_loads(old_changes.as_slice());
+                let get_old = |time: usize| {
+                    let (time, old_loads) = match time.cmp(&target) {
+                        Ordering::Less => (time, &old_loads),
+                        Ordering::Equal => (index, &old_departures),
+                        Ordering::Greater => (time - 1, &old_loads),
+                    };
+                    time.checked_sub(old_start).and_then(|offset| old_loads.get(offset)).cloned().unwrap_or_default()
+                };
+
+                let mut new_changes =
+                    old_changes.iter().map(|(idx, change)| (to_new(*idx), *change)).collect::<Vec<_>>();
+                new_changes.push((target, change));
+                new_changes.sort_by_key(|(idx, _)| *idx);
+
+                let (new_start, new_loads, _) = get_dynamic_loads(new_changes.as_slice());
+                let new_end = new_start + new_loads.len() - 1;
+
+                new_loads.iter().zip(new_start..).all(|(load, time)| {
+                    let base = get_base(*compartment_idx, time) - get_old(time);
+                    let base = if time == target { base + extra[*compartment_idx] } else { base };
+                    compartment.capacity.can_fit(&(base + *load))
+                }) && is_free(*compartment_idx, (new_start, new_end))
+            }
+            None => try_assign(change.max_load(T::default()), (target, target), &mut extra),
+        };
+    }
+
+    true
+}
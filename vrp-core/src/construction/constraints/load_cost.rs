@@ -0,0 +1,204 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/load_cost_test.rs"]
+mod load_cost_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::*;
+use crate::models::problem::{Job, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+use std::marker::PhantomData;
+use std::slice::Iter;
+use std::sync::Arc;
+
+const LOAD_COSTS_DIMENSION_KEY: &str = "load_costs";
+
+/// Specifies vehicle travel cost and emissions which depend on its current load.
+#[derive(Clone, Debug, Default)]
+pub struct LoadCosts {
+    /// An extra cost per distance unit when vehicle is fully loaded. It is added on top of load
+    /// independent distance cost proportionally to load ratio.
+    pub full_load_per_distance: Cost,
+    /// Emissions per distance unit when vehicle is empty.
+    pub empty_emissions: f64,
+    /// Emissions per distance unit when vehicle is fully loaded.
+    pub full_emissions: f64,
+}
+
+impl LoadCosts {
+    /// Returns extra cost of traveling given distance with load at given ratio of vehicle capacity.
+    pub fn get_cost(&self, distance: Distance, load_ratio: f64) -> Cost {
+        distance * self.full_load_per_distance * load_ratio.clamp(0., 1.)
+    }
+
+    /// Returns emissions of traveling given distance with load at given ratio of vehicle capacity.
+    pub fn get_emissions(&self, distance: Distance, load_ratio: f64) -> f64 {
+        let load_ratio = load_ratio.clamp(0., 1.);
+
+        distance * (self.empty_emissions + (self.full_emissions - self.empty_emissions) * load_ratio)
+    }
+}
+
+/// A trait to get or set vehicle's load dependent costs.
+pub trait LoadCostsDimension {
+    /// Sets load dependent costs.
+    fn set_load_costs(&mut self, costs: LoadCosts) -> &mut Self;
+    /// Gets load dependent costs.
+    fn get_load_costs(&self) -> Option<&LoadCosts>;
+}
+
+impl LoadCostsDimension for Dimensions {
+    fn set_load_costs(&mut self, costs: LoadCosts) -> &mut Self {
+        self.set_value(LOAD_COSTS_DIMENSION_KEY, costs);
+        self
+    }
+
+    fn get_load_costs(&self) -> Option<&LoadCosts> {
+        self.get_value(LOAD_COSTS_DIMENSION_KEY)
+    }
+}
+
+/// A module which calculates travel cost and emissions dependent on current vehicle load.
+/// It relies on vehicle load tracked by `CapacityConstraintModule`, so it should be added after it.
+pub struct LoadCostModule<T: LoadOps> {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> LoadCostModule<T> {
+    /// Creates a new instance of `LoadCostModule`.
+    pub fn new(transport: Arc<dyn TransportCost + Send + Sync>) -> Self {
+        Self {
+            state_keys: vec![LOAD_COST_KEY, TOTAL_EMISSIONS_KEY],
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(LoadCostSoftActivityConstraint::<T> {
+                transport: transport.clone(),
+                phantom: PhantomData,
+            }))],
+            transport,
+            phantom: PhantomData,
+        }
+    }
+
+    fn recalculate_states(&self, route_ctx: &mut RouteContext) {
+        let route = route_ctx.route.as_ref();
+        let load_costs = if let Some(load_costs) = route.actor.vehicle.dimens.get_load_costs() {
+            load_costs
+        } else {
+            return;
+        };
+
+        let (cost, emissions) = route.tour.legs().filter_map(|(items, _)| items.first().zip(items.get(1))).fold(
+            (Cost::default(), 0.),
+            |(cost, emissions), (from, to)| {
+                let distance = get_distance(self.transport.as_ref(), route, from, to);
+                let load_ratio = get_load_ratio::<T>(route_ctx, from);
+
+                (
+                    cost + load_costs.get_cost(distance, load_ratio),
+                    emissions + load_costs.get_emissions(distance, load_ratio),
+                )
+            },
+        );
+
+        route_ctx.state_mut().put_route_state(LOAD_COST_KEY, cost);
+        route_ctx.state_mut().put_route_state(TOTAL_EMISSIONS_KEY, emissions);
+    }
+}
+
+impl<T: LoadOps> ConstraintModule for LoadCostModule<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        self.recalculate_states(ctx);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.recalculate_states(route_ctx);
+        })
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct LoadCostSoftActivityConstraint<T: LoadOps> {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> SoftActivityConstraint for LoadCostSoftActivityConstraint<T> {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let route = route_ctx.route.as_ref();
+        let load_costs = if let Some(load_costs) = route.actor.vehicle.dimens.get_load_costs() {
+            load_costs
+        } else {
+            return Cost::default();
+        };
+
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+        let prev_load =
+            route_ctx.state.get_activity_state::<T>(CURRENT_CAPACITY_KEY, prev).cloned().unwrap_or_default();
+
+        // NOTE only legs around target activity are considered: static demand of the target changes also
+        // load on other legs of the route, but this is ignored here for the sake of performance
+        let (load_in, load_out) = target.job.as_ref().and_then(|single| single.dimens.get_demand()).map_or(
+            (prev_load, prev_load),
+            |demand: &Demand<T>| {
+                let load_in = prev_load + demand.delivery.0;
+                (load_in, load_in + demand.change())
+            },
+        );
+
+        let get_cost = |from: &Activity, to: &Activity, load: &T| {
+            load_costs.get_cost(get_distance(self.transport.as_ref(), route, from, to), get_ratio(route, load))
+        };
+
+        let prev_target = get_cost(prev, target, &load_in);
+
+        activity_ctx.next.map_or(prev_target, |next| {
+            prev_target + get_cost(target, next, &load_out) - get_cost(prev, next, &prev_load)
+        })
+    }
+}
+
+fn get_distance(
+    transport: &(dyn TransportCost + Send + Sync),
+    route: &Route,
+    from: &Activity,
+    to: &Activity,
+) -> Distance {
+    transport.distance(route, from.place.location, to.place.location, TravelTime::Departure(from.schedule.departure))
+}
+
+fn get_load_ratio<T: LoadOps>(route_ctx: &RouteContext, activity: &Activity) -> f64 {
+    route_ctx
+        .state
+        .get_activity_state::<T>(CURRENT_CAPACITY_KEY, activity)
+        .map_or(0., |load| get_ratio(route_ctx.route.as_ref(), load))
+}
+
+fn get_ratio<T: LoadOps>(route: &Route, load: &T) -> f64 {
+    route.actor.vehicle.dimens.get_capacity().map_or(0., |capacity: &T| {
+        let ratio = load.ratio(capacity);
+        if ratio.is_finite() {
+            ratio
+        } else {
+            0.
+        }
+    })
+}
@@ -65,6 +65,16 @@ pub const TOTAL_DISTANCE_KEY: i32 = 3;
 pub const TOTAL_DURATION_KEY: i32 = 4;
 /// A key which track duration limit.
 pub const LIMIT_DURATION_KEY: i32 = 5;
+/// A key which tracks total lateness penalty.
+pub const LATENESS_PENALTY_KEY: i32 = 6;
+/// A key which tracks ride time slack of multi jobs on board.
+pub const RIDE_TIME_SLACK_KEY: i32 = 7;
+/// A key which tracks total extra travel cost, e.g. toll cost.
+pub const TOTAL_EXTRA_COST_KEY: i32 = 8;
+/// A key which tracks arrival time buffer needed to meet time window with required service level.
+pub const ARRIVAL_BUFFER_KEY: i32 = 9;
+/// A key which tracks positions of the last linehaul and the first backhaul activities.
+pub const HAUL_INDICES_KEY: i32 = 10;
 
 /// A key which tracks current vehicle capacity.
 pub const CURRENT_CAPACITY_KEY: i32 = 11;
@@ -76,6 +86,12 @@ pub const MAX_PAST_CAPACITY_KEY: i32 = 13;
 pub const RELOAD_INTERVALS_KEY: i32 = 14;
 /// A key which tracks max load in tour.
 pub const MAX_LOAD_KEY: i32 = 15;
+/// A key which tracks total load dependent travel cost.
+pub const LOAD_COST_KEY: i32 = 16;
+/// A key which tracks total emissions.
+pub const TOTAL_EMISSIONS_KEY: i32 = 17;
+/// A key which tracks compartment loads.
+pub const COMPARTMENT_LOADS_KEY: i32 = 18;
 
 mod pipeline;
 pub use self::pipeline::*;
@@ -83,15 +99,27 @@ pub use self::pipeline::*;
 mod transport;
 pub use self::transport::*;
 
+mod ride_time;
+pub use self::ride_time::*;
+
 mod capacity;
 pub use self::capacity::*;
 
+mod compartment;
+pub use self::compartment::*;
+
+mod load_cost;
+pub use self::load_cost::*;
+
 mod locking;
 pub use self::locking::*;
 
 mod tour_size;
 pub use self::tour_size::*;
 
+mod backhaul;
+pub use self::backhaul::*;
+
 mod conditional;
 pub use self::conditional::*;
 
@@ -0,0 +1,203 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/ride_time_test.rs"]
+mod ride_time_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::*;
+use crate::models::common::{Dimensions, Duration, ValueDimension};
+use crate::models::problem::{ActivityCost, Job, Multi, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+use hashbrown::HashMap;
+use std::slice::Iter;
+use std::sync::Arc;
+
+const MAX_RIDE_TIME_DIMENSION_KEY: &str = "max_ride";
+
+/// A trait to get or set maximum ride time of the multi job.
+pub trait RideTimeDimension {
+    /// Sets maximum ride time.
+    fn set_max_ride_time(&mut self, max_ride_time: Duration) -> &mut Self;
+    /// Gets maximum ride time.
+    fn get_max_ride_time(&self) -> Option<&Duration>;
+}
+
+impl RideTimeDimension for Dimensions {
+    fn set_max_ride_time(&mut self, max_ride_time: Duration) -> &mut Self {
+        self.set_value(MAX_RIDE_TIME_DIMENSION_KEY, max_ride_time);
+        self
+    }
+
+    fn get_max_ride_time(&self) -> Option<&Duration> {
+        self.get_value(MAX_RIDE_TIME_DIMENSION_KEY)
+    }
+}
+
+/// A module which limits ride time of multi jobs: time between departure from the first job's activity
+/// and arrival to the last one, e.g. how long goods or passengers stay on board between pickup and delivery.
+pub struct RideTimeConstraintModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl RideTimeConstraintModule {
+    /// Creates a new instance of `RideTimeConstraintModule`.
+    pub fn new(
+        code: i32,
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+    ) -> Self {
+        Self {
+            state_keys: vec![RIDE_TIME_SLACK_KEY],
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(RideTimeHardActivityConstraint {
+                code,
+                activity,
+                transport,
+            }))],
+        }
+    }
+}
+
+impl ConstraintModule for RideTimeConstraintModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        update_ride_time_slack(ctx);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(update_ride_time_slack)
+    }
+
+    fn merge(&self, source: Job, _candidate: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct RideTimeHardActivityConstraint {
+    code: i32,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for RideTimeHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let route = route_ctx.route.as_ref();
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        // NOTE sub jobs are inserted in order, so the first one is already in the tour before target
+        if let Some((multi, max_ride_time)) = get_multi_with_ride_time(target) {
+            let first_departure = route
+                .tour
+                .all_activities()
+                .take(activity_ctx.index + 1)
+                .find(|activity| get_multi_with_ride_time(activity).map_or(false, |(other, _)| other == multi))
+                .map(|activity| activity.schedule.departure);
+
+            if first_departure.map_or(false, |first_departure| arrival - first_departure > max_ride_time) {
+                return Some(ActivityConstraintViolation { code: self.code, stopped: true });
+            }
+        }
+
+        // NOTE waiting time is ignored here, so the check is pessimistic
+        let next = activity_ctx.next?;
+        let slack = *route_ctx.state.get_activity_state::<Duration>(RIDE_TIME_SLACK_KEY, next)?;
+
+        let target_departure = self.activity.estimate_departure(route, target, arrival);
+        let next_arrival = target_departure
+            + self.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(target_departure),
+            );
+
+        if next_arrival - next.schedule.arrival > slack {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns a pointer to the multi job with its max ride time if activity belongs to such job.
+fn get_multi_with_ride_time(activity: &Activity) -> Option<(*const Multi, Duration)> {
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| Multi::roots(single))
+        .and_then(|multi| multi.dimens.get_max_ride_time().map(|max_ride_time| (Arc::as_ptr(&multi), *max_ride_time)))
+}
+
+/// Returns first and last activity indices of the multi jobs together with their max ride time.
+fn get_ride_spans(route: &Route) -> Vec<(usize, usize, Duration)> {
+    route
+        .tour
+        .all_activities()
+        .enumerate()
+        .fold(HashMap::<*const Multi, (usize, usize, Duration)>::default(), |mut acc, (idx, activity)| {
+            if let Some((multi, max_ride_time)) = get_multi_with_ride_time(activity) {
+                acc.entry(multi).or_insert((idx, idx, max_ride_time)).1 = idx;
+            }
+
+            acc
+        })
+        .into_values()
+        .collect()
+}
+
+/// Updates ride time slack: how much arrival time at activity can be shifted without violating
+/// ride time of jobs which are on board.
+fn update_ride_time_slack(route_ctx: &mut RouteContext) {
+    let spans = get_ride_spans(route_ctx.route.as_ref());
+    if spans.is_empty() {
+        return;
+    }
+
+    let (route, state) = route_ctx.as_mut();
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+
+    let slacks = spans.into_iter().filter(|(first, last, _)| first < last).fold(
+        vec![None; activities.len()],
+        |mut slacks, (first, last, max_ride_time)| {
+            let ride_time = activities[last].schedule.arrival - activities[first].schedule.departure;
+            let slack = max_ride_time - ride_time;
+
+            (first + 1..=last).for_each(|idx| {
+                slacks[idx] = Some(slacks[idx].map_or(slack, |other: Duration| other.min(slack)));
+            });
+
+            slacks
+        },
+    );
+
+    activities.iter().zip(slacks).for_each(|(activity, slack)| {
+        if let Some(slack) = slack {
+            state.put_activity_state(RIDE_TIME_SLACK_KEY, activity, slack);
+        }
+    });
+}
@@ -5,9 +5,10 @@ mod transport_test;
 use crate::construction::constraints::*;
 use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
 use crate::models::common::{Cost, Distance, Duration, Timestamp};
-use crate::models::problem::{ActivityCost, Actor, Job, Single, TransportCost, TravelTime};
+use crate::models::problem::{estimate_lateness, ActivityCost, Actor, Job, Single, TransportCost, TravelTime};
 use crate::models::solution::{Activity, Route};
 use crate::models::OP_START_MSG;
+use rosomaxa::algorithms::math::get_normal_quantile;
 use rosomaxa::prelude::compare_floats;
 use std::cmp::Ordering;
 use std::slice::Iter;
@@ -18,14 +19,24 @@ use std::sync::Arc;
 /// A function which returns travel limits for given actor.
 pub type TravelLimitFunc = Arc<dyn Fn(&Actor) -> (Option<Distance>, Option<Duration>) + Send + Sync>;
 
+/// A function which returns service level for given actor: a probability to arrive at activity before
+/// its time window end when travel durations are uncertain (have non-zero variance).
+pub type ServiceLevelFunc = Arc<dyn Fn(&Actor) -> Option<f64> + Send + Sync>;
+
 /// A module which checks whether vehicle can serve activity taking into account their time windows
 /// and traveling constraints. Also it is responsible for transport cost calculations.
+///
+/// When service level is specified for the actor, the module works in robust mode: arrival time at
+/// each activity is assumed to be normally distributed with variance accumulated from travel duration
+/// variances along the route, and time windows are checked against the arrival time quantile which
+/// corresponds to the service level.
 pub struct TransportConstraintModule {
     state_keys: Vec<i32>,
     constraints: Vec<ConstraintVariant>,
     activity: Arc<dyn ActivityCost + Send + Sync>,
     transport: Arc<dyn TransportCost + Send + Sync>,
     limit_func: TravelLimitFunc,
+    service_level_func: ServiceLevelFunc,
 }
 
 impl ConstraintModule for TransportConstraintModule {
@@ -37,8 +48,10 @@ impl ConstraintModule for TransportConstraintModule {
     fn accept_route_state(&self, ctx: &mut RouteContext) {
         let activity = self.activity.as_ref();
         let transport = self.transport.as_ref();
+        let quantile = get_service_quantile(&self.service_level_func, ctx.route.actor.as_ref());
 
         Self::update_route_schedules(ctx, activity, transport);
+        Self::update_arrival_buffers(ctx, transport, quantile);
         Self::update_route_states(ctx, activity, transport);
         // NOTE Rescheduling during the insertion process makes sense only if the traveling limit
         // is set (for duration limit, not for distance).
@@ -59,8 +72,10 @@ impl ConstraintModule for TransportConstraintModule {
         ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
             let activity = self.activity.as_ref();
             let transport = self.transport.as_ref();
+            let quantile = get_service_quantile(&self.service_level_func, route_ctx.route.actor.as_ref());
 
             Self::update_route_schedules(route_ctx, activity, transport);
+            Self::update_arrival_buffers(route_ctx, transport, quantile);
             Self::update_route_states(route_ctx, activity, transport);
             Self::update_statistics(route_ctx, transport);
         })
@@ -86,6 +101,7 @@ impl TransportConstraintModule {
         transport: Arc<dyn TransportCost + Send + Sync>,
         activity: Arc<dyn ActivityCost + Send + Sync>,
         limit_func: TravelLimitFunc,
+        service_level_func: ServiceLevelFunc,
         time_window_code: i32,
         distance_code: i32,
         duration_code: i32,
@@ -97,6 +113,9 @@ impl TransportConstraintModule {
                 TOTAL_DISTANCE_KEY,
                 TOTAL_DURATION_KEY,
                 LIMIT_DURATION_KEY,
+                LATENESS_PENALTY_KEY,
+                TOTAL_EXTRA_COST_KEY,
+                ARRIVAL_BUFFER_KEY,
             ],
             constraints: vec![
                 ConstraintVariant::HardRoute(Arc::new(TimeHardRouteConstraint { code: time_window_code })),
@@ -105,6 +124,7 @@ impl TransportConstraintModule {
                     code: time_window_code,
                     activity: activity.clone(),
                     transport: transport.clone(),
+                    service_level_func: service_level_func.clone(),
                 })),
                 ConstraintVariant::HardActivity(Arc::new(TravelHardActivityConstraint {
                     limit_func: limit_func.clone(),
@@ -120,6 +140,7 @@ impl TransportConstraintModule {
             activity,
             transport,
             limit_func,
+            service_level_func,
         }
     }
 
@@ -172,7 +193,9 @@ impl TransportConstraintModule {
             let (end_time, prev_loc, waiting) = acc;
             let latest_departure =
                 end_time - transport.duration(&route, act.place.location, prev_loc, TravelTime::Arrival(end_time));
-            let latest_arrival_time = activity.estimate_arrival(&route, act, latest_departure);
+            let arrival_buffer = state.get_activity_state::<f64>(ARRIVAL_BUFFER_KEY, act).cloned().unwrap_or(0.);
+            let latest_arrival_time =
+                activity.estimate_arrival(&route, act, latest_departure).min(act.place.time.end - arrival_buffer);
             let future_waiting = waiting + (act.place.time.start - act.schedule.arrival).max(0.);
 
             state.put_activity_state(LATEST_ARRIVAL_KEY, act, latest_arrival_time);
@@ -182,6 +205,35 @@ impl TransportConstraintModule {
         });
     }
 
+    /// Updates arrival buffers which are needed to meet time windows with given quantile of arrival time.
+    fn update_arrival_buffers(
+        route_ctx: &mut RouteContext,
+        transport: &(dyn TransportCost + Send + Sync),
+        quantile: Option<f64>,
+    ) {
+        let quantile = if let Some(quantile) = quantile { quantile } else { return };
+
+        let init = {
+            let start = route_ctx.route.tour.start().unwrap();
+            (start.place.location, start.schedule.departure, 0_f64)
+        };
+
+        let route = route_ctx.route.clone();
+        let (route_mut, state) = route_ctx.as_mut();
+
+        // NOTE variance is accumulated along the whole route: waiting does not absorb uncertainty
+        route_mut.tour.all_activities().skip(1).fold(init, |(loc, dep, variance), act| {
+            let variance =
+                variance + transport.duration_variance(&route, loc, act.place.location, TravelTime::Departure(dep));
+
+            if act.job.is_some() {
+                state.put_activity_state(ARRIVAL_BUFFER_KEY, act, quantile * variance.sqrt());
+            }
+
+            (act.place.location, act.schedule.departure, variance)
+        });
+    }
+
     pub(crate) fn update_statistics(route_ctx: &mut RouteContext, transport: &(dyn TransportCost + Send + Sync)) {
         let route = route_ctx.route.as_ref();
         let start = route.tour.start().unwrap();
@@ -189,15 +241,26 @@ impl TransportConstraintModule {
 
         let total_dur = end.schedule.departure - start.schedule.departure;
 
-        let init = (start.place.location, start.schedule.departure, Distance::default());
-        let (_, _, total_dist) = route.tour.all_activities().skip(1).fold(init, |(loc, dep, total_dist), a| {
-            let total_dist = total_dist + transport.distance(route, loc, a.place.location, TravelTime::Departure(dep));
+        let init = (start.place.location, start.schedule.departure, Distance::default(), Cost::default());
+        let (_, _, total_dist, total_extra) =
+            route.tour.all_activities().skip(1).fold(init, |(loc, dep, total_dist, total_extra), a| {
+                let travel_time = TravelTime::Departure(dep);
+                let total_dist = total_dist + transport.distance(route, loc, a.place.location, travel_time);
+                let total_extra = total_extra + transport.extra_cost(route, loc, a.place.location, travel_time);
 
-            (a.place.location, a.schedule.departure, total_dist)
-        });
+                (a.place.location, a.schedule.departure, total_dist, total_extra)
+            });
+
+        let total_penalty = route
+            .tour
+            .all_activities()
+            .map(|activity| estimate_lateness(activity, activity.schedule.arrival).1)
+            .sum::<Cost>();
 
         route_ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, total_dist);
         route_ctx.state_mut().put_route_state(TOTAL_DURATION_KEY, total_dur);
+        route_ctx.state_mut().put_route_state(LATENESS_PENALTY_KEY, total_penalty);
+        route_ctx.state_mut().put_route_state(TOTAL_EXTRA_COST_KEY, total_extra);
     }
 
     /// Tries to move forward route's departure time.
@@ -269,6 +332,7 @@ struct TimeHardActivityConstraint {
     code: i32,
     activity: Arc<dyn ActivityCost + Send + Sync>,
     transport: Arc<dyn TransportCost + Send + Sync>,
+    service_level_func: ServiceLevelFunc,
 }
 
 impl HardActivityConstraint for TimeHardActivityConstraint {
@@ -313,6 +377,10 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
         if arr_time_at_next > latest_arr_time_at_next {
             return fail(self.code);
         }
+
+        let (target_buffer, next_buffer_change) = self.estimate_arrival_buffers(route_ctx, activity_ctx);
+        let latest_arr_time_at_next = latest_arr_time_at_next - next_buffer_change;
+
         if target.place.time.start > latest_arr_time_at_next {
             return stop(self.code);
         }
@@ -333,8 +401,11 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
                 TravelTime::Arrival(latest_arr_time_at_next),
             );
 
-        let latest_arr_time_at_target =
-            target.place.time.end.min(self.activity.estimate_arrival(route, target, latest_departure_at_target));
+        let latest_arr_time_at_target = (target.place.time.end - target_buffer).min(self.activity.estimate_arrival(
+            route,
+            target,
+            latest_departure_at_target,
+        ));
 
         if arr_time_at_target > latest_arr_time_at_target {
             return stop(self.code);
@@ -362,6 +433,53 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
     }
 }
 
+impl TimeHardActivityConstraint {
+    /// Estimates arrival buffer at target activity and change of arrival buffer at next activity.
+    /// As buffer change is the largest at next activity, it is safe to apply it to all activities after.
+    fn estimate_arrival_buffers(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> (f64, f64) {
+        let route = route_ctx.route.as_ref();
+        let quantile = if let Some(quantile) = get_service_quantile(&self.service_level_func, route.actor.as_ref()) {
+            quantile
+        } else {
+            return (0., 0.);
+        };
+
+        let get_variance = |activity: &Activity| {
+            let buffer = route_ctx.state.get_activity_state::<f64>(ARRIVAL_BUFFER_KEY, activity).cloned().unwrap_or(0.);
+            (buffer / quantile).powi(2)
+        };
+        let get_leg_variance = |from: &Activity, to: &Activity, departure: Timestamp| {
+            self.transport.duration_variance(
+                route,
+                from.place.location,
+                to.place.location,
+                TravelTime::Departure(departure),
+            )
+        };
+
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+        let departure = prev.schedule.departure;
+
+        let prev_to_target = get_leg_variance(prev, target, departure);
+        let target_buffer = quantile * (get_variance(prev) + prev_to_target).sqrt();
+
+        let next_buffer_change = match activity_ctx.next {
+            Some(next) if next.job.is_some() => {
+                let next_variance = get_variance(next);
+                let variance_change = (prev_to_target + get_leg_variance(target, next, departure)
+                    - get_leg_variance(prev, next, departure))
+                .max(0.);
+
+                quantile * ((next_variance + variance_change).sqrt() - next_variance.sqrt())
+            }
+            _ => 0.,
+        };
+
+        (target_buffer, next_buffer_change)
+    }
+}
+
 /// A hard activity constraint which allows to limit actor's traveling distance and time.
 struct TravelHardActivityConstraint {
     limit_func: TravelLimitFunc,
@@ -537,12 +655,16 @@ fn try_advance_departure_time(
 
     let latest_allowed_departure = route.actor.detail.start.as_ref().and_then(|s| s.time.latest).unwrap_or(f64::MAX);
     let last_departure_time = start.schedule.departure;
+    let get_arrival_buffer = |activity: &Activity| {
+        route_ctx.state.get_activity_state::<f64>(ARRIVAL_BUFFER_KEY, activity).cloned().unwrap_or(0.)
+    };
 
     let new_departure_time = if optimize_whole_tour {
         let (total_waiting_time, max_shift) =
             route.tour.all_activities().rev().fold((0., f64::MAX), |(total_waiting_time, max_shift), activity| {
                 let waiting_time = (activity.place.time.start - activity.schedule.arrival).max(0.);
-                let remaining_time = (activity.place.time.end - activity.schedule.arrival - waiting_time).max(0.);
+                let latest_arrival = activity.place.time.end - get_arrival_buffer(activity);
+                let remaining_time = (latest_arrival - activity.schedule.arrival - waiting_time).max(0.);
 
                 (total_waiting_time + waiting_time, waiting_time + remaining_time.min(max_shift))
             });
@@ -557,7 +679,9 @@ fn try_advance_departure_time(
             TravelTime::Departure(last_departure_time),
         );
 
-        last_departure_time.max(first.place.time.start - start_to_first).min(latest_allowed_departure)
+        let first_arrival = first.place.time.start.min(first.place.time.end - get_arrival_buffer(first));
+
+        last_departure_time.max(first_arrival - start_to_first).min(latest_allowed_departure)
     };
 
     if new_departure_time > last_departure_time {
@@ -591,6 +715,11 @@ fn try_recede_departure_time(route_ctx: &RouteContext) -> Option<Timestamp> {
     }
 }
 
+/// Returns arrival time quantile which corresponds to the service level of the actor.
+fn get_service_quantile(service_level_func: &ServiceLevelFunc, actor: &Actor) -> Option<f64> {
+    (service_level_func)(actor).map(get_normal_quantile).filter(|quantile| *quantile > 0.)
+}
+
 #[allow(clippy::unnecessary_wraps)]
 fn fail(code: i32) -> Option<ActivityConstraintViolation> {
     Some(ActivityConstraintViolation { code, stopped: true })
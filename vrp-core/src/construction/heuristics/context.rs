@@ -233,7 +233,15 @@ impl RouteContext {
         let distance = self.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
         let duration = self.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
 
-        get_cost(&actor.vehicle.costs, distance, duration) + get_cost(&actor.driver.costs, distance, duration)
+        let penalty = self.state.get_route_state::<f64>(LATENESS_PENALTY_KEY).cloned().unwrap_or(0.);
+        let load_cost = self.state.get_route_state::<f64>(LOAD_COST_KEY).cloned().unwrap_or(0.);
+        let extra_cost = self.state.get_route_state::<f64>(TOTAL_EXTRA_COST_KEY).cloned().unwrap_or(0.);
+
+        get_cost(&actor.vehicle.costs, distance, duration)
+            + get_cost(&actor.driver.costs, distance, duration)
+            + penalty
+            + load_cost
+            + extra_cost
     }
 
     /// Unwraps given `RouteContext` as pair of mutable references.
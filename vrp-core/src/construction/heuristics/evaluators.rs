@@ -248,10 +248,12 @@ fn analyze_insertion_in_route_leg(
     };
     let start_time = route_ctx.route.tour.start().unwrap().schedule.departure;
     // analyze service details
-    single.places.iter().try_fold(out, |in1, detail| {
+    single.places.iter().enumerate().try_fold(out, |in1, (idx, detail)| {
         // analyze detail time windows
-        detail.times.iter().try_fold(in1, |in2, time| {
+        detail.times.iter().enumerate().try_fold(in1, |in2, (time_idx, time)| {
             target.place = Place {
+                idx,
+                time_idx,
                 location: detail.location.unwrap_or(prev.place.location),
                 duration: detail.duration,
                 time: time.to_time_window(start_time),
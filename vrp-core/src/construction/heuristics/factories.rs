@@ -43,6 +43,8 @@ pub fn create_insertion_context(problem: Arc<Problem>, environment: Arc<Environm
 
                     Activity {
                         place: ActivityPlace {
+                            idx: 0,
+                            time_idx: 0,
                             location: place.location.unwrap_or(previous_location),
                             duration: place.duration,
                             time,
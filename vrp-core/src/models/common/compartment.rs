@@ -0,0 +1,86 @@
+use crate::models::common::{Dimensions, LoadOps, ValueDimension};
+use hashbrown::HashSet;
+use std::sync::Arc;
+
+const COMPARTMENTS_DIMENSION_KEY: &str = "cmpts";
+const PRODUCT_DIMENSION_KEY: &str = "prdct";
+
+/// Represents a product type of customer's demand.
+pub struct Product {
+    /// A product id.
+    pub id: String,
+    /// Ids of products which cannot share the same compartment with this one.
+    pub incompatible: HashSet<String>,
+}
+
+impl Product {
+    /// Creates a new instance of `Product`.
+    pub fn new(id: String, incompatible: HashSet<String>) -> Self {
+        Self { id, incompatible }
+    }
+
+    /// Checks whether two products can be loaded into the same compartment.
+    pub fn is_compatible(&self, other: &Product) -> bool {
+        self.id == other.id || !(self.incompatible.contains(&other.id) || other.incompatible.contains(&self.id))
+    }
+}
+
+/// Represents a vehicle compartment: a physically separated part of vehicle with its own capacity.
+/// Each job's demand is loaded into exactly one compartment.
+pub struct Compartment<T: LoadOps> {
+    /// A compartment capacity.
+    pub capacity: T,
+    /// Ids of products allowed to be loaded into the compartment. All products are allowed, if not set.
+    pub products: Option<HashSet<String>>,
+    /// A compartment dimensions.
+    pub dimens: Dimensions,
+}
+
+impl<T: LoadOps> Compartment<T> {
+    /// Checks whether demand of given product can be loaded into the compartment.
+    pub fn can_load(&self, product: Option<&Product>) -> bool {
+        match (&self.products, product) {
+            (None, _) => true,
+            (Some(products), Some(product)) => products.contains(&product.id),
+            (Some(_), None) => false,
+        }
+    }
+}
+
+/// A trait to get or set vehicle's compartments.
+pub trait CompartmentDimension<T: LoadOps> {
+    /// Sets compartments.
+    fn set_compartments(&mut self, compartments: Vec<Compartment<T>>) -> &mut Self;
+    /// Gets compartments.
+    fn get_compartments(&self) -> Option<&Vec<Compartment<T>>>;
+}
+
+/// A trait to get or set product of job's demand.
+pub trait ProductDimension {
+    /// Sets product.
+    fn set_product(&mut self, product: Arc<Product>) -> &mut Self;
+    /// Gets product.
+    fn get_product(&self) -> Option<&Arc<Product>>;
+}
+
+impl<T: LoadOps> CompartmentDimension<T> for Dimensions {
+    fn set_compartments(&mut self, compartments: Vec<Compartment<T>>) -> &mut Self {
+        self.set_value(COMPARTMENTS_DIMENSION_KEY, compartments);
+        self
+    }
+
+    fn get_compartments(&self) -> Option<&Vec<Compartment<T>>> {
+        self.get_value(COMPARTMENTS_DIMENSION_KEY)
+    }
+}
+
+impl ProductDimension for Dimensions {
+    fn set_product(&mut self, product: Arc<Product>) -> &mut Self {
+        self.set_value(PRODUCT_DIMENSION_KEY, product);
+        self
+    }
+
+    fn get_product(&self) -> Option<&Arc<Product>> {
+        self.get_value(PRODUCT_DIMENSION_KEY)
+    }
+}
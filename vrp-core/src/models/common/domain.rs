@@ -185,6 +185,35 @@ impl TimeInterval {
     }
 }
 
+/// Specifies a soft time window: an activity can be started after the time window end, but not
+/// later than max allowed lateness. Each time unit of lateness is penalized.
+#[derive(Clone, Debug)]
+pub struct SoftTimeWindow {
+    /// An original time window.
+    pub time: TimeWindow,
+    /// Max allowed lateness after time window end.
+    pub max_lateness: Duration,
+    /// A penalty per time unit of lateness.
+    pub penalty: f64,
+}
+
+impl SoftTimeWindow {
+    /// Creates a new instance of `SoftTimeWindow`.
+    pub fn new(time: TimeWindow, max_lateness: Duration, penalty: f64) -> Self {
+        Self { time, max_lateness, penalty }
+    }
+
+    /// Returns a hard time window which includes max allowed lateness.
+    pub fn to_hard_window(&self) -> TimeWindow {
+        TimeWindow::new(self.time.start, self.time.end + self.max_lateness)
+    }
+
+    /// Returns lateness if activity is started at given time.
+    pub fn get_lateness(&self, start: Timestamp) -> Duration {
+        (start - self.time.end).max(0.)
+    }
+}
+
 /// Represents a schedule.
 #[derive(Clone, Debug)]
 pub struct Schedule {
@@ -270,3 +299,26 @@ impl PartialEq for TimeInterval {
         self.earliest == other.earliest && self.latest == other.latest
     }
 }
+
+/// Specifies soft time windows of the job: for each job place, there is an optional soft time window
+/// per each time window of the place in the same order.
+pub type SoftTimeWindows = Vec<Vec<Option<SoftTimeWindow>>>;
+
+/// A trait to get or set soft time windows.
+pub trait SoftTimeWindowDimension {
+    /// Sets soft time windows.
+    fn set_soft_time_windows(&mut self, windows: SoftTimeWindows) -> &mut Self;
+    /// Gets soft time windows if present.
+    fn get_soft_time_windows(&self) -> Option<&SoftTimeWindows>;
+}
+
+impl SoftTimeWindowDimension for Dimensions {
+    fn set_soft_time_windows(&mut self, windows: SoftTimeWindows) -> &mut Self {
+        self.set_value("soft_tws", windows);
+        self
+    }
+
+    fn get_soft_time_windows(&self) -> Option<&SoftTimeWindows> {
+        self.get_value("soft_tws")
+    }
+}
@@ -1,5 +1,8 @@
 //! Common models.
 
+mod compartment;
+pub use self::compartment::*;
+
 mod load;
 pub use self::load::*;
 
@@ -57,6 +57,7 @@ fn create_example_fleet() -> Arc<Fleet> {
         details: vec![VehicleDetail {
             start: Some(VehiclePlace { location: 0, time: TimeInterval::default() }),
             end: None,
+            links: vec![],
         }],
     })];
 
@@ -74,6 +75,7 @@ pub fn create_example_problem() -> Arc<Problem> {
         transport.clone(),
         activity.clone(),
         Arc::new(|_| (None, None)),
+        Arc::new(|_| None),
         1,
         2,
         3,
@@ -101,6 +101,7 @@ pub trait ActivityCost {
 
         waiting * (actor.driver.costs.per_waiting_time + actor.vehicle.costs.per_waiting_time)
             + service * (actor.driver.costs.per_service_time + actor.vehicle.costs.per_service_time)
+            + estimate_lateness(activity, arrival).1
     }
 
     /// Estimates departure time for activity and actor at given arrival time.
@@ -110,6 +111,23 @@ pub trait ActivityCost {
     fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp;
 }
 
+/// Returns lateness and its penalty for the activity started at given arrival time. Lateness is
+/// non-zero only if activity's job has soft time window and activity is started after its end.
+pub fn estimate_lateness(activity: &Activity, arrival: Timestamp) -> (Duration, Cost) {
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.dimens.get_soft_time_windows())
+        .and_then(|windows| windows.get(activity.place.idx))
+        .and_then(|windows| windows.get(activity.place.time_idx))
+        .and_then(|window| window.as_ref())
+        .map(|window| {
+            let lateness = window.get_lateness(arrival.max(activity.place.time.start));
+            (lateness, lateness * window.penalty)
+        })
+        .unwrap_or((0., 0.))
+}
+
 /// An actor independent activity costs.
 #[derive(Default)]
 pub struct SimpleActivityCost {}
@@ -196,6 +214,19 @@ pub trait TransportCost {
 
         distance * (actor.driver.costs.per_distance + actor.vehicle.costs.per_distance)
             + duration * (actor.driver.costs.per_driving_time + actor.vehicle.costs.per_driving_time)
+            + self.extra_cost(route, from, to, travel_time)
+    }
+
+    /// Returns time-dependent extra travel cost (e.g. toll cost) between two locations for given actor.
+    /// This cost does not depend on actor costs and is added to transport cost as is.
+    fn extra_cost(&self, _route: &Route, _from: Location, _to: Location, _travel_time: TravelTime) -> Cost {
+        Cost::default()
+    }
+
+    /// Returns time-dependent variance of travel duration between two locations for given actor.
+    /// Zero variance means that travel duration is deterministic.
+    fn duration_variance(&self, _route: &Route, _from: Location, _to: Location, _travel_time: TravelTime) -> f64 {
+        0.
     }
 
     /// Returns time-independent travel duration between locations specific for given profile.
@@ -251,6 +282,14 @@ impl TransportCost for DynamicTransportCost {
     fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
         self.inner.distance(route, from, to, travel_time)
     }
+
+    fn extra_cost(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Cost {
+        self.inner.extra_cost(route, from, to, travel_time)
+    }
+
+    fn duration_variance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> f64 {
+        self.inner.duration_variance(route, from, to, travel_time)
+    }
 }
 
 /// Contains matrix routing data for specific profile and, optionally, time.
@@ -263,12 +302,27 @@ pub struct MatrixData {
     pub durations: Vec<Duration>,
     /// Travel distances.
     pub distances: Vec<Distance>,
+    /// Extra travel costs, e.g. toll costs.
+    pub extra_costs: Option<Vec<Cost>>,
+    /// Variances of travel durations which specify their uncertainty.
+    pub duration_variances: Option<Vec<f64>>,
 }
 
 impl MatrixData {
     /// Creates `MatrixData` instance.
     pub fn new(index: usize, timestamp: Option<Timestamp>, durations: Vec<Duration>, distances: Vec<Distance>) -> Self {
-        Self { index, timestamp, durations, distances }
+        Self { index, timestamp, durations, distances, extra_costs: None, duration_variances: None }
+    }
+
+    /// Creates `MatrixData` instance with extra travel costs.
+    pub fn new_with_extra_costs(
+        index: usize,
+        timestamp: Option<Timestamp>,
+        durations: Vec<Duration>,
+        distances: Vec<Distance>,
+        extra_costs: Vec<Cost>,
+    ) -> Self {
+        Self { index, timestamp, durations, distances, extra_costs: Some(extra_costs), duration_variances: None }
     }
 }
 
@@ -292,6 +346,19 @@ pub fn create_matrix_transport_cost(costs: Vec<MatrixData>) -> Result<Arc<dyn Tr
         return Err("duration lengths don't match".to_string());
     }
 
+    if costs
+        .iter()
+        .any(|matrix| matrix.extra_costs.as_ref().map_or(false, |costs| costs.len() != matrix.distances.len()))
+    {
+        return Err("extra cost and distance collections have different length".to_string());
+    }
+
+    if costs.iter().any(|matrix| {
+        matrix.duration_variances.as_ref().map_or(false, |variances| variances.len() != matrix.durations.len())
+    }) {
+        return Err("duration variance and duration collections have different length".to_string());
+    }
+
     Ok(if costs.iter().any(|costs| costs.timestamp.is_some()) {
         Arc::new(TimeAwareMatrixTransportCost::new(costs, size)?)
     } else {
@@ -303,6 +370,8 @@ pub fn create_matrix_transport_cost(costs: Vec<MatrixData>) -> Result<Arc<dyn Tr
 struct TimeAgnosticMatrixTransportCost {
     durations: Vec<Vec<Duration>>,
     distances: Vec<Vec<Distance>>,
+    extra_costs: Vec<Option<Vec<Cost>>>,
+    duration_variances: Vec<Option<Vec<f64>>>,
     size: usize,
 }
 
@@ -320,14 +389,17 @@ impl TimeAgnosticMatrixTransportCost {
             return Err("duplicate profiles can be passed only for time aware routing".to_string());
         }
 
-        let (durations, distances) = costs.into_iter().fold((vec![], vec![]), |mut acc, data| {
-            acc.0.push(data.durations);
-            acc.1.push(data.distances);
+        let (durations, distances, extra_costs, duration_variances) =
+            costs.into_iter().fold((vec![], vec![], vec![], vec![]), |mut acc, data| {
+                acc.0.push(data.durations);
+                acc.1.push(data.distances);
+                acc.2.push(data.extra_costs);
+                acc.3.push(data.duration_variances);
 
-            acc
-        });
+                acc
+            });
 
-        Ok(Self { durations, distances, size })
+        Ok(Self { durations, distances, extra_costs, duration_variances, size })
     }
 }
 
@@ -347,11 +419,33 @@ impl TransportCost for TimeAgnosticMatrixTransportCost {
     fn distance(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
         self.distance_approx(&route.actor.vehicle.profile, from, to)
     }
+
+    fn extra_cost(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Cost {
+        self.extra_costs
+            .get(route.actor.vehicle.profile.index)
+            .and_then(|costs| costs.as_ref())
+            .map_or(Cost::default(), |costs| *costs.get(from * self.size + to).unwrap())
+    }
+
+    fn duration_variance(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> f64 {
+        let profile = &route.actor.vehicle.profile;
+
+        self.duration_variances
+            .get(profile.index)
+            .and_then(|variances| variances.as_ref())
+            .map_or(0., |variances| *variances.get(from * self.size + to).unwrap() * profile.scale * profile.scale)
+    }
 }
 
 /// A time aware matrix costs.
+///
+/// Each matrix defines travel info which is applicable starting from its timestamp till the timestamp
+/// of the next one (the first matrix is also used before its timestamp). Travel duration is calculated
+/// by integrating travel speed over these time buckets, so the vehicle which departs later never arrives
+/// earlier (FIFO property). Travel duration for the given arrival time is calculated exactly by performing
+/// the same integration backward in time.
 struct TimeAwareMatrixTransportCost {
-    costs: HashMap<usize, (Vec<u64>, Vec<MatrixData>)>,
+    costs: HashMap<usize, (Vec<Timestamp>, Vec<MatrixData>)>,
     size: usize,
 }
 
@@ -371,8 +465,8 @@ impl TimeAwareMatrixTransportCost {
         let costs = costs
             .into_iter()
             .map(|(profile, mut matrices)| {
-                matrices.sort_by(|a, b| (a.timestamp.unwrap() as u64).cmp(&(b.timestamp.unwrap() as u64)));
-                let timestamps = matrices.iter().map(|matrix| matrix.timestamp.unwrap() as u64).collect();
+                matrices.sort_by(|a, b| compare_floats(a.timestamp.unwrap(), b.timestamp.unwrap()));
+                let timestamps = matrices.iter().map(|matrix| matrix.timestamp.unwrap()).collect();
 
                 (profile, (timestamps, matrices))
             })
@@ -381,85 +475,121 @@ impl TimeAwareMatrixTransportCost {
         Ok(Self { costs, size })
     }
 
-    fn interpolate_duration(
-        &self,
-        profile: &Profile,
-        from: Location,
-        to: Location,
-        travel_time: TravelTime,
-    ) -> Duration {
-        let timestamp = match travel_time {
-            TravelTime::Arrival(arrival) => arrival,
-            TravelTime::Departure(departure) => departure,
-        };
-
+    /// Returns matrix applicable at given travel time.
+    fn get_matrix(&self, profile: &Profile, travel_time: TravelTime) -> &MatrixData {
         let (timestamps, matrices) = self.costs.get(&profile.index).unwrap();
-        let data_idx = from * self.size + to;
-
-        profile.scale
-            * match timestamps.binary_search(&(timestamp as u64)) {
-                Ok(matrix_idx) => *matrices.get(matrix_idx).unwrap().durations.get(data_idx).unwrap(),
-                Err(matrix_idx) if matrix_idx == 0 => *matrices.first().unwrap().durations.get(data_idx).unwrap(),
-                Err(matrix_idx) if matrix_idx == matrices.len() => {
-                    *matrices.last().unwrap().durations.get(data_idx).unwrap()
-                }
-                Err(matrix_idx) => {
-                    let left_matrix = matrices.get(matrix_idx - 1).unwrap();
-                    let right_matrix = matrices.get(matrix_idx).unwrap();
-
-                    let left_value = *matrices.get(matrix_idx - 1).unwrap().durations.get(data_idx).unwrap();
-                    let right_value = *matrices.get(matrix_idx).unwrap().durations.get(data_idx).unwrap();
-
-                    // perform linear interpolation
-                    let ratio = (timestamp - left_matrix.timestamp.unwrap())
-                        / (right_matrix.timestamp.unwrap() - left_matrix.timestamp.unwrap());
+        let matrix_idx = match travel_time {
+            TravelTime::Departure(departure) => timestamps.partition_point(|&timestamp| timestamp <= departure),
+            TravelTime::Arrival(arrival) => timestamps.partition_point(|&timestamp| timestamp < arrival),
+        }
+        .max(1)
+            - 1;
 
-                    left_value + ratio * (right_value - left_value)
-                }
-            }
+        matrices.get(matrix_idx).unwrap()
     }
 
-    fn interpolate_distance(
+    /// Integrates travel over time buckets and returns travel duration and distance.
+    fn integrate_travel(
         &self,
         profile: &Profile,
         from: Location,
         to: Location,
         travel_time: TravelTime,
-    ) -> Distance {
-        let timestamp = match travel_time {
-            TravelTime::Arrival(arrival) => arrival,
-            TravelTime::Departure(departure) => departure,
-        };
-
+    ) -> (Duration, Distance) {
         let (timestamps, matrices) = self.costs.get(&profile.index).unwrap();
         let data_idx = from * self.size + to;
 
-        match timestamps.binary_search(&(timestamp as u64)) {
-            Ok(matrix_idx) => *matrices.get(matrix_idx).unwrap().distances.get(data_idx).unwrap(),
-            Err(matrix_idx) if matrix_idx == 0 => *matrices.first().unwrap().distances.get(data_idx).unwrap(),
-            Err(matrix_idx) if matrix_idx == matrices.len() => {
-                *matrices.last().unwrap().distances.get(data_idx).unwrap()
+        let get_leg_info = |matrix_idx: usize| {
+            let matrix = matrices.get(matrix_idx).unwrap();
+            (*matrix.durations.get(data_idx).unwrap() * profile.scale, *matrix.distances.get(data_idx).unwrap())
+        };
+
+        // NOTE progress is a traveled fraction of the leg: each bucket defines its own progress rate, so
+        // travel time is a strictly increasing function of departure time.
+        let (mut progress, mut distance) = (0., 0.);
+
+        match travel_time {
+            TravelTime::Departure(departure) => {
+                let mut time = departure;
+                let mut matrix_idx = timestamps.partition_point(|&timestamp| timestamp <= time).max(1) - 1;
+
+                loop {
+                    let (bucket_duration, bucket_distance) = get_leg_info(matrix_idx);
+                    let remaining = (1. - progress) * bucket_duration;
+
+                    match timestamps.get(matrix_idx + 1) {
+                        Some(&bucket_end) if time + remaining > bucket_end => {
+                            let ratio = (bucket_end - time) / bucket_duration;
+                            progress += ratio;
+                            distance += ratio * bucket_distance;
+                            time = bucket_end;
+                            matrix_idx += 1;
+                        }
+                        _ => {
+                            distance += (1. - progress) * bucket_distance;
+                            return (time + remaining - departure, distance);
+                        }
+                    }
+                }
+            }
+            TravelTime::Arrival(arrival) => {
+                let mut time = arrival;
+                let mut matrix_idx = timestamps.partition_point(|&timestamp| timestamp < time).max(1) - 1;
+
+                loop {
+                    let (bucket_duration, bucket_distance) = get_leg_info(matrix_idx);
+                    let remaining = (1. - progress) * bucket_duration;
+                    let bucket_start = *timestamps.get(matrix_idx).unwrap();
+
+                    if matrix_idx > 0 && time - remaining < bucket_start {
+                        let ratio = (time - bucket_start) / bucket_duration;
+                        progress += ratio;
+                        distance += ratio * bucket_distance;
+                        time = bucket_start;
+                        matrix_idx -= 1;
+                    } else {
+                        distance += (1. - progress) * bucket_distance;
+                        return (arrival - (time - remaining), distance);
+                    }
+                }
             }
-            Err(matrix_idx) => *matrices.get(matrix_idx - 1).unwrap().distances.get(data_idx).unwrap(),
         }
     }
 }
 
 impl TransportCost for TimeAwareMatrixTransportCost {
     fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
-        self.interpolate_duration(profile, from, to, TravelTime::Departure(0.))
+        self.integrate_travel(profile, from, to, TravelTime::Departure(0.)).0
     }
 
     fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
-        self.interpolate_distance(profile, from, to, TravelTime::Departure(0.))
+        self.integrate_travel(profile, from, to, TravelTime::Departure(0.)).1
     }
 
     fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
-        self.interpolate_duration(&route.actor.vehicle.profile, from, to, travel_time)
+        self.integrate_travel(&route.actor.vehicle.profile, from, to, travel_time).0
     }
 
     fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
-        self.interpolate_distance(&route.actor.vehicle.profile, from, to, travel_time)
+        self.integrate_travel(&route.actor.vehicle.profile, from, to, travel_time).1
+    }
+
+    fn extra_cost(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Cost {
+        // NOTE extra cost is not integrated over time buckets: the one applicable at travel time is used
+        self.get_matrix(&route.actor.vehicle.profile, travel_time)
+            .extra_costs
+            .as_ref()
+            .map_or(Cost::default(), |costs| *costs.get(from * self.size + to).unwrap())
+    }
+
+    fn duration_variance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> f64 {
+        // NOTE similar to extra cost, variance is taken from the matrix applicable at travel time
+        let profile = &route.actor.vehicle.profile;
+
+        self.get_matrix(profile, travel_time)
+            .duration_variances
+            .as_ref()
+            .map_or(0., |variances| *variances.get(from * self.size + to).unwrap() * profile.scale * profile.scale)
     }
 }
 
@@ -23,15 +23,17 @@ pub struct Costs {
     pub per_service_time: f64,
 }
 
-/// Represents driver detail (reserved for future use).
+/// Represents driver detail (driver shift).
 #[derive(Clone, Hash, Eq, PartialEq)]
-pub struct DriverDetail {}
+pub struct DriverDetail {
+    /// Time window when driver is available.
+    pub time: TimeWindow,
+}
 
 /// Represents a driver, person who drives Vehicle.
 /// Introduced to allow the following scenarios:
 /// * reuse vehicle multiple times with different drivers
 /// * solve best driver-vehicle match problem.
-/// NOTE: At the moment, it is not used.
 pub struct Driver {
     /// Specifies operating costs for driver.
     pub costs: Costs,
@@ -39,7 +41,9 @@ pub struct Driver {
     /// Dimensions which contains extra work requirements.
     pub dimens: Dimensions,
 
-    /// Specifies driver details.
+    /// Specifies driver details. A driver with at least one detail is a limited resource: it can
+    /// be assigned to only one vehicle at the same time. A driver without details is not limited
+    /// and can be paired with any vehicle (e.g. default driver).
     pub details: Vec<DriverDetail>,
 }
 
@@ -53,6 +57,18 @@ pub struct VehiclePlace {
     pub time: TimeInterval,
 }
 
+/// Specifies a link between two consecutive vehicle shifts: vehicle is off duty within link time
+/// and continues the tour on the next shift from the place where it spent it.
+#[derive(Clone, Hash, Eq, PartialEq)]
+pub struct VehicleLink {
+    /// A location where vehicle spends link time, e.g. a hotel. When omitted, vehicle stays
+    /// where it stopped.
+    pub location: Option<Location>,
+
+    /// A time when vehicle is off duty.
+    pub time: TimeWindow,
+}
+
 /// Represents a vehicle detail (vehicle shift).
 #[derive(Clone, Hash, Eq, PartialEq)]
 pub struct VehicleDetail {
@@ -61,6 +77,9 @@ pub struct VehicleDetail {
 
     /// A place where vehicle ends.
     pub end: Option<VehiclePlace>,
+
+    /// Links between shifts joined into the detail, ordered by time. Empty for a single shift.
+    pub links: Vec<VehicleLink>,
 }
 
 /// Represents a vehicle.
@@ -89,6 +108,9 @@ pub struct ActorDetail {
 
     /// Time window when actor allowed to work.
     pub time: TimeWindow,
+
+    /// Links between shifts of actor's vehicle within actor's time.
+    pub links: Vec<VehicleLink>,
 }
 
 /// Represents an actor.
@@ -127,8 +149,7 @@ pub struct Fleet {
 impl Fleet {
     /// Creates a new instance of `Fleet`.
     pub fn new(drivers: Vec<Arc<Driver>>, vehicles: Vec<Arc<Vehicle>>, group_key: ActorGroupKeyFn) -> Fleet {
-        // TODO we should also consider multiple drivers to support smart vehicle-driver assignment.
-        assert_eq!(drivers.len(), 1);
+        assert!(!drivers.is_empty());
         assert!(!vehicles.is_empty());
 
         let profiles: HashMap<usize, Profile> = vehicles.iter().map(|v| (v.profile.index, v.profile.clone())).collect();
@@ -139,18 +160,20 @@ impl Fleet {
         let mut actors: Vec<Arc<Actor>> = Default::default();
         vehicles.iter().for_each(|vehicle| {
             vehicle.details.iter().for_each(|detail| {
-                actors.push(Arc::new(Actor {
-                    vehicle: vehicle.clone(),
-                    driver: drivers.first().unwrap().clone(),
-                    detail: ActorDetail {
-                        start: detail.start.clone(),
-                        end: detail.end.clone(),
-                        time: TimeWindow {
-                            start: detail.start.as_ref().and_then(|s| s.time.earliest).unwrap_or(0.),
-                            end: detail.end.as_ref().and_then(|e| e.time.latest).unwrap_or(f64::MAX),
-                        },
-                    },
-                }));
+                drivers.iter().for_each(|driver| {
+                    let driver_details = if driver.details.is_empty() {
+                        vec![None]
+                    } else {
+                        driver.details.iter().map(Some).collect::<Vec<_>>()
+                    };
+
+                    actors.extend(
+                        driver_details
+                            .into_iter()
+                            .filter_map(|driver_detail| create_actor_detail(detail, driver_detail))
+                            .map(|detail| Arc::new(Actor { vehicle: vehicle.clone(), driver: driver.clone(), detail })),
+                    );
+                });
             });
         });
 
@@ -164,6 +187,43 @@ impl Fleet {
     }
 }
 
+/// Creates actor detail from vehicle detail restricting it by driver's availability if specified.
+/// Returns `None` if vehicle and driver cannot work together due to time constraints.
+fn create_actor_detail(vehicle_detail: &VehicleDetail, driver_detail: Option<&DriverDetail>) -> Option<ActorDetail> {
+    let vehicle_time = TimeWindow {
+        start: vehicle_detail.start.as_ref().and_then(|s| s.time.earliest).unwrap_or(0.),
+        end: vehicle_detail.end.as_ref().and_then(|e| e.time.latest).unwrap_or(f64::MAX),
+    };
+
+    let driver_detail = match driver_detail {
+        Some(driver_detail) => driver_detail,
+        None => {
+            return Some(ActorDetail {
+                start: vehicle_detail.start.clone(),
+                end: vehicle_detail.end.clone(),
+                time: vehicle_time,
+                links: vehicle_detail.links.clone(),
+            })
+        }
+    };
+
+    let time = vehicle_time.overlapping(&driver_detail.time)?;
+
+    let start = vehicle_detail.start.as_ref().map(|start| VehiclePlace {
+        location: start.location,
+        time: TimeInterval { earliest: Some(time.start), latest: start.time.latest.map(|latest| latest.min(time.end)) },
+    });
+
+    let end = vehicle_detail.end.as_ref().map(|end| VehiclePlace {
+        location: end.location,
+        time: TimeInterval { earliest: end.time.earliest, latest: Some(time.end) },
+    });
+
+    let links = vehicle_detail.links.iter().filter(|link| link.time.intersects(&time)).cloned().collect();
+
+    Some(ActorDetail { start, end, time, links })
+}
+
 impl PartialEq<Actor> for Actor {
     fn eq(&self, other: &Actor) -> bool {
         std::ptr::eq(&*self, &*other)
@@ -2,19 +2,31 @@
 #[path = "../../../tests/unit/models/solution/actor_test.rs"]
 mod actor_test;
 
+use crate::models::common::IdDimension;
 use crate::models::problem::{Actor, Fleet};
 use hashbrown::{HashMap, HashSet};
 use rosomaxa::prelude::Random;
+use rosomaxa::utils::CollectGroupBy;
 use std::sync::Arc;
 
 /// Specifies an entity responsible for providing actors and keeping track of their usage.
+/// Actors which share the same vehicle with different drivers or the same limited driver within
+/// intersecting time cannot be used simultaneously.
 pub struct Registry {
     available: HashMap<usize, HashSet<Arc<Actor>>>,
     index: HashMap<Arc<Actor>, usize>,
     all: Vec<Arc<Actor>>,
+    by_vehicle: Arc<ActorIndex>,
+    by_driver: Arc<ActorIndex>,
+    used: HashSet<Arc<Actor>>,
+    used_vehicles: HashMap<String, HashSet<Arc<Actor>>>,
+    used_drivers: HashMap<String, HashSet<Arc<Actor>>>,
     random: Arc<dyn Random + Send + Sync>,
 }
 
+/// Keeps actors grouped by their vehicle or limited driver id.
+type ActorIndex = HashMap<String, Vec<Arc<Actor>>>;
+
 impl Registry {
     /// Creates a new instance of `Registry`
     pub fn new(fleet: &Fleet, random: Arc<dyn Random + Send + Sync>) -> Self {
@@ -24,19 +36,69 @@ impl Registry {
             .flat_map(|(group_id, actors)| actors.iter().map(|a| (a.clone(), *group_id)).collect::<Vec<_>>())
             .collect();
 
-        Self { available: fleet.groups.clone(), index, all: fleet.actors.to_vec(), random }
+        Self {
+            available: fleet.groups.clone(),
+            index,
+            all: fleet.actors.to_vec(),
+            by_vehicle: Arc::new(fleet.actors.iter().cloned().collect_group_by_key(get_vehicle_key)),
+            by_driver: Arc::new(
+                fleet
+                    .actors
+                    .iter()
+                    .filter_map(|actor| get_driver_key(actor).map(|key| (key, actor.clone())))
+                    .collect_group_by(),
+            ),
+            used: Default::default(),
+            used_vehicles: Default::default(),
+            used_drivers: Default::default(),
+            random,
+        }
     }
 
-    /// Removes an actor from the list of available actors.
+    /// Removes an actor and all actors in conflict with it from the list of available actors.
     /// Returns whether the actor was present in the registry.
     pub fn use_actor(&mut self, actor: &Arc<Actor>) -> bool {
-        self.available.get_mut(self.index.get(actor).unwrap()).unwrap().remove(actor)
+        let is_present = self.available.get_mut(self.index.get(actor).unwrap()).unwrap().remove(actor);
+
+        if self.used.insert(actor.clone()) {
+            self.used_vehicles.entry(get_vehicle_key(actor)).or_default().insert(actor.clone());
+            if let Some(key) = get_driver_key(actor) {
+                self.used_drivers.entry(key).or_default().insert(actor.clone());
+            }
+
+            let (by_vehicle, by_driver) = (self.by_vehicle.clone(), self.by_driver.clone());
+            get_candidates(by_vehicle.as_ref(), by_driver.as_ref(), actor)
+                .filter(|candidate| is_conflict(actor, candidate))
+                .for_each(|conflict| {
+                    if let Some(group) = self.index.get(conflict) {
+                        self.available.get_mut(group).unwrap().remove(conflict);
+                    }
+                });
+        }
+
+        is_present
     }
 
-    /// Adds actor to the list of available actors.
+    /// Adds actor and actors released by it to the list of available actors.
     /// Returns whether the actor was not present in the registry.
     pub fn free_actor(&mut self, actor: &Arc<Actor>) -> bool {
-        self.available.get_mut(self.index.get(actor).unwrap()).unwrap().insert(actor.clone())
+        if self.used.remove(actor) {
+            if let Some(used) = self.used_vehicles.get_mut(&get_vehicle_key(actor)) {
+                used.remove(actor);
+            }
+            if let Some(used) = get_driver_key(actor).and_then(|key| self.used_drivers.get_mut(&key)) {
+                used.remove(actor);
+            }
+        }
+
+        let (by_vehicle, by_driver) = (self.by_vehicle.clone(), self.by_driver.clone());
+        get_candidates(by_vehicle.as_ref(), by_driver.as_ref(), actor)
+            .filter(|candidate| is_conflict(actor, candidate))
+            .for_each(|conflict| {
+                self.try_release(conflict);
+            });
+
+        self.try_release(actor)
     }
 
     /// Returns all actors.
@@ -64,6 +126,11 @@ impl Registry {
             available: self.available.clone(),
             index: self.index.clone(),
             all: self.all.clone(),
+            by_vehicle: self.by_vehicle.clone(),
+            by_driver: self.by_driver.clone(),
+            used: self.used.clone(),
+            used_vehicles: self.used_vehicles.clone(),
+            used_drivers: self.used_drivers.clone(),
             random: self.random.clone(),
         }
     }
@@ -86,7 +153,65 @@ impl Registry {
                 .map(|(actor, idx)| (actor.clone(), *idx))
                 .collect(),
             all: self.all.iter().filter(|actor| filter(actor.as_ref())).cloned().collect(),
+            by_vehicle: self.by_vehicle.clone(),
+            by_driver: self.by_driver.clone(),
+            used: self.used.clone(),
+            used_vehicles: self.used_vehicles.clone(),
+            used_drivers: self.used_drivers.clone(),
             random: self.random.clone(),
         }
     }
+
+    /// Makes actor available if it is known, not used and has no used actors in conflict.
+    fn try_release(&mut self, actor: &Arc<Actor>) -> bool {
+        let has_conflict = |used: Option<&HashSet<Arc<Actor>>>| {
+            used.iter().flat_map(|used| used.iter()).any(|other| is_conflict(other, actor))
+        };
+
+        let is_blocked = self.used.contains(actor)
+            || has_conflict(self.used_vehicles.get(&get_vehicle_key(actor)))
+            || has_conflict(get_driver_key(actor).and_then(|key| self.used_drivers.get(&key)));
+
+        match (is_blocked, self.index.get(actor)) {
+            (false, Some(group)) => self.available.get_mut(group).unwrap().insert(actor.clone()),
+            _ => false,
+        }
+    }
+}
+
+/// Returns actors which share the same vehicle or limited driver with given actor.
+fn get_candidates<'a>(
+    by_vehicle: &'a ActorIndex,
+    by_driver: &'a ActorIndex,
+    actor: &Arc<Actor>,
+) -> impl Iterator<Item = &'a Arc<Actor>> + 'a {
+    let vehicle_actors = by_vehicle.get(&get_vehicle_key(actor)).into_iter().flatten();
+    let driver_actors = get_driver_key(actor).and_then(|key| by_driver.get(&key)).into_iter().flatten();
+
+    vehicle_actors.chain(driver_actors)
+}
+
+/// Checks whether two actors cannot be used together at the same time: they share the same vehicle
+/// with different drivers or the same limited driver within intersecting time.
+fn is_conflict(a: &Actor, b: &Actor) -> bool {
+    let is_same_vehicle = Arc::ptr_eq(&a.vehicle, &b.vehicle);
+    let is_same_driver = Arc::ptr_eq(&a.driver, &b.driver);
+    let is_limited_driver = is_same_driver && !a.driver.details.is_empty();
+
+    a != b && a.detail.time.intersects(&b.detail.time) && ((is_same_vehicle && !is_same_driver) || is_limited_driver)
+}
+
+/// Returns vehicle id as index key. Vehicles without id share the same key, this is fine as
+/// actual conflict is always checked by `is_conflict`.
+fn get_vehicle_key(actor: &Arc<Actor>) -> String {
+    actor.vehicle.dimens.get_id().cloned().unwrap_or_default()
+}
+
+/// Returns driver id as index key if driver is limited.
+fn get_driver_key(actor: &Arc<Actor>) -> Option<String> {
+    if actor.driver.details.is_empty() {
+        None
+    } else {
+        Some(actor.driver.dimens.get_id().cloned().unwrap_or_default())
+    }
 }
@@ -32,6 +32,12 @@ pub struct CommuteInfo {
 /// Specifies activity place.
 #[derive(Clone, Debug)]
 pub struct Place {
+    /// An index of the place in the original job definition.
+    pub idx: usize,
+
+    /// An index of the time window in the original job's place definition.
+    pub time_idx: usize,
+
     /// Location where activity is performed.
     pub location: Location,
 
@@ -78,7 +84,13 @@ impl Activity {
     /// Creates an activity with a job.
     pub fn new_with_job(job: Arc<Single>) -> Self {
         Activity {
-            place: Place { location: 0, duration: 0.0, time: TimeWindow { start: 0.0, end: f64::MAX } },
+            place: Place {
+                idx: 0,
+                time_idx: 0,
+                location: 0,
+                duration: 0.0,
+                time: TimeWindow { start: 0.0, end: f64::MAX },
+            },
             schedule: Schedule { arrival: 0.0, departure: 0.0 },
             job: Some(job),
             commute: None,
@@ -89,6 +101,8 @@ impl Activity {
     pub fn deep_copy(&self) -> Self {
         Self {
             place: Place {
+                idx: self.place.idx,
+                time_idx: self.place.time_idx,
                 location: self.place.location,
                 duration: self.place.duration,
                 time: self.place.time.clone(),
@@ -2,7 +2,7 @@
 #[path = "../../../tests/unit/models/solution/tour_test.rs"]
 mod tour_test;
 
-use crate::models::common::Schedule;
+use crate::models::common::{Location, Schedule};
 use crate::models::problem::{Actor, Job};
 use crate::models::solution::{Activity, Place};
 use crate::models::OP_START_MSG;
@@ -13,6 +13,19 @@ use std::slice::{Iter, IterMut};
 /// A tour leg.
 pub type Leg<'a> = (&'a [Activity], usize);
 
+/// Represents a link between two consecutive vehicle shifts scheduled in the tour.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TourLink {
+    /// Index of activity from which vehicle departs on the leg with the link.
+    pub leg_index: usize,
+
+    /// A location where vehicle spends link time.
+    pub location: Location,
+
+    /// Arrival to the link location and departure from it.
+    pub schedule: Schedule,
+}
+
 /// Represents a tour, a smart container for jobs with their associated activities.
 #[derive(Default)]
 pub struct Tour {
@@ -24,6 +37,9 @@ pub struct Tour {
 
     /// Keeps track whether tour is set as closed.
     is_closed: bool,
+
+    /// Stores links between vehicle shifts in the order they are scheduled.
+    links: Vec<TourLink>,
 }
 
 impl Tour {
@@ -129,6 +145,21 @@ impl Tour {
         }
     }
 
+    /// Sets links between vehicle shifts. Similar to activity schedules, links are kept by a
+    /// constraint which schedules the tour and they are not updated when tour is changed.
+    pub fn set_links(&mut self, links: Vec<TourLink>) -> &mut Tour {
+        assert!(links.windows(2).all(|links| links[0].leg_index <= links[1].leg_index));
+        assert!(links.iter().all(|link| link.leg_index < self.activities.len()));
+        self.links = links;
+
+        self
+    }
+
+    /// Returns links between vehicle shifts scheduled in the tour.
+    pub fn links(&self) -> Iter<TourLink> {
+        self.links.iter()
+    }
+
     /// Returns all jobs.
     pub fn jobs(&'_ self) -> impl Iterator<Item = Job> + '_ {
         self.jobs.iter().cloned()
@@ -194,6 +225,7 @@ impl Tour {
             activities: self.activities.iter().map(|a| a.deep_copy()).collect(),
             jobs: self.jobs.clone(),
             is_closed: self.is_closed,
+            links: self.links.clone(),
         }
     }
 }
@@ -205,7 +237,7 @@ fn create_start_activity(actor: &Actor) -> Activity {
 
     Activity {
         schedule: Schedule { arrival: time.start, departure: time.start },
-        place: Place { location: start.location, duration: 0.0, time },
+        place: Place { idx: 0, time_idx: 0, location: start.location, duration: 0.0, time },
         job: None,
         commute: None,
     }
@@ -217,7 +249,7 @@ fn create_end_activity(actor: &Actor) -> Option<Activity> {
         let time = place.time.to_time_window();
         Activity {
             schedule: Schedule { arrival: time.start, departure: time.start },
-            place: Place { location: place.location, duration: 0.0, time },
+            place: Place { idx: 0, time_idx: 0, location: place.location, duration: 0.0, time },
             job: None,
             commute: None,
         }
@@ -192,6 +192,7 @@ pub fn create_context_operator_probability(
 
 pub use self::builder::create_default_init_operators;
 pub use self::builder::create_default_processing;
+pub use self::statik::create_default_crossover_operator;
 pub use self::statik::create_default_heuristic_operator;
 pub use self::statik::create_default_random_ruin;
 
@@ -219,6 +220,7 @@ mod builder {
             (wrap(Arc::new(RecreateWithBlinks::<SingleDimLoad>::new_with_defaults(random.clone()))), 1),
             (wrap(Arc::new(RecreateWithPerturbation::new_with_defaults(random.clone()))), 1),
             (wrap(Arc::new(RecreateWithNearestNeighbor::new(random.clone()))), 1),
+            (wrap(Arc::new(RecreateWithSplit::new_with_defaults(random.clone()))), 1),
         ]
     }
 
@@ -243,29 +245,8 @@ mod statik {
         problem: Arc<Problem>,
         environment: Arc<Environment>,
     ) -> TargetHeuristicOperator {
-        let random = environment.random.clone();
         // initialize recreate
-        let recreate = Arc::new(WeightedRecreate::new(vec![
-            (Arc::new(RecreateWithSkipBest::new(1, 2, random.clone())), 50),
-            (Arc::new(RecreateWithRegret::new(2, 3, random.clone())), 20),
-            (Arc::new(RecreateWithCheapest::new(random.clone())), 20),
-            (Arc::new(RecreateWithPerturbation::new_with_defaults(random.clone())), 10),
-            (Arc::new(RecreateWithSkipBest::new(3, 4, random.clone())), 5),
-            (Arc::new(RecreateWithGaps::new(2, 20, random.clone())), 5),
-            // TODO use dimension size from problem
-            (Arc::new(RecreateWithBlinks::<SingleDimLoad>::new_with_defaults(random.clone())), 5),
-            (Arc::new(RecreateWithFarthest::new(random.clone())), 2),
-            (Arc::new(RecreateWithSkipBest::new(4, 8, random.clone())), 2),
-            (Arc::new(RecreateWithNearestNeighbor::new(random.clone())), 1),
-            (Arc::new(RecreateWithSlice::new(random.clone())), 1),
-            (
-                Arc::new(RecreateWithSkipRandom::default_explorative_phased(
-                    Arc::new(RecreateWithCheapest::new(random.clone())),
-                    random.clone(),
-                )),
-                1,
-            ),
-        ]));
+        let recreate = create_default_recreate(environment.random.clone());
 
         // initialize ruin
         let close_route = Arc::new(CloseRouteRemoval::default());
@@ -303,6 +284,38 @@ mod statik {
         ]))
     }
 
+    /// Creates default crossover operator with default parameters.
+    pub fn create_default_crossover_operator(environment: Arc<Environment>) -> TargetHeuristicOperator {
+        Arc::new(CrossoverSearch::new(
+            Arc::new(WeightedCrossover::default()),
+            create_default_recreate(environment.random.clone()),
+        ))
+    }
+
+    fn create_default_recreate(random: Arc<dyn Random + Send + Sync>) -> Arc<dyn Recreate + Send + Sync> {
+        Arc::new(WeightedRecreate::new(vec![
+            (Arc::new(RecreateWithSkipBest::new(1, 2, random.clone())), 50),
+            (Arc::new(RecreateWithRegret::new(2, 3, random.clone())), 20),
+            (Arc::new(RecreateWithCheapest::new(random.clone())), 20),
+            (Arc::new(RecreateWithPerturbation::new_with_defaults(random.clone())), 10),
+            (Arc::new(RecreateWithSkipBest::new(3, 4, random.clone())), 5),
+            (Arc::new(RecreateWithGaps::new(2, 20, random.clone())), 5),
+            // TODO use dimension size from problem
+            (Arc::new(RecreateWithBlinks::<SingleDimLoad>::new_with_defaults(random.clone())), 5),
+            (Arc::new(RecreateWithFarthest::new(random.clone())), 2),
+            (Arc::new(RecreateWithSkipBest::new(4, 8, random.clone())), 2),
+            (Arc::new(RecreateWithNearestNeighbor::new(random.clone())), 1),
+            (Arc::new(RecreateWithSlice::new(random.clone())), 1),
+            (
+                Arc::new(RecreateWithSkipRandom::default_explorative_phased(
+                    Arc::new(RecreateWithCheapest::new(random.clone())),
+                    random.clone(),
+                )),
+                1,
+            ),
+        ]))
+    }
+
     /// Creates default local search operator.
     pub fn create_default_local_search(environment: Arc<Environment>) -> TargetHeuristicOperator {
         let random = environment.random.clone();
@@ -390,6 +403,7 @@ mod dynamic {
             ))
             .collect::<Vec<_>>();
 
+        let crossover = statik::create_default_crossover_operator(environment.clone());
         let inner_search = statik::create_default_heuristic_operator(problem, environment);
 
         let mutations: Vec<(TargetHeuristicOperator, String)> = vec![
@@ -406,6 +420,10 @@ mod dynamic {
                 "local_exch_intra_route_random".to_string(),
             ),
             (Arc::new(LocalSearch::new(Arc::new(ExchangeSequence::default()))), "local_exch_sequence".to_string()),
+            (Arc::new(LocalSearch::new(Arc::new(ExchangeTwoOpt::default()))), "local_exch_two_opt".to_string()),
+            (Arc::new(LocalSearch::new(Arc::new(ExchangeOrOpt::default()))), "local_exch_or_opt".to_string()),
+            (Arc::new(LocalSearch::new(Arc::new(ExchangeThreeOpt::default()))), "local_exch_three_opt".to_string()),
+            (Arc::new(LocalSearch::new(Arc::new(ExchangeCross::default()))), "local_exch_cross".to_string()),
             (
                 Arc::new(LocalSearch::new(Arc::new(RescheduleDeparture::default()))),
                 "local_reschedule_departure".to_string(),
@@ -419,6 +437,7 @@ mod dynamic {
                 Arc::new(LocalSearch::new(Arc::new(ExchangeSwapStar::new(random.clone())))),
                 "local_swap_star".to_string(),
             ),
+            (crossover, "crossover".to_string()),
         ];
 
         recreates
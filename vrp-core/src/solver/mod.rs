@@ -56,15 +56,22 @@
 //!        chances to be selected.
 //!     - **mutation**: a mutation operator is applied to selected individual. Default implementation
 //!       uses `ruin and recreate` principle described in next section.
+//!     - **recombination**: occasionally, selected individual is combined with a mate picked from
+//!       population using a crossover operator.
 //!     - **population adjustments**: new individual is added to population, then the population is
 //!       sorted and shrinked to keep it under specific size limits with best-fit individuals and
 //!       some intermediate.
 //! - **main loop end**: exit evolution loop when one of termination criteria are met. See [`termination`]
 //!       module for details.
 //!
-//! As there is no crossover operator involved and offspring is produced from one parent, this algorithm
-//! can be characterized as parthenogenesis based EA. This approach eliminates design of feasible
-//! crossover operator which is a challenging task in case of VRP.
+//! As offspring is mostly produced from one parent, this algorithm can be characterized as
+//! parthenogenesis based EA. This approach eliminates design of feasible crossover operator which
+//! is a challenging task in case of VRP. However, crossover operators are used alongside: an
+//! offspring produced by Selective Route Exchange (SREX) or Order Crossover (OX) is repaired using
+//! recreate methods. When rosomaxa population is used, mates are picked from different nodes of its
+//! network in order to exploit diversity of the population. See [`crossover`] module for details.
+//!
+//!  [`crossover`]: search/index.html
 //!
 //!  [`termination`]: termination/index.html
 //!
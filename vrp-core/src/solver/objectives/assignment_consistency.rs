@@ -0,0 +1,113 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/assignment_consistency_test.rs"]
+mod assignment_consistency_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::*;
+use crate::models::common::Cost;
+use crate::models::problem::*;
+use crate::models::Lock;
+use hashbrown::HashMap;
+use rosomaxa::prelude::*;
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+
+type LockCondition = Arc<dyn Fn(&Actor) -> bool + Sync + Send>;
+
+/// Allows to keep jobs assigned to the same actors as specified by locks, e.g. to serve recurring
+/// customers by the same driver across several plans. In contrast to strict locking, locks are soft:
+/// a job can be assigned to another actor, but such reassignment is penalized. Only lock conditions
+/// are considered: lock order and position are ignored.
+pub struct AssignmentConsistency {}
+
+impl AssignmentConsistency {
+    /// Creates a soft constraint and an objective which minimizes amount of reassigned jobs.
+    pub fn new_minimized(locks: &[Arc<Lock>]) -> (TargetConstraint, TargetObjective) {
+        let conditions = Arc::new(locks.iter().fold(HashMap::new(), |mut acc, lock| {
+            lock.details.iter().flat_map(|detail| detail.jobs.iter()).for_each(|job| {
+                acc.insert(job.clone(), lock.condition.clone());
+            });
+            acc
+        }));
+
+        let constraint = AssignmentConsistencyConstraint {
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(AssignmentConsistencySoftRouteConstraint {
+                conditions: conditions.clone(),
+            }))],
+            keys: vec![],
+        };
+
+        (Arc::new(constraint), Arc::new(AssignmentConsistencyObjective { conditions }))
+    }
+}
+
+struct AssignmentConsistencyConstraint {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for AssignmentConsistencyConstraint {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct AssignmentConsistencySoftRouteConstraint {
+    conditions: Arc<HashMap<Job, LockCondition>>,
+}
+
+impl SoftRouteConstraint for AssignmentConsistencySoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, route_ctx: &RouteContext, job: &Job) -> Cost {
+        if is_reassigned(self.conditions.as_ref(), route_ctx.route.actor.as_ref(), job) {
+            let max_cost = route_ctx.get_route_cost();
+            if compare_floats(max_cost, 0.) == Ordering::Equal {
+                1E9
+            } else {
+                max_cost * 2.
+            }
+        } else {
+            Cost::default()
+        }
+    }
+}
+
+struct AssignmentConsistencyObjective {
+    conditions: Arc<HashMap<Job, LockCondition>>,
+}
+
+impl Objective for AssignmentConsistencyObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        get_reassigned_jobs(self.conditions.as_ref(), solution.solution.routes.as_slice()) as f64
+    }
+}
+
+fn is_reassigned(conditions: &HashMap<Job, LockCondition>, actor: &Actor, job: &Job) -> bool {
+    conditions.get(job).map_or(false, |condition| !(condition)(actor))
+}
+
+fn get_reassigned_jobs(conditions: &HashMap<Job, LockCondition>, routes: &[RouteContext]) -> usize {
+    routes
+        .iter()
+        .map(|route_ctx| {
+            let actor = route_ctx.route.actor.as_ref();
+            route_ctx.route.tour.jobs().filter(|job| is_reassigned(conditions, actor, job)).count()
+        })
+        .sum()
+}
@@ -3,6 +3,9 @@
 use crate::construction::heuristics::InsertionContext;
 use std::cmp::Ordering;
 
+mod assignment_consistency;
+pub use self::assignment_consistency::AssignmentConsistency;
+
 mod generic_value;
 pub use self::generic_value::*;
 
@@ -3,7 +3,7 @@
 mod total_transport_test;
 
 use super::*;
-use crate::construction::constraints::{TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
+use crate::construction::constraints::{TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY, TOTAL_EMISSIONS_KEY};
 use crate::models::common::Cost;
 use crate::models::problem::TargetObjective;
 use rosomaxa::prelude::*;
@@ -40,6 +40,16 @@ impl TotalDuration {
     }
 }
 
+/// An objective function for total emissions minimization as a target.
+pub struct TotalEmissions;
+
+impl TotalEmissions {
+    /// Creates an objective to minimize total emissions calculated from load dependent vehicle costs.
+    pub fn minimize() -> TargetObjective {
+        new_with_route_state_key(TOTAL_EMISSIONS_KEY)
+    }
+}
+
 struct TotalTransport {
     fitness: Arc<dyn Fn(&InsertionContext) -> f64 + Send + Sync>,
 }
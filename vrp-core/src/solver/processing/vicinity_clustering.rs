@@ -141,6 +141,8 @@ impl HeuristicSolutionProcessing for VicinityClustering {
 
                         activities.push(Activity {
                             place: Place {
+                                idx: 0,
+                                time_idx: 0,
                                 location: place.location.unwrap(),
                                 duration: info.service_time,
                                 time: cluster_time.clone(),
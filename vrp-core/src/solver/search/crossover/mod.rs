@@ -0,0 +1,128 @@
+//! The crossover module contains various strategies to produce an offspring from two parents.
+
+use crate::construction::heuristics::*;
+use crate::models::problem::{Actor, Job};
+use crate::solver::RefinementContext;
+use hashbrown::HashSet;
+use rosomaxa::prelude::*;
+use std::sync::Arc;
+
+/// A trait which specifies logic to combine two solutions into a new one.
+pub trait Crossover {
+    /// Combines given parents into a new solution. Jobs which cannot be assigned are put into
+    /// required list, so the solution is expected to be repaired afterwards.
+    fn run(
+        &self,
+        refinement_ctx: &RefinementContext,
+        first: &InsertionContext,
+        second: &InsertionContext,
+    ) -> InsertionContext;
+}
+
+mod order_crossover;
+pub use self::order_crossover::OrderCrossover;
+
+mod selective_route_exchange;
+pub use self::selective_route_exchange::SelectiveRouteExchange;
+
+/// Provides the way to pick one crossover from the group of crossover methods.
+pub struct WeightedCrossover {
+    crossovers: Vec<Arc<dyn Crossover + Send + Sync>>,
+    weights: Vec<usize>,
+}
+
+impl WeightedCrossover {
+    /// Creates a new instance of `WeightedCrossover` using list of crossover strategies.
+    pub fn new(crossovers: Vec<(Arc<dyn Crossover + Send + Sync>, usize)>) -> Self {
+        let (crossovers, weights) = crossovers.into_iter().unzip();
+        Self { crossovers, weights }
+    }
+}
+
+impl Default for WeightedCrossover {
+    fn default() -> Self {
+        Self::new(vec![(Arc::new(SelectiveRouteExchange::default()), 2), (Arc::new(OrderCrossover::default()), 1)])
+    }
+}
+
+impl Crossover for WeightedCrossover {
+    fn run(
+        &self,
+        refinement_ctx: &RefinementContext,
+        first: &InsertionContext,
+        second: &InsertionContext,
+    ) -> InsertionContext {
+        let index = first.environment.random.weighted(self.weights.as_slice());
+        self.crossovers[index].run(refinement_ctx, first, second)
+    }
+}
+
+/// Removes given routes with all their jobs from the solution. Removed jobs are put into required list.
+fn remove_routes(insertion_ctx: &mut InsertionContext, actors: &HashSet<Arc<Actor>>) {
+    let solution = &mut insertion_ctx.solution;
+
+    let removed = solution.routes.iter().filter(|route_ctx| actors.contains(&route_ctx.route.actor)).cloned();
+    removed.collect::<Vec<_>>().into_iter().for_each(|route_ctx| {
+        solution.routes.retain(|rc| *rc != route_ctx);
+        solution.registry.free_route(&route_ctx);
+        solution.required.extend(route_ctx.route.tour.jobs());
+    });
+}
+
+/// Removes given jobs from all routes. Removed jobs are put into required list.
+fn remove_jobs(insertion_ctx: &mut InsertionContext, jobs: &HashSet<Job>) {
+    let solution = &mut insertion_ctx.solution;
+
+    solution.routes.iter_mut().for_each(|route_ctx| {
+        let removed = route_ctx.route.tour.jobs().filter(|job| jobs.contains(job)).collect::<Vec<_>>();
+        removed.into_iter().for_each(|job| {
+            route_ctx.route_mut().tour.remove(&job);
+            solution.required.push(job);
+        });
+    });
+}
+
+/// Tries to insert the job at the end of the route which belongs to given actor. If actor is not
+/// used yet, the route is taken from registry. Returns false if job cannot be inserted.
+fn insert_last(insertion_ctx: &mut InsertionContext, actor: &Arc<Actor>, job: &Job) -> bool {
+    let route_ctx = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .find(|route_ctx| route_ctx.route.actor == *actor)
+        .cloned()
+        .or_else(|| insertion_ctx.solution.registry.next_with_actor(actor.as_ref()));
+
+    let route_ctx = if let Some(route_ctx) = route_ctx { route_ctx } else { return false };
+
+    let leg_selector = VariableLegSelector::new(insertion_ctx.environment.random.clone());
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext {
+        constraint: &insertion_ctx.problem.constraint,
+        job,
+        leg_selector: &leg_selector,
+        result_selector: &result_selector,
+    };
+
+    let insertion = evaluate_job_insertion_in_route(
+        insertion_ctx,
+        &eval_ctx,
+        &route_ctx,
+        InsertionPosition::Last,
+        InsertionResult::make_failure(),
+    );
+
+    match insertion {
+        InsertionResult::Success(success) => {
+            apply_insertion_success(insertion_ctx, success);
+            true
+        }
+        InsertionResult::Failure(_) => false,
+    }
+}
+
+/// Removes duplicates from required jobs keeping their order.
+fn dedup_required(insertion_ctx: &mut InsertionContext) {
+    let mut visited = HashSet::new();
+    insertion_ctx.solution.required.retain(|job| visited.insert(job.clone()));
+}
@@ -0,0 +1,128 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/crossover/order_crossover_test.rs"]
+mod order_crossover_test;
+
+use super::*;
+
+/// A crossover strategy which implements Order Crossover (OX) for the giant-tour representation:
+/// the giant tour of the offspring keeps a random slice of the first parent's giant tour and
+/// the rest of the jobs in the order they appear in the second parent's one. The giant tour is
+/// split back into routes greedily: a new route is started once a job cannot be appended to
+/// the current one.
+#[derive(Default)]
+pub struct OrderCrossover {}
+
+impl Crossover for OrderCrossover {
+    fn run(
+        &self,
+        _refinement_ctx: &RefinementContext,
+        first: &InsertionContext,
+        second: &InsertionContext,
+    ) -> InsertionContext {
+        let mut insertion_ctx = first.deep_copy();
+
+        let locked = &insertion_ctx.solution.locked;
+        let has_locked = |route_ctx: &RouteContext| route_ctx.route.tour.jobs().any(|job| locked.contains(&job));
+
+        // NOTE routes with locked jobs are kept as they are
+        let routes = &insertion_ctx.solution.routes;
+        let kept_jobs = routes
+            .iter()
+            .filter(|route_ctx| has_locked(route_ctx))
+            .flat_map(|route_ctx| route_ctx.route.tour.jobs())
+            .collect::<HashSet<_>>();
+        let actors = routes
+            .iter()
+            .filter(|route_ctx| !has_locked(route_ctx))
+            .map(|route_ctx| route_ctx.route.actor.clone())
+            .collect::<HashSet<_>>();
+
+        let first_tour = get_giant_tour(first, &kept_jobs);
+        let second_tour = get_giant_tour(second, &kept_jobs);
+
+        if first_tour.is_empty() {
+            return insertion_ctx;
+        }
+
+        let (start, end) = get_slice_bounds(&insertion_ctx, first_tour.len());
+        let giant_tour = get_offspring_tour(first_tour.as_slice(), second_tour.as_slice(), (start, end));
+
+        let ignored = insertion_ctx.solution.ignored.iter().cloned().collect::<HashSet<_>>();
+        let giant_tour = giant_tour.into_iter().filter(|job| !ignored.contains(job)).collect::<Vec<_>>();
+
+        remove_routes(&mut insertion_ctx, &actors);
+        insertion_ctx.restore();
+
+        split_giant_tour(&mut insertion_ctx, giant_tour);
+        dedup_required(&mut insertion_ctx);
+
+        insertion_ctx.restore();
+
+        insertion_ctx
+    }
+}
+
+/// Returns jobs in the order of routes and their activities excluding given jobs.
+fn get_giant_tour(insertion_ctx: &InsertionContext, excluded: &HashSet<Job>) -> Vec<Job> {
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route.tour.jobs())
+        .filter(|job| !excluded.contains(job))
+        .collect()
+}
+
+/// Returns random slice bounds (inclusive) of the giant tour with given size.
+fn get_slice_bounds(insertion_ctx: &InsertionContext, size: usize) -> (usize, usize) {
+    let random = &insertion_ctx.environment.random;
+    let last = (size - 1) as i32;
+
+    let start = random.uniform_int(0, last) as usize;
+    let end = random.uniform_int(start as i32, last) as usize;
+
+    (start, end)
+}
+
+/// Creates a new giant tour: jobs from the slice of the first tour keep their positions, other
+/// positions are filled with jobs in the order of the second tour starting after the slice end
+/// position. Jobs which are present only in the first tour are appended at the end.
+fn get_offspring_tour(first: &[Job], second: &[Job], (start, end): (usize, usize)) -> Vec<Job> {
+    let slice = &first[start..=end];
+    let kept = slice.iter().collect::<HashSet<_>>();
+
+    let rotation = (end + 1).min(second.len());
+    let mut fillers = second[rotation..]
+        .iter()
+        .chain(second[..rotation].iter())
+        .filter(|job| !kept.contains(job))
+        .collect::<Vec<_>>();
+
+    let second = second.iter().collect::<HashSet<_>>();
+    fillers.extend(first.iter().filter(|job| !kept.contains(job) && !second.contains(job)));
+
+    let (head, tail) = fillers.split_at((first.len() - end - 1).min(fillers.len()));
+
+    tail.iter().copied().chain(slice.iter()).chain(head.iter().copied()).cloned().collect()
+}
+
+/// Splits giant tour into routes by appending jobs to the current route. When the job cannot be
+/// appended, a next available actor is used. Jobs which cannot be inserted are put into required list.
+fn split_giant_tour(insertion_ctx: &mut InsertionContext, giant_tour: Vec<Job>) {
+    let mut actor: Option<Arc<Actor>> = None;
+
+    giant_tour.into_iter().for_each(|job| {
+        let is_inserted = actor.as_ref().map_or(false, |actor| insert_last(insertion_ctx, actor, &job));
+
+        if !is_inserted {
+            let next_actors = insertion_ctx.solution.registry.next().map(|route_ctx| route_ctx.route.actor.clone());
+            let next_actors = next_actors.collect::<Vec<_>>();
+
+            actor = next_actors.into_iter().find(|actor| insert_last(insertion_ctx, actor, &job));
+
+            if actor.is_none() {
+                insertion_ctx.solution.required.push(job);
+            }
+        }
+    });
+}
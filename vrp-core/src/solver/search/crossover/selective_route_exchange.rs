@@ -0,0 +1,102 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/crossover/selective_route_exchange_test.rs"]
+mod selective_route_exchange_test;
+
+use super::*;
+use rand::prelude::SliceRandom;
+
+/// A crossover strategy which implements Selective Route Exchange (SREX): a few routes of the
+/// second parent are copied into the first one and their jobs are removed from the rest of
+/// the routes. Routes of the first parent which use the same actors are removed completely.
+pub struct SelectiveRouteExchange {
+    /// Specifies maximum amount of exchanged routes.
+    max_routes: usize,
+    /// Specifies threshold ratio of maximum exchanged routes.
+    threshold: f64,
+}
+
+impl SelectiveRouteExchange {
+    /// Creates a new instance of `SelectiveRouteExchange`.
+    pub fn new(max_routes: usize, threshold: f64) -> Self {
+        assert!(max_routes > 0);
+
+        Self { max_routes, threshold }
+    }
+}
+
+impl Default for SelectiveRouteExchange {
+    fn default() -> Self {
+        Self::new(4, 0.5)
+    }
+}
+
+impl Crossover for SelectiveRouteExchange {
+    fn run(
+        &self,
+        _refinement_ctx: &RefinementContext,
+        first: &InsertionContext,
+        second: &InsertionContext,
+    ) -> InsertionContext {
+        let mut insertion_ctx = first.deep_copy();
+        let random = insertion_ctx.environment.random.clone();
+
+        let mut routes = get_exchangeable_routes(&insertion_ctx, second);
+        if routes.is_empty() {
+            return insertion_ctx;
+        }
+
+        let max = (routes.len() as f64 * self.threshold).round().max(1.) as usize;
+        let affected = random.uniform_int(1, self.max_routes.min(max) as i32) as usize;
+
+        routes.shuffle(&mut random.get_rng());
+        routes.truncate(affected);
+
+        let ignored = insertion_ctx.solution.ignored.iter().cloned().collect::<HashSet<_>>();
+        let routes = routes
+            .into_iter()
+            .map(|(actor, jobs)| (actor, jobs.into_iter().filter(|job| !ignored.contains(job)).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+
+        let actors = routes.iter().map(|(actor, _)| actor.clone()).collect::<HashSet<_>>();
+        let jobs = routes.iter().flat_map(|(_, jobs)| jobs.iter().cloned()).collect::<HashSet<_>>();
+
+        remove_routes(&mut insertion_ctx, &actors);
+        remove_jobs(&mut insertion_ctx, &jobs);
+        dedup_required(&mut insertion_ctx);
+        insertion_ctx.restore();
+
+        routes.iter().for_each(|(actor, jobs)| {
+            jobs.iter().for_each(|job| {
+                insert_last(&mut insertion_ctx, actor, job);
+            });
+        });
+
+        insertion_ctx.restore();
+
+        insertion_ctx
+    }
+}
+
+/// Returns routes of the second parent which can be copied into the first one: neither these
+/// routes nor routes of the first parent served by the same actors have locked jobs.
+fn get_exchangeable_routes(insertion_ctx: &InsertionContext, other: &InsertionContext) -> Vec<(Arc<Actor>, Vec<Job>)> {
+    let locked = &insertion_ctx.solution.locked;
+    let has_locked = |route_ctx: &RouteContext| route_ctx.route.tour.jobs().any(|job| locked.contains(&job));
+
+    let locked_actors = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .filter(|route_ctx| has_locked(route_ctx))
+        .map(|route_ctx| route_ctx.route.actor.clone())
+        .collect::<HashSet<_>>();
+
+    other
+        .solution
+        .routes
+        .iter()
+        .filter(|route_ctx| route_ctx.route.tour.has_jobs())
+        .filter(|route_ctx| !has_locked(route_ctx) && !locked_actors.contains(&route_ctx.route.actor))
+        .map(|route_ctx| (route_ctx.route.actor.clone(), route_ctx.route.tour.jobs().collect()))
+        .collect()
+}
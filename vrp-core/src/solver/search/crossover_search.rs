@@ -0,0 +1,61 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/crossover_search_test.rs"]
+mod crossover_search_test;
+
+use super::*;
+use crate::construction::heuristics::finalize_insertion_ctx;
+use rosomaxa::prelude::*;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// A search operator which recombines given solution with a mate selected from the population.
+/// An offspring produced by crossover is repaired using recreate method.
+pub struct CrossoverSearch {
+    crossover: Arc<dyn Crossover + Send + Sync>,
+    recreate: Arc<dyn Recreate + Send + Sync>,
+}
+
+impl CrossoverSearch {
+    /// Creates a new instance of `CrossoverSearch` using given crossover and recreate methods.
+    pub fn new(crossover: Arc<dyn Crossover + Send + Sync>, recreate: Arc<dyn Recreate + Send + Sync>) -> Self {
+        Self { crossover, recreate }
+    }
+}
+
+impl HeuristicOperator for CrossoverSearch {
+    type Context = RefinementContext;
+    type Objective = ProblemObjective;
+    type Solution = InsertionContext;
+
+    fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let refinement_ctx = heuristic_ctx;
+
+        match select_mate(refinement_ctx, solution) {
+            Some(mate) => {
+                let offspring = self.crossover.run(refinement_ctx, solution, mate);
+                let mut insertion_ctx = self.recreate.run(refinement_ctx, offspring);
+
+                finalize_insertion_ctx(&mut insertion_ctx);
+
+                insertion_ctx
+            }
+            None => solution.deep_copy(),
+        }
+    }
+}
+
+/// Selects a random mate which differs from the given solution.
+fn select_mate<'a>(refinement_ctx: &'a RefinementContext, solution: &InsertionContext) -> Option<&'a InsertionContext> {
+    let fitness = solution.get_fitness().collect::<Vec<_>>();
+    let mates = refinement_ctx
+        .population()
+        .select_mates()
+        .filter(|mate| mate.get_fitness().zip(fitness.iter()).any(|(a, b)| compare_floats(a, *b) != Ordering::Equal))
+        .collect::<Vec<_>>();
+
+    if mates.is_empty() {
+        None
+    } else {
+        Some(mates[solution.environment.random.uniform_int(0, (mates.len() - 1) as i32) as usize])
+    }
+}
@@ -0,0 +1,253 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/exchange_cross_test.rs"]
+mod exchange_cross_test;
+
+use super::*;
+use crate::models::common::{Cost, Location, Timestamp};
+use crate::models::problem::Job;
+use hashbrown::HashMap;
+
+/// A local search operator which applies CROSS-exchange moves: it swaps two segments of jobs between
+/// a pair of routes. Each segment can be reversed (I-CROSS) and the first one can be empty, so the move
+/// also relocates a segment from one route to another. To keep neighbourhood small, a move is
+/// considered only when it connects a job with one of its nearest neighbors (granular search).
+pub struct ExchangeCross {
+    max_segment_size: usize,
+    max_neighbors: usize,
+    max_checks: usize,
+}
+
+impl ExchangeCross {
+    /// Creates a new instance of `ExchangeCross`.
+    pub fn new(max_segment_size: usize, max_neighbors: usize, max_checks: usize) -> Self {
+        assert!(max_segment_size > 0);
+        assert!(max_neighbors > 0);
+        assert!(max_checks > 0);
+
+        Self { max_segment_size, max_neighbors, max_checks }
+    }
+}
+
+impl Default for ExchangeCross {
+    fn default() -> Self {
+        Self::new(3, 10, 4)
+    }
+}
+
+impl LocalOperator for ExchangeCross {
+    fn explore(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+    ) -> Option<InsertionContext> {
+        let route_idx = get_route_idx_with_singles(insertion_ctx, 1)?;
+        let objective = refinement_ctx.problem.objective.as_ref();
+
+        let mut candidates = get_cross_moves(insertion_ctx, route_idx, self.max_segment_size, self.max_neighbors);
+        candidates.sort_by(|(a, _), (b, _)| compare_floats(*a, *b));
+
+        candidates.into_iter().take(self.max_checks).find_map(|(_, cross_move)| {
+            let new_ctx = reinsert_jobs(insertion_ctx, cross_move.get_route_jobs(insertion_ctx))?;
+
+            if objective.total_order(insertion_ctx, &new_ctx) == Ordering::Greater {
+                Some(new_ctx)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// Specifies a segment of route jobs: route index, index of the first job, length and reversal flag.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct CrossSegment {
+    route_idx: usize,
+    start: usize,
+    length: usize,
+    reversed: bool,
+}
+
+/// Specifies CROSS-exchange move: segments are swapped between their routes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct CrossMove(CrossSegment, CrossSegment);
+
+impl CrossMove {
+    /// Returns new job orders of both routes.
+    fn get_route_jobs(&self, insertion_ctx: &InsertionContext) -> Vec<(usize, Vec<Job>)> {
+        let CrossMove(first, second) = self;
+        let first_jobs = get_route_jobs(insertion_ctx, first.route_idx);
+        let second_jobs = get_route_jobs(insertion_ctx, second.route_idx);
+
+        vec![
+            (first.route_idx, replace_segment(first_jobs.as_slice(), first, second_jobs.as_slice(), second)),
+            (second.route_idx, replace_segment(second_jobs.as_slice(), second, first_jobs.as_slice(), first)),
+        ]
+    }
+}
+
+/// Returns CROSS-exchange moves with negative estimated cost change for given route. The first
+/// segment starts right after a job from the route, the second one starts with its neighbor.
+fn get_cross_moves(
+    insertion_ctx: &InsertionContext,
+    route_idx: usize,
+    max_segment_size: usize,
+    max_neighbors: usize,
+) -> Vec<(Cost, CrossMove)> {
+    let routes = &insertion_ctx.solution.routes;
+    let locked = &insertion_ctx.solution.locked;
+    let transport = insertion_ctx.problem.transport.as_ref();
+
+    let route_jobs = routes
+        .iter()
+        .enumerate()
+        .filter(|(_, route_ctx)| route_ctx.route.tour.has_jobs() && has_singles_only(route_ctx))
+        .map(|(idx, route_ctx)| {
+            let jobs = get_route_jobs(insertion_ctx, idx);
+            let locations = get_route_locations(&route_ctx.route);
+            let is_locked = jobs.iter().map(|(job, _)| locked.contains(job)).collect::<Vec<_>>();
+            (idx, (jobs, locations, is_locked))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let positions = route_jobs
+        .iter()
+        .filter(|(idx, _)| **idx != route_idx)
+        .flat_map(|(idx, (jobs, _, _))| {
+            jobs.iter().enumerate().map(move |(position, (job, _))| (job, (*idx, position)))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let (jobs, locations, is_locked) = route_jobs.get(&route_idx).unwrap();
+    let actor = routes[route_idx].route.actor.as_ref();
+
+    let is_free = |is_locked: &[bool], start: usize, length: usize| is_locked[start..start + length].iter().all(|l| !l);
+    let get_reversals = |length: usize| if length > 1 { vec![false, true] } else { vec![false] };
+
+    let anchors = jobs
+        .iter()
+        .enumerate()
+        .flat_map(|(anchor, (job, _))| {
+            insertion_ctx
+                .problem
+                .jobs
+                .neighbors(&actor.vehicle.profile, job, Timestamp::default())
+                .take(max_neighbors)
+                .filter_map(|(neighbor, _)| positions.get(neighbor).map(|position| (anchor, *position)))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    anchors
+        .into_iter()
+        .flat_map(|(anchor, (other_idx, other_start))| {
+            let (other_jobs, other_locations, other_is_locked) = route_jobs.get(&other_idx).unwrap();
+            let other_actor = routes[other_idx].route.actor.as_ref();
+            let start = anchor + 1;
+
+            (0..=max_segment_size.min(jobs.len() - start))
+                .filter(move |length| is_free(is_locked, start, *length))
+                .flat_map(move |length| {
+                    (1..=max_segment_size.min(other_jobs.len() - other_start))
+                        .filter(move |other_length| is_free(other_is_locked, other_start, *other_length))
+                        .map(move |other_length| (length, other_length))
+                })
+                .flat_map(move |(length, other_length)| {
+                    get_reversals(length).into_iter().flat_map(move |reversed| {
+                        get_reversals(other_length).into_iter().map(move |other_reversed| {
+                            CrossMove(
+                                CrossSegment { route_idx, start, length, reversed },
+                                CrossSegment {
+                                    route_idx: other_idx,
+                                    start: other_start,
+                                    length: other_length,
+                                    reversed: other_reversed,
+                                },
+                            )
+                        })
+                    })
+                })
+                .filter_map(move |cross_move| {
+                    let CrossMove(first, second) = &cross_move;
+                    let delta = estimate_replacement(transport, actor, locations, first, other_locations, second)
+                        + estimate_replacement(transport, other_actor, other_locations, second, locations, first);
+
+                    if compare_floats(delta, 0.) == Ordering::Less {
+                        Some((delta, cross_move))
+                    } else {
+                        None
+                    }
+                })
+        })
+        .collect()
+}
+
+/// Estimates cost change when the segment of the route is replaced by another segment.
+fn estimate_replacement(
+    transport: &(dyn TransportCost + Send + Sync),
+    actor: &Actor,
+    locations: &[Option<Location>],
+    segment: &CrossSegment,
+    other_locations: &[Option<Location>],
+    other_segment: &CrossSegment,
+) -> Cost {
+    // NOTE locations include route start, so job index is shifted by one
+    let prev = locations[segment.start];
+    let next = locations[segment.start + segment.length + 1];
+
+    let old_cost = estimate_path(transport, actor, prev, get_segment_locations(locations, segment, false), next);
+    let new_cost = estimate_path(
+        transport,
+        actor,
+        prev,
+        get_segment_locations(other_locations, other_segment, other_segment.reversed),
+        next,
+    );
+
+    new_cost - old_cost
+}
+
+fn get_segment_locations(
+    locations: &[Option<Location>],
+    segment: &CrossSegment,
+    reversed: bool,
+) -> Vec<Option<Location>> {
+    let mut locations = locations[(segment.start + 1)..(segment.start + segment.length + 1)].to_vec();
+    if reversed {
+        locations.reverse();
+    }
+
+    locations
+}
+
+fn estimate_path(
+    transport: &(dyn TransportCost + Send + Sync),
+    actor: &Actor,
+    prev: Option<Location>,
+    locations: Vec<Option<Location>>,
+    next: Option<Location>,
+) -> Cost {
+    let path = once(prev).chain(locations).chain(once(next)).collect::<Vec<_>>();
+
+    path.windows(2).map(|leg| estimate_leg_cost(transport, actor, leg[0], leg[1])).sum()
+}
+
+/// Returns jobs of the route where the segment is replaced by another one.
+fn replace_segment(
+    jobs: &[(Job, Location)],
+    segment: &CrossSegment,
+    other_jobs: &[(Job, Location)],
+    other_segment: &CrossSegment,
+) -> Vec<Job> {
+    let mut inserted =
+        other_jobs[other_segment.start..(other_segment.start + other_segment.length)].iter().collect::<Vec<_>>();
+    if other_segment.reversed {
+        inserted.reverse();
+    }
+
+    jobs[..segment.start]
+        .iter()
+        .chain(inserted)
+        .chain(jobs[(segment.start + segment.length)..].iter())
+        .map(|(job, _)| job.clone())
+        .collect()
+}
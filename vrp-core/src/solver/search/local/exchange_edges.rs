@@ -0,0 +1,346 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/exchange_edges_test.rs"]
+mod exchange_edges_test;
+
+use super::*;
+use crate::models::common::{Cost, Location};
+use crate::models::problem::Job;
+
+/// A local search operator which applies 2-opt moves: it removes two edges from the route and
+/// reconnects it by reversing a subsequence of jobs between them.
+pub struct ExchangeTwoOpt {
+    settings: EdgeSettings,
+}
+
+impl ExchangeTwoOpt {
+    /// Creates a new instance of `ExchangeTwoOpt`.
+    pub fn new(max_iterations: usize, max_checks: usize) -> Self {
+        Self { settings: EdgeSettings::new(max_iterations, max_checks) }
+    }
+}
+
+impl Default for ExchangeTwoOpt {
+    fn default() -> Self {
+        Self::new(10, 4)
+    }
+}
+
+impl LocalOperator for ExchangeTwoOpt {
+    fn explore(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+    ) -> Option<InsertionContext> {
+        explore_edge_moves(refinement_ctx, insertion_ctx, &self.settings, 2, get_two_opt_moves)
+    }
+}
+
+/// A local search operator which applies Or-opt moves: it relocates a short sequence of jobs
+/// to another position within the same route, keeping or reversing its order.
+pub struct ExchangeOrOpt {
+    settings: EdgeSettings,
+    max_segment_size: usize,
+}
+
+impl ExchangeOrOpt {
+    /// Creates a new instance of `ExchangeOrOpt`.
+    pub fn new(max_iterations: usize, max_checks: usize, max_segment_size: usize) -> Self {
+        assert!(max_segment_size > 0);
+
+        Self { settings: EdgeSettings::new(max_iterations, max_checks), max_segment_size }
+    }
+}
+
+impl Default for ExchangeOrOpt {
+    fn default() -> Self {
+        Self::new(10, 4, 3)
+    }
+}
+
+impl LocalOperator for ExchangeOrOpt {
+    fn explore(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+    ) -> Option<InsertionContext> {
+        let max_segment_size = self.max_segment_size;
+
+        explore_edge_moves(refinement_ctx, insertion_ctx, &self.settings, 2, |size| {
+            get_or_opt_moves(size, max_segment_size)
+        })
+    }
+}
+
+/// A local search operator which applies 3-opt moves: it removes three edges from the route and
+/// reconnects two subsequences of jobs between them in a different order, optionally reversed.
+/// Only pure 3-opt reconnections are considered, 2-opt ones are covered by `ExchangeTwoOpt`.
+pub struct ExchangeThreeOpt {
+    settings: EdgeSettings,
+    max_segment_size: usize,
+}
+
+impl ExchangeThreeOpt {
+    /// Creates a new instance of `ExchangeThreeOpt`.
+    pub fn new(max_iterations: usize, max_checks: usize, max_segment_size: usize) -> Self {
+        assert!(max_segment_size > 0);
+
+        Self { settings: EdgeSettings::new(max_iterations, max_checks), max_segment_size }
+    }
+}
+
+impl Default for ExchangeThreeOpt {
+    fn default() -> Self {
+        Self::new(10, 4, 8)
+    }
+}
+
+impl LocalOperator for ExchangeThreeOpt {
+    fn explore(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+    ) -> Option<InsertionContext> {
+        let max_segment_size = self.max_segment_size;
+
+        explore_edge_moves(refinement_ctx, insertion_ctx, &self.settings, 3, |size| {
+            get_three_opt_moves(size, max_segment_size)
+        })
+    }
+}
+
+/// Specifies common settings of edge exchange operators.
+struct EdgeSettings {
+    /// Max amount of applied improving moves.
+    max_iterations: usize,
+    /// Max amount of promising moves checked against constraints and objective on each iteration.
+    max_checks: usize,
+}
+
+impl EdgeSettings {
+    fn new(max_iterations: usize, max_checks: usize) -> Self {
+        assert!(max_iterations > 0);
+        assert!(max_checks > 0);
+
+        Self { max_iterations, max_checks }
+    }
+}
+
+/// Specifies a subsequence of route jobs: start index, end index (exclusive) and reversal flag.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Segment(usize, usize, bool);
+
+/// Specifies an edge move as a new order of segments which covers all route jobs.
+type EdgeMove = Vec<Segment>;
+
+/// Explores edge moves of a route systematically: on each iteration, moves are sorted by estimated
+/// transport cost gain and the most promising ones are applied by reinserting route jobs in a new
+/// order, so the constraint pipeline is respected. The first move which improves the solution by
+/// objective is accepted.
+fn explore_edge_moves<F, I>(
+    refinement_ctx: &RefinementContext,
+    insertion_ctx: &InsertionContext,
+    settings: &EdgeSettings,
+    min_jobs: usize,
+    get_moves: F,
+) -> Option<InsertionContext>
+where
+    F: Fn(usize) -> I,
+    I: Iterator<Item = EdgeMove>,
+{
+    let route_idx = get_route_idx_with_singles(insertion_ctx, min_jobs)?;
+    let objective = refinement_ctx.problem.objective.as_ref();
+
+    let mut improved: Option<InsertionContext> = None;
+
+    for _ in 0..settings.max_iterations {
+        let current_ctx = improved.as_ref().unwrap_or(insertion_ctx);
+        let jobs = get_route_jobs(current_ctx, route_idx);
+        let locked = get_locked_indices(current_ctx, jobs.as_slice());
+        let edge_costs = EdgeCosts::new(current_ctx, route_idx);
+
+        let mut candidates = get_moves(jobs.len())
+            .filter(|edge_move| is_locked_kept(edge_move, locked.as_slice()))
+            .filter_map(|edge_move| {
+                let delta = edge_costs.estimate(edge_move.as_slice()) - edge_costs.total;
+                if compare_floats(delta, 0.) == Ordering::Less {
+                    Some((delta, edge_move))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by(|(a, _), (b, _)| compare_floats(*a, *b));
+
+        let new_ctx = candidates.into_iter().take(settings.max_checks).find_map(|(_, edge_move)| {
+            let new_ctx = reinsert_jobs(current_ctx, vec![(route_idx, get_ordered_jobs(jobs.as_slice(), &edge_move))])?;
+
+            if objective.total_order(current_ctx, &new_ctx) == Ordering::Greater {
+                Some(new_ctx)
+            } else {
+                None
+            }
+        });
+
+        match new_ctx {
+            Some(new_ctx) => improved = Some(new_ctx),
+            None => break,
+        }
+    }
+
+    improved
+}
+
+/// Returns 2-opt moves: reverse any subsequence of jobs.
+fn get_two_opt_moves(size: usize) -> impl Iterator<Item = EdgeMove> {
+    (0..size).flat_map(move |start| {
+        ((start + 2)..=size)
+            .map(move |end| vec![Segment(0, start, false), Segment(start, end, true), Segment(end, size, false)])
+    })
+}
+
+/// Returns Or-opt moves: relocate a subsequence of jobs not longer than max segment size.
+fn get_or_opt_moves(size: usize, max_segment_size: usize) -> impl Iterator<Item = EdgeMove> {
+    (0..size).flat_map(move |start| {
+        (1..=max_segment_size.min(size - start)).flat_map(move |length| {
+            let end = start + length;
+            let reversals: &[bool] = if length > 1 { &[false, true] } else { &[false] };
+
+            (0..=size).filter(move |target| *target < start || *target > end).flat_map(move |target| {
+                reversals.iter().map(move |&reversed| {
+                    if target < start {
+                        vec![
+                            Segment(0, target, false),
+                            Segment(start, end, reversed),
+                            Segment(target, start, false),
+                            Segment(end, size, false),
+                        ]
+                    } else {
+                        vec![
+                            Segment(0, start, false),
+                            Segment(end, target, false),
+                            Segment(start, end, reversed),
+                            Segment(target, size, false),
+                        ]
+                    }
+                })
+            })
+        })
+    })
+}
+
+/// Returns pure 3-opt moves for two adjacent subsequences not longer than max segment size each.
+fn get_three_opt_moves(size: usize, max_segment_size: usize) -> impl Iterator<Item = EdgeMove> {
+    (0..size).flat_map(move |first| {
+        ((first + 1)..=(first + max_segment_size).min(size)).flat_map(move |second| {
+            ((second + 1)..=(second + max_segment_size).min(size)).flat_map(move |third| {
+                let head = Segment(0, first, false);
+                let tail = Segment(third, size, false);
+                let (left, right) = ((first, second), (second, third));
+
+                [
+                    (left, true, right, true),
+                    (right, false, left, false),
+                    (right, false, left, true),
+                    (right, true, left, false),
+                ]
+                .into_iter()
+                .map(move |((a_start, a_end), a_rev, (b_start, b_end), b_rev)| {
+                    vec![head, Segment(a_start, a_end, a_rev), Segment(b_start, b_end, b_rev), tail]
+                })
+            })
+        })
+    })
+}
+
+fn get_locked_indices(insertion_ctx: &InsertionContext, jobs: &[(Job, Location)]) -> Vec<usize> {
+    let locked = &insertion_ctx.solution.locked;
+
+    jobs.iter().enumerate().filter(|(_, (job, _))| locked.contains(job)).map(|(idx, _)| idx).collect()
+}
+
+/// Checks that locked jobs stay at their positions after the move.
+fn is_locked_kept(edge_move: &[Segment], locked: &[usize]) -> bool {
+    locked.iter().all(|&idx| {
+        unwrap_from_result(edge_move.iter().try_fold(0, |offset, &Segment(start, end, reversed)| {
+            if (start..end).contains(&idx) {
+                let position = if reversed { offset + end - 1 - idx } else { offset + idx - start };
+                Err(position)
+            } else {
+                Ok(offset + end - start)
+            }
+        })) == idx
+    })
+}
+
+fn get_ordered_jobs(jobs: &[(Job, Location)], edge_move: &[Segment]) -> Vec<Job> {
+    edge_move
+        .iter()
+        .flat_map(|&Segment(start, end, reversed)| {
+            let segment = jobs[start..end].iter().map(|(job, _)| job.clone());
+            if reversed {
+                segment.rev().collect::<Vec<_>>()
+            } else {
+                segment.collect()
+            }
+        })
+        .collect()
+}
+
+/// Estimates time independent transport cost of the route for different orders of its jobs.
+struct EdgeCosts {
+    /// Transport costs between route start, jobs and route end.
+    edges: Vec<Vec<Cost>>,
+    /// Prefix sums of transport costs between consecutive jobs in forward direction.
+    forward: Vec<Cost>,
+    /// Prefix sums of transport costs between consecutive jobs in backward direction.
+    backward: Vec<Cost>,
+    /// Total cost of the current order.
+    total: Cost,
+}
+
+impl EdgeCosts {
+    fn new(insertion_ctx: &InsertionContext, route_idx: usize) -> Self {
+        let route = &insertion_ctx.solution.routes[route_idx].route;
+        let transport = insertion_ctx.problem.transport.as_ref();
+        let nodes = get_route_locations(route);
+
+        let edges = nodes
+            .iter()
+            .map(|from| nodes.iter().map(|to| estimate_leg_cost(transport, route.actor.as_ref(), *from, *to)).collect())
+            .collect::<Vec<Vec<_>>>();
+
+        let size = nodes.len() - 2;
+        let (forward, backward) = (0..size).fold((vec![0.], vec![0.]), |(mut forward, mut backward), idx| {
+            if idx + 1 < size {
+                forward.push(forward.last().unwrap() + edges[idx + 1][idx + 2]);
+                backward.push(backward.last().unwrap() + edges[idx + 2][idx + 1]);
+            }
+            (forward, backward)
+        });
+
+        let mut edge_costs = Self { edges, forward, backward, total: Cost::default() };
+        edge_costs.total = edge_costs.estimate(&[Segment(0, size, false)]);
+
+        edge_costs
+    }
+
+    /// Estimates cost of the route with the job order specified by the move.
+    fn estimate(&self, edge_move: &[Segment]) -> Cost {
+        let end = self.edges.len() - 1;
+
+        let (last, cost) = edge_move.iter().filter(|Segment(start, end, _)| start < end).fold(
+            (0, Cost::default()),
+            |(prev, cost), &Segment(start, end, reversed)| {
+                let (first, last, inner) = if reversed {
+                    (end, start + 1, self.backward[end - 1] - self.backward[start])
+                } else {
+                    (start + 1, end, self.forward[end - 1] - self.forward[start])
+                };
+
+                (last, cost + self.edges[prev][first] + inner)
+            },
+        );
+
+        cost + self.edges[last][end]
+    }
+}
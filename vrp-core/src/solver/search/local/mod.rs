@@ -1,11 +1,22 @@
 //! This module contains various Local Search operators.
 
 use crate::construction::heuristics::*;
+use crate::models::common::{Cost, Location};
+use crate::models::problem::{Actor, Job, TransportCost};
+use crate::models::solution::Route;
 use crate::solver::RefinementContext;
+use hashbrown::HashSet;
 use rosomaxa::prelude::*;
 use std::cmp::Ordering;
+use std::iter::once;
 use std::sync::Arc;
 
+mod exchange_cross;
+pub use self::exchange_cross::*;
+
+mod exchange_edges;
+pub use self::exchange_edges::*;
+
 mod exchange_inter_route;
 pub use self::exchange_inter_route::*;
 
@@ -85,3 +96,122 @@ fn apply_insertion(insertion_ctx: &mut InsertionContext, success: InsertionSucce
 
     apply_insertion_success(insertion_ctx, success)
 }
+
+/// Removes given jobs from the routes and inserts them back in the given order at the end of each
+/// route. Returns a new insertion context if all jobs are inserted successfully.
+fn reinsert_jobs(insertion_ctx: &InsertionContext, routes: Vec<(usize, Vec<Job>)>) -> Option<InsertionContext> {
+    let mut new_insertion_ctx = insertion_ctx.deep_copy();
+
+    // NOTE a job can be moved between the routes, so it is removed from any of them
+    let removed = routes.iter().flat_map(|(_, jobs)| jobs.iter()).collect::<HashSet<_>>();
+    routes.iter().for_each(|(route_idx, _)| {
+        let route_ctx = new_insertion_ctx.solution.routes.get_mut(*route_idx).unwrap();
+        let jobs = route_ctx.route.tour.jobs().filter(|job| removed.contains(job)).collect::<Vec<_>>();
+        jobs.iter().for_each(|job| {
+            route_ctx.route_mut().tour.remove(job);
+        });
+        new_insertion_ctx.problem.constraint.accept_route_state(route_ctx);
+    });
+
+    let leg_selector = VariableLegSelector::new(new_insertion_ctx.environment.random.clone());
+    let result_selector = BestResultSelector::default();
+
+    let is_inserted = routes.iter().all(|(route_idx, jobs)| {
+        jobs.iter().all(|job| {
+            let eval_ctx = EvaluationContext {
+                constraint: &new_insertion_ctx.problem.constraint,
+                job,
+                leg_selector: &leg_selector,
+                result_selector: &result_selector,
+            };
+
+            let insertion = evaluate_job_insertion_in_route(
+                &new_insertion_ctx,
+                &eval_ctx,
+                new_insertion_ctx.solution.routes.get(*route_idx).unwrap(),
+                InsertionPosition::Last,
+                InsertionResult::make_failure(),
+            );
+
+            match insertion {
+                InsertionResult::Success(success) => {
+                    apply_insertion_success(&mut new_insertion_ctx, success);
+                    true
+                }
+                InsertionResult::Failure(_) => false,
+            }
+        })
+    });
+
+    if is_inserted {
+        finalize_insertion_ctx(&mut new_insertion_ctx);
+        Some(new_insertion_ctx)
+    } else {
+        None
+    }
+}
+
+/// Returns locations of route start, job activities and route end. Start or end is `None` when
+/// the route doesn't have it.
+fn get_route_locations(route: &Route) -> Vec<Option<Location>> {
+    once(route.tour.start().map(|start| start.place.location))
+        .chain(route.tour.all_activities().filter(|activity| activity.job.is_some()).map(|a| Some(a.place.location)))
+        .chain(once(route.actor.detail.end.as_ref().map(|end| end.location)))
+        .collect()
+}
+
+/// Estimates time independent transport cost of the leg for given actor. Missing location means
+/// that there is no leg, so its cost is zero.
+fn estimate_leg_cost(
+    transport: &(dyn TransportCost + Send + Sync),
+    actor: &Actor,
+    from: Option<Location>,
+    to: Option<Location>,
+) -> Cost {
+    match (from, to) {
+        (Some(from), Some(to)) => {
+            let profile = &actor.vehicle.profile;
+            let per_distance = actor.driver.costs.per_distance + actor.vehicle.costs.per_distance;
+            let per_driving_time = actor.driver.costs.per_driving_time + actor.vehicle.costs.per_driving_time;
+
+            transport.distance_approx(profile, from, to) * per_distance
+                + transport.duration_approx(profile, from, to) * per_driving_time
+        }
+        _ => Cost::default(),
+    }
+}
+
+/// Returns index of a random route which has enough jobs to be modified by moves which change order
+/// of job activities. Routes with multi jobs are not considered as reversing or relocating their
+/// activities can break the order of sub jobs.
+fn get_route_idx_with_singles(insertion_ctx: &InsertionContext, min_jobs: usize) -> Option<usize> {
+    let routes = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .enumerate()
+        .filter(|(_, route_ctx)| route_ctx.route.tour.job_count() >= min_jobs && has_singles_only(route_ctx))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+
+    if routes.is_empty() {
+        None
+    } else {
+        Some(routes[insertion_ctx.environment.random.uniform_int(0, (routes.len() - 1) as i32) as usize])
+    }
+}
+
+/// Checks whether route has only single jobs.
+fn has_singles_only(route_ctx: &RouteContext) -> bool {
+    route_ctx.route.tour.jobs().all(|job| job.as_single().is_some())
+}
+
+/// Returns route jobs with their locations in the order of their activities.
+fn get_route_jobs(insertion_ctx: &InsertionContext, route_idx: usize) -> Vec<(Job, Location)> {
+    insertion_ctx.solution.routes[route_idx]
+        .route
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.retrieve_job().map(|job| (job, activity.place.location)))
+        .collect()
+}
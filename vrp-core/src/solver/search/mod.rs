@@ -11,6 +11,9 @@ use crate::models::problem::ProblemObjective;
 use crate::solver::{RefinementContext, TargetHeuristicOperator};
 use rosomaxa::prelude::HeuristicOperator;
 
+mod crossover;
+pub use self::crossover::*;
+
 mod local;
 pub use self::local::*;
 
@@ -23,6 +26,9 @@ pub use self::ruin::*;
 mod utils;
 pub(crate) use self::utils::*;
 
+mod crossover_search;
+pub use self::crossover_search::CrossoverSearch;
+
 mod decompose_search;
 pub use self::decompose_search::DecomposeSearch;
 
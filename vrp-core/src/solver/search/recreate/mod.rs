@@ -43,6 +43,9 @@ pub use self::recreate_with_skip_random::RecreateWithSkipRandom;
 mod recreate_with_slice;
 pub use self::recreate_with_slice::RecreateWithSlice;
 
+mod recreate_with_split;
+pub use self::recreate_with_split::RecreateWithSplit;
+
 /// Provides the way to run one of multiple recreate methods.
 pub struct WeightedRecreate {
     recreates: Vec<Arc<dyn Recreate + Send + Sync>>,
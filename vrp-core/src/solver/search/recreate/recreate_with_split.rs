@@ -0,0 +1,256 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/recreate/recreate_with_split_test.rs"]
+mod recreate_with_split_test;
+
+use crate::construction::heuristics::*;
+use crate::models::common::{Cost, Location};
+use crate::models::problem::{Actor, Job};
+use crate::solver::search::recreate::Recreate;
+use crate::solver::search::RecreateWithCheapest;
+use crate::solver::RefinementContext;
+use rosomaxa::prelude::*;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// A recreate method which implements route-first cluster-second construction: required jobs are
+/// ordered into a giant tour using nearest neighbour heuristic and the tour is optimally partitioned
+/// into feasible routes for the available fleet using split procedure (Prins, 2004). Jobs which cannot
+/// be handled that way (e.g. multi jobs or jobs which do not fit the fleet) are inserted using cheapest
+/// insertion.
+///
+/// The method assumes homogeneous fleet: routes are evaluated using the first available actor.
+///
+/// Route length is bounded by `max_route_size` jobs, so the split costs O(n·L²) route evaluations
+/// instead of O(n³), where `n` is the size of the giant tour and `L` is the max route size.
+pub struct RecreateWithSplit {
+    max_route_size: usize,
+    recreate: RecreateWithCheapest,
+}
+
+impl RecreateWithSplit {
+    /// Creates a new instance of `RecreateWithSplit`.
+    pub fn new(max_route_size: usize, random: Arc<dyn Random + Send + Sync>) -> Self {
+        assert!(max_route_size > 0);
+
+        Self { max_route_size, recreate: RecreateWithCheapest::new(random) }
+    }
+
+    /// Creates a new instance of `RecreateWithSplit` with default parameters.
+    pub fn new_with_defaults(random: Arc<dyn Random + Send + Sync>) -> Self {
+        Self::new(50, random)
+    }
+}
+
+impl Recreate for RecreateWithSplit {
+    fn run(&self, refinement_ctx: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        let mut insertion_ctx = insertion_ctx;
+
+        prepare_insertion_ctx(&mut insertion_ctx);
+
+        // NOTE split assumes homogeneous fleet, so the first available actor is used for estimations
+        let actor = insertion_ctx.solution.registry.next().next().map(|route_ctx| route_ctx.route.actor.clone());
+
+        if let Some(actor) = actor {
+            let giant_tour = get_giant_tour(&insertion_ctx, actor.as_ref());
+            // NOTE actors in conflict (e.g. sharing the same vehicle) are counted too, so it is an upper bound
+            let max_routes = insertion_ctx.solution.registry.resources().available().count();
+            let bounds = get_split_bounds(giant_tour.len(), max_routes, |start| {
+                get_route_costs(&insertion_ctx, actor.as_ref(), &giant_tour[start..], self.max_route_size)
+            });
+
+            bounds.into_iter().for_each(|(start, end)| insert_route(&mut insertion_ctx, &giant_tour[start..=end]));
+        }
+
+        self.recreate.run(refinement_ctx, insertion_ctx)
+    }
+}
+
+/// Orders required single jobs into a giant tour using nearest neighbour heuristic starting from
+/// a random job. Jobs without location are not included.
+fn get_giant_tour(insertion_ctx: &InsertionContext, actor: &Actor) -> Vec<Job> {
+    let transport = insertion_ctx.problem.transport.as_ref();
+    let profile = &actor.vehicle.profile;
+
+    let mut jobs = insertion_ctx
+        .solution
+        .required
+        .iter()
+        .filter_map(|job| get_location(job).map(|location| (job.clone(), location)))
+        .collect::<Vec<_>>();
+
+    if jobs.is_empty() {
+        return vec![];
+    }
+
+    let first = insertion_ctx.environment.random.uniform_int(0, (jobs.len() - 1) as i32) as usize;
+    let mut tour = vec![jobs.swap_remove(first)];
+
+    while !jobs.is_empty() {
+        let (_, last) = tour.last().unwrap();
+        let (idx, _) = jobs
+            .iter()
+            .enumerate()
+            .map(|(idx, (_, location))| (idx, transport.distance_approx(profile, *last, *location)))
+            .min_by(|(_, a), (_, b)| compare_floats(*a, *b))
+            .unwrap();
+
+        tour.push(jobs.swap_remove(idx));
+    }
+
+    tour.into_iter().map(|(job, _)| job).collect()
+}
+
+fn get_location(job: &Job) -> Option<Location> {
+    job.as_single().and_then(|single| single.places.first()).and_then(|place| place.location)
+}
+
+/// Returns costs of the routes which serve jobs starting from the first one: the cost at index `k`
+/// corresponds to the route with first `k + 1` jobs. Stops at the first job which cannot be appended
+/// or when the route has reached max size.
+fn get_route_costs(insertion_ctx: &InsertionContext, actor: &Actor, jobs: &[Job], max_size: usize) -> Vec<Cost> {
+    let mut route_ctx = match insertion_ctx.solution.registry.next_with_actor(actor) {
+        Some(route_ctx) => route_ctx.deep_copy(),
+        None => return vec![],
+    };
+
+    let mut total = Cost::default();
+
+    jobs.iter()
+        .take(max_size)
+        .map_while(|job| {
+            let success = evaluate_insertion_last(insertion_ctx, &route_ctx, job)?;
+
+            let route = route_ctx.route_mut();
+            success.activities.into_iter().for_each(|(activity, index)| {
+                route.tour.insert_at(activity, index + 1);
+            });
+            insertion_ctx.problem.constraint.accept_route_state(&mut route_ctx);
+
+            total += success.cost;
+
+            Some(total)
+        })
+        .collect()
+}
+
+/// Optimally partitions the giant tour of given size into at most `max_routes` routes using Bellman's
+/// algorithm on the auxiliary graph where each arc corresponds to a feasible route (Prins, 2004).
+/// A label is kept per each amount of used routes. When a job cannot start any route or there is
+/// not enough routes to serve all jobs, the job is skipped: partition with the least skipped jobs
+/// is preferred. Returns inclusive bounds of the routes.
+fn get_split_bounds<F>(size: usize, max_routes: usize, get_route_costs: F) -> Vec<(usize, usize)>
+where
+    F: Fn(usize) -> Vec<Cost>,
+{
+    // NOTE a label at `[routes][i]` keeps amount of skipped jobs and the best cost to serve first `i`
+    // jobs using `routes` routes, its predecessor and whether the arc from predecessor is a route
+    type Label = (usize, Cost, usize, bool);
+
+    let max_routes = max_routes.min(size);
+    let mut labels: Vec<Vec<Option<Label>>> = vec![vec![None; size + 1]; max_routes + 1];
+    labels[0][0] = Some((0, Cost::default(), 0, false));
+
+    let compare = |(a_skipped, a_cost, _, _): &Label, (b_skipped, b_cost, _, _): &Label| {
+        a_skipped.cmp(b_skipped).then_with(|| compare_floats(*a_cost, *b_cost))
+    };
+
+    let update = |labels: &mut Vec<Vec<Option<Label>>>, routes: usize, end: usize, label: Label| {
+        let is_better = match labels[routes][end].as_ref() {
+            Some(old) => compare(&label, old) == Ordering::Less,
+            None => true,
+        };
+
+        if is_better {
+            labels[routes][end] = Some(label);
+        }
+    };
+
+    (0..size).for_each(|start| {
+        let reachable = (0..=max_routes)
+            .filter_map(|routes| labels[routes][start].map(|(skipped, cost, _, _)| (routes, skipped, cost)))
+            .collect::<Vec<_>>();
+
+        let has_routes = reachable.iter().any(|(routes, _, _)| *routes < max_routes);
+        let route_costs = if has_routes { get_route_costs(start) } else { vec![] };
+
+        reachable.into_iter().for_each(|(routes, skipped, cost)| {
+            update(&mut labels, routes, start + 1, (skipped + 1, cost, start, false));
+
+            if routes < max_routes {
+                route_costs.iter().enumerate().for_each(|(offset, route_cost)| {
+                    update(&mut labels, routes + 1, start + offset + 1, (skipped, cost + route_cost, start, true));
+                });
+            }
+        });
+    });
+
+    let mut routes = (0..=max_routes)
+        .filter_map(|routes| labels[routes][size].map(|label| (routes, label)))
+        .min_by(|(_, a), (_, b)| compare(a, b))
+        .map(|(routes, _)| routes)
+        .expect("split label should be reachable");
+
+    let mut bounds = vec![];
+    let mut end = size;
+
+    while end > 0 {
+        let (_, _, start, is_route) = labels[routes][end].expect("split label should be reachable");
+        if is_route {
+            bounds.push((start, end - 1));
+            routes -= 1;
+        }
+        end = start;
+    }
+
+    bounds.reverse();
+
+    bounds
+}
+
+/// Inserts jobs into a new route using the next available actor. Jobs which cannot be inserted
+/// are kept in required list.
+fn insert_route(insertion_ctx: &mut InsertionContext, jobs: &[Job]) {
+    let actor = if let Some(route_ctx) = insertion_ctx.solution.registry.next().next() {
+        route_ctx.route.actor.clone()
+    } else {
+        return;
+    };
+
+    jobs.iter().for_each(|job| {
+        let route_ctx = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .find(|route_ctx| route_ctx.route.actor == actor)
+            .cloned()
+            .or_else(|| insertion_ctx.solution.registry.next_with_actor(actor.as_ref()));
+
+        if let Some(success) = route_ctx.and_then(|route_ctx| evaluate_insertion_last(insertion_ctx, &route_ctx, job)) {
+            apply_insertion_success(insertion_ctx, success);
+        }
+    });
+}
+
+fn evaluate_insertion_last(
+    insertion_ctx: &InsertionContext,
+    route_ctx: &RouteContext,
+    job: &Job,
+) -> Option<InsertionSuccess> {
+    let leg_selector = VariableLegSelector::new(insertion_ctx.environment.random.clone());
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext {
+        constraint: &insertion_ctx.problem.constraint,
+        job,
+        leg_selector: &leg_selector,
+        result_selector: &result_selector,
+    };
+
+    evaluate_job_insertion_in_route(
+        insertion_ctx,
+        &eval_ctx,
+        route_ctx,
+        InsertionPosition::Last,
+        InsertionResult::make_failure(),
+    )
+    .into_success()
+}
@@ -28,6 +28,7 @@ pub fn create_constraint_pipeline_with_transport() -> ConstraintPipeline {
         TestTransportCost::new_shared(),
         TestActivityCost::new_shared(),
         Arc::new(|_| (None, None)),
+        Arc::new(|_| None),
         1,
         2,
         3,
@@ -23,6 +23,7 @@ pub fn test_actor_with_profile(profile_idx: usize) -> Arc<Actor> {
                 time: TimeInterval { earliest: None, latest: Some(DEFAULT_ACTOR_TIME_WINDOW.end) },
             }),
             time: DEFAULT_ACTOR_TIME_WINDOW,
+            links: vec![],
         },
     })
 }
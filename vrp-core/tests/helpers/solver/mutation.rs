@@ -87,6 +87,8 @@ pub fn rearrange_jobs_in_routes(insertion_ctx: &mut InsertionContext, job_order:
             let place = job.places.first().unwrap();
             route_ctx.route_mut().tour.insert_last(Activity {
                 place: Place {
+                    idx: 0,
+                    time_idx: 0,
                     location: place.location.unwrap(),
                     duration: place.duration,
                     time: place.times.first().unwrap().to_time_window(0.),
@@ -151,3 +153,28 @@ impl LegConstraint {
         Self { disallowed_pairs, ignore }
     }
 }
+
+/// Adds hard route constraint which binds given jobs to specific vehicles as it is done for breaks
+/// or reloads.
+pub fn add_vehicle_job_constraint(problem: &mut Problem, bound_jobs: Vec<(&str, &str)>) {
+    let bound_jobs = bound_jobs.into_iter().map(|(job, vehicle)| (job.to_string(), vehicle.to_string())).collect();
+    unsafe { as_mut(problem.constraint.as_ref()) }
+        .add_constraint(&ConstraintVariant::HardRoute(Arc::new(VehicleJobConstraint { bound_jobs })));
+}
+
+struct VehicleJobConstraint {
+    bound_jobs: HashMap<String, String>,
+}
+
+impl HardRouteConstraint for VehicleJobConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        let vehicle_id = job.dimens().get_id().and_then(|job_id| self.bound_jobs.get(job_id));
+
+        match vehicle_id {
+            Some(vehicle_id) if Some(vehicle_id) != ctx.route.actor.vehicle.dimens.get_id() => {
+                Some(RouteConstraintViolation { code: 8 })
+            }
+            _ => None,
+        }
+    }
+}
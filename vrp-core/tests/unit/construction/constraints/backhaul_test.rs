@@ -0,0 +1,91 @@
+use super::*;
+use crate::helpers::construction::constraints::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::SingleDimLoad;
+
+fn create_activities(demands: &[i32]) -> Vec<Activity> {
+    demands
+        .iter()
+        .map(|demand| {
+            if *demand == 0 {
+                test_activity_with_job(Arc::new(test_single()))
+            } else {
+                test_activity_with_job(test_single_with_simple_demand(create_simple_demand(*demand)))
+            }
+        })
+        .collect()
+}
+
+fn create_backhaul_route_ctx(demands: &[i32]) -> (ConstraintPipeline, RouteContext) {
+    let pipeline =
+        create_constraint_pipeline_with_module(Arc::new(BackhaulModule::new::<SingleDimLoad>(Arc::new(|_| true), 1)));
+    let mut route_ctx = create_route_context_with_activities(&test_fleet(), "v1", create_activities(demands));
+    pipeline.accept_route_state(&mut route_ctx);
+
+    (pipeline, route_ctx)
+}
+
+parameterized_test! {can_update_haul_indices, (demands, expected), {
+    can_update_haul_indices_impl(demands, expected);
+}}
+
+can_update_haul_indices! {
+    case01_empty: (vec![], (None, None)),
+    case02_linehauls_only: (vec![-1, -1], (Some(2), None)),
+    case03_backhauls_only: (vec![1, 1], (None, Some(1))),
+    case04_mixed: (vec![-1, 0, -1, 1, 0, 1], (Some(3), Some(4))),
+}
+
+fn can_update_haul_indices_impl(demands: Vec<i32>, expected: HaulIndices) {
+    let (_, route_ctx) = create_backhaul_route_ctx(demands.as_slice());
+
+    let result = route_ctx.state.get_route_state::<HaulIndices>(HAUL_INDICES_KEY).cloned();
+
+    assert_eq!(result, Some(expected));
+}
+
+parameterized_test! {can_evaluate_haul_order, (demands, target, index, expected), {
+    can_evaluate_haul_order_impl(demands, target, index, expected);
+}}
+
+can_evaluate_haul_order! {
+    case01_linehaul_before_linehaul: (vec![-1, -1], -1, 0, None),
+    case02_linehaul_after_linehauls: (vec![-1, -1], -1, 2, None),
+    case03_linehaul_before_backhaul: (vec![-1, 1], -1, 1, None),
+    case04_linehaul_after_backhaul: (vec![-1, 1], -1, 2, Some(true)),
+    case05_linehaul_after_service: (vec![-1, 0, 1], -1, 2, None),
+
+    case06_backhaul_after_linehauls: (vec![-1, -1], 1, 2, None),
+    case07_backhaul_before_linehaul: (vec![-1, -1], 1, 1, Some(false)),
+    case08_backhaul_before_backhaul: (vec![-1, 1], 1, 2, None),
+    case09_backhaul_at_start: (vec![1, 1], 1, 0, None),
+
+    case10_service_anywhere: (vec![-1, 1], 0, 0, None),
+}
+
+fn can_evaluate_haul_order_impl(demands: Vec<i32>, target: i32, index: usize, expected: Option<bool>) {
+    let (pipeline, route_ctx) = create_backhaul_route_ctx(demands.as_slice());
+    let target = create_activities(&[target]).pop().unwrap();
+    let prev = route_ctx.route.tour.get(index).unwrap();
+    let next = route_ctx.route.tour.get(index + 1);
+
+    let result = pipeline.evaluate_hard_activity(&route_ctx, &ActivityContext { index, prev, target: &target, next });
+
+    assert_eq!(result, expected.map(|stopped| ActivityConstraintViolation { code: 1, stopped }));
+}
+
+#[test]
+fn can_skip_actors_without_backhauls() {
+    let pipeline =
+        create_constraint_pipeline_with_module(Arc::new(BackhaulModule::new::<SingleDimLoad>(Arc::new(|_| false), 1)));
+    let mut route_ctx = create_route_context_with_activities(&test_fleet(), "v1", create_activities(&[-1, 1]));
+    pipeline.accept_route_state(&mut route_ctx);
+    let target = create_activities(&[-1]).pop().unwrap();
+    let prev = route_ctx.route.tour.get(2).unwrap();
+
+    let result =
+        pipeline.evaluate_hard_activity(&route_ctx, &ActivityContext { index: 2, prev, target: &target, next: None });
+
+    assert_eq!(result, None);
+}
@@ -0,0 +1,144 @@
+use crate::construction::constraints::*;
+use crate::construction::heuristics::ActivityContext;
+use crate::helpers::construction::constraints::*;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::*;
+use crate::models::problem::{Job, Single, Vehicle};
+use hashbrown::HashSet;
+use std::sync::Arc;
+
+const COMPARTMENT_CODE: i32 = 7;
+
+fn create_product(id: &str) -> Arc<Product> {
+    let incompatible = match id {
+        "a" => vec!["b"],
+        "b" => vec!["a"],
+        _ => vec![],
+    };
+
+    Arc::new(Product::new(id.to_string(), incompatible.into_iter().map(|id| id.to_string()).collect()))
+}
+
+fn create_single_with_product(size: i32, product: Option<&str>) -> Arc<Single> {
+    let mut single = test_single();
+    single.dimens.set_demand(create_simple_demand(size));
+    if let Some(product) = product {
+        single.dimens.set_product(create_product(product));
+    }
+
+    Arc::new(single)
+}
+
+fn create_test_vehicle(compartments: Vec<(i32, Option<Vec<&str>>)>) -> Vehicle {
+    let mut vehicle = VehicleBuilder::default().id("v1").capacity(compartments.iter().map(|(c, _)| *c).sum()).build();
+    vehicle.dimens.set_compartments(
+        compartments
+            .into_iter()
+            .map(|(capacity, products)| Compartment {
+                capacity: SingleDimLoad::new(capacity),
+                products: products.map(|products| products.into_iter().map(|p| p.to_string()).collect::<HashSet<_>>()),
+                dimens: Default::default(),
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    vehicle
+}
+
+parameterized_test! {can_assign_compartments, (demands, expected), {
+    can_assign_compartments_impl(demands, expected);
+}}
+
+can_assign_compartments! {
+    case01_same_product: (vec![(-5, "a"), (-5, "a")], Some(vec![None, Some(0), Some(0), None])),
+    case02_incompatible_products: (vec![(-5, "a"), (-5, "b")], Some(vec![None, Some(0), Some(1), None])),
+    case03_compatible_products: (vec![(-5, "a"), (-5, "c")], Some(vec![None, Some(0), Some(0), None])),
+    case04_no_space: (vec![(-8, "a"), (-8, "b")], None),
+    case05_delivery_then_pickup: (vec![(-8, "a"), (8, "b")], Some(vec![None, Some(0), Some(0), None])),
+    case06_pickup_then_delivery: (vec![(8, "b"), (-8, "a")], None),
+}
+
+fn can_assign_compartments_impl(demands: Vec<(i32, &str)>, expected: Option<Vec<Option<usize>>>) {
+    let vehicle = create_test_vehicle(vec![(10, None), (5, None)]);
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+    let route = create_route_with_activities(
+        &fleet,
+        "v1",
+        demands
+            .into_iter()
+            .map(|(size, product)| test_activity_with_job(create_single_with_product(size, Some(product))))
+            .collect(),
+    );
+    let compartments: &Vec<Compartment<SingleDimLoad>> = route.actor.vehicle.dimens.get_compartments().unwrap();
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+
+    let result = assign_compartments(activities.as_slice(), compartments.as_slice(), &|_| false);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_activity_insertion, (size, product, expected), {
+    can_evaluate_activity_insertion_impl(size, product, expected);
+}}
+
+can_evaluate_activity_insertion! {
+    case01: (-2, "a", None),
+    case02: (-5, "b", None),
+    case03: (-6, "b", Some(ActivityConstraintViolation { code: COMPARTMENT_CODE, stopped: false })),
+    case04: (-6, "a", Some(ActivityConstraintViolation { code: COMPARTMENT_CODE, stopped: false })),
+    case05: (10, "b", None),
+    case06: (11, "b", Some(ActivityConstraintViolation { code: COMPARTMENT_CODE, stopped: false })),
+}
+
+fn can_evaluate_activity_insertion_impl(size: i32, product: &str, expected: Option<ActivityConstraintViolation>) {
+    let vehicle = create_test_vehicle(vec![(10, None), (5, None)]);
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![test_activity_with_job(create_single_with_product(-8, Some("a")))],
+    );
+    let pipeline = create_constraint_pipeline_with_module(Arc::new(CompartmentConstraintModule::<SingleDimLoad>::new(
+        COMPARTMENT_CODE,
+    )));
+    pipeline.accept_route_state(&mut route_ctx);
+    let target = test_activity_with_job(create_single_with_product(size, Some(product)));
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route.tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(2),
+    };
+
+    let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_job_on_route, (size, product, expected), {
+    can_evaluate_job_on_route_impl(size, product, expected);
+}}
+
+can_evaluate_job_on_route! {
+    case01_allowed_product: (-5, Some("a"), None),
+    case02_allowed_in_other_compartment: (-5, Some("c"), None),
+    case03_no_product: (-5, None, None),
+    case04_not_allowed_product: (-6, Some("b"), Some(RouteConstraintViolation { code: COMPARTMENT_CODE })),
+    case05_too_big_demand: (-11, Some("a"), Some(RouteConstraintViolation { code: COMPARTMENT_CODE })),
+}
+
+fn can_evaluate_job_on_route_impl(size: i32, product: Option<&str>, expected: Option<RouteConstraintViolation>) {
+    let vehicle = create_test_vehicle(vec![(10, Some(vec!["a"])), (5, None)]);
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![]);
+    let pipeline = create_constraint_pipeline_with_module(Arc::new(CompartmentConstraintModule::<SingleDimLoad>::new(
+        COMPARTMENT_CODE,
+    )));
+    let job = Job::Single(create_single_with_product(size, product));
+
+    let result = pipeline.evaluate_hard_route(&create_empty_solution_context(), &route_ctx, &job);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,96 @@
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext};
+use crate::helpers::construction::constraints::create_simple_demand;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Location, SingleDimLoad};
+use crate::models::problem::{Fleet, Vehicle};
+use crate::models::solution::Activity;
+use std::sync::Arc;
+
+fn create_test_vehicle() -> Vehicle {
+    let mut vehicle = VehicleBuilder::default().id("v1").capacity(10).build();
+    vehicle.dimens.set_load_costs(LoadCosts { full_load_per_distance: 1., empty_emissions: 1., full_emissions: 3. });
+
+    vehicle
+}
+
+fn create_test_fleet() -> Fleet {
+    FleetBuilder::default().add_driver(test_driver()).add_vehicle(create_test_vehicle()).build()
+}
+
+fn create_delivery_activity(location: Location, size: i32) -> Activity {
+    let mut activity = test_activity_with_job(test_single_with_simple_demand(create_simple_demand(-size)));
+    activity.place.location = location;
+
+    activity
+}
+
+fn create_pipeline() -> ConstraintPipeline {
+    let mut constraint = ConstraintPipeline::default();
+    constraint.add_module(Arc::new(CapacityConstraintModule::<SingleDimLoad>::new(
+        TestActivityCost::new_shared(),
+        TestTransportCost::new_shared(),
+        2,
+    )));
+    constraint.add_module(Arc::new(LoadCostModule::<SingleDimLoad>::new(TestTransportCost::new_shared())));
+
+    constraint
+}
+
+#[test]
+fn can_calculate_load_cost_and_emissions() {
+    let fleet = create_test_fleet();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![create_delivery_activity(10, 4), create_delivery_activity(20, 6)],
+    );
+
+    create_pipeline().accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.state.get_route_state::<f64>(LOAD_COST_KEY).cloned(), Some(16.));
+    assert_eq!(route_ctx.state.get_route_state::<f64>(TOTAL_EMISSIONS_KEY).cloned(), Some(72.));
+}
+
+#[test]
+fn can_ignore_vehicle_without_load_costs() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").capacity(10).build())
+        .build();
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", vec![create_delivery_activity(10, 4)]);
+
+    create_pipeline().accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.state.get_route_state::<f64>(LOAD_COST_KEY), None);
+    assert_eq!(route_ctx.state.get_route_state::<f64>(TOTAL_EMISSIONS_KEY), None);
+}
+
+parameterized_test! {can_estimate_activity_insertion, (index, expected), {
+    can_estimate_activity_insertion_impl(index, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case01_after_start: (0, 20.),
+    case02_before_end: (1, 6.),
+}
+
+fn can_estimate_activity_insertion_impl(index: usize, expected: f64) {
+    let fleet = create_test_fleet();
+    let mut route_ctx: RouteContext =
+        create_route_context_with_activities(&fleet, "v1", vec![create_delivery_activity(10, 4)]);
+    let pipeline = create_pipeline();
+    pipeline.accept_route_state(&mut route_ctx);
+    let target = create_delivery_activity(20, 6);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = pipeline.evaluate_soft_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,103 @@
+use crate::construction::constraints::*;
+use crate::construction::heuristics::ActivityContext;
+use crate::helpers::construction::constraints::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Dimensions, IdDimension, Location};
+use crate::models::problem::{Multi, Single};
+use crate::models::solution::Activity;
+use std::sync::Arc;
+
+const RIDE_TIME_CODE: i32 = 8;
+
+fn create_multi_with_ride_time(locations: Vec<Location>, max_ride_time: f64) -> Arc<Multi> {
+    let mut dimens = Dimensions::default();
+    dimens.set_id("multi");
+    dimens.set_max_ride_time(max_ride_time);
+
+    Multi::new_shared(locations.into_iter().map(|location| test_single_with_location(Some(location))).collect(), dimens)
+}
+
+fn create_activity_with_single(single: &Arc<Single>, location: Location) -> Activity {
+    let mut activity = test_activity_with_location(location);
+    activity.job = Some(single.clone());
+
+    activity
+}
+
+fn create_pipeline() -> ConstraintPipeline {
+    create_constraint_pipeline_with_module(Arc::new(RideTimeConstraintModule::new(
+        RIDE_TIME_CODE,
+        TestActivityCost::new_shared(),
+        TestTransportCost::new_shared(),
+    )))
+}
+
+parameterized_test! {can_check_ride_time_of_jobs_on_board, (index, location, expected), {
+    can_check_ride_time_of_jobs_on_board_impl(index, location, expected);
+}}
+
+can_check_ride_time_of_jobs_on_board! {
+    case01_no_detour: (1, 15, None),
+    case02_small_detour: (1, 8, None),
+    case03_big_detour: (1, 7, Some(ActivityConstraintViolation { code: RIDE_TIME_CODE, stopped: false })),
+    case04_far_detour: (1, 25, Some(ActivityConstraintViolation { code: RIDE_TIME_CODE, stopped: false })),
+    case05_after_delivery: (2, 50, None),
+}
+
+fn can_check_ride_time_of_jobs_on_board_impl(
+    index: usize,
+    location: Location,
+    expected: Option<ActivityConstraintViolation>,
+) {
+    let multi = create_multi_with_ride_time(vec![10, 20], 15.);
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![create_activity_with_single(&multi.jobs[0], 10), create_activity_with_single(&multi.jobs[1], 20)],
+    );
+    let pipeline = create_pipeline();
+    pipeline.accept_route_state(&mut route_ctx);
+    let target = test_activity_with_location(location);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_ride_time_of_target_job, (location, expected), {
+    can_check_ride_time_of_target_job_impl(location, expected);
+}}
+
+can_check_ride_time_of_target_job! {
+    case01_within_limit: (20, None),
+    case02_at_limit: (25, None),
+    case03_exceeds_limit: (26, Some(ActivityConstraintViolation { code: RIDE_TIME_CODE, stopped: true })),
+}
+
+fn can_check_ride_time_of_target_job_impl(location: Location, expected: Option<ActivityConstraintViolation>) {
+    let multi = create_multi_with_ride_time(vec![10, location], 15.);
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut route_ctx =
+        create_route_context_with_activities(&fleet, "v1", vec![create_activity_with_single(&multi.jobs[0], 10)]);
+    let pipeline = create_pipeline();
+    pipeline.accept_route_state(&mut route_ctx);
+    let target = create_activity_with_single(&multi.jobs[1], location);
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route.tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(2),
+    };
+
+    let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result, expected);
+}
@@ -23,6 +23,7 @@ fn create_detail(
             location,
             time: time.map_or(Default::default(), |(_, end)| TimeInterval { earliest: None, latest: Some(end) }),
         }),
+        links: vec![],
     }
 }
 
@@ -133,11 +134,23 @@ mod timing {
                 "v1",
                 vec![
                     ActivityBuilder::default()
-                        .place(Place { location: 10, duration: 5., time: TimeWindow { start: 20., end: 30. } })
+                        .place(Place {
+                            idx: 0,
+                            time_idx: 0,
+                            location: 10,
+                            duration: 5.,
+                            time: TimeWindow { start: 20., end: 30. },
+                        })
                         .schedule(Schedule::new(10., 25.))
                         .build(),
                     ActivityBuilder::default()
-                        .place(Place { location: 20, duration: 10., time: TimeWindow { start: 50., end: 100. } })
+                        .place(Place {
+                            idx: 0,
+                            time_idx: 0,
+                            location: 20,
+                            duration: 10.,
+                            time: TimeWindow { start: 50., end: 100. },
+                        })
                         .schedule(Schedule::new(35., 60.))
                         .build(),
                 ],
@@ -161,7 +174,7 @@ mod timing {
             .build();
         let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![]);
         let target = Box::new(Activity {
-            place: Place { location: 5, duration: 1.0, time: DEFAULT_ACTIVITY_TIME_WINDOW },
+            place: Place { idx: 0, time_idx: 0, location: 5, duration: 1.0, time: DEFAULT_ACTIVITY_TIME_WINDOW },
             schedule: DEFAULT_ACTIVITY_SCHEDULE,
             job: None,
             commute: None,
@@ -189,16 +202,28 @@ mod timing {
             "v1",
             vec![
                 ActivityBuilder::default()
-                    .place(Place { location: 10, duration: 0.0, time: DEFAULT_ACTIVITY_TIME_WINDOW.clone() })
+                    .place(Place {
+                        idx: 0,
+                        time_idx: 0,
+                        location: 10,
+                        duration: 0.0,
+                        time: DEFAULT_ACTIVITY_TIME_WINDOW.clone(),
+                    })
                     .schedule(Schedule { arrival: 0.0, departure: 10.0 })
                     .build(),
                 ActivityBuilder::default()
-                    .place(Place { location: 20, duration: 0.0, time: TimeWindow { start: 40.0, end: 70.0 } })
+                    .place(Place {
+                        idx: 0,
+                        time_idx: 0,
+                        location: 20,
+                        duration: 0.0,
+                        time: TimeWindow { start: 40.0, end: 70.0 },
+                    })
                     .build(),
             ],
         );
         let target = Box::new(Activity {
-            place: Place { location: 30, duration: 10.0, time: DEFAULT_ACTIVITY_TIME_WINDOW },
+            place: Place { idx: 0, time_idx: 0, location: 30, duration: 10.0, time: DEFAULT_ACTIVITY_TIME_WINDOW },
             schedule: DEFAULT_ACTIVITY_SCHEDULE,
             job: None,
             commute: None,
@@ -366,6 +391,7 @@ mod traveling {
                     }
                 },
             ),
+            Arc::new(|_| None),
             1,
             2,
             3,
@@ -470,6 +496,7 @@ mod time_dependent {
             ),
             Arc::new(DynamicActivityCost::new(reserved_times).unwrap()),
             Arc::new(|_| (None, None)),
+            Arc::new(|_| None),
             1,
             2,
             3,
@@ -623,3 +650,123 @@ mod time_dependent {
         }
     }
 }
+
+mod robust {
+    use super::*;
+    use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+    use crate::models::problem::{TransportCost, TravelTime};
+    use crate::models::solution::Route;
+    use rosomaxa::algorithms::math::get_normal_quantile;
+
+    /// A transport cost with travel duration variance equal to travel duration.
+    #[derive(Default)]
+    struct UncertainTransportCost {
+        inner: TestTransportCost,
+    }
+
+    impl TransportCost for UncertainTransportCost {
+        fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+            self.inner.duration_approx(profile, from, to)
+        }
+
+        fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+            self.inner.distance_approx(profile, from, to)
+        }
+
+        fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+            self.inner.duration(route, from, to, travel_time)
+        }
+
+        fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+            self.inner.distance(route, from, to, travel_time)
+        }
+
+        fn duration_variance(&self, _: &Route, from: Location, to: Location, _: TravelTime) -> f64 {
+            fake_routing(from, to)
+        }
+    }
+
+    fn create_constraint_pipeline_and_route(
+        service_level: Option<f64>,
+        activities: Vec<ActivityData>,
+    ) -> (ConstraintPipeline, RouteContext) {
+        let activities = activities
+            .into_iter()
+            .map(|(loc, (start, end), dur)| {
+                test_activity_with_location_tw_and_duration(loc, TimeWindow::new(start, end), dur)
+            })
+            .collect();
+
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![VehicleBuilder::default()
+                .id("v1")
+                .details(vec![create_detail((Some(0), Some(0)), Some((0., 1000.)))])
+                .build()])
+            .build();
+        let route_ctx = create_route_context_with_activities(&fleet, "v1", activities);
+
+        let pipeline = create_constraint_pipeline_with_module(Arc::new(TransportConstraintModule::new(
+            Arc::new(UncertainTransportCost::default()),
+            TestActivityCost::new_shared(),
+            Arc::new(|_| (None, None)),
+            Arc::new(move |_| service_level),
+            1,
+            2,
+            3,
+        )));
+
+        (pipeline, route_ctx)
+    }
+
+    #[test]
+    fn can_update_arrival_buffers() {
+        let quantile = get_normal_quantile(0.9);
+        let (pipeline, mut route_ctx) =
+            create_constraint_pipeline_and_route(Some(0.9), vec![(10, (0., 1000.), 0.), (20, (0., 30.), 0.)]);
+
+        pipeline.accept_route_state(&mut route_ctx);
+
+        let get_state = |key: i32, index: usize| {
+            let activity = route_ctx.route.tour.get(index).unwrap();
+            route_ctx.state.get_activity_state::<f64>(key, activity).cloned().unwrap()
+        };
+        assert!((get_state(ARRIVAL_BUFFER_KEY, 1) - quantile * 10_f64.sqrt()).abs() < 1E-9);
+        assert!((get_state(ARRIVAL_BUFFER_KEY, 2) - quantile * 20_f64.sqrt()).abs() < 1E-9);
+        assert!((get_state(LATEST_ARRIVAL_KEY, 2) - (30. - quantile * 20_f64.sqrt())).abs() < 1E-9);
+    }
+
+    parameterized_test! {can_check_time_windows_with_service_level, (service_level, target, next_end, expected), {
+        can_check_time_windows_with_service_level_impl(service_level, target, next_end, expected);
+    }}
+
+    can_check_time_windows_with_service_level! {
+        case01_deterministic_target: (None, (10, 12.), 1000., None),
+        case02_robust_target_late: (Some(0.9), (10, 12.), 1000., Some(ActivityConstraintViolation { code: 1, stopped: false })),
+        case03_robust_target_in_time: (Some(0.9), (10, 15.), 1000., None),
+        case04_median_service_level: (Some(0.5), (10, 12.), 1000., None),
+        case05_deterministic_next: (None, (25, 1000.), 36., None),
+        case06_robust_next_late: (Some(0.9), (25, 1000.), 36., Some(ActivityConstraintViolation { code: 1, stopped: false })),
+        case07_robust_next_in_time: (Some(0.9), (25, 1000.), 40., None),
+    }
+
+    fn can_check_time_windows_with_service_level_impl(
+        service_level: Option<f64>,
+        target: (Location, Timestamp),
+        next_end: Timestamp,
+        expected: Option<ActivityConstraintViolation>,
+    ) {
+        let (pipeline, mut route_ctx) =
+            create_constraint_pipeline_and_route(service_level, vec![(20, (0., next_end), 0.)]);
+        pipeline.accept_route_state(&mut route_ctx);
+
+        let prev = route_ctx.route.tour.get(0).unwrap();
+        let target = test_activity_with_location_tw_and_duration(target.0, TimeWindow::new(0., target.1), 0.);
+        let next = route_ctx.route.tour.get(1);
+        let activity_ctx = ActivityContext { index: 0, prev, target: &target, next };
+
+        let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -16,7 +16,13 @@ type JobPlace = crate::models::problem::Place;
 
 fn create_activity_at(loc_and_time: usize) -> Activity {
     ActivityBuilder::default()
-        .place(Place { location: loc_and_time, duration: 0.0, time: DEFAULT_JOB_TIME_SPAN.to_time_window(0.) })
+        .place(Place {
+            idx: 0,
+            time_idx: 0,
+            location: loc_and_time,
+            duration: 0.0,
+            time: DEFAULT_JOB_TIME_SPAN.to_time_window(0.),
+        })
         .schedule(Schedule { arrival: loc_and_time as Timestamp, departure: loc_and_time as Timestamp })
         .build()
 }
@@ -164,6 +170,7 @@ mod single {
                                 location: v1_end_location,
                                 time: TimeInterval { earliest: None, latest: Some(100.) },
                             }),
+                            links: vec![],
                         }])
                         .build(),
                     VehicleBuilder::default()
@@ -177,6 +184,7 @@ mod single {
                                 location: v2_end_location,
                                 time: TimeInterval { earliest: None, latest: Some(100.) },
                             }),
+                            links: vec![],
                         }])
                         .build(),
                 ])
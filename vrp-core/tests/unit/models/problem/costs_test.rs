@@ -1,5 +1,7 @@
 use super::*;
-use crate::helpers::models::solution::{create_empty_route_ctx, test_actor_with_profile};
+use crate::helpers::models::problem::SingleBuilder;
+use crate::helpers::models::solution::{create_empty_route_ctx, test_actor_with_profile, ActivityBuilder};
+use crate::models::solution::Place;
 
 fn create_matrix_data(
     profile: Profile,
@@ -12,6 +14,8 @@ fn create_matrix_data(
         timestamp,
         durations: vec![duration.0; duration.1],
         distances: vec![distance.0; distance.1],
+        extra_costs: None,
+        duration_variances: None,
     }
 }
 
@@ -69,37 +73,181 @@ fn can_return_error_when_mixing_timestamps() {
     );
 }
 
+fn create_time_aware_costs(route0: &Route, route1: &Route) -> TimeAwareMatrixTransportCost {
+    let p0 = route0.actor.vehicle.profile.clone();
+    let p1 = route1.actor.vehicle.profile.clone();
+
+    TimeAwareMatrixTransportCost::new(
+        vec![
+            create_matrix_data(p0.clone(), Some(0.), (100., 2), (10., 2)),
+            create_matrix_data(p0, Some(100.), (200., 2), (20., 2)),
+            create_matrix_data(p1.clone(), Some(0.), (200., 2), (5., 2)),
+            create_matrix_data(p1, Some(100.), (400., 2), (5., 2)),
+        ],
+        2,
+    )
+    .unwrap()
+}
+
 #[test]
-fn can_interpolate_durations() {
+fn can_integrate_travel_over_time_buckets() {
     let route0 = Route { actor: test_actor_with_profile(0), tour: Default::default() };
     let route1 = Route { actor: test_actor_with_profile(1), tour: Default::default() };
     let p0 = route0.actor.vehicle.profile.clone();
     let p1 = route1.actor.vehicle.profile.clone();
+    let costs = create_time_aware_costs(&route0, &route1);
+
+    for &(departure, duration, distance) in
+        &[(0., 100., 10.), (20., 120., 12.), (50., 150., 15.), (100., 200., 20.), (150., 200., 20.)]
+    {
+        assert_eq!(costs.duration(&route0, 0, 1, TravelTime::Departure(departure)), duration);
+        assert_eq!(costs.distance(&route0, 0, 1, TravelTime::Departure(departure)), distance);
+    }
+
+    for &(departure, duration) in &[(0., 300.), (50., 350.), (100., 400.)] {
+        assert_eq!(costs.duration(&route1, 0, 1, TravelTime::Departure(departure)), duration);
+    }
+
+    assert_eq!(costs.distance(&route1, 0, 1, TravelTime::Departure(0.)), 5.);
+
+    assert_eq!(costs.duration_approx(&p0, 0, 1), 100.);
+    assert_eq!(costs.distance_approx(&p0, 0, 1), 10.);
+    assert_eq!(costs.distance_approx(&p1, 0, 1), 5.);
+}
+
+#[test]
+fn can_calculate_travel_backward_from_arrival() {
+    let route0 = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let route1 = Route { actor: test_actor_with_profile(1), tour: Default::default() };
+    let costs = create_time_aware_costs(&route0, &route1);
+
+    for &(arrival, duration, distance) in
+        &[(100., 100., 10.), (140., 120., 12.), (200., 150., 15.), (300., 200., 20.), (50., 100., 10.)]
+    {
+        assert_eq!(costs.duration(&route0, 0, 1, TravelTime::Arrival(arrival)), duration);
+        assert_eq!(costs.distance(&route0, 0, 1, TravelTime::Arrival(arrival)), distance);
+    }
+
+    for departure in (0..50).map(|idx| idx as f64 * 7.) {
+        let arrival = departure + costs.duration(&route1, 0, 1, TravelTime::Departure(departure));
+        let duration = costs.duration(&route1, 0, 1, TravelTime::Arrival(arrival));
+
+        assert!((arrival - duration - departure).abs() < 1E-6);
+    }
+}
 
+#[test]
+fn can_keep_fifo_property() {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
     let costs = TimeAwareMatrixTransportCost::new(
         vec![
-            create_matrix_data(p0.clone(), Some(0.), (100., 2), (1., 2)),
-            create_matrix_data(p0.clone(), Some(10.), (200., 2), (1., 2)),
-            create_matrix_data(p1.clone(), Some(0.), (300., 2), (5., 2)),
-            create_matrix_data(p1.clone(), Some(10.), (400., 2), (5., 2)),
+            create_matrix_data(profile.clone(), Some(0.), (1000., 2), (1., 2)),
+            create_matrix_data(profile.clone(), Some(100.), (10., 2), (1., 2)),
+            create_matrix_data(profile, Some(200.), (1000., 2), (1., 2)),
         ],
         2,
     )
     .unwrap();
 
-    for &(timestamp, duration) in &[(0., 100.), (10., 200.), (15., 200.), (3., 130.), (5., 150.), (7., 170.)] {
-        assert_eq!(costs.duration(&route0, 0, 1, TravelTime::Departure(timestamp)), duration);
-    }
+    let arrivals = (0..300)
+        .map(|departure| departure as f64)
+        .map(|departure| departure + costs.duration(&route, 0, 1, TravelTime::Departure(departure)))
+        .collect::<Vec<_>>();
 
-    for &(timestamp, duration) in &[(0., 300.), (10., 400.), (15., 400.), (3., 330.), (5., 350.), (7., 370.)] {
-        assert_eq!(costs.duration(&route1, 0, 1, TravelTime::Departure(timestamp)), duration);
-    }
+    assert!(arrivals.windows(2).all(|pair| pair[0] <= pair[1]));
+}
 
-    assert_eq!(costs.distance(&route0, 0, 1, TravelTime::Departure(0.)), 1.);
-    assert_eq!(costs.distance(&route1, 0, 1, TravelTime::Departure(0.)), 5.);
+#[test]
+fn can_use_extra_costs() {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
+    let with_extra_costs = |timestamp: Option<Timestamp>, extra_costs: Vec<Cost>| MatrixData {
+        extra_costs: Some(extra_costs),
+        ..create_matrix_data(profile.clone(), timestamp, (1., 4), (1., 4))
+    };
+
+    let agnostic = create_matrix_transport_cost(vec![with_extra_costs(None, vec![0., 5., 7., 0.])]).unwrap();
+    assert_eq!(agnostic.extra_cost(&route, 0, 1, TravelTime::Departure(0.)), 5.);
+    assert_eq!(agnostic.extra_cost(&route, 1, 0, TravelTime::Departure(0.)), 7.);
+    let no_extra =
+        create_matrix_transport_cost(vec![create_matrix_data(profile.clone(), None, (1., 4), (1., 4))]).unwrap();
+    assert_eq!(
+        agnostic.cost(&route, 0, 1, TravelTime::Departure(0.)),
+        no_extra.cost(&route, 0, 1, TravelTime::Departure(0.)) + 5.
+    );
 
-    assert_eq!(costs.distance_approx(&p0, 0, 1), 1.);
-    assert_eq!(costs.distance_approx(&p1, 0, 1), 5.);
+    let aware = create_matrix_transport_cost(vec![
+        with_extra_costs(Some(0.), vec![0., 5., 5., 0.]),
+        with_extra_costs(Some(100.), vec![0., 10., 10., 0.]),
+    ])
+    .unwrap();
+    assert_eq!(aware.extra_cost(&route, 0, 1, TravelTime::Departure(50.)), 5.);
+    assert_eq!(aware.extra_cost(&route, 0, 1, TravelTime::Departure(150.)), 10.);
+    assert_eq!(aware.extra_cost(&route, 0, 1, TravelTime::Arrival(100.)), 5.);
+
+    assert_eq!(
+        create_matrix_transport_cost(vec![with_extra_costs(None, vec![0.])]).err(),
+        Some("extra cost and distance collections have different length".to_string())
+    );
+}
+
+#[test]
+fn can_use_duration_variances() {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
+    let with_variances = |timestamp: Option<Timestamp>, variances: Vec<f64>| MatrixData {
+        duration_variances: Some(variances),
+        ..create_matrix_data(profile.clone(), timestamp, (1., 4), (1., 4))
+    };
+
+    let agnostic = create_matrix_transport_cost(vec![with_variances(None, vec![0., 4., 9., 0.])]).unwrap();
+    assert_eq!(agnostic.duration_variance(&route, 0, 1, TravelTime::Departure(0.)), 4.);
+    assert_eq!(agnostic.duration_variance(&route, 1, 0, TravelTime::Departure(0.)), 9.);
+    let deterministic =
+        create_matrix_transport_cost(vec![create_matrix_data(profile.clone(), None, (1., 4), (1., 4))]).unwrap();
+    assert_eq!(deterministic.duration_variance(&route, 0, 1, TravelTime::Departure(0.)), 0.);
+
+    let aware = create_matrix_transport_cost(vec![
+        with_variances(Some(0.), vec![0., 4., 4., 0.]),
+        with_variances(Some(100.), vec![0., 16., 16., 0.]),
+    ])
+    .unwrap();
+    assert_eq!(aware.duration_variance(&route, 0, 1, TravelTime::Departure(50.)), 4.);
+    assert_eq!(aware.duration_variance(&route, 0, 1, TravelTime::Departure(150.)), 16.);
+
+    assert_eq!(
+        create_matrix_transport_cost(vec![with_variances(None, vec![0.])]).err(),
+        Some("duration variance and duration collections have different length".to_string())
+    );
+}
+
+parameterized_test! {can_estimate_lateness, (time_idx, arrival, expected), {
+    can_estimate_lateness_impl(time_idx, arrival, expected);
+}}
+
+can_estimate_lateness! {
+    case01: (1, 0., (0., 0.)),
+    case02: (1, 10., (0., 0.)),
+    case03: (1, 15., (5., 10.)),
+    case04: (1, 20., (10., 20.)),
+    case05_hard_window: (0, 15., (0., 0.)),
+}
+
+fn can_estimate_lateness_impl(time_idx: usize, arrival: Timestamp, expected: (Duration, Cost)) {
+    let soft_window = SoftTimeWindow::new(TimeWindow::new(5., 10.), 10., 2.);
+    let mut dimens = Dimensions::default();
+    dimens.set_soft_time_windows(vec![vec![None, Some(soft_window.clone())]]);
+    let job = SingleBuilder::default()
+        .times(vec![TimeWindow::new(0., 5.), soft_window.to_hard_window()])
+        .dimens(dimens)
+        .build_shared();
+    let activity = ActivityBuilder::default()
+        .place(Place { idx: 0, time_idx, location: 0, duration: 0., time: soft_window.to_hard_window() })
+        .job(Some(job))
+        .build();
+
+    assert_eq!(estimate_lateness(&activity, arrival), expected);
 }
 
 parameterized_test! {can_search_for_reserved_time, (times, tests), {
@@ -1,4 +1,6 @@
-use crate::helpers::models::problem::{test_driver, test_vehicle, FleetBuilder};
+use crate::helpers::models::problem::{test_driver, test_vehicle, test_vehicle_detail, FleetBuilder};
+use crate::models::common::TimeWindow;
+use crate::models::problem::{Driver, DriverDetail, Vehicle, VehicleDetail, VehicleLink};
 
 #[test]
 fn fleet_creates_unique_profiles_from_vehicles() {
@@ -19,3 +21,65 @@ fn fleet_creates_unique_profiles_from_vehicles() {
         vec![profile1, profile2]
     )
 }
+
+#[test]
+fn fleet_creates_actors_restricted_by_driver_availability() {
+    let create_driver = |times: Vec<(f64, f64)>| Driver {
+        details: times.into_iter().map(|(start, end)| DriverDetail { time: TimeWindow::new(start, end) }).collect(),
+        ..test_driver()
+    };
+
+    let fleet = FleetBuilder::default()
+        .add_driver(create_driver(vec![(100., 200.), (2000., 3000.)]))
+        .add_driver(create_driver(vec![(500., 1500.)]))
+        .add_vehicle(test_vehicle(0))
+        .build();
+
+    let mut actors = fleet
+        .actors
+        .iter()
+        .map(|actor| {
+            let start = actor.detail.start.as_ref().unwrap();
+            let end = actor.detail.end.as_ref().unwrap();
+            (actor.detail.time.start, actor.detail.time.end, start.time.earliest, end.time.latest)
+        })
+        .collect::<Vec<_>>();
+    actors.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    assert_eq!(actors, vec![(100., 200., Some(100.), Some(200.)), (500., 1000., Some(500.), Some(1000.))]);
+}
+
+#[test]
+fn fleet_creates_actors_with_links_restricted_by_driver_availability() {
+    let driver = Driver {
+        details: vec![(100., 200.), (500., 1500.)]
+            .into_iter()
+            .map(|(start, end)| DriverDetail { time: TimeWindow::new(start, end) })
+            .collect(),
+        ..test_driver()
+    };
+    let vehicle = Vehicle {
+        details: vec![VehicleDetail {
+            links: vec![(150., 160.), (300., 400.), (600., 700.)]
+                .into_iter()
+                .map(|(start, end)| VehicleLink { location: None, time: TimeWindow::new(start, end) })
+                .collect(),
+            ..test_vehicle_detail()
+        }],
+        ..test_vehicle(0)
+    };
+
+    let fleet = FleetBuilder::default().add_driver(driver).add_vehicle(vehicle).build();
+
+    let mut actors = fleet
+        .actors
+        .iter()
+        .map(|actor| {
+            let links = actor.detail.links.iter().map(|link| (link.time.start, link.time.end)).collect::<Vec<_>>();
+            (actor.detail.time.start, links)
+        })
+        .collect::<Vec<_>>();
+    actors.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    assert_eq!(actors, vec![(100., vec![(150., 160.)]), (500., vec![(600., 700.)])]);
+}
@@ -1,7 +1,7 @@
 use crate::helpers::models::domain::test_random;
 use crate::helpers::models::problem::{test_driver, test_vehicle_detail, FleetBuilder, VehicleBuilder};
-use crate::models::common::TimeInterval;
-use crate::models::problem::{Actor, VehicleDetail, VehiclePlace};
+use crate::models::common::{IdDimension, TimeInterval, TimeWindow};
+use crate::models::problem::{Actor, Driver, DriverDetail, VehicleDetail, VehiclePlace};
 use crate::models::solution::Registry;
 use std::cmp::Ordering::Less;
 use std::sync::Arc;
@@ -63,6 +63,107 @@ fn create_two_test_vehicle_details() -> Vec<VehicleDetail> {
         VehicleDetail {
             start: Some(VehiclePlace { location: 1, time: TimeInterval { earliest: Some(0.), latest: None } }),
             end: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: None, latest: Some(50.) } }),
+            links: vec![],
         },
     ]
 }
+
+fn create_limited_driver(id: &str, times: Vec<(f64, f64)>) -> Driver {
+    let mut driver = test_driver();
+    driver.dimens.set_id(id);
+    driver.details = times.into_iter().map(|(start, end)| DriverDetail { time: TimeWindow::new(start, end) }).collect();
+
+    driver
+}
+
+fn get_available_pairs(registry: &Registry) -> Vec<(String, String)> {
+    let mut pairs = registry
+        .available()
+        .map(|actor| (actor.vehicle.dimens.get_id().unwrap().clone(), actor.driver.dimens.get_id().unwrap().clone()))
+        .collect::<Vec<_>>();
+    pairs.sort();
+
+    pairs
+}
+
+fn find_actor(registry: &Registry, vehicle_id: &str, driver_id: &str) -> Arc<Actor> {
+    registry
+        .all()
+        .find(|actor| {
+            actor.vehicle.dimens.get_id().unwrap() == vehicle_id && actor.driver.dimens.get_id().unwrap() == driver_id
+        })
+        .unwrap()
+}
+
+#[test]
+fn can_block_actors_sharing_vehicle_or_limited_driver() {
+    let fleet = FleetBuilder::default()
+        .add_driver(create_limited_driver("d1", vec![(0., 1000.)]))
+        .add_driver(create_limited_driver("d2", vec![(0., 1000.)]))
+        .add_vehicles(vec![VehicleBuilder::default().id("v1").build(), VehicleBuilder::default().id("v2").build()])
+        .build();
+    let mut registry = Registry::new(&fleet, test_random());
+    assert_eq!(registry.available().count(), 4);
+
+    let actor = find_actor(&registry, "v1", "d1");
+    assert!(registry.use_actor(&actor));
+    assert_eq!(get_available_pairs(&registry), vec![("v2".to_string(), "d2".to_string())]);
+
+    let other = find_actor(&registry, "v2", "d2");
+    assert!(registry.use_actor(&other));
+    assert_eq!(registry.available().count(), 0);
+
+    assert!(registry.free_actor(&actor));
+    assert_eq!(
+        get_available_pairs(&registry),
+        vec![("v1".to_string(), "d1".to_string())],
+        "actors sharing vehicle or driver with used actor should stay blocked"
+    );
+
+    assert!(!registry.free_actor(&actor));
+    registry.free_actor(&other);
+    assert_eq!(registry.available().count(), 4);
+}
+
+#[test]
+fn can_use_limited_driver_in_non_intersecting_shifts() {
+    let fleet = FleetBuilder::default()
+        .add_driver(create_limited_driver("d1", vec![(0., 100.), (200., 300.)]))
+        .add_vehicles(vec![VehicleBuilder::default().id("v1").build(), VehicleBuilder::default().id("v2").build()])
+        .build();
+    let mut registry = Registry::new(&fleet, test_random());
+    assert_eq!(registry.available().count(), 4);
+
+    let actor = registry.all().find(|actor| actor.detail.time.start == 0.).unwrap();
+    registry.use_actor(&actor);
+
+    assert!(registry.available().all(|actor| actor.detail.time.start == 200.));
+    assert_eq!(registry.available().count(), 2);
+}
+
+#[test]
+fn can_keep_actor_blocked_by_other_used_actor_after_free() {
+    let fleet = FleetBuilder::default()
+        .add_driver(create_limited_driver("d1", vec![(0., 1000.)]))
+        .add_driver(create_limited_driver("d2", vec![(0., 1000.)]))
+        .add_vehicles(vec![
+            VehicleBuilder::default().id("v1").build(),
+            VehicleBuilder::default().id("v2").build(),
+            VehicleBuilder::default().id("v3").build(),
+        ])
+        .build();
+    let mut registry = Registry::new(&fleet, test_random());
+    let actor = find_actor(&registry, "v1", "d1");
+    let other = find_actor(&registry, "v2", "d2");
+    registry.use_actor(&actor);
+    registry.use_actor(&other);
+    assert!(registry.available().next().is_none());
+
+    assert!(registry.free_actor(&actor));
+
+    assert_eq!(
+        get_available_pairs(&registry),
+        vec![("v1".to_string(), "d1".to_string()), ("v3".to_string(), "d1".to_string())],
+        "actor sharing vehicle with freed actor and driver with used actor should stay blocked"
+    );
+}
@@ -190,3 +190,18 @@ fn can_get_start_and_end() {
     assert_eq!(get_memory_address(tour.start().unwrap()), get_memory_address(&tour.activities[0]));
     assert_eq!(get_memory_address(tour.end().unwrap()), get_memory_address(&tour.activities[3]));
 }
+
+#[test]
+fn can_set_and_get_links() {
+    let mut tour = get_test_tour();
+    let links = vec![
+        TourLink { leg_index: 1, location: 1, schedule: Schedule::new(10., 20.) },
+        TourLink { leg_index: 2, location: 2, schedule: Schedule::new(30., 40.) },
+    ];
+
+    tour.set_links(links.clone());
+    let copy = tour.deep_copy();
+
+    assert_eq!(tour.links().cloned().collect::<Vec<_>>(), links);
+    assert_eq!(copy.links().cloned().collect::<Vec<_>>(), links);
+}
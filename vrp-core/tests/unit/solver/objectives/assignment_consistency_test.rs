@@ -0,0 +1,77 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_solution_context};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::IdDimension;
+use crate::models::solution::Activity;
+use crate::models::{LockDetail, LockOrder, LockPosition};
+
+fn create_job(id: &str) -> Job {
+    let mut single = test_single();
+    single.dimens.set_id(id);
+
+    Job::Single(Arc::new(single))
+}
+
+fn create_lock(vehicle_id: &str, jobs: Vec<Job>) -> Arc<Lock> {
+    let vehicle_id = vehicle_id.to_string();
+    Arc::new(Lock::new(
+        Arc::new(move |actor| actor.vehicle.dimens.get_id() == Some(&vehicle_id)),
+        vec![LockDetail::new(LockOrder::Any, LockPosition::Any, jobs)],
+        false,
+    ))
+}
+
+fn create_fleet() -> Fleet {
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build()
+}
+
+fn create_activity(job: &Job) -> Activity {
+    Activity { job: job.as_single().cloned(), ..test_activity() }
+}
+
+#[test]
+fn can_count_reassigned_jobs() {
+    let fleet = create_fleet();
+    let (job1, job2, job3) = (create_job("job1"), create_job("job2"), create_job("job3"));
+    let (_, objective) = AssignmentConsistency::new_minimized(&[
+        create_lock("v1", vec![job1.clone()]),
+        create_lock("v2", vec![job2.clone()]),
+    ]);
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes = vec![create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![create_activity(&job1), create_activity(&job2), create_activity(&job3)],
+    )];
+
+    let fitness = objective.fitness(&insertion_ctx);
+
+    assert_eq!(fitness, 1.);
+}
+
+parameterized_test! {can_estimate_job_insertion, (vehicle_id, expected), {
+    can_estimate_job_insertion_impl(vehicle_id, expected);
+}}
+
+can_estimate_job_insertion! {
+    case01_locked_vehicle: ("v1", 0.),
+    case02_another_vehicle: ("v2", 1E9),
+}
+
+fn can_estimate_job_insertion_impl(vehicle_id: &str, expected: Cost) {
+    let fleet = create_fleet();
+    let job = create_job("job1");
+    let (module, _) = AssignmentConsistency::new_minimized(&[create_lock("v1", vec![job.clone()])]);
+    let pipeline = create_constraint_pipeline_with_module(module);
+    let route_ctx = create_route_context_with_activities(&fleet, vehicle_id, vec![]);
+    let solution_ctx = create_empty_solution_context();
+
+    let cost = pipeline.evaluate_soft_route(&solution_ctx, &route_ctx, &job);
+
+    assert_eq!(cost, expected);
+}
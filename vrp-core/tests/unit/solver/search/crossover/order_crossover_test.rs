@@ -0,0 +1,49 @@
+use super::*;
+use crate::helpers::models::domain::{get_customer_ids_from_jobs, get_customer_ids_from_routes};
+use crate::helpers::solver::*;
+use rosomaxa::prelude::Environment;
+
+parameterized_test! {can_create_offspring_tour, (first, second, bounds, expected), {
+    can_create_offspring_tour_impl(first, second, bounds, expected);
+}}
+
+can_create_offspring_tour! {
+    case01_same_jobs: (vec!["c0", "c1", "c2", "c3", "c4", "c5"], vec!["c5", "c4", "c3", "c2", "c1", "c0"], (2, 3),
+        vec!["c5", "c4", "c2", "c3", "c1", "c0"]),
+    case02_whole_slice: (vec!["c0", "c1", "c2"], vec!["c2", "c1", "c0"], (0, 2), vec!["c0", "c1", "c2"]),
+    case03_first_only_job: (vec!["c0", "c1", "c2"], vec!["c2", "c0"], (0, 0), vec!["c0", "c2", "c1"]),
+    case04_second_only_job: (vec!["c0", "c1"], vec!["c1", "c2", "c0"], (1, 1), vec!["c0", "c2", "c1"]),
+}
+
+fn can_create_offspring_tour_impl(first: Vec<&str>, second: Vec<&str>, bounds: (usize, usize), expected: Vec<&str>) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let insertion_ctx =
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()));
+    let first = get_jobs_by_ids(&insertion_ctx, first.as_slice());
+    let second = get_jobs_by_ids(&insertion_ctx, second.as_slice());
+
+    let result = get_offspring_tour(first.as_slice(), second.as_slice(), bounds);
+
+    assert_eq!(get_customer_ids_from_jobs(result.as_slice()), expected);
+}
+
+#[test]
+fn can_split_giant_tour_into_routes() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let first =
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()));
+    let mut second = first.deep_copy();
+    rearrange_jobs_in_routes(&mut second, &[vec!["c5", "c4", "c3"], vec!["c2", "c1", "c0"]]);
+    let refinement_ctx = create_default_refinement_ctx(first.problem.clone());
+
+    let result = OrderCrossover::default().run(&refinement_ctx, &first, &second);
+
+    let mut ids = get_customer_ids_from_routes(&result)
+        .into_iter()
+        .flatten()
+        .chain(get_customer_ids_from_jobs(result.solution.required.as_slice()))
+        .collect::<Vec<_>>();
+    ids.sort();
+    assert_eq!(ids, vec!["c0", "c1", "c2", "c3", "c4", "c5"]);
+    assert!(!result.solution.routes.is_empty());
+}
@@ -0,0 +1,65 @@
+use super::*;
+use crate::helpers::models::domain::{get_customer_ids_from_jobs, get_customer_ids_from_routes};
+use crate::helpers::solver::*;
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+
+fn create_parents(first_order: &[Vec<&str>], second_order: &[Vec<&str>]) -> (InsertionContext, InsertionContext) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let environment = create_test_environment_with_random(Arc::new(FakeRandom::new(vec![1], vec![])));
+    let mut first = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment);
+    let mut second = first.deep_copy();
+
+    rearrange_jobs_in_routes(&mut first, first_order);
+    rearrange_jobs_in_routes(&mut second, second_order);
+
+    (first, second)
+}
+
+fn get_assigned_and_required(insertion_ctx: &InsertionContext) -> Vec<String> {
+    let mut ids = get_customer_ids_from_routes(insertion_ctx)
+        .into_iter()
+        .flatten()
+        .chain(get_customer_ids_from_jobs(insertion_ctx.solution.required.as_slice()))
+        .collect::<Vec<_>>();
+    ids.sort();
+
+    ids
+}
+
+parameterized_test! {can_exchange_routes, (second_order, expected_routes), {
+    can_exchange_routes_impl(second_order, expected_routes);
+}}
+
+can_exchange_routes! {
+    case01_move_single_job: (vec![vec!["c0", "c1", "c2", "c3"], vec!["c4", "c5"]],
+        vec![vec!["c0", "c1", "c2", "c3"], vec!["c4", "c5"]]),
+    case02_swap_jobs: (vec![vec!["c0", "c4", "c2"], vec!["c3", "c1", "c5"]],
+        vec![vec!["c0", "c4", "c2"], vec!["c3", "c5"]]),
+}
+
+fn can_exchange_routes_impl(second_order: Vec<Vec<&str>>, expected_routes: Vec<Vec<&str>>) {
+    let (first, second) = create_parents(&[vec!["c0", "c1", "c2"], vec!["c3", "c4", "c5"]], second_order.as_slice());
+    let refinement_ctx = create_default_refinement_ctx(first.problem.clone());
+
+    let result = SelectiveRouteExchange::new(1, 1.).run(&refinement_ctx, &first, &second);
+
+    let routes = get_customer_ids_from_routes(&result);
+    assert!(expected_routes.iter().any(|expected| routes.iter().any(|route| route == expected)));
+    assert_eq!(get_assigned_and_required(&result), vec!["c0", "c1", "c2", "c3", "c4", "c5"]);
+}
+
+#[test]
+fn can_keep_routes_with_locked_jobs() {
+    let (first, second) = create_parents(
+        &[vec!["c0", "c1", "c2"], vec!["c3", "c4", "c5"]],
+        &[vec!["c0", "c4", "c2"], vec!["c3", "c1", "c5"]],
+    );
+    let first = promote_to_locked(first, &["c0", "c3"]);
+    let refinement_ctx = create_default_refinement_ctx(first.problem.clone());
+
+    let result = SelectiveRouteExchange::default().run(&refinement_ctx, &first, &second);
+
+    assert_eq!(get_customer_ids_from_routes(&result), vec![vec!["c0", "c1", "c2"], vec!["c3", "c4", "c5"]]);
+    assert!(result.solution.required.is_empty());
+}
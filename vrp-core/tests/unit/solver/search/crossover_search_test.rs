@@ -0,0 +1,51 @@
+use super::*;
+use crate::helpers::solver::{generate_matrix_routes_with_defaults, rearrange_jobs_in_routes};
+use crate::solver::{create_default_crossover_operator, GreedyPopulation};
+use hashbrown::HashSet;
+use rosomaxa::evolution::TelemetryMode;
+use rosomaxa::utils::Timer;
+
+fn create_refinement_ctx(problem: Arc<crate::models::Problem>, environment: Arc<Environment>) -> RefinementContext {
+    let population = Box::new(GreedyPopulation::new(problem.objective.clone(), 1, None));
+    RefinementContext::new(problem, population, TelemetryMode::None, environment)
+}
+
+#[test]
+fn can_recombine_with_mate_from_population() {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 7, false);
+    let problem = Arc::new(problem);
+    let insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone());
+    let mut mate = insertion_ctx.deep_copy();
+    let order =
+        (0..7).map(|route| (0..5).map(|job| format!("c{}", job * 7 + route)).collect::<Vec<_>>()).collect::<Vec<_>>();
+    let order = order.iter().map(|route| route.iter().map(String::as_str).collect()).collect::<Vec<Vec<_>>>();
+    rearrange_jobs_in_routes(&mut mate, order.as_slice());
+    let mut refinement_ctx = create_refinement_ctx(problem, environment.clone());
+    refinement_ctx.on_initial(mate, Timer::start());
+
+    let result = create_default_crossover_operator(environment).search(&refinement_ctx, &insertion_ctx);
+
+    let solution = &result.solution;
+    assert!(solution.unassigned.is_empty());
+    assert!(solution.required.is_empty());
+    assert_eq!(
+        solution.routes.iter().flat_map(|route_ctx| route_ctx.route.tour.jobs()).collect::<HashSet<_>>().len(),
+        35
+    );
+    assert_eq!(solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>(), 35);
+}
+
+#[test]
+fn can_return_copy_without_mates() {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let problem = Arc::new(problem);
+    let insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone());
+    let refinement_ctx = create_refinement_ctx(problem, environment.clone());
+
+    let result = create_default_crossover_operator(environment).search(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(result.solution.routes.len(), 2);
+    assert!(result.solution.required.is_empty());
+}
@@ -0,0 +1,141 @@
+use super::*;
+use crate::helpers::models::domain::{get_customer_ids_from_jobs, get_customer_ids_from_routes};
+use crate::helpers::solver::*;
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+use std::sync::Arc;
+
+parameterized_test! {can_use_exchange_cross, (route_idx, jobs_order, locked_ids, max_segment_size, expected), {
+    can_use_exchange_cross_impl(route_idx, jobs_order, locked_ids, max_segment_size, expected);
+}}
+
+can_use_exchange_cross! {
+    case01_cross: (0, vec![vec!["c0", "c4", "c5"], vec!["c3", "c1", "c2"]], vec![], 3,
+        Some(vec![vec!["c0", "c3", "c4", "c5"], vec!["c1", "c2"]])),
+    case02_cross_reversed: (0, vec![vec!["c0", "c5", "c4"], vec!["c3", "c2", "c1"]], vec![], 3,
+        Some(vec![vec!["c0", "c1", "c2", "c5", "c4"], vec!["c3"]])),
+    case03_relocate: (1, vec![vec!["c0", "c1", "c2", "c4"], vec!["c3", "c5"]], vec![], 3,
+        Some(vec![vec!["c0", "c1", "c2"], vec!["c3", "c4", "c5"]])),
+    case04_short_segment: (0, vec![vec!["c0", "c4", "c5"], vec!["c3", "c1", "c2"]], vec![], 1,
+        Some(vec![vec!["c0", "c3", "c4", "c5"], vec!["c1", "c2"]])),
+    case05_partially_locked: (1, vec![vec!["c0", "c4", "c5"], vec!["c3", "c1", "c2"]], vec!["c0", "c3"], 3,
+        Some(vec![vec!["c0", "c1"], vec!["c3", "c4", "c5", "c2"]])),
+    case06_locked: (0, vec![vec!["c0", "c4", "c5"], vec!["c3", "c1", "c2"]], vec!["c3", "c4", "c1"], 3, None),
+    case07_optimal: (0, vec![vec!["c0", "c1", "c2"], vec!["c3", "c4", "c5"]], vec![], 3, None),
+}
+
+fn can_use_exchange_cross_impl(
+    route_idx: i32,
+    jobs_order: Vec<Vec<&str>>,
+    locked_ids: Vec<&str>,
+    max_segment_size: usize,
+    expected: Option<Vec<Vec<&str>>>,
+) {
+    let insertion_ctx = create_insertion_ctx(route_idx, jobs_order, locked_ids, vec![]);
+
+    let result = explore(&insertion_ctx, max_segment_size);
+
+    assert_result(result, expected);
+}
+
+parameterized_test! {can_use_exchange_cross_with_vehicle_bound_job, (route_idx, jobs_order, bound_jobs, expected), {
+    can_use_exchange_cross_with_vehicle_bound_job_impl(route_idx, jobs_order, bound_jobs, expected);
+}}
+
+can_use_exchange_cross_with_vehicle_bound_job! {
+    case01_bound_job_kept: (0, vec![vec!["c0", "c4", "c5"], vec!["c3", "c1", "c2"]], vec![("c1", "1")],
+        Some(vec![vec!["c0", "c3", "c4", "c5"], vec!["c1", "c2"]])),
+    case02_bound_job_moved: (0, vec![vec!["c0", "c5", "c4"], vec!["c3", "c2", "c1"]], vec![("c1", "1")], None),
+    case03_bound_job_in_both: (0, vec![vec!["c0", "c4", "c5"], vec!["c3", "c1", "c2"]], vec![("c3", "1"), ("c4", "0")], None),
+}
+
+fn can_use_exchange_cross_with_vehicle_bound_job_impl(
+    route_idx: i32,
+    jobs_order: Vec<Vec<&str>>,
+    bound_jobs: Vec<(&str, &str)>,
+    expected: Option<Vec<Vec<&str>>>,
+) {
+    let insertion_ctx = create_insertion_ctx(route_idx, jobs_order, vec![], bound_jobs);
+
+    let result = explore(&insertion_ctx, 3);
+
+    assert_result(result, expected);
+}
+
+fn create_insertion_ctx(
+    route_idx: i32,
+    jobs_order: Vec<Vec<&str>>,
+    locked_ids: Vec<&str>,
+    bound_jobs: Vec<(&str, &str)>,
+) -> InsertionContext {
+    let environment = create_test_environment_with_random(Arc::new(FakeRandom::new(vec![route_idx], vec![])));
+    let (mut problem, solution) = generate_matrix_routes_with_defaults(3, 2, true);
+    add_vehicle_job_constraint(&mut problem, bound_jobs);
+    let mut insertion_ctx = promote_to_locked(
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment),
+        locked_ids.as_slice(),
+    );
+    rearrange_jobs_in_routes(&mut insertion_ctx, jobs_order.as_slice());
+    insertion_ctx.problem.constraint.accept_solution_state(&mut insertion_ctx.solution);
+
+    insertion_ctx
+}
+
+fn explore(insertion_ctx: &InsertionContext, max_segment_size: usize) -> Option<InsertionContext> {
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    ExchangeCross::new(max_segment_size, 10, 4).explore(&refinement_ctx, insertion_ctx)
+}
+
+fn assert_result(result: Option<InsertionContext>, expected: Option<Vec<Vec<&str>>>) {
+    match (result, expected) {
+        (Some(result), Some(expected)) => {
+            compare_with_ignore(get_customer_ids_from_routes(&result).as_slice(), expected.as_slice(), "")
+        }
+        (None, None) => {}
+        (result, expected) => {
+            unreachable!(
+                "unexpected result: {:?}, expected: {:?}",
+                result.map(|r| get_customer_ids_from_routes(&r)),
+                expected
+            )
+        }
+    }
+}
+
+parameterized_test! {can_replace_segment, (segment, other_segment, expected), {
+    can_replace_segment_impl(segment, other_segment, expected);
+}}
+
+can_replace_segment! {
+    case01_same_size: ((1, 2, false), (0, 2, false), vec!["c0", "c3", "c4"]),
+    case02_reversed: ((1, 2, false), (0, 2, true), vec!["c0", "c4", "c3"]),
+    case03_empty: ((1, 0, false), (1, 1, false), vec!["c0", "c4", "c1", "c2"]),
+    case04_longer: ((0, 1, false), (0, 3, false), vec!["c3", "c4", "c5", "c1", "c2"]),
+}
+
+fn can_replace_segment_impl(segment: (usize, usize, bool), other_segment: (usize, usize, bool), expected: Vec<&str>) {
+    let create_segment = |route_idx: usize, (start, length, reversed): (usize, usize, bool)| CrossSegment {
+        route_idx,
+        start,
+        length,
+        reversed,
+    };
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, true);
+    let insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(vec![], vec![]))),
+    );
+    let jobs = get_route_jobs(&insertion_ctx, 0);
+    let other_jobs = get_route_jobs(&insertion_ctx, 1);
+
+    let result = replace_segment(
+        jobs.as_slice(),
+        &create_segment(0, segment),
+        other_jobs.as_slice(),
+        &create_segment(1, other_segment),
+    );
+
+    assert_eq!(get_customer_ids_from_jobs(result.as_slice()), expected);
+}
@@ -0,0 +1,147 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_ids_from_routes;
+use crate::helpers::solver::*;
+use rosomaxa::prelude::Environment;
+use std::sync::Arc;
+
+fn create_insertion_ctx(
+    jobs_order: &[&str],
+    locked_ids: &[&str],
+    disallowed_pairs: Vec<(&str, &str)>,
+    is_open_vrp: bool,
+) -> InsertionContext {
+    let (mut problem, solution) = generate_matrix_routes_with_defaults(jobs_order.len(), 1, is_open_vrp);
+    add_leg_constraint(&mut problem, disallowed_pairs);
+    let mut insertion_ctx = promote_to_locked(
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default())),
+        locked_ids,
+    );
+    rearrange_jobs_in_routes(&mut insertion_ctx, &[jobs_order.to_vec()]);
+    insertion_ctx.problem.constraint.accept_solution_state(&mut insertion_ctx.solution);
+
+    insertion_ctx
+}
+
+fn create_operator(name: &str) -> Box<dyn LocalOperator> {
+    match name {
+        "two-opt" => Box::new(ExchangeTwoOpt::default()),
+        "or-opt" => Box::new(ExchangeOrOpt::default()),
+        "three-opt" => Box::new(ExchangeThreeOpt::default()),
+        _ => unreachable!(),
+    }
+}
+
+parameterized_test! {can_use_edge_operator, (operator, jobs_order, locked_ids, disallowed_pairs, is_open_vrp, expected), {
+    can_use_edge_operator_impl(operator, jobs_order, locked_ids, disallowed_pairs, is_open_vrp, expected);
+}}
+
+can_use_edge_operator! {
+    case01_two_opt: ("two-opt", &["c0", "c3", "c2", "c1", "c4", "c5"], &[], vec![], false,
+        Some(vec!["c0", "c1", "c2", "c3", "c4", "c5"])),
+    case02_two_opt_open: ("two-opt", &["c0", "c1", "c5", "c4", "c3", "c2"], &[], vec![], true,
+        Some(vec!["c0", "c1", "c2", "c3", "c4", "c5"])),
+    case03_two_opt_locked: ("two-opt", &["c0", "c3", "c2", "c1", "c4", "c5"], &["c1", "c2", "c3"], vec![], false, None),
+    case04_two_opt_optimal: ("two-opt", &["c0", "c1", "c2", "c3", "c4", "c5"], &[], vec![], false, None),
+
+    case05_or_opt: ("or-opt", &["c0", "c2", "c3", "c4", "c1", "c5"], &[], vec![], false,
+        Some(vec!["c0", "c1", "c2", "c3", "c4", "c5"])),
+    case06_or_opt_segment: ("or-opt", &["c0", "c4", "c5", "c1", "c2", "c3"], &[], vec![], true,
+        Some(vec!["c0", "c1", "c2", "c3", "c4", "c5"])),
+    case07_or_opt_constrained: ("or-opt", &["c0", "c2", "c3", "c4", "c1", "c5"], &[], vec![("c1", "cX")], false,
+        Some(vec!["c0", "c2", "c3", "c5", "c4", "c1"])),
+
+    case08_three_opt: ("three-opt", &["c0", "c3", "c4", "c1", "c2", "c5"], &[], vec![], true,
+        Some(vec!["c0", "c1", "c2", "c3", "c4", "c5"])),
+    case09_three_opt_locked: ("three-opt", &["c0", "c3", "c4", "c1", "c2", "c5"], &["c5", "c4"], vec![], false,
+        Some(vec!["c0", "c1", "c4", "c3", "c2", "c5"])),
+}
+
+fn can_use_edge_operator_impl(
+    operator: &str,
+    jobs_order: &[&str],
+    locked_ids: &[&str],
+    disallowed_pairs: Vec<(&str, &str)>,
+    is_open_vrp: bool,
+    expected: Option<Vec<&str>>,
+) {
+    let insertion_ctx = create_insertion_ctx(jobs_order, locked_ids, disallowed_pairs, is_open_vrp);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result = create_operator(operator).explore(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(
+        result.map(|insertion_ctx| get_customer_ids_from_routes(&insertion_ctx)),
+        expected.map(|ids| { vec![ids.into_iter().map(|id| id.to_string()).collect::<Vec<_>>()] })
+    );
+}
+
+parameterized_test! {can_use_edge_operator_with_vehicle_bound_job, (operator, jobs_order, disallowed_pairs, is_open_vrp, expected), {
+    can_use_edge_operator_with_vehicle_bound_job_impl(operator, jobs_order, disallowed_pairs, is_open_vrp, expected);
+}}
+
+can_use_edge_operator_with_vehicle_bound_job! {
+    case01_two_opt: ("two-opt", &["c0", "c3", "c2", "c1", "c4", "c5"], vec![], false,
+        Some(vec!["c0", "c1", "c2", "c3", "c4", "c5"])),
+    case02_two_opt_constrained: ("two-opt", &["c0", "c3", "c2", "c1", "c4", "c5"], vec![("c1", "cX")], false,
+        Some(vec!["c0", "c2", "c3", "c5", "c4", "c1"])),
+    case03_or_opt: ("or-opt", &["c0", "c2", "c3", "c4", "c1", "c5"], vec![], false,
+        Some(vec!["c0", "c1", "c2", "c3", "c4", "c5"])),
+    case04_three_opt: ("three-opt", &["c0", "c3", "c4", "c1", "c2", "c5"], vec![], true,
+        Some(vec!["c0", "c1", "c2", "c3", "c4", "c5"])),
+}
+
+fn can_use_edge_operator_with_vehicle_bound_job_impl(
+    operator: &str,
+    jobs_order: &[&str],
+    disallowed_pairs: Vec<(&str, &str)>,
+    is_open_vrp: bool,
+    expected: Option<Vec<&str>>,
+) {
+    let mut insertion_ctx = create_insertion_ctx(jobs_order, &[], disallowed_pairs, is_open_vrp);
+    // NOTE c2 is bound to the vehicle as break or reload
+    add_vehicle_job_constraint(Arc::get_mut(&mut insertion_ctx.problem).unwrap(), vec![("c2", "0")]);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result = create_operator(operator).explore(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(
+        result.map(|insertion_ctx| get_customer_ids_from_routes(&insertion_ctx)),
+        expected.map(|ids| { vec![ids.into_iter().map(|id| id.to_string()).collect::<Vec<_>>()] })
+    );
+}
+
+parameterized_test! {can_check_locked_positions, (edge_move, locked, expected), {
+    can_check_locked_positions_impl(edge_move, locked, expected);
+}}
+
+can_check_locked_positions! {
+    case01_not_moved: (vec![Segment(0, 1, false), Segment(1, 4, true), Segment(4, 5, false)], vec![0, 2, 4], true),
+    case02_reversed: (vec![Segment(0, 1, false), Segment(1, 4, true), Segment(4, 5, false)], vec![1], false),
+    case03_relocated: (vec![Segment(0, 1, false), Segment(3, 5, false), Segment(1, 3, false)], vec![0], true),
+    case04_shifted: (vec![Segment(0, 1, false), Segment(3, 5, false), Segment(1, 3, false)], vec![3], false),
+}
+
+fn can_check_locked_positions_impl(edge_move: Vec<Segment>, locked: Vec<usize>, expected: bool) {
+    assert_eq!(is_locked_kept(edge_move.as_slice(), locked.as_slice()), expected);
+}
+
+parameterized_test! {can_estimate_edge_move_cost, (is_open_vrp, edge_move, expected), {
+    can_estimate_edge_move_cost_impl(is_open_vrp, edge_move, expected);
+}}
+
+can_estimate_edge_move_cost! {
+    case01_closed_same: (false, vec![Segment(0, 4, false)], 12.),
+    case02_closed_reversed: (false, vec![Segment(0, 1, false), Segment(1, 3, true), Segment(3, 4, false)], 16.),
+    case03_closed_whole_reversed: (false, vec![Segment(0, 4, true)], 12.),
+    case04_open_same: (true, vec![Segment(0, 4, false)], 6.),
+    case05_open_whole_reversed: (true, vec![Segment(0, 4, true)], 12.),
+    case06_open_relocated: (true, vec![Segment(2, 4, false), Segment(0, 2, false)], 14.),
+}
+
+fn can_estimate_edge_move_cost_impl(is_open_vrp: bool, edge_move: Vec<Segment>, expected: Cost) {
+    let insertion_ctx = create_insertion_ctx(&["c0", "c1", "c2", "c3"], &[], vec![], is_open_vrp);
+
+    let result = EdgeCosts::new(&insertion_ctx, 0).estimate(edge_move.as_slice());
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,54 @@
+use super::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use hashbrown::HashSet;
+
+parameterized_test! {can_split_giant_tour, (route_costs, max_routes, expected), {
+    can_split_giant_tour_impl(route_costs, max_routes, expected);
+}}
+
+can_split_giant_tour! {
+    case01_single_route: (vec![vec![1., 2., 3.], vec![1., 2.], vec![1.]], 3, vec![(0, 2)]),
+    case02_capacity_limit: (vec![vec![10., 12.], vec![10., 12.], vec![10., 12.], vec![10.]], 4, vec![(0, 1), (2, 3)]),
+    case03_cheaper_split: (vec![vec![1., 10., 20.], vec![1., 2.], vec![1.]], 3, vec![(0, 0), (1, 2)]),
+    case04_skip_job: (vec![vec![1.], vec![], vec![1.]], 3, vec![(0, 0), (2, 2)]),
+    case05_empty_tour: (vec![], 3, vec![]),
+    case06_fleet_limit_expensive_split: (vec![vec![1., 10., 20.], vec![1., 2.], vec![1.]], 1, vec![(0, 2)]),
+    case07_fleet_limit_skip_jobs: (vec![vec![10., 12.], vec![10., 14.], vec![10., 14.], vec![10.]], 1, vec![(0, 1)]),
+    case08_fleet_limit_cheaper_routes: (vec![vec![5., 6.], vec![1., 2.], vec![1., 3.], vec![1.]], 1, vec![(1, 2)]),
+    case09_no_fleet: (vec![vec![1.], vec![1.]], 0, vec![]),
+}
+
+fn can_split_giant_tour_impl(route_costs: Vec<Vec<Cost>>, max_routes: usize, expected: Vec<(usize, usize)>) {
+    let bounds = get_split_bounds(route_costs.len(), max_routes, |start| route_costs[start].clone());
+
+    assert_eq!(bounds, expected);
+}
+
+parameterized_test! {can_assign_all_jobs, max_route_size, {
+    can_assign_all_jobs_impl(max_route_size);
+}}
+
+can_assign_all_jobs! {
+    case01_unbounded: 12,
+    case02_more_routes_than_vehicles: 2,
+}
+
+fn can_assign_all_jobs_impl(max_route_size: usize) {
+    let (problem, _) = generate_matrix_routes_with_defaults(4, 3, false);
+    let problem = Arc::new(problem);
+    let refinement_ctx = create_default_refinement_ctx(problem.clone());
+    let insertion_ctx = InsertionContext::new(problem, refinement_ctx.environment.clone());
+
+    let result = RecreateWithSplit::new(max_route_size, refinement_ctx.environment.random.clone())
+        .run(&refinement_ctx, insertion_ctx);
+
+    let solution = &result.solution;
+    assert!(solution.unassigned.is_empty());
+    assert!(solution.required.is_empty());
+    assert!(!solution.routes.is_empty());
+    assert!(solution.routes.len() <= 3);
+    assert_eq!(
+        solution.routes.iter().flat_map(|route_ctx| route_ctx.route.tour.jobs()).collect::<HashSet<_>>().len(),
+        12
+    );
+}
@@ -3,22 +3,31 @@
 mod assignment_test;
 
 use super::*;
+use crate::constraints::{get_day_index, is_visit_allowed, JobSync};
+use crate::extensions::get_service_duration;
 use crate::format::solution::activity_matcher::*;
 use crate::format::{get_coord_index, get_job_index};
 use crate::utils::combine_error_results;
 use hashbrown::HashSet;
 use std::cmp::Ordering;
 use vrp_core::construction::clustering::vicinity::ServingPolicy;
+use vrp_core::models::common::ValueDimension;
 use vrp_core::prelude::compare_floats;
+use vrp_core::utils::CollectGroupBy;
 
 /// Checks assignment of jobs and vehicles.
 pub fn check_assignment(ctx: &CheckerContext) -> Result<(), Vec<String>> {
     combine_error_results(&[
         check_vehicles(ctx),
+        check_drivers(ctx),
         check_jobs_presence(ctx),
         check_jobs_match(ctx),
         check_dispatch(ctx),
         check_groups(ctx),
+        check_ride_time(ctx),
+        check_visits(ctx),
+        check_transfers(ctx),
+        check_docks(ctx),
     ])
 }
 
@@ -42,6 +51,42 @@ fn check_vehicles(ctx: &CheckerContext) -> Result<(), String> {
     Ok(())
 }
 
+/// Checks that drivers are known in problem and each of them drives only one vehicle at the same time.
+fn check_drivers(ctx: &CheckerContext) -> Result<(), String> {
+    let all_drivers: HashSet<_> =
+        ctx.problem.fleet.drivers.iter().flat_map(|drivers| drivers.iter()).flat_map(|d| d.driver_ids.iter()).collect();
+    let mut used_drivers = HashMap::<String, Vec<TimeWindow>>::new();
+
+    ctx.solution.tours.iter().try_for_each(|tour| match (all_drivers.is_empty(), tour.driver_id.as_ref()) {
+        (true, None) => Ok(()),
+        (_, Some(driver_id)) if !all_drivers.contains(driver_id) => {
+            Err(format!("used driver with unknown id: '{}' in tour '{}'", driver_id, tour.vehicle_id))
+        }
+        (false, None) => Err(format!("tour '{}' has no driver assigned", tour.vehicle_id)),
+        (_, Some(driver_id)) => {
+            let time = get_tour_time(tour)?;
+            let driver_times = used_drivers.entry(driver_id.clone()).or_insert_with(Vec::new);
+
+            if driver_times.iter().any(|other| other.intersects(&time)) {
+                Err(format!("driver '{}' is used in more than one tour at the same time", driver_id))
+            } else {
+                driver_times.push(time);
+                Ok(())
+            }
+        }
+    })
+}
+
+fn get_tour_time(tour: &Tour) -> Result<TimeWindow, String> {
+    let departure = tour.stops.first().map(|stop| parse_time(&stop.schedule().departure));
+    let arrival = tour.stops.last().map(|stop| parse_time(&stop.schedule().arrival));
+
+    departure
+        .zip(arrival)
+        .map(|(departure, arrival)| TimeWindow::new(departure, arrival))
+        .ok_or_else(|| format!("cannot get tour time for '{}'", tour.vehicle_id))
+}
+
 /// Checks job task rules.
 fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
     struct JobAssignment {
@@ -61,7 +106,8 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
     let activity_types: HashSet<_> = vec!["pickup", "delivery", "service", "replacement"].into_iter().collect();
 
     let all_jobs = ctx.problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect::<HashMap<_, _>>();
-    let mut used_jobs = HashMap::<String, JobAssignment>::new();
+    // NOTE visits of periodic job are different jobs in the same tour or in different tours
+    let mut used_jobs = HashMap::<(String, Option<usize>), JobAssignment>::new();
 
     ctx.solution.tours.iter().try_for_each(|tour| {
         tour.stops
@@ -71,10 +117,13 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
             .filter(|(_, activity)| activity_types.contains(&activity.activity_type.as_str()))
             .try_for_each(|(idx, activity)| {
                 let tour_info = (tour.vehicle_id.clone(), tour.shift_index);
-                let asgn =
-                    used_jobs.entry(activity.job_id.clone()).or_insert_with(|| new_assignment(tour_info.clone()));
+                let asgn = used_jobs
+                    .entry((activity.job_id.clone(), activity.visit_index))
+                    .or_insert_with(|| new_assignment(tour_info.clone()));
 
-                if asgn.tour_info != tour_info {
+                // NOTE tasks of synchronized job are served by different vehicles
+                let is_sync = all_jobs.get(&activity.job_id).map_or(false, |job| job.sync.is_some());
+                if asgn.tour_info != tour_info && !is_sync {
                     return Err(format!("job served in multiple tours: '{}'", activity.job_id));
                 }
 
@@ -90,7 +139,7 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
             })
     })?;
 
-    used_jobs.iter().try_for_each(|(id, asgn)| {
+    used_jobs.iter().try_for_each(|((id, _), asgn)| {
         // TODO validate whether each job task is served once
         let job = all_jobs.get(id).ok_or_else(|| format!("Cannot find job with id {}", id))?;
         let expected_tasks = job.pickups.as_ref().map_or(0, |p| p.len())
@@ -127,20 +176,22 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
         return Err("duplicated job ids in the list of unassigned jobs".to_string());
     }
 
+    let used_job_ids = used_jobs.into_iter().map(|((id, _), _)| id).collect::<HashSet<_>>();
+
     unique_unassigned_jobs.iter().try_for_each(|job_id| {
-        if !all_jobs.contains_key(job_id) {
-            return Err(format!("unknown job id in the list of unassigned jobs: '{}'", job_id));
-        }
+        let job = all_jobs
+            .get(job_id)
+            .ok_or_else(|| format!("unknown job id in the list of unassigned jobs: '{}'", job_id))?;
 
-        if used_jobs.contains_key(job_id) {
+        // NOTE periodic job can be assigned partially
+        if used_job_ids.contains(job_id) && job.visits.is_none() {
             return Err(format!("job present as assigned and unassigned: '{}'", job_id));
         }
 
         Ok(())
     })?;
 
-    let all_used_job =
-        unique_unassigned_jobs.into_iter().chain(used_jobs.into_iter().map(|(id, _)| id)).collect::<Vec<_>>();
+    let all_used_job = unique_unassigned_jobs.into_iter().chain(used_job_ids).collect::<HashSet<_>>();
 
     if all_used_job.len() != all_jobs.len() {
         return Err(format!(
@@ -180,7 +231,7 @@ fn check_jobs_match(ctx: &CheckerContext) -> Result<(), String> {
                                                 true
                                             }
                                         },
-                                        Ok(Some(JobInfo(_, _, place, time))) => {
+                                        Ok(Some(JobInfo(_, single, place, time))) => {
                                             let not_equal = |left: f64, right: f64| compare_floats(left, right) != Ordering::Equal;
                                             let parking = ctx
                                                 .clustering
@@ -197,8 +248,14 @@ fn check_jobs_match(ctx: &CheckerContext) -> Result<(), String> {
                                                 | (_, Some(_), Ok(None))
                                                 | (&None, &Some(_), Ok(Some(_))) => true,
                                                 (_, None, Ok(None)) => {
-                                                    let expected_departure = time.start.max(place.time.start) + place.duration + extra_time;
-                                                    not_equal(time.end, expected_departure)
+                                                    let duration = get_service_duration(&single, &place, Some(&tour.type_id), ctx.get_driver_type(tour));
+                                                    let expected_departure = time.start.max(place.time.start) + duration + extra_time;
+                                                    // NOTE vehicle might wait for other vehicles serving synchronized job
+                                                    if single.dimens.get_value::<JobSync>("sync").is_some() {
+                                                        compare_floats(time.end, expected_departure) == Ordering::Less
+                                                    } else {
+                                                        not_equal(time.end, expected_departure)
+                                                    }
                                                 }
                                                 (Some(config), Some(commute), Ok(Some(d_commute))) => {
                                                     let (service_time, parking) = match config.serving {
@@ -236,7 +293,8 @@ fn check_jobs_match(ctx: &CheckerContext) -> Result<(), String> {
                                     }
                                 }
                                 Stop::Transit(stop) => {
-                                    try_match_transit_activity(&ctx.problem, tour, stop, activity).is_err()
+                                    activity.activity_type != "rest"
+                                        && try_match_transit_activity(&ctx.problem, tour, stop, activity).is_err()
                                 }
                             }
                         }
@@ -345,6 +403,8 @@ fn check_groups(ctx: &CheckerContext) -> Result<(), String> {
             tour.stops
                 .iter()
                 .flat_map(|stop| stop.activities().iter())
+                // NOTE transfer activity of two-echelon job is served by another tour
+                .filter(|activity| activity.activity_type != "transfer")
                 .flat_map(|activity| ctx.get_job_by_id(&activity.job_id))
                 .flat_map(|job| job.group.as_ref())
                 .for_each(|group| {
@@ -368,3 +428,260 @@ fn check_groups(ctx: &CheckerContext) -> Result<(), String> {
         Err(format!("job groups are not respected: '{}'", err_info))
     }
 }
+
+/// Checks that time between departure from the first job activity and arrival to the last one
+/// does not exceed job's max ride time.
+fn check_ride_time(ctx: &CheckerContext) -> Result<(), String> {
+    let mut job_ids = ctx
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .filter(|(_, activity)| activity.activity_type != "transfer")
+        .filter_map(|(stop, activity)| {
+            ctx.get_job_by_id(&activity.job_id)
+                .and_then(|job| job.max_ride_time)
+                .map(|max_ride_time| (ctx.get_activity_time(stop, activity), activity, max_ride_time))
+        })
+        .fold(HashMap::<String, (f64, f64, f64)>::default(), |mut acc, (time, activity, max_ride_time)| {
+            acc.entry(activity.job_id.clone()).or_insert((time.end, time.start, max_ride_time)).1 = time.start;
+
+            acc
+        })
+        .into_iter()
+        .filter(|(_, (departure, arrival, max_ride_time))| arrival - departure > *max_ride_time)
+        .map(|(job_id, _)| job_id)
+        .collect::<Vec<_>>();
+
+    if job_ids.is_empty() {
+        Ok(())
+    } else {
+        job_ids.sort();
+        Err(format!("max ride time is violated for jobs: '{}'", job_ids.join(", ")))
+    }
+}
+
+/// Checks that visits of periodic jobs are unique and follow spacing and day pattern rules.
+fn check_visits(ctx: &CheckerContext) -> Result<(), String> {
+    let job_index = get_job_index(&ctx.core_problem);
+    let coord_index = get_coord_index(&ctx.core_problem);
+    let (_, offset) = get_planning_horizon(&ctx.problem);
+
+    let visit_days = ctx
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter().map(move |stop| (tour, stop)))
+        .filter_map(|(tour, stop)| match stop {
+            Stop::Point(stop) => Some((tour, stop)),
+            Stop::Transit(_) => None,
+        })
+        .flat_map(|(tour, stop)| stop.activities.iter().map(move |activity| (tour, stop, activity)))
+        .filter(|(_, _, activity)| activity.visit_index.is_some())
+        .map(|(tour, stop, activity)| {
+            // NOTE time window of the visit is limited by one day
+            match try_match_point_job(tour, stop, activity, job_index, coord_index)? {
+                Some(JobInfo(_, _, place, _)) => Ok((
+                    activity.job_id.clone(),
+                    (activity.visit_index.unwrap(), get_day_index(place.time.start, offset)),
+                )),
+                None => Err(format!("cannot match visit of job '{}'", activity.job_id)),
+            }
+        })
+        .collect::<Result<Vec<_>, String>>()?
+        .into_iter()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect_group_by::<String, (usize, i64)>();
+
+    visit_days.iter().try_for_each(|(job_id, visits)| {
+        let job_visits = ctx
+            .get_job_by_id(job_id)
+            .and_then(|job| job.visits.as_ref())
+            .ok_or_else(|| format!("job '{}' has visit index, but it is not periodic", job_id))?;
+
+        let unique_visits = visits.iter().map(|(visit_index, _)| *visit_index).collect::<HashSet<_>>();
+        if unique_visits.len() != visits.len() || unique_visits.iter().any(|&index| index >= job_visits.count) {
+            return Err(format!("invalid visit indices for job '{}'", job_id));
+        }
+
+        let policy = get_periodic_policy(job_visits);
+        if visits.iter().all(|visit| is_visit_allowed(&policy, *visit, visits.as_slice())) {
+            Ok(())
+        } else {
+            Err(format!("visit days violate spacing or patterns for job '{}'", job_id))
+        }
+    })
+}
+
+/// Checks that both parts of two-echelon jobs are served by vehicles of proper echelons and second echelon
+/// tour does not depart before goods are transferred at the hub.
+fn check_transfers(ctx: &CheckerContext) -> Result<(), String> {
+    let hubs = ctx
+        .problem
+        .plan
+        .hubs
+        .iter()
+        .flat_map(|hubs| hubs.iter())
+        .map(|hub| (hub.id.clone(), hub))
+        .collect::<HashMap<_, _>>();
+
+    let (firsts, seconds) =
+        ctx.solution.tours.iter().flat_map(|tour| tour.stops.iter().map(move |stop| (tour, stop))).fold(
+            (HashMap::new(), HashMap::new()),
+            |(mut firsts, mut seconds), (tour, stop)| {
+                stop.activities()
+                    .iter()
+                    .filter(|activity| ctx.get_job_by_id(&activity.job_id).map_or(false, |job| job.hub.is_some()))
+                    .for_each(|activity| match activity.activity_type.as_str() {
+                        "transfer" => {
+                            firsts.insert(activity.job_id.clone(), (tour, ctx.get_activity_time(stop, activity).end));
+                        }
+                        "delivery" => {
+                            seconds.insert(activity.job_id.clone(), tour);
+                        }
+                        _ => {}
+                    });
+
+                (firsts, seconds)
+            },
+        );
+
+    let expected =
+        ctx.problem.plan.jobs.iter().filter_map(|job| job.hub.as_ref().map(|hub_id| (job, hub_id))).try_fold(
+            HashSet::new(),
+            |mut acc, (job, hub_id)| {
+                let hub = hubs.get(hub_id).ok_or_else(|| format!("unknown hub '{}' of job '{}'", hub_id, job.id))?;
+
+                match (firsts.get(&job.id), seconds.get(&job.id)) {
+                    (None, None) => Ok(acc),
+                    (Some((first, transfer_time)), Some(second)) => {
+                        if !hub.first_echelon.contains(&first.type_id) || !hub.second_echelon.contains(&second.type_id)
+                        {
+                            return Err(format!("vehicle echelons are not respected for job '{}'", job.id));
+                        }
+
+                        let departure = second
+                            .stops
+                            .first()
+                            .map(|stop| parse_time(&stop.schedule().departure))
+                            .ok_or_else(|| format!("cannot get departure of tour '{}'", second.vehicle_id))?;
+
+                        if compare_floats(*transfer_time, departure) == Ordering::Greater {
+                            return Err(format!(
+                                "tour '{}' departs before job '{}' is transferred",
+                                second.vehicle_id, job.id
+                            ));
+                        }
+
+                        acc.insert((
+                            job.id.clone(),
+                            (first.vehicle_id.clone(), first.shift_index),
+                            (second.vehicle_id.clone(), second.shift_index),
+                        ));
+
+                        Ok(acc)
+                    }
+                    _ => Err(format!("job '{}' is served only by one echelon", job.id)),
+                }
+            },
+        )?;
+
+    let actual = ctx
+        .solution
+        .transfers
+        .iter()
+        .flat_map(|transfers| transfers.iter())
+        .map(|transfer| {
+            (
+                transfer.job_id.clone(),
+                (transfer.from.vehicle_id.clone(), transfer.from.shift_index),
+                (transfer.to.vehicle_id.clone(), transfer.to.shift_index),
+            )
+        })
+        .collect::<HashSet<_>>();
+
+    if expected == actual {
+        Ok(())
+    } else {
+        Err("transfers in solution do not match tours".to_string())
+    }
+}
+
+/// Checks that depot docks are not overbooked, tours depart after loading, arrive before unloading and
+/// are unloaded before their shift end.
+fn check_docks(ctx: &CheckerContext) -> Result<(), String> {
+    let depots = ctx
+        .problem
+        .plan
+        .depots
+        .iter()
+        .flat_map(|depots| depots.iter())
+        .map(|depot| (depot.id.clone(), depot))
+        .collect::<HashMap<_, _>>();
+
+    let assignments = ctx.solution.docks.iter().flat_map(|docks| docks.iter()).collect::<Vec<_>>();
+
+    assignments.iter().try_for_each(|assignment| {
+        let depot = depots
+            .get(&assignment.depot_id)
+            .ok_or_else(|| format!("unknown depot '{}' in dock assignments", assignment.depot_id))?;
+
+        if assignment.dock >= depot.docks {
+            return Err(format!("invalid dock index {} at depot '{}'", assignment.dock, depot.id));
+        }
+
+        let tour = ctx
+            .solution
+            .tours
+            .iter()
+            .find(|tour| tour.vehicle_id == assignment.vehicle_id && tour.shift_index == assignment.shift_index)
+            .ok_or_else(|| format!("unknown tour '{}' in dock assignments", assignment.vehicle_id))?;
+
+        let (start, end) = (parse_time(&assignment.time.start), parse_time(&assignment.time.end));
+        let is_valid = match assignment.operation.as_str() {
+            "loading" => tour
+                .stops
+                .first()
+                .map_or(false, |stop| compare_floats(end, parse_time(&stop.schedule().departure)) != Ordering::Greater),
+            "unloading" => {
+                let shift = ctx.get_vehicle_shift(tour)?;
+                let latest = shift.end.as_ref().map_or(f64::MAX, |end| parse_time(&end.latest));
+
+                compare_floats(end, latest) != Ordering::Greater
+                    && tour.stops.last().map_or(false, |stop| {
+                        compare_floats(start, parse_time(&stop.schedule().arrival)) != Ordering::Less
+                    })
+            }
+            _ => return Err(format!("unknown dock operation '{}'", assignment.operation)),
+        };
+
+        if is_valid {
+            Ok(())
+        } else {
+            Err(format!(
+                "tour '{}' does not respect {} time at depot '{}'",
+                tour.vehicle_id, assignment.operation, depot.id
+            ))
+        }
+    })?;
+
+    assignments
+        .iter()
+        .map(|assignment| {
+            let time = (parse_time(&assignment.time.start), parse_time(&assignment.time.end));
+            ((assignment.depot_id.clone(), assignment.dock), time)
+        })
+        .collect_group_by()
+        .into_iter()
+        .try_for_each(|((depot_id, dock), mut times): ((String, usize), Vec<(f64, f64)>)| {
+            times.sort_by(|(a, _), (b, _)| compare_floats(*a, *b));
+
+            if times.windows(2).any(|pair| compare_floats(pair[0].1, pair[1].0) == Ordering::Greater) {
+                Err(format!("dock {} at depot '{}' is used by several tours at the same time", dock, depot_id))
+            } else {
+                Ok(())
+            }
+        })
+}
@@ -3,11 +3,16 @@
 mod breaks_test;
 
 use super::*;
+use crate::format_time;
 use crate::utils::combine_error_results;
 
 /// Checks that breaks are properly assigned.
 pub fn check_breaks(context: &CheckerContext) -> Result<(), Vec<String>> {
-    combine_error_results(&[check_break_assignment(context)])
+    combine_error_results(&[
+        check_break_assignment(context),
+        check_driving_hours(context),
+        check_overnight_stays(context),
+    ])
 }
 
 fn check_break_assignment(context: &CheckerContext) -> Result<(), String> {
@@ -185,3 +190,146 @@ fn get_break_violation_count(solution: &Solution, tour: &Tour) -> usize {
             .count()
     })
 }
+
+/// Checks that driving hours limits are respected: breaks and daily rests are taken in time.
+/// Any rest, break or overnight activity is considered as a rest, all other non-driving time is considered as a duty.
+fn check_driving_hours(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let limit = if let Some(limit) = vehicle.limits.as_ref().and_then(|limits| limits.driving_hours.as_ref()) {
+            limit
+        } else {
+            return Ok(());
+        };
+
+        let split_break = limit.split_break.as_ref().and_then(|parts| match parts.as_slice() {
+            [first, second] => Some((*first, *second)),
+            _ => None,
+        });
+
+        // NOTE solution time is truncated to seconds, so one second tolerance is allowed per leg
+        let (mut continuous, mut daily, mut duty, mut legs, mut has_first_part) = (0., 0., 0., 0., false);
+
+        let get_error = |name: &str, actual: f64, expected: f64| {
+            format!(
+                "{} limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                name, expected, actual, tour.vehicle_id, tour.shift_index
+            )
+        };
+
+        tour.stops.windows(2).try_for_each(|stops| {
+            let (from, to) = match stops {
+                [from, to] => (from, to),
+                _ => unreachable!(),
+            };
+
+            let driving = parse_time(&to.schedule().arrival) - parse_time(&from.schedule().departure);
+            continuous += driving;
+            daily += driving;
+            duty += driving;
+            legs += 1.;
+
+            if continuous > limit.max_continuous_driving + legs {
+                return Err(get_error("continuous driving", continuous, limit.max_continuous_driving));
+            }
+
+            if daily > limit.max_daily_driving + legs {
+                return Err(get_error("daily driving", daily, limit.max_daily_driving));
+            }
+
+            if let Some(max_daily_duty) = limit.max_daily_duty.filter(|max_daily_duty| duty > *max_daily_duty + legs) {
+                return Err(get_error("daily duty", duty, max_daily_duty));
+            }
+
+            let stop_end = parse_time(&to.schedule().departure);
+            let last_time = to
+                .activities()
+                .iter()
+                .filter(|activity| matches!(activity.activity_type.as_str(), "rest" | "break" | "overnight"))
+                .fold(parse_time(&to.schedule().arrival), |time, activity| {
+                    let rest_time = get_time_window(to, activity);
+                    let rest = rest_time.duration();
+                    duty += rest_time.start - time;
+
+                    if rest >= limit.daily_rest {
+                        continuous = 0.;
+                        daily = 0.;
+                        duty = 0.;
+                        legs = 0.;
+                        has_first_part = false;
+                    } else if rest >= limit.break_duration
+                        || split_break.map_or(false, |(_, second)| has_first_part && rest >= second)
+                    {
+                        continuous = 0.;
+                        has_first_part = false;
+                    } else if split_break.map_or(false, |(first, _)| rest >= first) {
+                        has_first_part = true;
+                    }
+
+                    rest_time.end
+                });
+
+            duty += stop_end - last_time;
+
+            Ok(())
+        })
+    })
+}
+
+/// Checks that vehicle with linked shifts is off duty between shifts: it spends link time in overnight stay
+/// at the end location of previous shift, if specified.
+fn check_overnight_stays(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let shifts = context.get_linked_vehicle_shifts(tour)?;
+        let stays = shifts
+            .windows(2)
+            .filter_map(|pair| match pair {
+                [prev, next] => prev.end.as_ref().map(|end| {
+                    (TimeWindow::new(parse_time(&end.latest), parse_time(&next.start.earliest)), end.location.as_ref())
+                }),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+
+        let intersects =
+            |time: &TimeWindow| stays.iter().find(|(stay, _)| time.start < stay.end && stay.start < time.end);
+        let get_error = |name: &str, time: &TimeWindow| {
+            format!(
+                "{} at '{}'-'{}' intersects with overnight stay, vehicle id '{}', shift index: {}",
+                name,
+                format_time(time.start),
+                format_time(time.end),
+                tour.vehicle_id,
+                tour.shift_index
+            )
+        };
+
+        tour.stops.windows(2).try_for_each(|stops| {
+            let driving = match stops {
+                [from, to] => {
+                    TimeWindow::new(parse_time(&from.schedule().departure), parse_time(&to.schedule().arrival))
+                }
+                _ => unreachable!(),
+            };
+
+            intersects(&driving).map_or(Ok(()), |_| Err(get_error("driving", &driving)))
+        })?;
+
+        tour.stops.iter().flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity))).try_for_each(
+            |(stop, activity)| {
+                let time = get_time_window(stop, activity);
+
+                match (activity.activity_type.as_str(), intersects(&time)) {
+                    ("departure" | "arrival", _) | (_, None) => Ok(()),
+                    ("overnight", Some((_, Some(location))))
+                        if context.get_activity_location(stop, activity).as_ref() != Some(*location) =>
+                    {
+                        Err(get_error("overnight stay at unexpected location", &time))
+                    }
+                    ("overnight", Some(_)) => Ok(()),
+                    (activity_type, Some(_)) => Err(get_error(format!("activity '{}'", activity_type).as_str(), &time)),
+                }
+            },
+        )
+    })
+}
@@ -10,8 +10,9 @@ use vrp_core::models::common::{Load, MultiDimLoad};
 /// Checks that vehicle load is assigned correctly. The following rules are checked:
 /// * max vehicle's capacity is not violated
 /// * load change is correct
+/// * backhauls are served after linehauls
 pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), Vec<String>> {
-    combine_error_results(&[check_vehicle_load_assignment(context)])
+    combine_error_results(&[check_vehicle_load_assignment(context), check_backhauls(context)])
 }
 
 fn check_vehicle_load_assignment(context: &CheckerContext) -> Result<(), String> {
@@ -125,6 +126,31 @@ fn check_vehicle_load_assignment(context: &CheckerContext) -> Result<(), String>
     })
 }
 
+fn check_backhauls(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        if !context.get_vehicle(&tour.vehicle_id)?.backhauls.unwrap_or(false) {
+            return Ok(());
+        }
+
+        tour.stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .try_fold(false, |has_backhaul, (stop, activity)| {
+                let activity_type = context.get_activity_type(tour, stop, activity)?;
+
+                match get_demand(context, activity, &activity_type)? {
+                    (DemandType::StaticDelivery, demand) if has_backhaul && demand.is_not_empty() => Err(format!(
+                        "linehaul job '{}' is served after backhaul in tour '{}'",
+                        activity.job_id, tour.vehicle_id
+                    )),
+                    (DemandType::StaticPickup, demand) => Ok(has_backhaul || demand.is_not_empty()),
+                    _ => Ok(has_backhaul),
+                }
+            })
+            .map(|_| ())
+    })
+}
+
 enum DemandType {
     None,
     StaticPickup,
@@ -154,6 +180,7 @@ fn get_demand(
 
     let demand_type = match (is_dynamic, activity.activity_type.as_ref()) {
         (_, "replacement") => DemandType::StaticPickupDelivery,
+        (_, "transfer") => DemandType::StaticDelivery,
         (true, "pickup") => DemandType::DynamicPickup,
         (true, "delivery") => DemandType::DynamicDelivery,
         (false, "pickup") => DemandType::StaticPickup,
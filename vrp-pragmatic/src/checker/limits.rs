@@ -7,7 +7,7 @@ use crate::utils::combine_error_results;
 
 /// NOTE to ensure distance/duration correctness, routing check should be performed first.
 pub fn check_limits(context: &CheckerContext) -> Result<(), Vec<String>> {
-    combine_error_results(&[check_shift_limits(context), check_shift_time(context)])
+    combine_error_results(&[check_shift_limits(context), check_shift_time(context), check_battery_charge(context)])
 }
 
 /// Check that shift limits are not violated:
@@ -65,12 +65,12 @@ fn check_shift_time(context: &CheckerContext) -> Result<(), String> {
         let departure = parse_time(&start.schedule().departure);
         let arrival = parse_time(&end.schedule().arrival);
 
-        let has_match = vehicle
-            .shifts
-            .iter()
-            .map(|shift| {
-                let start = parse_time(&shift.start.earliest);
-                let end = shift.end.as_ref().map(|end| parse_time(&end.latest)).unwrap_or(f64::MAX);
+        let has_match = get_linked_shifts(&vehicle.shifts)
+            .into_iter()
+            .map(|(_, shifts)| {
+                let (first, last) = (shifts.first().unwrap(), shifts.last().unwrap());
+                let start = parse_time(&first.start.earliest);
+                let end = last.end.as_ref().map(|end| parse_time(&end.latest)).unwrap_or(f64::MAX);
 
                 (start, end)
             })
@@ -86,3 +86,60 @@ fn check_shift_time(context: &CheckerContext) -> Result<(), String> {
         }
     })
 }
+
+/// Checks that battery charge of electric vehicle never goes below zero and it is reported correctly.
+fn check_battery_charge(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each::<_, Result<_, String>>(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let battery = if let Some(battery) = vehicle.battery.as_ref() { battery } else { return Ok(()) };
+
+        let initial = battery.initial.unwrap_or(battery.capacity);
+        let full_consumption = battery.full_load_consumption.unwrap_or(battery.consumption);
+        let get_consumption = |distance: i64, load: &[i32]| {
+            let ratio = load
+                .iter()
+                .zip(vehicle.capacity.iter())
+                .filter(|(_, capacity)| **capacity > 0)
+                .map(|(load, capacity)| *load as f64 / *capacity as f64)
+                .fold(0_f64, |acc, ratio| acc.max(ratio))
+                .min(1.);
+
+            distance as f64 * (battery.consumption + (full_consumption - battery.consumption) * ratio)
+        };
+
+        tour.stops
+            .iter()
+            .filter(|stop| stop.as_point().is_some())
+            .try_fold((None, initial), |(prev, charge): (Option<&PointStop>, f64), stop| {
+                let point = stop.as_point().unwrap();
+                let charge =
+                    prev.map_or(charge, |prev| charge - get_consumption(point.distance - prev.distance, &prev.load));
+
+                if charge < -1E-6 {
+                    return Err(format!(
+                        "battery is depleted: charge on arrival is {}, vehicle id '{}', shift index: {}",
+                        charge, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+
+                let charge = point.activities.iter().filter(|activity| activity.activity_type == "charging").try_fold(
+                    charge,
+                    |charge, activity| match context.get_activity_type(tour, stop, activity)? {
+                        ActivityType::Charging(station) => {
+                            Ok((charge + station.rate * station.duration).min(battery.capacity))
+                        }
+                        _ => Err(format!("cannot find charging station for tour '{}'", tour.vehicle_id)),
+                    },
+                )?;
+
+                match point.charge {
+                    Some(actual) if (actual - charge).abs() < 1E-3 => Ok((Some(point), charge)),
+                    actual => Err(format!(
+                        "battery charge mismatch, expected: {}, got: {:?}, vehicle id '{}', shift index: {}",
+                        charge, actual, tour.vehicle_id, tour.shift_index
+                    )),
+                }
+            })
+            .map(|_| ())
+    })
+}
@@ -41,6 +41,8 @@ enum ActivityType {
     Depot(VehicleDispatch),
     Break(VehicleBreak),
     Reload(VehicleReload),
+    Charging(VehicleChargingStation),
+    Rest,
 }
 
 impl CheckerContext {
@@ -51,9 +53,11 @@ impl CheckerContext {
         matrices: Option<Vec<Matrix>>,
         solution: Solution,
     ) -> Result<Self, Vec<String>> {
+        let coord_index = CoordIndex::new(&problem);
+        // NOTE only not completed activities of tours in progress are present in the solution
+        let problem = apply_tour_states(problem);
         let job_map = problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect();
         let clustering = core_problem.extras.get_cluster_config().cloned();
-        let coord_index = CoordIndex::new(&problem);
         let profile_index = if matrices.is_none() {
             HashMap::new()
         } else {
@@ -134,8 +138,17 @@ impl CheckerContext {
         })
     }
 
-    /// Gets vehicle shift where activity is used.
+    /// Gets vehicle shift where activity is used. Linked shifts are merged into one shift which ends
+    /// with the end of the last linked shift.
     fn get_vehicle_shift(&self, tour: &Tour) -> Result<VehicleShift, String> {
+        let shifts = self.get_linked_vehicle_shifts(tour)?;
+        let (first, last) = (shifts.first().unwrap(), shifts.last().unwrap());
+
+        Ok(VehicleShift { end: last.end.clone(), ..first.clone() })
+    }
+
+    /// Gets vehicle shifts where activity is used: a single shift or a chain of linked shifts.
+    fn get_linked_vehicle_shifts(&self, tour: &Tour) -> Result<Vec<VehicleShift>, String> {
         let tour_time = TimeWindow::new(
             parse_time(
                 &tour.stops.first().as_ref().ok_or_else(|| "cannot get first activity".to_string())?.schedule().arrival,
@@ -145,17 +158,18 @@ impl CheckerContext {
             ),
         );
 
-        self.get_vehicle(&tour.vehicle_id)?
-            .shifts
-            .iter()
-            .find(|shift| {
+        get_linked_shifts(&self.get_vehicle(&tour.vehicle_id)?.shifts)
+            .into_iter()
+            .map(|(_, shifts)| shifts)
+            .find(|shifts| {
+                let (first, last) = (shifts.first().unwrap(), shifts.last().unwrap());
                 let shift_time = TimeWindow::new(
-                    parse_time(&shift.start.earliest),
-                    shift.end.as_ref().map_or_else(|| f64::MAX, |place| parse_time(&place.latest)),
+                    parse_time(&first.start.earliest),
+                    last.end.as_ref().map_or_else(|| f64::MAX, |place| parse_time(&place.latest)),
                 );
                 shift_time.intersects(&tour_time)
             })
-            .cloned()
+            .map(|shifts| shifts.into_iter().cloned().collect())
             .ok_or_else(|| format!("cannot find shift for tour with vehicle if: '{}'", tour.vehicle_id))
     }
 
@@ -172,7 +186,8 @@ impl CheckerContext {
 
         match activity.activity_type.as_str() {
             "departure" | "arrival" => Ok(ActivityType::Terminal),
-            "pickup" | "delivery" | "service" | "replacement" => {
+            "rest" | "overnight" => Ok(ActivityType::Rest),
+            "pickup" | "delivery" | "service" | "replacement" | "transfer" => {
                 self.job_map.get(activity.job_id.as_str()).map_or_else(
                     || Err(format!("cannot find job with id '{}'", activity.job_id)),
                     |job| Ok(ActivityType::Job(job.clone())),
@@ -226,6 +241,16 @@ impl CheckerContext {
                 })
                 .map(|r| ActivityType::Reload(r.clone()))
                 .ok_or_else(|| format!("cannot find reload for tour '{}'", tour.vehicle_id)),
+            "charging" => shift
+                .charging_stations
+                .as_ref()
+                .and_then(|stations| {
+                    stations.iter().find(|s| {
+                        location.as_ref().map_or(false, |location| s.location == *location) && s.tag == activity.job_tag
+                    })
+                })
+                .map(|s| ActivityType::Charging(s.clone()))
+                .ok_or_else(|| format!("cannot find charging station for tour '{}'", tour.vehicle_id)),
             "dispatch" => shift
                 .dispatch
                 .as_ref()
@@ -238,6 +263,18 @@ impl CheckerContext {
         }
     }
 
+    fn get_driver_type(&self, tour: &Tour) -> Option<&String> {
+        let driver_id = tour.driver_id.as_ref()?;
+
+        self.problem
+            .fleet
+            .drivers
+            .iter()
+            .flat_map(|drivers| drivers.iter())
+            .find(|driver| driver.driver_ids.contains(driver_id))
+            .map(|driver| &driver.type_id)
+    }
+
     fn get_job_by_id(&self, job_id: &str) -> Option<&Job> {
         self.problem.plan.jobs.iter().find(|job| job.id == job_id)
     }
@@ -396,6 +433,7 @@ fn match_job_task<'a>(
         "delivery" => job.deliveries.as_ref(),
         "service" => job.services.as_ref(),
         "replacement" => job.replacements.as_ref(),
+        "transfer" => job.deliveries.as_ref(),
         _ => None,
     };
 
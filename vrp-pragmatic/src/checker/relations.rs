@@ -5,14 +5,18 @@ mod relations_test;
 use super::*;
 use crate::utils::combine_error_results;
 use hashbrown::HashSet;
+use std::cmp::Ordering;
+use vrp_core::prelude::compare_floats;
 
 /// Checks relation rules.
 pub fn check_relations(context: &CheckerContext) -> Result<(), Vec<String>> {
-    combine_error_results(&[check_relations_assignment(context)])
+    combine_error_results(&[check_relations_assignment(context), check_sync(context)])
 }
 
 fn check_relations_assignment(context: &CheckerContext) -> Result<(), String> {
-    let reserved_ids = vec!["departure", "arrival", "break", "dispatch", "reload"].into_iter().collect::<HashSet<_>>();
+    let reserved_ids = vec!["departure", "arrival", "break", "dispatch", "reload", "charging", "rest", "overnight"]
+        .into_iter()
+        .collect::<HashSet<_>>();
 
     (0_usize..)
         .zip(context.problem.plan.relations.as_ref().map_or(vec![].iter(), |relations| relations.iter()))
@@ -23,7 +27,7 @@ fn check_relations_assignment(context: &CheckerContext) -> Result<(), String> {
                 tour
             } else {
                 return match relation.type_field {
-                    RelationType::Any => Ok(()),
+                    RelationType::Any | RelationType::Preferred => Ok(()),
                     _ => tour.map(|_| ()),
                 };
             };
@@ -86,12 +90,81 @@ fn check_relations_assignment(context: &CheckerContext) -> Result<(), String> {
                         Ok(())
                     }
                 }
+                RelationType::Preferred => Ok(()),
             }
         })?;
 
     Ok(())
 }
 
+/// Checks that tasks of synchronized jobs are served by different vehicles within allowed time offset.
+fn check_sync(context: &CheckerContext) -> Result<(), String> {
+    let assignments = context.solution.tours.iter().try_fold(HashMap::new(), |acc, tour| {
+        tour.stops.iter().flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity))).try_fold(
+            acc,
+            |mut acc, (stop, activity)| {
+                let job = match context.get_job_by_id(&activity.job_id) {
+                    Some(job) if job.sync.is_some() => job,
+                    _ => return Ok(acc),
+                };
+
+                let activity_type = context.get_activity_type(tour, stop, activity)?;
+                let duration = context.visit_job(
+                    activity,
+                    &activity_type,
+                    |_, task| {
+                        task.places
+                            .iter()
+                            .find(|place| place.tag == activity.job_tag)
+                            .or_else(|| task.places.first())
+                            .map_or(0., |place| {
+                                let durations = place.durations.as_ref();
+                                let driver_duration = context.get_driver_type(tour).and_then(|driver_type| {
+                                    durations.and_then(|durations| durations.drivers.as_ref()?.get(driver_type))
+                                });
+                                let vehicle_duration =
+                                    durations.and_then(|durations| durations.vehicles.as_ref()?.get(&tour.type_id));
+
+                                driver_duration.or(vehicle_duration).cloned().unwrap_or(place.duration)
+                            })
+                    },
+                    || 0.,
+                )?;
+                let service_start = context.get_activity_time(stop, activity).end - duration;
+
+                acc.entry(job.id.clone())
+                    .or_insert_with(Vec::new)
+                    .push(((&tour.vehicle_id, tour.shift_index), service_start));
+
+                Ok::<_, String>(acc)
+            },
+        )
+    })?;
+
+    assignments.into_iter().try_for_each(|(job_id, tasks)| {
+        let max_offset =
+            context.get_job_by_id(&job_id).and_then(|job| job.sync.as_ref()).map_or(0., |sync| sync.max_offset);
+
+        let tours = tasks.iter().map(|(tour, _)| *tour).collect::<HashSet<_>>();
+        if tours.len() != tasks.len() {
+            return Err(format!("synchronized job '{}' has tasks served by the same vehicle", job_id));
+        }
+
+        let (min, max) =
+            tasks.iter().fold((f64::MAX, f64::MIN), |(min, max), (_, start)| (min.min(*start), max.max(*start)));
+        if compare_floats(max - min, max_offset) == Ordering::Greater {
+            return Err(format!(
+                "synchronized job '{}' has service start offset {} greater than allowed {}",
+                job_id,
+                max - min,
+                max_offset
+            ));
+        }
+
+        Ok(())
+    })
+}
+
 fn get_tour_by_vehicle_id(vehicle_id: &str, shift_index: Option<usize>, solution: &Solution) -> Result<Tour, String> {
     solution
         .tours
@@ -43,17 +43,17 @@ fn check_routing_rules(context: &CheckerContext) -> Result<(), String> {
                         (distance, duration, to.distance)
                     }
                     (_, Stop::Transit(transit)) => {
+                        // NOTE time spent at transit stop is added to travel time of the leg
                         let duration = parse_time(&transit.time.departure) - parse_time(&transit.time.arrival);
-                        (0_i64, duration as i64, total_distance)
+                        return Ok((arrival_time + duration as i64, total_distance));
                     }
                     (Stop::Transit(_), Stop::Point(to)) => {
                         assert!(leg_idx > 0);
-                        let from = tour
-                            .stops
-                            .get(leg_idx - 1)
-                            .unwrap()
-                            .as_point()
-                            .expect("two consistent transit stops are not supported");
+                        let from = tour.stops[..leg_idx]
+                            .iter()
+                            .rev()
+                            .find_map(|stop| stop.as_point())
+                            .expect("transit stop should be preceded by point stop");
                         let (distance, duration) = get_matrix_data(from, to)?;
                         (distance, duration, to.distance)
                     }
@@ -0,0 +1,447 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/charging_test.rs"]
+mod charging_test;
+
+use crate::constraints::*;
+use hashbrown::HashSet;
+use std::iter::once;
+use std::marker::PhantomData;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::*;
+use vrp_core::models::problem::{ActivityCost, Job, Single, TransportCost, TravelTime};
+use vrp_core::models::solution::{Activity, Route};
+
+/// A tolerance used to detect that battery is depleted.
+const EPSILON: f64 = 1E-6;
+
+/// Specifies a battery of electric vehicle.
+#[derive(Clone, Debug)]
+pub struct BatteryPolicy {
+    /// Battery capacity.
+    pub capacity: f64,
+    /// Battery charge at the start of the shift.
+    pub initial: f64,
+    /// Energy consumption per distance unit when vehicle is empty.
+    pub empty_consumption: f64,
+    /// Energy consumption per distance unit when vehicle is fully loaded.
+    pub full_consumption: f64,
+}
+
+impl BatteryPolicy {
+    /// Returns energy consumed on given distance when vehicle load is at given ratio of its capacity.
+    pub fn get_consumption(&self, distance: Distance, load_ratio: f64) -> f64 {
+        let load_ratio = load_ratio.clamp(0., 1.);
+
+        distance * (self.empty_consumption + (self.full_consumption - self.empty_consumption) * load_ratio)
+    }
+
+    /// Returns battery charge after charging at the station with given rate within given duration.
+    pub fn charge(&self, current: f64, rate: f64, duration: Duration) -> f64 {
+        (current + rate * duration).min(self.capacity)
+    }
+}
+
+/// A multi trip strategy which treats charging stations as reloads of vehicle battery.
+pub struct ChargingMultiTrip<T: LoadOps> {
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> Default for ChargingMultiTrip<T> {
+    fn default() -> Self {
+        Self { phantom: PhantomData }
+    }
+}
+
+impl<T: LoadOps> MultiTrip<T> for ChargingMultiTrip<T> {
+    fn is_reload_job(&self, job: &Job) -> bool {
+        job.as_single().map_or(false, |single| self.is_reload_single(single))
+    }
+
+    fn is_reload_single(&self, single: &Single) -> bool {
+        single.dimens.get_value::<String>("type").map_or(false, |t| t == "charging")
+    }
+
+    fn is_assignable(&self, route: &Route, job: &Job) -> bool {
+        self.is_reload_job(job) && get_battery(route).is_some() && is_shift_job_assignable(route, job.to_single())
+    }
+
+    fn is_reload_needed(&self, _: &T, _: &T) -> bool {
+        // NOTE charging is needed due to battery level, not due to vehicle load
+        false
+    }
+
+    fn has_reloads(&self, route_ctx: &RouteContext) -> bool {
+        route_ctx.route.tour.all_activities().any(|activity| self.get_reload(activity).is_some())
+    }
+
+    fn get_reload<'a>(&self, activity: &'a Activity) -> Option<&'a Arc<Single>> {
+        as_single_job(activity, |job| self.is_reload_single(job))
+    }
+
+    fn get_reloads<'a>(
+        &'a self,
+        route: &'a Route,
+        jobs: &'a [Job],
+    ) -> Box<dyn Iterator<Item = Job> + 'a + Send + Sync> {
+        get_shift_jobs(route, jobs, move |single| self.is_reload_single(single))
+    }
+}
+
+/// A module which keeps battery charge of electric vehicle non-negative. Charging stations are
+/// optional jobs which are promoted to required ones when battery charge of the route goes below
+/// threshold, similar to reloads. Energy consumption depends on traveled distance and vehicle load,
+/// so the module has to be added after capacity module.
+pub struct ChargingModule<T: LoadOps> {
+    code: i32,
+    threshold: f64,
+    state_keys: Vec<i32>,
+    conditional: ConditionalJobModule,
+    constraints: Vec<ConstraintVariant>,
+    multi_trip: Arc<ChargingMultiTrip<T>>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl<T: LoadOps> ChargingModule<T> {
+    /// Creates a new instance of `ChargingModule`. Threshold specifies a ratio of battery capacity
+    /// below which charging stations are considered for insertion.
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        threshold: f64,
+        code: i32,
+    ) -> Self {
+        let multi_trip = Arc::new(ChargingMultiTrip::<T>::default());
+
+        Self {
+            code,
+            threshold,
+            state_keys: vec![BATTERY_KEY],
+            conditional: ConditionalJobModule::new(Box::new(ConcreteJobContextTransition {
+                remove_required: {
+                    let multi_trip = multi_trip.clone();
+                    move |_, _, job| multi_trip.is_reload_job(job)
+                },
+                promote_required: |_, _, _| false,
+                remove_locked: |_, _, _| false,
+                promote_locked: {
+                    let multi_trip = multi_trip.clone();
+                    move |_, _, job| multi_trip.is_reload_job(job)
+                },
+            })),
+            constraints: vec![
+                ConstraintVariant::SoftRoute(Arc::new(ChargingSoftRouteConstraint { multi_trip: multi_trip.clone() })),
+                ConstraintVariant::HardRoute(Arc::new(ChargingHardRouteConstraint {
+                    code,
+                    multi_trip: multi_trip.clone(),
+                })),
+                ConstraintVariant::HardActivity(Arc::new(ChargingHardActivityConstraint::<T> {
+                    code,
+                    transport: transport.clone(),
+                    phantom: PhantomData,
+                })),
+            ],
+            multi_trip,
+            activity,
+            transport,
+        }
+    }
+
+    fn recalculate_states(&self, route_ctx: &mut RouteContext) {
+        if let Some(battery) = get_battery(&route_ctx.route) {
+            let activities = get_activities_with_loads::<T>(route_ctx);
+            let min_charge = get_min_charge(&route_ctx.route, battery, self.transport.as_ref(), activities.as_slice());
+
+            route_ctx.state_mut().put_route_state(BATTERY_KEY, min_charge);
+        }
+    }
+
+    fn is_battery_low(&self, route_ctx: &RouteContext) -> bool {
+        get_battery(&route_ctx.route)
+            .zip(route_ctx.state.get_route_state::<f64>(BATTERY_KEY))
+            .map_or(false, |(battery, min_charge)| *min_charge < battery.capacity * self.threshold)
+    }
+
+    /// Promotes charging stations to required when some jobs cannot be reached without charging.
+    fn promote_charging_for_unassigned(&self, ctx: &mut SolutionContext) {
+        // NOTE such jobs cannot be inserted, so battery of their route never goes below threshold
+        if ctx.unassigned.values().any(|code| *code == self.code) {
+            let jobs = ctx.ignored.iter().filter(|job| self.multi_trip.is_reload_job(job)).cloned().collect::<Vec<_>>();
+            ctx.ignored.retain(|job| !self.multi_trip.is_reload_job(job));
+            ctx.required.extend(jobs);
+        }
+    }
+
+    /// Removes charging stations which are not needed to keep battery charge non-negative.
+    fn remove_trivial_charging(&self, ctx: &mut SolutionContext) {
+        let mut extra_ignored = Vec::new();
+        ctx.routes.iter_mut().filter(|route_ctx| self.multi_trip.has_reloads(route_ctx)).for_each(|route_ctx| {
+            let battery = get_battery(&route_ctx.route).cloned();
+            let mut loads =
+                get_activities_with_loads::<T>(route_ctx).into_iter().map(|(_, load)| load).collect::<Vec<_>>();
+
+            let charging_indices = route_ctx
+                .route
+                .tour
+                .all_activities()
+                .enumerate()
+                .filter(|(_, activity)| self.multi_trip.get_reload(activity).is_some())
+                .map(|(idx, _)| idx)
+                .collect::<Vec<_>>();
+
+            charging_indices.into_iter().rev().for_each(|idx| {
+                let is_trivial = battery.as_ref().map_or(true, |battery| {
+                    let remaining = route_ctx
+                        .route
+                        .tour
+                        .all_activities()
+                        .zip(loads.iter().cloned())
+                        .enumerate()
+                        .filter(|(other_idx, _)| *other_idx != idx)
+                        .map(|(_, activity)| activity)
+                        .collect::<Vec<_>>();
+
+                    get_min_charge(&route_ctx.route, battery, self.transport.as_ref(), remaining.as_slice()) >= -EPSILON
+                });
+
+                if is_trivial {
+                    let job = route_ctx
+                        .route
+                        .tour
+                        .get(idx)
+                        .and_then(|activity| activity.job.clone())
+                        .map(Job::Single)
+                        .expect("charging activity should have job");
+
+                    loads.remove(idx);
+                    assert!(route_ctx.route_mut().tour.remove(&job));
+                    extra_ignored.push(job);
+                }
+            });
+
+            if route_ctx.is_stale() {
+                update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref());
+            }
+        });
+        ctx.ignored.extend(extra_ignored);
+
+        // NOTE routes which had only charging stations become empty
+        let registry = &mut ctx.registry;
+        ctx.routes.retain(|route_ctx| {
+            if route_ctx.route.tour.has_jobs() {
+                true
+            } else {
+                registry.free_route(route_ctx);
+                false
+            }
+        });
+    }
+}
+
+impl<T: LoadOps> ConstraintModule for ChargingModule<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+        self.accept_route_state(route_ctx);
+
+        if self.multi_trip.is_reload_job(job) {
+            // move all unassigned charging stations back to ignored
+            let jobs = self.multi_trip.get_reloads(&route_ctx.route, &solution_ctx.required).collect::<HashSet<_>>();
+            solution_ctx.required.retain(|job| !jobs.contains(job));
+            solution_ctx.unassigned.retain(|job, _| !jobs.contains(job));
+            solution_ctx.ignored.extend(jobs);
+            // NOTE reevaluate insertion of unassigned due to battery constraint jobs
+            solution_ctx.unassigned.iter_mut().for_each(|pair| {
+                if *pair.1 == self.code {
+                    *pair.1 = 0;
+                }
+            });
+        } else if self.is_battery_low(route_ctx) {
+            // move all charging stations for this shift to required
+            let jobs = self
+                .multi_trip
+                .get_reloads(&route_ctx.route, &solution_ctx.ignored)
+                .chain(self.multi_trip.get_reloads(&route_ctx.route, &solution_ctx.required))
+                .collect::<HashSet<_>>();
+
+            solution_ctx.ignored.retain(|job| !jobs.contains(job));
+            solution_ctx.locked.extend(jobs.iter().cloned());
+            solution_ctx.required.extend(jobs);
+        }
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        self.recalculate_states(ctx);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        self.conditional.accept_solution_state(ctx);
+        self.promote_charging_for_unassigned(ctx);
+        self.remove_trivial_charging(ctx);
+
+        ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.recalculate_states(route_ctx);
+        })
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        if once(&source).chain(once(&candidate)).any(|job| self.multi_trip.is_reload_job(job)) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Returns battery policy of the route's vehicle if it is electric.
+pub(crate) fn get_battery(route: &Route) -> Option<&BatteryPolicy> {
+    route.actor.vehicle.dimens.get_value::<BatteryPolicy>("battery")
+}
+
+/// Returns charging rate if activity is a visit of a charging station.
+pub(crate) fn get_charging_rate(activity: &Activity) -> Option<f64> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_value::<f64>("charging_rate")).cloned()
+}
+
+fn get_activities_with_loads<T: LoadOps>(route_ctx: &RouteContext) -> Vec<(&Activity, T)> {
+    route_ctx
+        .route
+        .tour
+        .all_activities()
+        .map(|activity| {
+            (
+                activity,
+                route_ctx.state.get_activity_state::<T>(CURRENT_CAPACITY_KEY, activity).cloned().unwrap_or_default(),
+            )
+        })
+        .collect()
+}
+
+/// Simulates battery charge over given activities with vehicle load after departure from each of them and
+/// returns min battery charge on arrival.
+fn get_min_charge<T: LoadOps>(
+    route: &Route,
+    battery: &BatteryPolicy,
+    transport: &(dyn TransportCost + Send + Sync),
+    activities: &[(&Activity, T)],
+) -> f64 {
+    let capacity = route.actor.vehicle.dimens.get_capacity();
+    // NOTE ratio is not defined for vehicle without capacity
+    let get_ratio =
+        |load: &T| capacity.map(|capacity| load.ratio(capacity)).filter(|ratio| ratio.is_finite()).unwrap_or(0.);
+
+    let mut prev: Option<(&Activity, T)> = None;
+    let mut charge = battery.initial;
+    let mut min_charge = battery.initial;
+
+    activities.iter().for_each(|(activity, load)| {
+        if let Some((prev, prev_load)) = prev {
+            let distance = transport.distance(
+                route,
+                prev.place.location,
+                activity.place.location,
+                TravelTime::Departure(prev.schedule.departure),
+            );
+
+            charge -= battery.get_consumption(distance, get_ratio(&prev_load));
+            min_charge = min_charge.min(charge);
+        }
+
+        if let Some(rate) = get_charging_rate(activity) {
+            charge = battery.charge(charge, rate, activity.place.duration);
+        }
+
+        prev = Some((activity, *load));
+    });
+
+    min_charge
+}
+
+struct ChargingSoftRouteConstraint<T: LoadOps> {
+    multi_trip: Arc<ChargingMultiTrip<T>>,
+}
+
+impl<T: LoadOps> SoftRouteConstraint for ChargingSoftRouteConstraint<T> {
+    fn estimate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> f64 {
+        if self.multi_trip.is_reload_job(job) {
+            0. - ctx.route.actor.vehicle.costs.fixed.max(1000.)
+        } else {
+            0.
+        }
+    }
+}
+
+/// Locks charging stations to specific vehicles.
+struct ChargingHardRouteConstraint<T: LoadOps> {
+    code: i32,
+    multi_trip: Arc<ChargingMultiTrip<T>>,
+}
+
+impl<T: LoadOps> HardRouteConstraint for ChargingHardRouteConstraint<T> {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        if self.multi_trip.is_reload_job(job) && !self.multi_trip.is_assignable(&ctx.route, job) {
+            Some(RouteConstraintViolation { code: self.code })
+        } else {
+            None
+        }
+    }
+}
+
+struct ChargingHardActivityConstraint<T: LoadOps> {
+    code: i32,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> HardActivityConstraint for ChargingHardActivityConstraint<T> {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let battery = get_battery(&route_ctx.route)?;
+
+        let mut activities = get_activities_with_loads::<T>(route_ctx);
+        let index = activity_ctx.index;
+        let prev_load = activities.get(index).map(|(_, load)| *load).unwrap_or_default();
+
+        // NOTE static delivery is loaded at the start of the reload interval and static pickup is
+        // brought to its end, so consumption of the whole interval can be affected
+        let demand = activity_ctx.target.job.as_ref().and_then(|single| single.dimens.get_demand()).cloned();
+        let target_load = demand.as_ref().map_or(prev_load, |demand: &Demand<T>| {
+            let (start_idx, end_idx) = route_ctx
+                .state
+                .get_route_state::<Vec<(usize, usize)>>(RELOAD_INTERVALS_KEY)
+                .and_then(|intervals| intervals.iter().find(|(start, end)| *start <= index && index <= *end))
+                .cloned()
+                .unwrap_or((0, activities.len() - 1));
+
+            activities.iter_mut().enumerate().skip(start_idx).take(end_idx - start_idx + 1).for_each(
+                |(idx, (_, load))| {
+                    *load =
+                        if idx <= index { *load + demand.delivery.0 } else { *load + demand.pickup.0 + demand.change() }
+                },
+            );
+
+            prev_load + demand.pickup.0 + demand.change()
+        });
+
+        activities.insert(index + 1, (activity_ctx.target, target_load));
+
+        if get_min_charge(&route_ctx.route, battery, self.transport.as_ref(), activities.as_slice()) < -EPSILON {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,599 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/docks_test.rs"]
+mod docks_test;
+
+use crate::constraints::*;
+use crate::extensions::get_actor_service_duration;
+use hashbrown::HashMap;
+use std::cmp::Ordering;
+use std::slice::Iter;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::*;
+use vrp_core::models::problem::{ActivityCost, Job, Single, TransportCost, TravelTime};
+use vrp_core::prelude::compare_floats;
+
+/// Specifies a duration of dock operation which depends on amount of goods.
+#[derive(Clone, Debug)]
+pub struct DockRate {
+    /// A fixed duration of the operation.
+    pub fixed: Duration,
+    /// A duration per unit of goods.
+    pub per_unit: Duration,
+}
+
+impl DockRate {
+    /// Returns duration of the operation for given amount of goods.
+    pub fn duration(&self, amount: f64) -> Duration {
+        self.fixed + self.per_unit * amount
+    }
+}
+
+/// Specifies a depot with limited amount of docks shared by all tours.
+#[derive(Clone, Debug)]
+pub struct DockPolicy {
+    /// Id of the depot.
+    pub depot_id: String,
+    /// Amount of docks.
+    pub docks: usize,
+    /// A rate of loading before departure.
+    pub loading: Option<DockRate>,
+    /// A rate of unloading after arrival.
+    pub unloading: Option<DockRate>,
+}
+
+/// Specifies depots where vehicle is loaded before departure and unloaded after arrival.
+#[derive(Clone, Debug, Default)]
+pub struct VehicleDocks {
+    /// A depot at the start location of the vehicle.
+    pub start: Option<Arc<DockPolicy>>,
+    /// A depot at the end location of the vehicle.
+    pub end: Option<Arc<DockPolicy>>,
+}
+
+/// Specifies a type of dock operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DockOperation {
+    /// Loading of goods before departure.
+    Loading,
+    /// Unloading of goods after arrival.
+    Unloading,
+}
+
+/// Specifies usage of the dock by the route.
+#[derive(Clone, Debug)]
+pub struct DockUsage {
+    /// Index of the route.
+    pub route_index: usize,
+    /// A depot policy.
+    pub policy: Arc<DockPolicy>,
+    /// Index of the dock.
+    pub dock: usize,
+    /// A type of operation.
+    pub operation: DockOperation,
+    /// A time when dock is occupied.
+    pub time: TimeWindow,
+}
+
+/// Keeps amount of goods loaded at depot before the first reload and unloaded at depot after
+/// the last reload in the tour.
+struct DockLoad {
+    loading: f64,
+    first_reload: usize,
+    unloading: f64,
+    last_reload: usize,
+}
+
+/// A dock module schedules loading and unloading operations of all tours on limited amount of depot docks.
+/// A vehicle which starts at the depot is loaded right before departure, so its departure is delayed till
+/// loading is done. A vehicle which ends at the depot is unloaded after arrival and occupies the dock, so
+/// other vehicles might wait for it.
+///
+/// Dock schedule of the solution is kept in solution state and used by hard route constraint to check that
+/// delays caused by insertion keep all tours feasible: each tour has to depart not later than its latest
+/// feasible departure and has to be unloaded before its shift end. The latest arrival which leaves time for
+/// unloading is kept in state of the route end activity and checked by hard activity constraint.
+pub struct DockModule {
+    state_key: i32,
+    keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    is_multi_dimen: bool,
+}
+
+impl DockModule {
+    /// Creates a new instance of `DockModule`.
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        is_multi_dimen: bool,
+        code: i32,
+        state_key: i32,
+    ) -> Self {
+        Self {
+            state_key,
+            keys: vec![state_key],
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(DockHardRouteConstraint {
+                    code,
+                    state_key,
+                    transport: transport.clone(),
+                    is_multi_dimen,
+                })),
+                ConstraintVariant::HardActivity(Arc::new(DockHardActivityConstraint {
+                    code,
+                    state_key,
+                    activity: activity.clone(),
+                    transport: transport.clone(),
+                    is_multi_dimen,
+                })),
+            ],
+            activity,
+            transport,
+            is_multi_dimen,
+        }
+    }
+
+    /// Schedules dock operations, sets departure of routes to the end of their loading and keeps
+    /// resulting dock schedule in solution and route states.
+    fn schedule(&self, ctx: &mut SolutionContext) {
+        let tours = get_solution_tours(ctx, self.transport.as_ref(), self.is_multi_dimen);
+
+        let route_ctxs = &mut ctx.routes;
+        let usages = schedule_docks(tours, |route_index, departure| {
+            let route_ctx = route_ctxs.get_mut(route_index).unwrap();
+            let current = route_ctx.route.tour.start().map_or(departure, |start| start.schedule.departure);
+
+            if compare_floats(current, departure) != Ordering::Equal {
+                route_ctx.route_mut().tour.get_mut(0).unwrap().schedule.departure = departure;
+                update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref());
+            }
+
+            route_ctx.route.tour.end().map(|end| end.schedule.arrival)
+        });
+
+        usages.iter().filter(|usage| usage.operation == DockOperation::Unloading).for_each(|usage| {
+            let route_ctx = ctx.routes.get_mut(usage.route_index).unwrap();
+            let (route, state) = route_ctx.as_mut();
+
+            if let Some(end) = route.tour.end() {
+                let latest = end.place.time.end - (usage.time.end - end.schedule.arrival);
+                state.put_activity_state(self.state_key, end, latest);
+            }
+        });
+
+        let tours = get_solution_tours(ctx, self.transport.as_ref(), self.is_multi_dimen);
+        ctx.state.insert(self.state_key, Arc::new(tours));
+    }
+}
+
+impl ConstraintModule for DockModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+        // NOTE any insertion can change dock availability for other routes
+        self.schedule(solution_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let load = get_dock_load(ctx.route.as_ref(), self.is_multi_dimen);
+
+        ctx.state_mut().put_route_state(self.state_key, load);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        self.schedule(ctx);
+    }
+
+    fn merge(&self, source: Job, _candidate: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct DockHardRouteConstraint {
+    code: i32,
+    state_key: i32,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    is_multi_dimen: bool,
+}
+
+impl HardRouteConstraint for DockHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        let docks = get_vehicle_docks(route_ctx)?;
+        let tours = solution_ctx.state.get(&self.state_key).and_then(|state| state.downcast_ref::<Vec<DockTour>>())?;
+
+        // NOTE assume that job is loaded and unloaded at depot even if it is served between reloads
+        let (delivery, pickup) = get_job_amounts(job, self.is_multi_dimen);
+        let get_extra = |rate: Option<&DockRate>, amount: f64| rate.map_or(0., |rate| rate.per_unit * amount);
+        let loading = get_extra(docks.start.as_ref().and_then(|policy| policy.loading.as_ref()), delivery);
+        let unloading = get_extra(docks.end.as_ref().and_then(|policy| policy.unloading.as_ref()), pickup);
+
+        // NOTE a new route occupies docks even without goods
+        let is_empty = !route_ctx.route.tour.has_jobs();
+        if !is_empty && compare_floats(loading + unloading, 0.) != Ordering::Greater {
+            return None;
+        }
+
+        let route_index = solution_ctx
+            .routes
+            .iter()
+            .position(|other| other.route.actor == route_ctx.route.actor)
+            .unwrap_or(solution_ctx.routes.len());
+
+        let mut tours = tours.clone();
+        match tours.iter_mut().find(|tour| tour.route_index == route_index) {
+            Some(tour) => {
+                tour.loading.iter_mut().for_each(|(_, duration)| *duration += loading);
+                tour.unloading.iter_mut().for_each(|(_, duration)| *duration += unloading);
+            }
+            None => tours.extend(get_dock_tour(route_index, route_ctx.route.as_ref(), (delivery, pickup))),
+        }
+
+        let is_feasible = get_feasible_departures(tours).map_or(false, |departures| {
+            // NOTE departure of the new route is known only after scheduling, so check that the job can be
+            // served when route departs at the end of loading
+            let departure = departures.get(&route_index).cloned();
+            match (is_empty, departure, route_ctx.route.tour.start()) {
+                (true, Some(departure), Some(start)) => {
+                    get_job_places(route_ctx, job).all(|(location, duration, time)| {
+                        can_depart_at(
+                            route_ctx,
+                            self.transport.as_ref(),
+                            departure,
+                            (0, start, route_ctx.route.tour.end()),
+                            (location, duration, &time),
+                        )
+                    })
+                }
+                _ => true,
+            }
+        });
+
+        if is_feasible {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+struct DockHardActivityConstraint {
+    code: i32,
+    state_key: i32,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    is_multi_dimen: bool,
+}
+
+impl HardActivityConstraint for DockHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let docks = get_vehicle_docks(route_ctx)?;
+
+        match route_ctx.state.get_route_state::<DockLoad>(self.state_key) {
+            Some(load) => self.evaluate_with_load(route_ctx, activity_ctx, docks, load),
+            None => {
+                let load = get_dock_load(route_ctx.route.as_ref(), self.is_multi_dimen);
+                self.evaluate_with_load(route_ctx, activity_ctx, docks, &load)
+            }
+        }
+    }
+}
+
+impl DockHardActivityConstraint {
+    fn evaluate_with_load(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        docks: &VehicleDocks,
+        load: &DockLoad,
+    ) -> Option<ActivityConstraintViolation> {
+        let route = route_ctx.route.as_ref();
+        let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+        let start = route.tour.start()?;
+
+        // NOTE goods delivered after reload are not loaded and goods picked up before reload are not
+        // unloaded at the depot
+        let (delivery, pickup) = get_demand_amounts(target, self.is_multi_dimen);
+        let delivery = if activity_ctx.index < load.first_reload { delivery } else { 0. };
+        let pickup = if activity_ctx.index >= load.last_reload { pickup } else { 0. };
+
+        let ready = docks.start.as_ref().and_then(|policy| policy.loading.as_ref()).map(|rate| {
+            (start.schedule.departure + rate.per_unit * delivery)
+                .max(start.place.time.start + rate.duration(load.loading + delivery))
+        });
+
+        if let Some(ready) = ready {
+            let duration = get_actor_service_duration(route.actor.as_ref(), target);
+            let place = (target.place.location, duration, &target.place.time);
+            let activities = (activity_ctx.index, prev, next);
+
+            if !can_depart_at(route_ctx, self.transport.as_ref(), ready, activities, place) {
+                return self.violation();
+            }
+        }
+
+        let rate = docks.end.as_ref().and_then(|policy| policy.unloading.as_ref());
+        let (rate, end) = match rate.zip(route.tour.end()).zip(next) {
+            Some((data, _)) => data,
+            None => return None,
+        };
+
+        let latest = route_ctx
+            .state
+            .get_activity_state::<Timestamp>(self.state_key, end)
+            .cloned()
+            .unwrap_or_else(|| end.place.time.end - rate.duration(load.unloading));
+
+        // NOTE shift of the next activity is used as upper bound of the shift of the route end
+        let delay = ready.map_or(0., |ready| (ready - start.schedule.departure).max(0.));
+        let prev_departure = prev.schedule.departure + delay;
+        let arrival = prev_departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(prev_departure),
+            );
+        let departure = self.activity.estimate_departure(route, target, arrival);
+        let next = next.unwrap();
+        let next_arrival = departure
+            + self.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(departure),
+            );
+        let shift = (next_arrival - next.schedule.arrival).max(0.);
+
+        if end.schedule.arrival + shift + rate.per_unit * pickup > latest {
+            self.violation()
+        } else {
+            None
+        }
+    }
+
+    fn violation(&self) -> Option<ActivityConstraintViolation> {
+        Some(ActivityConstraintViolation { code: self.code, stopped: false })
+    }
+}
+
+/// Keeps information about dock operations of the route.
+#[derive(Clone)]
+pub(crate) struct DockTour {
+    route_index: usize,
+    vehicle_id: String,
+    earliest: Timestamp,
+    departure: Timestamp,
+    arrival: Option<Timestamp>,
+    latest_departure: Timestamp,
+    latest_end: Timestamp,
+    loading: Option<(Arc<DockPolicy>, Duration)>,
+    unloading: Option<(Arc<DockPolicy>, Duration)>,
+}
+
+/// Returns dock operations of given routes.
+pub(crate) fn get_dock_tours(routes: &[&Route], is_multi_dimen: bool) -> Vec<DockTour> {
+    routes
+        .iter()
+        .enumerate()
+        .filter(|(_, route)| route.tour.has_jobs())
+        .filter_map(|(route_index, route)| {
+            let load = get_dock_load(route, is_multi_dimen);
+            get_dock_tour(route_index, route, (load.loading, load.unloading))
+        })
+        .collect()
+}
+
+/// Returns dock operations of the route with given amounts of loaded and unloaded goods.
+fn get_dock_tour(route_index: usize, route: &Route, amounts: (f64, f64)) -> Option<DockTour> {
+    let docks = route.actor.vehicle.dimens.get_value::<VehicleDocks>("docks")?;
+    let get_operation = |policy: &Option<Arc<DockPolicy>>, rate: fn(&DockPolicy) -> Option<&DockRate>, amount| {
+        policy.as_ref().and_then(|policy| rate(policy).map(|rate| (policy.clone(), rate.duration(amount))))
+    };
+
+    let loading = get_operation(&docks.start, |policy| policy.loading.as_ref(), amounts.0);
+    let unloading = get_operation(&docks.end, |policy| policy.unloading.as_ref(), amounts.1);
+
+    if loading.is_none() && unloading.is_none() {
+        return None;
+    }
+
+    let start = route.tour.start()?;
+    let end = route.tour.end();
+
+    Some(DockTour {
+        route_index,
+        vehicle_id: route.actor.vehicle.dimens.get_id().cloned().unwrap_or_default(),
+        earliest: start.place.time.start,
+        departure: start.schedule.departure,
+        arrival: end.map(|end| end.schedule.arrival),
+        latest_departure: route.actor.detail.start.as_ref().and_then(|start| start.time.latest).unwrap_or(f64::MAX),
+        latest_end: end.map_or(f64::MAX, |end| end.place.time.end),
+        loading,
+        unloading,
+    })
+}
+
+/// Returns dock operations of the solution routes with their latest feasible departures.
+fn get_solution_tours(
+    ctx: &SolutionContext,
+    transport: &(dyn TransportCost + Send + Sync),
+    is_multi_dimen: bool,
+) -> Vec<DockTour> {
+    let routes = ctx.routes.iter().map(|route_ctx| route_ctx.route.as_ref()).collect::<Vec<_>>();
+
+    get_dock_tours(routes.as_slice(), is_multi_dimen)
+        .into_iter()
+        .map(|tour| {
+            let route_ctx = ctx.routes.get(tour.route_index).unwrap();
+            let latest_departure = get_latest_departure(route_ctx, transport).unwrap_or(tour.latest_departure);
+
+            DockTour { latest_departure, ..tour }
+        })
+        .collect()
+}
+
+/// Returns departures of tours when their dock schedule keeps all of them feasible. Arrival of the tour
+/// is assumed to be shifted by the delay of its departure.
+fn get_feasible_departures(tours: Vec<DockTour>) -> Option<HashMap<usize, Timestamp>> {
+    let limits = tours
+        .iter()
+        .map(|tour| (tour.route_index, (tour.departure, tour.arrival, tour.latest_departure, tour.latest_end)))
+        .collect::<HashMap<_, _>>();
+
+    let mut departures = HashMap::default();
+    let usages = schedule_docks(tours, |route_index, departure| {
+        let (current, arrival, _, _) = limits.get(&route_index).unwrap();
+        departures.insert(route_index, departure);
+
+        arrival.map(|arrival| arrival + (departure - current).max(0.))
+    });
+
+    let is_feasible = usages.iter().all(|usage| {
+        let (_, _, latest_departure, latest_end) = limits.get(&usage.route_index).unwrap();
+        let limit = match usage.operation {
+            DockOperation::Loading => latest_departure,
+            DockOperation::Unloading => latest_end,
+        };
+
+        compare_floats(usage.time.end, *limit) != Ordering::Greater
+    });
+
+    if is_feasible {
+        Some(departures)
+    } else {
+        None
+    }
+}
+
+/// Schedules dock operations of given tours: operations are processed in order of their ready time, each
+/// using the dock of the depot which is released first. Loading is ready at the shift start and unloading at
+/// arrival. A `depart` function is called with the end of loading, which is a new departure time of the
+/// route, and returns its arrival time.
+pub(crate) fn schedule_docks<F>(tours: Vec<DockTour>, mut depart: F) -> Vec<DockUsage>
+where
+    F: FnMut(usize, Timestamp) -> Option<Timestamp>,
+{
+    let mut pending = tours
+        .iter()
+        .map(|tour| match &tour.loading {
+            Some(_) => (tour.earliest, DockOperation::Loading, tour),
+            None => (tour.arrival.unwrap_or(f64::MAX), DockOperation::Unloading, tour),
+        })
+        .collect::<Vec<_>>();
+
+    let mut docks: HashMap<String, Vec<Timestamp>> = HashMap::default();
+    let mut usages = Vec::new();
+
+    while let Some(idx) = pending
+        .iter()
+        .enumerate()
+        .min_by(|(_, (a_ready, _, a_tour)), (_, (b_ready, _, b_tour))| {
+            compare_floats(*a_ready, *b_ready).then_with(|| a_tour.vehicle_id.cmp(&b_tour.vehicle_id))
+        })
+        .map(|(idx, _)| idx)
+    {
+        let (ready, operation, tour) = pending.swap_remove(idx);
+        let (policy, duration) = match operation {
+            DockOperation::Loading => tour.loading.as_ref(),
+            DockOperation::Unloading => tour.unloading.as_ref(),
+        }
+        .unwrap();
+
+        let releases = docks.entry(policy.depot_id.clone()).or_insert_with(|| vec![f64::MIN; policy.docks]);
+        let (dock, release) = match releases.iter().cloned().enumerate().min_by(|(_, a), (_, b)| compare_floats(*a, *b))
+        {
+            Some(dock) => dock,
+            None => continue,
+        };
+
+        let start = ready.max(release);
+        let end = start + duration;
+        releases[dock] = end;
+
+        usages.push(DockUsage {
+            route_index: tour.route_index,
+            policy: policy.clone(),
+            dock,
+            operation,
+            time: TimeWindow::new(start, end),
+        });
+
+        if operation == DockOperation::Loading {
+            let arrival = depart(tour.route_index, end);
+
+            if let Some(arrival) = arrival.filter(|_| tour.unloading.is_some()) {
+                pending.push((arrival, DockOperation::Unloading, tour));
+            }
+        }
+    }
+
+    usages
+}
+
+fn get_vehicle_docks(route_ctx: &RouteContext) -> Option<&VehicleDocks> {
+    route_ctx.route.actor.vehicle.dimens.get_value::<VehicleDocks>("docks")
+}
+
+/// Returns amount of goods loaded before departure and unloaded after arrival.
+fn get_dock_load(route: &Route, is_multi_dimen: bool) -> DockLoad {
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+    let first_reload = activities.iter().position(|activity| is_reload_activity(activity)).unwrap_or(activities.len());
+    let last_reload = activities.iter().rposition(|activity| is_reload_activity(activity)).unwrap_or(0);
+
+    let loading = activities[..first_reload].iter().map(|activity| get_demand_amounts(activity, is_multi_dimen).0);
+    let unloading = activities[last_reload..].iter().map(|activity| get_demand_amounts(activity, is_multi_dimen).1);
+
+    DockLoad { loading: loading.sum(), first_reload, unloading: unloading.sum(), last_reload }
+}
+
+/// Returns total static delivery and pickup amounts of the job.
+fn get_job_amounts(job: &Job, is_multi_dimen: bool) -> (f64, f64) {
+    let single = match job {
+        Job::Single(single) => single,
+        // NOTE multi job consists of pickups and deliveries between them, so goods are not handled at depot
+        Job::Multi(_) => return (0., 0.),
+    };
+
+    get_single_amounts(single.as_ref(), is_multi_dimen)
+}
+
+/// Returns total static delivery and pickup amounts of the activity.
+fn get_demand_amounts(activity: &Activity, is_multi_dimen: bool) -> (f64, f64) {
+    activity.job.as_ref().map_or((0., 0.), |single| get_single_amounts(single.as_ref(), is_multi_dimen))
+}
+
+fn get_single_amounts(single: &Single, is_multi_dimen: bool) -> (f64, f64) {
+    if is_multi_dimen {
+        let total = |load: &MultiDimLoad| load.load.iter().take(load.size).sum::<i32>() as f64;
+        single
+            .dimens
+            .get_demand()
+            .map_or((0., 0.), |demand: &Demand<MultiDimLoad>| (total(&demand.delivery.0), total(&demand.pickup.0)))
+    } else {
+        single.dimens.get_demand().map_or((0., 0.), |demand: &Demand<SingleDimLoad>| {
+            (demand.delivery.0.value as f64, demand.pickup.0.value as f64)
+        })
+    }
+}
+
+fn is_reload_activity(activity: &Activity) -> bool {
+    activity.job.as_ref().and_then(|single| single.dimens.get_value::<String>("type")).map_or(false, |t| t == "reload")
+}
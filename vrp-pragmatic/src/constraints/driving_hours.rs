@@ -0,0 +1,122 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/driving_hours_test.rs"]
+mod driving_hours_test;
+
+use vrp_core::models::common::*;
+
+/// A tolerance used to detect that driving limit is reached.
+const EPSILON: f64 = 1E-6;
+
+/// Specifies driving hours regulation rules, e.g. EU 561/2006.
+#[derive(Clone, Debug)]
+pub struct DrivingHoursPolicy {
+    /// Max continuous driving time before a break.
+    pub max_continuous_driving: Duration,
+    /// A break duration required after continuous driving.
+    pub break_duration: Duration,
+    /// Durations of two parts of the break if it can be split.
+    pub split_break: Option<(Duration, Duration)>,
+    /// Max driving time between two daily rests.
+    pub max_daily_driving: Duration,
+    /// A daily rest duration.
+    pub daily_rest: Duration,
+    /// Max duty time between two daily rests.
+    pub max_daily_duty: Option<Duration>,
+}
+
+/// Represents a mandatory rest inserted into the route.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DrivingRest {
+    /// Index of activity from which vehicle departs on the leg with the rest.
+    pub leg_index: usize,
+    /// True if rest is taken at the activity's location before departure, otherwise it is taken on the way.
+    pub at_stop: bool,
+    /// Rest time.
+    pub time: TimeWindow,
+}
+
+/// Keeps track of accumulated driving and duty time since the last break or daily rest.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct DrivingState {
+    pub continuous_driving: Duration,
+    pub daily_driving: Duration,
+    pub daily_duty: Duration,
+    pub has_first_part: bool,
+}
+
+impl DrivingState {
+    /// Returns driving time left till daily rest is required by daily driving or duty limit.
+    pub fn daily_left(&self, policy: &DrivingHoursPolicy) -> Duration {
+        (policy.max_daily_driving - self.daily_driving)
+            .min(policy.max_daily_duty.map_or(f64::MAX, |max_duty| max_duty - self.daily_duty))
+    }
+
+    /// Updates state when driver is off duty for given duration, e.g. between linked shifts.
+    pub fn rest(&mut self, policy: &DrivingHoursPolicy, duration: Duration) {
+        if duration >= policy.daily_rest {
+            *self = DrivingState::default();
+        } else if duration >= policy.break_duration {
+            self.continuous_driving = 0.;
+            self.has_first_part = false;
+        }
+    }
+}
+
+/// Simulates driving starting from the time when vehicle is ready to leave the activity.
+/// Returns actual departure and arrival times.
+pub(crate) fn drive(
+    policy: &DrivingHoursPolicy,
+    state: &mut DrivingState,
+    ready: Timestamp,
+    driving: Duration,
+    on_rest: &mut dyn FnMut(bool, TimeWindow),
+) -> (Timestamp, Timestamp) {
+    let mut time = ready;
+    let mut remaining = driving;
+    let mut departure = None;
+
+    let mut rest = |time: &mut Timestamp, is_moving: bool, duration: Duration| {
+        on_rest(!is_moving, TimeWindow::new(*time, *time + duration));
+        *time += duration;
+    };
+
+    while remaining > EPSILON {
+        let is_moving = departure.is_some();
+        let continuous_left = policy.max_continuous_driving - state.continuous_driving;
+        let daily_left = state.daily_left(policy);
+
+        if daily_left < EPSILON {
+            rest(&mut time, is_moving, policy.daily_rest);
+            *state = DrivingState::default();
+        } else if continuous_left < EPSILON {
+            let duration = match (policy.split_break, state.has_first_part) {
+                (Some((_, second)), true) => second,
+                _ => policy.break_duration,
+            };
+            rest(&mut time, is_moving, duration);
+            state.continuous_driving = 0.;
+            state.has_first_part = false;
+        } else if let Some((first, _)) = policy.split_break.filter(|_| {
+            // NOTE take the first part of the split break at the stop when break is needed on the way
+            !is_moving
+                && !state.has_first_part
+                && state.continuous_driving > 0.
+                && continuous_left < remaining
+                && continuous_left < daily_left
+        }) {
+            rest(&mut time, is_moving, first);
+            state.has_first_part = true;
+        } else {
+            departure.get_or_insert(time);
+
+            let chunk = remaining.min(continuous_left).min(daily_left);
+            time += chunk;
+            remaining -= chunk;
+            state.continuous_driving += chunk;
+            state.daily_driving += chunk;
+            state.daily_duty += chunk;
+        }
+    }
+
+    (departure.unwrap_or(time), time)
+}
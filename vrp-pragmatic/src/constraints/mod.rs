@@ -20,6 +20,18 @@ pub const TOUR_ORDER_KEY: i32 = 1003;
 pub const AREA_VALUE_KEY: i32 = 1004;
 /// A key which tracks area order state.
 pub const AREA_ORDER_KEY: i32 = 1005;
+/// A key which tracks vehicle rests state.
+pub const REST_KEY: i32 = 1006;
+/// A key which tracks days of periodic job visits.
+pub const PERIODIC_KEY: i32 = 1007;
+/// A key which tracks min battery charge of electric vehicle.
+pub const BATTERY_KEY: i32 = 1008;
+/// A key which tracks transfer times of two-echelon jobs.
+pub const TRANSFER_KEY: i32 = 1009;
+/// A key which tracks service start times of synchronized jobs.
+pub const SYNC_KEY: i32 = 1010;
+/// A key which tracks amount of goods loaded at depot dock.
+pub const DOCK_KEY: i32 = 1011;
 
 fn as_single_job<F>(activity: &Activity, condition: F) -> Option<&Arc<Single>>
 where
@@ -54,21 +66,47 @@ pub use self::areas::AreaModule;
 mod breaks;
 pub use self::breaks::{BreakModule, BreakPolicy};
 
+mod charging;
+pub(crate) use self::charging::{get_battery, get_charging_rate};
+pub use self::charging::{BatteryPolicy, ChargingModule};
+
 mod compatibility;
 pub use self::compatibility::CompatibilityModule;
 
 mod dispatch;
 pub use self::dispatch::DispatchModule;
 
+mod docks;
+pub(crate) use self::docks::{get_dock_tours, schedule_docks};
+pub use self::docks::{DockModule, DockOperation, DockPolicy, DockRate, VehicleDocks};
+
+mod driving_hours;
+pub use self::driving_hours::{DrivingHoursPolicy, DrivingRest};
+
 mod groups;
 pub use self::groups::GroupModule;
 
+mod periodic;
+pub(crate) use self::periodic::{get_day_index, get_weekday, is_visit_allowed, SECONDS_IN_DAY};
+pub use self::periodic::{JobVisit, PeriodicModule, PeriodicPolicy};
+
 mod reloads;
 pub use self::reloads::ReloadMultiTrip;
+pub(crate) use self::reloads::{get_shift_jobs, is_shift_job_assignable};
 
 mod reachable;
 pub use self::reachable::ReachableModule;
 
+mod rests;
+pub use self::rests::{get_driving_rests, RestModule};
+
 mod skills;
-pub use self::skills::JobSkills;
-pub use self::skills::SkillsModule;
+pub use self::skills::{JobPreferredSkills, JobSkills};
+pub use self::skills::{PreferredSkillsModule, SkillsModule};
+
+mod transfers;
+pub(crate) use self::transfers::{can_depart_at, get_job_places, get_latest_departure, get_single_transfer};
+pub use self::transfers::{JobTransfer, TransferEchelon, TransferModule, TransferPolicy};
+
+mod sync;
+pub use self::sync::{JobSync, SyncModule, SyncPolicy};
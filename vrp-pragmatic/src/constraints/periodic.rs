@@ -0,0 +1,241 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/periodic_test.rs"]
+mod periodic_test;
+
+use hashbrown::HashMap;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Timestamp, ValueDimension};
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::Activity;
+use vrp_core::utils::CollectGroupBy;
+
+/// Amount of seconds in one day.
+pub(crate) const SECONDS_IN_DAY: f64 = 86400.;
+
+/// Specifies how visits of a periodic job are spread over planning period.
+#[derive(Clone, Debug)]
+pub struct PeriodicPolicy {
+    /// Minimum amount of days between two consecutive visits.
+    pub min_days_between: usize,
+    /// Allowed visit day patterns: a day of week (zero is Monday) for each visit.
+    pub patterns: Option<Vec<Vec<usize>>>,
+}
+
+/// Specifies a visit of a periodic job.
+#[derive(Clone, Debug)]
+pub struct JobVisit {
+    /// Id of the periodic job.
+    pub job_id: String,
+    /// Index of the visit.
+    pub index: usize,
+    /// Visit policy of the periodic job.
+    pub policy: Arc<PeriodicPolicy>,
+}
+
+/// Keeps days of assigned visits per periodic job id.
+type VisitDays = HashMap<String, Vec<(usize, i64)>>;
+
+/// A periodic module enforces spacing and day patterns between visits of periodic jobs. Each visit
+/// is a separate job which has visit dimension and which time windows are limited by one day, so
+/// a day of the visit is defined by the time window used.
+/// NOTE: day indices are calculated using given UTC offset.
+pub struct PeriodicModule {
+    code: i32,
+    state_key: i32,
+    offset: i64,
+    keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl PeriodicModule {
+    /// Creates a new instance of `PeriodicModule`.
+    pub fn new(offset: i64, code: i32, state_key: i32) -> Self {
+        Self {
+            code,
+            state_key,
+            offset,
+            keys: vec![state_key],
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(PeriodicHardRouteConstraint { code, state_key, offset })),
+                ConstraintVariant::HardActivity(Arc::new(PeriodicHardActivityConstraint { code, state_key, offset })),
+            ],
+        }
+    }
+
+    fn get_visit_days(&self, solution_ctx: &SolutionContext) -> Arc<VisitDays> {
+        Arc::new(
+            solution_ctx
+                .routes
+                .iter()
+                .flat_map(|route_ctx| route_ctx.route.tour.all_activities())
+                .filter_map(|activity| {
+                    get_activity_visit(activity).map(|visit| {
+                        (visit.job_id.clone(), (visit.index, get_day_index(activity.place.time.start, self.offset)))
+                    })
+                })
+                .collect_group_by(),
+        )
+    }
+}
+
+impl ConstraintModule for PeriodicModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        if get_job_visit(job).is_some() {
+            // NOTE other routes are not updated to avoid marking them as stale: their visit days
+            // are compared with solution ones on route level
+            let visit_days = self.get_visit_days(solution_ctx);
+            solution_ctx.state.insert(self.state_key, visit_days.clone());
+            solution_ctx.routes[route_index].state_mut().put_route_state_raw(self.state_key, visit_days);
+        }
+    }
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        let visit_days = self.get_visit_days(ctx);
+        ctx.state.insert(self.state_key, visit_days.clone());
+        ctx.routes.iter_mut().for_each(|route_ctx| {
+            route_ctx.state_mut().put_route_state_raw(self.state_key, visit_days.clone());
+        });
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        match (get_job_visit(&source), get_job_visit(&candidate)) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Returns index of the day which contains given timestamp.
+pub(crate) fn get_day_index(time: Timestamp, offset: i64) -> i64 {
+    ((time + offset as f64) / SECONDS_IN_DAY).floor() as i64
+}
+
+/// Returns day of week of the day with given index: zero is Monday.
+pub(crate) fn get_weekday(day_index: i64) -> usize {
+    // NOTE unix epoch starts on Thursday
+    (day_index + 3).rem_euclid(7) as usize
+}
+
+/// Checks whether the visit on the given day is allowed by policy and days of other visits.
+pub(crate) fn is_visit_allowed(policy: &PeriodicPolicy, visit: (usize, i64), others: &[(usize, i64)]) -> bool {
+    let (visit_index, day) = visit;
+    let others = || others.iter().filter(|(other_index, _)| *other_index != visit_index);
+    let interval = policy.min_days_between as i64 + 1;
+
+    let is_spaced = others().all(|&(other_index, other_day)| {
+        // NOTE reserve enough days for visits in between
+        let required = (visit_index as i64 - other_index as i64) * interval;
+        if visit_index > other_index {
+            day - other_day >= required
+        } else {
+            day - other_day <= required
+        }
+    });
+
+    let matches_pattern = policy.patterns.as_ref().map_or(true, |patterns| {
+        patterns.iter().any(|pattern| {
+            std::iter::once(&visit).chain(others()).all(|&(visit_index, day)| {
+                pattern.get(visit_index).map_or(false, |weekday| *weekday == get_weekday(day))
+            })
+        })
+    });
+
+    is_spaced && matches_pattern
+}
+
+struct PeriodicHardRouteConstraint {
+    code: i32,
+    state_key: i32,
+    offset: i64,
+}
+
+impl HardRouteConstraint for PeriodicHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        let visit = get_job_visit(job)?;
+
+        let others = solution_ctx
+            .state
+            .get(&self.state_key)
+            .and_then(|state| state.downcast_ref::<VisitDays>())
+            .and_then(|visit_days| visit_days.get(&visit.job_id))?;
+
+        // NOTE exact check is done on activity level when route has actual visit days
+        let route_others = route_ctx
+            .state
+            .get_route_state::<VisitDays>(self.state_key)
+            .and_then(|visit_days| visit_days.get(&visit.job_id));
+        if route_others == Some(others) {
+            return None;
+        }
+
+        // NOTE otherwise, only visits which days are allowed within the whole vehicle shift can be accepted
+        let shift_time = &route_ctx.route.actor.detail.time;
+        let is_allowed = job
+            .places()
+            .flat_map(|place| place.times.iter())
+            .filter_map(|time| time.as_time_window())
+            .filter(|time| time.intersects(shift_time))
+            .all(|time| {
+                let day = get_day_index(time.start, self.offset);
+                is_visit_allowed(visit.policy.as_ref(), (visit.index, day), others.as_slice())
+            });
+
+        if is_allowed {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+struct PeriodicHardActivityConstraint {
+    code: i32,
+    state_key: i32,
+    offset: i64,
+}
+
+impl HardActivityConstraint for PeriodicHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let visit = get_activity_visit(activity_ctx.target)?;
+        let visit_days = route_ctx.state.get_route_state::<VisitDays>(self.state_key)?;
+        let others = visit_days.get(&visit.job_id).map_or(&[] as &[_], |others| others.as_slice());
+
+        let day = get_day_index(activity_ctx.target.place.time.start, self.offset);
+
+        if is_visit_allowed(visit.policy.as_ref(), (visit.index, day), others) {
+            None
+        } else {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        }
+    }
+}
+
+fn get_job_visit(job: &Job) -> Option<&JobVisit> {
+    job.dimens().get_value::<JobVisit>("visit")
+}
+
+fn get_activity_visit(activity: &Activity) -> Option<&JobVisit> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_value::<JobVisit>("visit"))
+}
@@ -32,15 +32,7 @@ impl<T: LoadOps> MultiTrip<T> for ReloadMultiTrip<T> {
     }
 
     fn is_assignable(&self, route: &Route, job: &Job) -> bool {
-        if self.is_reload_job(job) {
-            let job = job.to_single();
-            let vehicle_id = get_vehicle_id_from_job(job).unwrap();
-            let shift_index = get_shift_index(&job.dimens);
-
-            is_correct_vehicle(route, vehicle_id, shift_index)
-        } else {
-            false
-        }
+        self.is_reload_job(job) && is_shift_job_assignable(route, job.to_single())
     }
 
     fn is_reload_needed(&self, current: &T, max_capacity: &T) -> bool {
@@ -64,20 +56,40 @@ impl<T: LoadOps> MultiTrip<T> for ReloadMultiTrip<T> {
         route: &'a Route,
         jobs: &'a [Job],
     ) -> Box<dyn Iterator<Item = Job> + 'a + Send + Sync> {
-        let shift_index = get_shift_index(&route.actor.vehicle.dimens);
-        let vehicle_id = route.actor.vehicle.dimens.get_id().unwrap();
-
-        Box::new(
-            jobs.iter()
-                .filter(move |job| match job {
-                    Job::Single(job) => {
-                        self.is_reload_single(job)
-                            && get_shift_index(&job.dimens) == shift_index
-                            && get_vehicle_id_from_job(job).unwrap() == vehicle_id
-                    }
-                    _ => false,
-                })
-                .cloned(),
-        )
+        get_shift_jobs(route, jobs, move |single| self.is_reload_single(single))
     }
 }
+
+/// Returns true if given vehicle specific job, e.g. reload, belongs to the route's vehicle shift.
+pub(crate) fn is_shift_job_assignable(route: &Route, job: &Arc<Single>) -> bool {
+    let vehicle_id = get_vehicle_id_from_job(job).unwrap();
+    let shift_index = get_shift_index(&job.dimens);
+
+    is_correct_vehicle(route, vehicle_id, shift_index)
+}
+
+/// Returns vehicle specific jobs which belong to the route's vehicle shift.
+pub(crate) fn get_shift_jobs<'a, F>(
+    route: &'a Route,
+    jobs: &'a [Job],
+    is_shift_job: F,
+) -> Box<dyn Iterator<Item = Job> + 'a + Send + Sync>
+where
+    F: Fn(&Single) -> bool + 'a + Send + Sync,
+{
+    let shift_index = get_shift_index(&route.actor.vehicle.dimens);
+    let vehicle_id = route.actor.vehicle.dimens.get_id().unwrap();
+
+    Box::new(
+        jobs.iter()
+            .filter(move |job| match job {
+                Job::Single(job) => {
+                    is_shift_job(job)
+                        && get_shift_index(&job.dimens) == shift_index
+                        && get_vehicle_id_from_job(job).unwrap() == vehicle_id
+                }
+                _ => false,
+            })
+            .cloned(),
+    )
+}
@@ -0,0 +1,495 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/rests_test.rs"]
+mod rests_test;
+
+use crate::constraints::driving_hours::{drive, DrivingState};
+use crate::constraints::{DrivingHoursPolicy, DrivingRest, REST_KEY};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::*;
+use vrp_core::models::problem::{estimate_lateness, ActivityCost, Job, TransportCost, TravelTime, VehicleLink};
+use vrp_core::models::solution::{Activity, Route, TourLink};
+
+/// Keeps driving state at the activity together with time when vehicle is ready to leave it.
+#[derive(Clone)]
+struct RestCheckpoint {
+    state: DrivingState,
+    ready: Timestamp,
+    /// Continuous driving which can be added at the activity without extra break in the rest of the route.
+    continuous_slack: Duration,
+    /// Daily driving or duty which can be added at the activity without extra daily rest in the rest of the route.
+    daily_slack: Duration,
+}
+
+/// A module which schedules vehicle rests: a break after continuous driving and a daily rest after
+/// daily driving or duty limit is reached, both required by driving hours policy, and off duty time
+/// between linked shifts. When vehicle cannot reach the next activity and serve it before the shift
+/// ends, it spends link time where it stopped or at the link location and continues the tour on the
+/// next shift.
+/// NOTE: it is assumed that a driver is rested at the beginning of the shift and waiting time
+/// is not considered as a rest.
+/// NOTE: the module tightens latest arrival times of transport module taking into account rests,
+/// so insertion is evaluated only on legs around the inserted activity: a change in the driving
+/// state of the next activity is estimated conservatively as an extra break and daily rest. Also,
+/// activities served before a link are not moved to the next shift, they have to be reinserted.
+pub struct RestModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl RestModule {
+    /// Creates a new instance of `RestModule` with codes reported for vehicles with driving hours
+    /// policy and for vehicles with linked shifts only.
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        driving_code: i32,
+        link_code: i32,
+    ) -> Self {
+        Self {
+            state_keys: vec![REST_KEY],
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(RestHardActivityConstraint {
+                driving_code,
+                link_code,
+                activity: activity.clone(),
+                transport: transport.clone(),
+            }))],
+            activity,
+            transport,
+        }
+    }
+}
+
+impl ConstraintModule for RestModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        update_route_schedule_with_rests(ctx, self.activity.as_ref(), self.transport.as_ref());
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            update_route_schedule_with_rests(route_ctx, self.activity.as_ref(), self.transport.as_ref())
+        })
+    }
+
+    fn merge(&self, source: Job, _candidate: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Returns mandatory rests of the route which has driving hours policy.
+pub fn get_driving_rests(
+    route: &Route,
+    activity: &(dyn ActivityCost + Send + Sync),
+    transport: &(dyn TransportCost + Send + Sync),
+) -> Vec<DrivingRest> {
+    RestScheduler::new(route, activity, transport)
+        .map_or_else(Vec::default, |scheduler| scheduler.schedule_route().rests)
+}
+
+fn get_driving_policy(route: &Route) -> Option<&DrivingHoursPolicy> {
+    route.actor.vehicle.dimens.get_value::<DrivingHoursPolicy>("driving_hours")
+}
+
+struct RestHardActivityConstraint {
+    driving_code: i32,
+    link_code: i32,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl RestHardActivityConstraint {
+    fn fail(&self, scheduler: &RestScheduler) -> Option<ActivityConstraintViolation> {
+        let code = if scheduler.policy.is_some() { self.driving_code } else { self.link_code };
+
+        Some(ActivityConstraintViolation { code, stopped: false })
+    }
+}
+
+impl HardActivityConstraint for RestHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let route = route_ctx.route.as_ref();
+        let scheduler = RestScheduler::new(route, self.activity.as_ref(), self.transport.as_ref())?;
+        let checkpoint = route_ctx.state.get_activity_state::<RestCheckpoint>(REST_KEY, activity_ctx.prev)?;
+
+        let mut state = checkpoint.state.clone();
+        let mut ready = checkpoint.ready;
+        let mut location = activity_ctx.prev.place.location;
+
+        let mut visit = |activity: &Activity, state: &mut DrivingState, ready: &mut Timestamp| {
+            let leg = scheduler.travel(0, location, state, *ready, activity, &mut |_| {}, &mut |_| {});
+
+            if !leg.is_in_time || leg.arrival > activity.place.time.end || leg.arrival > route.actor.detail.time.end {
+                return None;
+            }
+
+            *ready = self.activity.estimate_departure(route, activity, leg.arrival);
+            state.daily_duty += *ready - leg.arrival;
+            location = activity.place.location;
+
+            Some(leg.arrival)
+        };
+
+        if visit(activity_ctx.target, &mut state, &mut ready).is_none() {
+            return self.fail(&scheduler);
+        }
+
+        let next = activity_ctx.next?;
+        let arrival = if let Some(arrival) = visit(next, &mut state, &mut ready) {
+            arrival
+        } else {
+            return self.fail(&scheduler);
+        };
+
+        let checkpoint = route_ctx.state.get_activity_state::<RestCheckpoint>(REST_KEY, next)?;
+
+        // NOTE the rest of the route is not affected when state and schedule are the same
+        if checkpoint.state == state && checkpoint.ready == ready {
+            return None;
+        }
+
+        let penalty = scheduler.policy.map_or(0., |policy| get_rest_penalty(policy, &state, ready, checkpoint));
+        let latest_arrival =
+            route_ctx.state.get_activity_state::<f64>(LATEST_ARRIVAL_KEY, next).cloned().unwrap_or(next.place.time.end);
+
+        if arrival + penalty > latest_arrival {
+            self.fail(&scheduler)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns a conservative estimation of extra rest time needed in the rest of the route when driving
+/// state at the activity is changed.
+fn get_rest_penalty(
+    policy: &DrivingHoursPolicy,
+    state: &DrivingState,
+    ready: Timestamp,
+    checkpoint: &RestCheckpoint,
+) -> Duration {
+    let old = &checkpoint.state;
+
+    // NOTE earlier departure might increase waiting time which is counted as duty
+    let extra_duty = if policy.max_daily_duty.is_some() {
+        state.daily_duty + (checkpoint.ready - ready).max(0.) - old.daily_duty
+    } else {
+        0.
+    };
+    let extra_continuous = state.continuous_driving - old.continuous_driving;
+    let extra_daily = (state.daily_driving - old.daily_driving).max(extra_duty);
+
+    let needs_break = extra_continuous > checkpoint.continuous_slack || (old.has_first_part && !state.has_first_part);
+    let needs_daily_rest = extra_daily > checkpoint.daily_slack;
+
+    let break_penalty = if needs_break { policy.break_duration } else { 0. };
+    let daily_rest_penalty = if needs_daily_rest { policy.daily_rest } else { 0. };
+
+    break_penalty + daily_rest_penalty
+}
+
+fn update_route_schedule_with_rests(
+    route_ctx: &mut RouteContext,
+    activity: &(dyn ActivityCost + Send + Sync),
+    transport: &(dyn TransportCost + Send + Sync),
+) {
+    let route_schedule = if let Some(scheduler) = RestScheduler::new(route_ctx.route.as_ref(), activity, transport) {
+        scheduler.schedule_route()
+    } else {
+        return;
+    };
+
+    let (route, state) = route_ctx.as_mut();
+
+    route.tour.all_activities_mut().zip(route_schedule.schedules).for_each(|(activity, schedule)| {
+        activity.schedule = schedule;
+    });
+    route.tour.set_links(route_schedule.links);
+
+    route.tour.all_activities().zip(route_schedule.checkpoints).for_each(|(activity, checkpoint)| {
+        state.put_activity_state(REST_KEY, activity, checkpoint);
+    });
+
+    // NOTE tighten latest arrival times estimated by transport module taking into account rests on the way
+    let actor = route.actor.as_ref();
+    let init = (
+        actor.detail.time.end,
+        actor.detail.end.as_ref().or(actor.detail.start.as_ref()).map(|place| place.location).unwrap(),
+    );
+    route.tour.all_activities().enumerate().rev().filter(|(_, act)| act.job.is_some()).fold(
+        init,
+        |(end_time, next_location), (leg_index, act)| {
+            let latest_ready = route_schedule.latest_ready.get(leg_index).cloned().flatten().unwrap_or_else(|| {
+                let rest_time = route_schedule
+                    .rests
+                    .iter()
+                    .filter(|rest| rest.leg_index == leg_index)
+                    .map(|rest| rest.time.duration())
+                    .sum::<Duration>();
+                let travel_time = TravelTime::Arrival(end_time);
+
+                end_time - transport.duration(route, act.place.location, next_location, travel_time) - rest_time
+            });
+
+            let arrival_buffer = state.get_activity_state::<f64>(ARRIVAL_BUFFER_KEY, act).cloned().unwrap_or(0.);
+            let latest_arrival =
+                activity.estimate_arrival(route, act, latest_ready).min(act.place.time.end - arrival_buffer);
+            state.put_activity_state(LATEST_ARRIVAL_KEY, act, latest_arrival);
+
+            (latest_arrival, act.place.location)
+        },
+    );
+
+    let total_duration = route.tour.end().unwrap().schedule.departure - route.tour.start().unwrap().schedule.departure;
+    let total_penalty = route
+        .tour
+        .all_activities()
+        .map(|activity| estimate_lateness(activity, activity.schedule.arrival).1)
+        .sum::<Cost>();
+
+    state.put_route_state(TOTAL_DISTANCE_KEY, route_schedule.distance);
+    state.put_route_state(TOTAL_DURATION_KEY, total_duration);
+    state.put_route_state(LATENESS_PENALTY_KEY, total_penalty);
+}
+
+/// Keeps schedule of a single leg with rests.
+struct LegSchedule {
+    /// A departure time from the leg's start location.
+    departure: Timestamp,
+    /// An arrival time to the leg's end location.
+    arrival: Timestamp,
+    /// A total distance including detours to link locations.
+    distance: Distance,
+    /// False if vehicle cannot stop before link time starts.
+    is_in_time: bool,
+    /// The latest time when vehicle can be ready to depart and still stop before the first link starts.
+    latest_ready: Option<Timestamp>,
+    /// Continuous and daily driving slacks before the first link which resets them.
+    slack: (Option<Duration>, Option<Duration>),
+}
+
+/// Keeps schedule of the whole route with rests.
+struct RouteSchedule {
+    schedules: Vec<Schedule>,
+    checkpoints: Vec<RestCheckpoint>,
+    rests: Vec<DrivingRest>,
+    links: Vec<TourLink>,
+    latest_ready: Vec<Option<Timestamp>>,
+    distance: Distance,
+}
+
+struct RestScheduler<'a> {
+    route: &'a Route,
+    policy: Option<&'a DrivingHoursPolicy>,
+    links: &'a [VehicleLink],
+    activity: &'a (dyn ActivityCost + Send + Sync),
+    transport: &'a (dyn TransportCost + Send + Sync),
+}
+
+impl<'a> RestScheduler<'a> {
+    /// Creates a new scheduler if route's actor has driving hours policy or linked shifts.
+    fn new(
+        route: &'a Route,
+        activity: &'a (dyn ActivityCost + Send + Sync),
+        transport: &'a (dyn TransportCost + Send + Sync),
+    ) -> Option<Self> {
+        let policy = get_driving_policy(route);
+        let links = route.actor.detail.links.as_slice();
+
+        if policy.is_some() || !links.is_empty() {
+            Some(Self { route, policy, links, activity, transport })
+        } else {
+            None
+        }
+    }
+
+    /// Schedules route activities taking into account rests.
+    fn schedule_route(&self) -> RouteSchedule {
+        let route = self.route;
+        let start = route.tour.start().unwrap();
+
+        let mut state = DrivingState::default();
+        let mut ready = start.schedule.departure;
+        let mut schedules = vec![start.schedule.clone()];
+        let mut checkpoints = vec![(state.clone(), ready)];
+        let mut slacks = vec![];
+        let mut rests = vec![];
+        let mut links = vec![];
+        let mut latest_ready = vec![];
+        let mut distance = 0.;
+
+        route.tour.all_activities().collect::<Vec<_>>().windows(2).enumerate().for_each(|(leg_index, leg)| {
+            let (prev, next) = match leg {
+                [prev, next] => (prev, next),
+                _ => unreachable!(),
+            };
+
+            let leg = self.travel(
+                leg_index,
+                prev.place.location,
+                &mut state,
+                ready,
+                next,
+                &mut |rest| rests.push(rest),
+                &mut |link| links.push(link),
+            );
+
+            ready = self.activity.estimate_departure(route, next, leg.arrival);
+            state.daily_duty += ready - leg.arrival;
+
+            schedules.last_mut().unwrap().departure = leg.departure;
+            schedules.push(Schedule::new(leg.arrival, ready));
+            checkpoints.push((state.clone(), ready));
+            latest_ready.push(leg.latest_ready);
+            slacks.push(leg.slack);
+            distance += leg.distance;
+        });
+
+        // NOTE driving added before the activity is carried over rests required by driving limits till
+        // the end of the route or the first link long enough to reset driving state
+        let init = self.policy.map_or((f64::MAX, f64::MAX), |policy| {
+            (policy.max_continuous_driving - state.continuous_driving, state.daily_left(policy))
+        });
+        let (mut continuous_slack, mut daily_slack) = init;
+        let mut checkpoints = checkpoints
+            .into_iter()
+            .enumerate()
+            .rev()
+            .map(|(idx, (state, ready))| {
+                if let Some((continuous, daily)) = slacks.get(idx) {
+                    continuous_slack = continuous.unwrap_or(continuous_slack);
+                    daily_slack = daily.unwrap_or(daily_slack);
+                }
+
+                RestCheckpoint { state, ready, continuous_slack, daily_slack }
+            })
+            .collect::<Vec<_>>();
+        checkpoints.reverse();
+
+        RouteSchedule { schedules, checkpoints, rests, links, latest_ready, distance }
+    }
+
+    /// Simulates travel from the location where vehicle is ready to depart to the activity taking
+    /// mandatory rests on the way and spending link time when the activity cannot be reached and
+    /// served before the shift ends.
+    #[allow(clippy::too_many_arguments)]
+    fn travel(
+        &self,
+        leg_index: usize,
+        from: Location,
+        state: &mut DrivingState,
+        ready: Timestamp,
+        target: &Activity,
+        on_rest: &mut dyn FnMut(DrivingRest),
+        on_link: &mut dyn FnMut(TourLink),
+    ) -> LegSchedule {
+        let (route, transport) = (self.route, self.transport);
+
+        let mut location = from;
+        let mut time = ready;
+        let mut departure = None;
+        let mut distance = 0.;
+        let mut is_in_time = true;
+        let mut latest_ready = None;
+        let mut slack = (None, None);
+
+        for link in self.links.iter().filter(|link| link.time.end > ready) {
+            let duration = transport.duration(route, location, target.place.location, TravelTime::Departure(time));
+            let (_, arrival) = self.drive(&mut state.clone(), time, duration, &mut |_, _| {});
+
+            if self.activity.estimate_departure(route, target, arrival) <= link.time.start {
+                break;
+            }
+
+            let place = link.location.unwrap_or(location);
+            if place != location {
+                let travel_time = TravelTime::Departure(time);
+                let duration = transport.duration(route, location, place, travel_time);
+                let is_at_stop = location == from;
+
+                distance += transport.distance(route, location, place, travel_time);
+                let (link_departure, link_arrival) = self.drive(state, time, duration, &mut |at_stop, time| {
+                    on_rest(DrivingRest { leg_index, at_stop: at_stop && is_at_stop, time })
+                });
+
+                if is_at_stop {
+                    departure = Some(link_departure);
+                }
+                time = link_arrival;
+            }
+
+            is_in_time &= time <= link.time.start;
+            latest_ready.get_or_insert(ready + link.time.start - time);
+
+            let link_end = link.time.end.max(time);
+            on_link(TourLink { leg_index, location: place, schedule: Schedule::new(time, link_end) });
+
+            if let Some(policy) = self.policy {
+                let duration = link_end - time;
+                if duration >= policy.break_duration {
+                    slack.0.get_or_insert(policy.max_continuous_driving - state.continuous_driving);
+                }
+                if duration >= policy.daily_rest {
+                    slack.1.get_or_insert(state.daily_left(policy));
+                }
+
+                state.rest(policy, duration);
+            }
+
+            time = link_end;
+            location = place;
+        }
+
+        let travel_time = TravelTime::Departure(time);
+        let duration = transport.duration(route, location, target.place.location, travel_time);
+        let is_at_stop = location == from;
+
+        distance += transport.distance(route, location, target.place.location, travel_time);
+        let (leg_departure, arrival) = self.drive(state, time, duration, &mut |at_stop, time| {
+            on_rest(DrivingRest { leg_index, at_stop: at_stop && is_at_stop, time })
+        });
+
+        LegSchedule {
+            departure: departure.unwrap_or(leg_departure),
+            arrival,
+            distance,
+            is_in_time,
+            latest_ready,
+            slack,
+        }
+    }
+
+    /// Simulates driving taking mandatory rests if vehicle has driving hours policy.
+    fn drive(
+        &self,
+        state: &mut DrivingState,
+        ready: Timestamp,
+        duration: Duration,
+        on_rest: &mut dyn FnMut(bool, TimeWindow),
+    ) -> (Timestamp, Timestamp) {
+        match self.policy {
+            Some(policy) => drive(policy, state, ready, duration, on_rest),
+            None => (ready, ready + duration),
+        }
+    }
+}
@@ -6,9 +6,10 @@ use hashbrown::HashSet;
 use std::slice::Iter;
 use std::sync::Arc;
 use vrp_core::construction::constraints::*;
-use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
-use vrp_core::models::common::ValueDimension;
-use vrp_core::models::problem::Job;
+use vrp_core::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Cost, Dimensions, ValueDimension};
+use vrp_core::models::problem::{Actor, Job, TargetConstraint, TargetObjective};
+use vrp_core::rosomaxa::prelude::Objective;
 
 /// A job skills limitation for a vehicle.
 pub struct JobSkills {
@@ -18,6 +19,16 @@ pub struct JobSkills {
     pub one_of: Option<HashSet<String>>,
     /// Vehicle should have none of these skills defined.
     pub none_of: Option<HashSet<String>>,
+    /// Vehicle or driver should preferably have at least one of these skills defined.
+    pub preferred: Option<JobPreferredSkills>,
+}
+
+/// Skills which are preferred, but not required to serve the job.
+pub struct JobPreferredSkills {
+    /// Vehicle or driver should have at least one of these skills defined.
+    pub one_of: HashSet<String>,
+    /// A penalty added when job is served by actor without preferred skills.
+    pub weight: Cost,
 }
 
 /// A skills module provides way to control jobs/vehicle assignment.
@@ -83,6 +94,70 @@ impl ConstraintModule for SkillsModule {
     }
 }
 
+/// A preferred skills module penalizes assignment of jobs to actors without preferred skills.
+pub struct PreferredSkillsModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl PreferredSkillsModule {
+    /// Creates a soft constraint and an objective to minimize total penalty of jobs served by actors
+    /// without preferred skills.
+    pub fn new_objective() -> (TargetConstraint, TargetObjective) {
+        let module = Self {
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(PreferredSkillsSoftRouteConstraint {}))],
+            keys: vec![],
+        };
+
+        (Arc::new(module), Arc::new(PreferredSkillsObjective {}))
+    }
+}
+
+impl ConstraintModule for PreferredSkillsModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _candidate: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct PreferredSkillsSoftRouteConstraint {}
+
+impl SoftRouteConstraint for PreferredSkillsSoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Cost {
+        get_preference_penalty(ctx.route.actor.as_ref(), job)
+    }
+}
+
+struct PreferredSkillsObjective {}
+
+impl Objective for PreferredSkillsObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| {
+                route_ctx.route.tour.jobs().map(move |job| get_preference_penalty(route_ctx.route.actor.as_ref(), &job))
+            })
+            .sum()
+    }
+}
+
 struct SkillsHardRouteConstraint {
     code: i32,
 }
@@ -90,7 +165,16 @@ struct SkillsHardRouteConstraint {
 impl HardRouteConstraint for SkillsHardRouteConstraint {
     fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
         if let Some(job_skills) = get_skills(job) {
-            let vehicle_skills = ctx.route.actor.vehicle.dimens.get_value::<HashSet<String>>("skills");
+            let actor = ctx.route.actor.as_ref();
+            let driver_skills = actor.driver.dimens.get_value::<HashSet<String>>("skills");
+            let vehicle_skills = actor.vehicle.dimens.get_value::<HashSet<String>>("skills");
+
+            // NOTE driver skills extend vehicle skills
+            let actor_skills = driver_skills.map(|driver_skills| {
+                vehicle_skills.iter().flat_map(|skills| skills.iter()).chain(driver_skills.iter()).cloned().collect()
+            });
+            let vehicle_skills = actor_skills.as_ref().or(vehicle_skills);
+
             let is_ok = check_all_of(job_skills, &vehicle_skills)
                 && check_one_of(job_skills, &vehicle_skills)
                 && check_none_of(job_skills, &vehicle_skills);
@@ -128,6 +212,25 @@ fn check_none_of(job_skills: &JobSkills, vehicle_skills: &Option<&HashSet<String
     }
 }
 
+/// Returns a penalty of serving the job by given actor.
+fn get_preference_penalty(actor: &Actor, job: &Job) -> Cost {
+    let preferred = match get_skills(job).and_then(|skills| skills.preferred.as_ref()) {
+        Some(preferred) => preferred,
+        None => return Cost::default(),
+    };
+
+    // NOTE driver skills extend vehicle skills
+    let has_skill = |dimens: &Dimensions| {
+        dimens.get_value::<HashSet<String>>("skills").map_or(false, |skills| !skills.is_disjoint(&preferred.one_of))
+    };
+
+    if has_skill(&actor.vehicle.dimens) || has_skill(&actor.driver.dimens) {
+        Cost::default()
+    } else {
+        preferred.weight
+    }
+}
+
 fn get_skills(job: &Job) -> Option<&JobSkills> {
     job.dimens().get_value::<JobSkills>("skills")
 }
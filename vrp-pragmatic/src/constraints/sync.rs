@@ -0,0 +1,423 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/sync_test.rs"]
+mod sync_test;
+
+use crate::extensions::get_actor_service_duration;
+use hashbrown::{HashMap, HashSet};
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Duration, Location, TimeWindow, Timestamp, ValueDimension};
+use vrp_core::models::problem::{ActivityCost, Job, Single, TransportCost, TravelTime};
+use vrp_core::models::solution::Activity;
+use vrp_core::prelude::compare_floats;
+
+/// Specifies synchronization rules of job tasks.
+#[derive(Clone, Debug)]
+pub struct SyncPolicy {
+    /// Amount of job tasks which have to be served simultaneously.
+    pub size: usize,
+    /// Maximum difference between service start times of job tasks.
+    pub max_offset: Duration,
+}
+
+/// Specifies a task of synchronized job.
+#[derive(Clone, Debug)]
+pub struct JobSync {
+    /// Id of the synchronized job.
+    pub job_id: String,
+    /// Index of the task.
+    pub index: usize,
+    /// Synchronization policy.
+    pub policy: Arc<SyncPolicy>,
+}
+
+/// Keeps service start times of assigned tasks per synchronized job id.
+type SyncTimes = HashMap<String, Vec<(usize, Timestamp)>>;
+
+/// A sync module ensures that tasks of synchronized job are served by different vehicles and
+/// their service start times differ not more than allowed offset. To keep tasks synchronized,
+/// time windows of assigned tasks are narrowed, so vehicles wait for each other, if necessary.
+/// All tasks of the job are either assigned or unassigned.
+pub struct SyncModule {
+    code: i32,
+    state_key: i32,
+    keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl SyncModule {
+    /// Creates a new instance of `SyncModule`.
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        code: i32,
+        state_key: i32,
+    ) -> Self {
+        Self {
+            code,
+            state_key,
+            keys: vec![state_key],
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(SyncHardRouteConstraint {
+                    code,
+                    state_key,
+                    transport: transport.clone(),
+                })),
+                ConstraintVariant::HardActivity(Arc::new(SyncHardActivityConstraint {
+                    code,
+                    state_key,
+                    transport: transport.clone(),
+                })),
+            ],
+            activity,
+            transport,
+        }
+    }
+
+    /// Narrows time windows of assigned tasks, so they start within allowed offset from each other.
+    fn synchronize(&self, ctx: &mut SolutionContext) {
+        let times = get_sync_times(ctx);
+
+        ctx.routes.iter_mut().for_each(|route_ctx| {
+            let start = route_ctx.route.tour.start().map_or(0., |start| start.schedule.departure);
+            let windows = route_ctx
+                .route
+                .tour
+                .all_activities()
+                .enumerate()
+                .filter_map(|(idx, activity)| get_activity_sync(activity).map(|sync| (idx, activity, sync)))
+                .filter_map(|(idx, activity, sync)| {
+                    let original = get_original_time(activity, start)?;
+                    let window = times
+                        .get(&sync.job_id)
+                        .and_then(|starts| get_sync_window(starts.as_slice(), sync.policy.max_offset))
+                        .and_then(|window| window.overlapping(&original))
+                        .unwrap_or(original);
+
+                    if window != activity.place.time {
+                        Some((idx, window))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            if !windows.is_empty() {
+                windows.into_iter().for_each(|(idx, window)| {
+                    route_ctx.route_mut().tour.get_mut(idx).unwrap().place.time = window;
+                });
+                update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref());
+            }
+        });
+    }
+
+    /// Removes tasks of jobs which are assigned partially or cannot be synchronized.
+    fn remove_unsynchronized(&self, ctx: &mut SolutionContext) {
+        let times = get_sync_times(ctx);
+        let invalid = ctx
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route.tour.all_activities())
+            .filter_map(get_activity_sync)
+            .filter(|sync| {
+                times.get(&sync.job_id).map_or(true, |starts| {
+                    starts.len() != sync.policy.size || !is_synchronized(starts.as_slice(), sync.policy.max_offset)
+                })
+            })
+            .map(|sync| sync.job_id.clone())
+            .collect::<HashSet<_>>();
+
+        if invalid.is_empty() {
+            return;
+        }
+
+        let mut removed = Vec::new();
+        ctx.routes.iter_mut().for_each(|route_ctx| {
+            let jobs = route_ctx
+                .route
+                .tour
+                .jobs()
+                .filter(|job| get_job_sync(job).map_or(false, |sync| invalid.contains(&sync.job_id)))
+                .collect::<Vec<_>>();
+
+            if !jobs.is_empty() {
+                jobs.iter().for_each(|job| {
+                    route_ctx.route_mut().tour.remove(job);
+                });
+                update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref());
+                removed.extend(jobs);
+            }
+        });
+
+        // NOTE keep tasks together: they are either inserted or unassigned
+        removed.into_iter().for_each(|job| {
+            let job_id = get_job_sync(&job).map(|sync| sync.job_id.clone());
+            let is_required =
+                ctx.required.iter().any(|other| get_job_sync(other).map(|sync| &sync.job_id) == job_id.as_ref());
+
+            if is_required {
+                ctx.required.push(job);
+            } else {
+                ctx.unassigned.insert(job, self.code);
+            }
+        });
+
+        let registry = &mut ctx.registry;
+        ctx.routes.retain(|route_ctx| {
+            if route_ctx.route.tour.has_jobs() {
+                true
+            } else {
+                registry.free_route(route_ctx);
+                false
+            }
+        });
+    }
+
+    fn update_sync_times(&self, ctx: &mut SolutionContext, is_forced: bool) {
+        let times = get_sync_times(ctx);
+        let is_changed = ctx
+            .state
+            .get(&self.state_key)
+            .and_then(|state| state.downcast_ref::<SyncTimes>())
+            .map_or(true, |previous| *previous != times);
+
+        let times = Arc::new(times);
+        ctx.state.insert(self.state_key, times.clone());
+        ctx.routes
+            .iter_mut()
+            .filter(|route_ctx| {
+                // NOTE route state is wiped out when route is changed, so it has to be restored
+                is_changed || is_forced || route_ctx.state.get_route_state::<SyncTimes>(self.state_key).is_none()
+            })
+            .for_each(|route_ctx| {
+                route_ctx.state_mut().put_route_state_raw(self.state_key, times.clone());
+            });
+    }
+}
+
+impl ConstraintModule for SyncModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {
+        self.synchronize(solution_ctx);
+        self.update_sync_times(solution_ctx, false);
+    }
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        self.remove_unsynchronized(ctx);
+        self.synchronize(ctx);
+        self.update_sync_times(ctx, true);
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        match (get_job_sync(&source), get_job_sync(&candidate)) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct SyncHardRouteConstraint {
+    code: i32,
+    state_key: i32,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardRouteConstraint for SyncHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        let sync = get_job_sync(job)?;
+
+        let has_other_task = route_ctx.route.tour.jobs().any(|job| {
+            get_job_sync(&job).map_or(false, |other| other.job_id == sync.job_id && other.index != sync.index)
+        });
+        if has_other_task {
+            return Some(RouteConstraintViolation { code: self.code });
+        }
+
+        // NOTE route with sync times is checked precisely on activity level
+        if route_ctx.state.get_route_state::<SyncTimes>(self.state_key).is_some() {
+            return None;
+        }
+
+        let starts = solution_ctx
+            .state
+            .get(&self.state_key)
+            .and_then(|state| state.downcast_ref::<SyncTimes>())
+            .and_then(|times| times.get(&sync.job_id))
+            .map(|starts| get_other_starts(starts.as_slice(), sync))
+            .filter(|starts| !starts.is_empty())?;
+
+        let is_feasible = !route_ctx.route.tour.has_jobs() && {
+            let tour = &route_ctx.route.tour;
+            let (start, end) = (tour.start().unwrap(), tour.end());
+            let departure = start.schedule.departure;
+
+            job.to_single().places.iter().any(|place| {
+                let location = place.location.unwrap_or(start.place.location);
+                place.times.iter().map(|time| time.to_time_window(departure)).any(|time| {
+                    let place = (location, place.duration, &time);
+                    can_start_at(route_ctx, self.transport.as_ref(), starts.as_slice(), sync, (start, end), place)
+                })
+            })
+        };
+
+        if is_feasible {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+struct SyncHardActivityConstraint {
+    code: i32,
+    state_key: i32,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for SyncHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let sync = get_activity_sync(activity_ctx.target)?;
+        // NOTE route without sync times is checked by route constraint
+        let times = route_ctx.state.get_route_state::<SyncTimes>(self.state_key)?;
+        let starts = times.get(&sync.job_id).map(|starts| get_other_starts(starts.as_slice(), sync))?;
+
+        if starts.is_empty() {
+            return None;
+        }
+
+        let target = activity_ctx.target;
+        let duration = get_actor_service_duration(route_ctx.route.actor.as_ref(), target);
+        let place = (target.place.location, duration, &target.place.time);
+        let activities = (activity_ctx.prev, activity_ctx.next);
+
+        if can_start_at(route_ctx, self.transport.as_ref(), starts.as_slice(), sync, activities, place) {
+            None
+        } else {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        }
+    }
+}
+
+/// Checks whether given place can be served between given activities in sync with other tasks,
+/// probably, with waiting for them.
+fn can_start_at(
+    route_ctx: &RouteContext,
+    transport: &(dyn TransportCost + Send + Sync),
+    starts: &[Timestamp],
+    sync: &JobSync,
+    activities: (&Activity, Option<&Activity>),
+    place: (Location, Duration, &TimeWindow),
+) -> bool {
+    let route = route_ctx.route.as_ref();
+    let (prev, next) = activities;
+    let (location, duration, time) = place;
+    let max_offset = sync.policy.max_offset;
+
+    let earliest = starts.iter().cloned().fold(f64::MIN, f64::max) - max_offset;
+    let latest = starts.iter().cloned().fold(f64::MAX, f64::min) + max_offset;
+
+    let departure = prev.schedule.departure;
+    let arrival =
+        departure + transport.duration(route, prev.place.location, location, TravelTime::Departure(departure));
+    let service_start = arrival.max(time.start).max(earliest);
+
+    if service_start > time.end.min(latest) {
+        return false;
+    }
+
+    next.map_or(true, |next| {
+        let departure = service_start + duration;
+        let latest_arrival =
+            route_ctx.state.get_activity_state::<f64>(LATEST_ARRIVAL_KEY, next).cloned().unwrap_or(next.place.time.end);
+
+        departure + transport.duration(route, location, next.place.location, TravelTime::Departure(departure))
+            <= latest_arrival
+    })
+}
+
+/// Returns a time window where all assigned tasks can start keeping them synchronized.
+fn get_sync_window(starts: &[(usize, Timestamp)], max_offset: Duration) -> Option<TimeWindow> {
+    if starts.len() < 2 {
+        return None;
+    }
+
+    let latest_start = starts.iter().map(|(_, start)| *start).fold(f64::MIN, f64::max);
+    let earliest = latest_start - max_offset;
+    // NOTE early tasks wait till the earliest allowed start
+    let latest = starts.iter().map(|(_, start)| start.max(earliest)).fold(f64::MAX, f64::min) + max_offset;
+
+    Some(TimeWindow::new(earliest, latest))
+}
+
+fn is_synchronized(starts: &[(usize, Timestamp)], max_offset: Duration) -> bool {
+    let (min, max) =
+        starts.iter().fold((f64::MAX, f64::MIN), |(min, max), (_, start)| (min.min(*start), max.max(*start)));
+
+    compare_floats(max - min, max_offset) != Ordering::Greater
+}
+
+/// Returns original time window of the task which contains its current time window.
+fn get_original_time(activity: &Activity, departure: Timestamp) -> Option<TimeWindow> {
+    let single = activity.job.as_ref()?;
+    let current = &activity.place.time;
+
+    single
+        .places
+        .iter()
+        .filter(|place| place.location.map_or(true, |location| location == activity.place.location))
+        .flat_map(|place| place.times.iter().map(|time| time.to_time_window(departure)))
+        .find(|time| time.start <= current.start && current.end <= time.end)
+}
+
+fn get_other_starts(starts: &[(usize, Timestamp)], sync: &JobSync) -> Vec<Timestamp> {
+    starts.iter().filter(|(index, _)| *index != sync.index).map(|(_, start)| *start).collect()
+}
+
+fn get_sync_times(ctx: &SolutionContext) -> SyncTimes {
+    ctx.routes.iter().flat_map(|route_ctx| route_ctx.route.tour.all_activities()).fold(
+        SyncTimes::new(),
+        |mut acc, activity| {
+            if let Some(sync) = get_activity_sync(activity) {
+                let start = activity.schedule.arrival.max(activity.place.time.start);
+                acc.entry(sync.job_id.clone()).or_insert_with(Vec::new).push((sync.index, start));
+            }
+
+            acc
+        },
+    )
+}
+
+fn get_job_sync(job: &Job) -> Option<&JobSync> {
+    job.dimens().get_value::<JobSync>("sync")
+}
+
+fn get_activity_sync(activity: &Activity) -> Option<&JobSync> {
+    activity.job.as_ref().and_then(|single| get_single_sync(single))
+}
+
+fn get_single_sync(single: &Single) -> Option<&JobSync> {
+    single.dimens.get_value::<JobSync>("sync")
+}
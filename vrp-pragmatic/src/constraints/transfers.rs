@@ -0,0 +1,601 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/transfers_test.rs"]
+mod transfers_test;
+
+use crate::extensions::get_actor_service_duration;
+use hashbrown::{HashMap, HashSet};
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Duration, Location, TimeWindow, Timestamp, ValueDimension};
+use vrp_core::models::problem::{ActivityCost, Job, Single, TransportCost, TravelTime};
+use vrp_core::models::solution::Activity;
+use vrp_core::prelude::compare_floats;
+
+/// Specifies an echelon of two-echelon job part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferEchelon {
+    /// A part served by the vehicle which brings goods to the hub.
+    First,
+    /// A part served by the vehicle which takes goods at the hub and delivers them to the job.
+    Second,
+}
+
+/// Specifies a transfer hub where goods are moved between vehicles of two echelons.
+#[derive(Clone, Debug)]
+pub struct TransferPolicy {
+    /// Id of the hub.
+    pub hub_id: String,
+    /// Vehicle type ids of the first echelon.
+    pub first_echelon: HashSet<String>,
+    /// Vehicle type ids of the second echelon.
+    pub second_echelon: HashSet<String>,
+}
+
+/// Specifies a part of two-echelon job.
+#[derive(Clone, Debug)]
+pub struct JobTransfer {
+    /// Id of the two-echelon job.
+    pub job_id: String,
+    /// An echelon of the part.
+    pub echelon: TransferEchelon,
+    /// Transfer policy of the hub.
+    pub policy: Arc<TransferPolicy>,
+}
+
+/// Keeps time when goods are available at the hub and the latest feasible departure time of second
+/// echelon route per job id.
+type TransferTimes = HashMap<String, (Option<Timestamp>, Option<Timestamp>)>;
+
+/// A transfer module synchronizes tours of two echelons: the first echelon vehicle brings goods of
+/// the job to the hub and the second echelon vehicle, which starts at the hub, delivers them to the
+/// job location. The second echelon route cannot depart before goods of all its jobs are available,
+/// so its departure is delayed, while the first echelon vehicle has to finish transfer not later than
+/// the latest feasible departure of the second echelon route. Both parts of the job are either assigned
+/// or unassigned.
+///
+/// Transfer times are kept in route state: additionally, the latest arrival which keeps transfers in time
+/// is tracked for activities of the first echelon routes using the same state key.
+pub struct TransferModule {
+    code: i32,
+    state_key: i32,
+    keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl TransferModule {
+    /// Creates a new instance of `TransferModule`.
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        code: i32,
+        state_key: i32,
+    ) -> Self {
+        Self {
+            code,
+            state_key,
+            keys: vec![state_key],
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(TransferHardRouteConstraint {
+                    code,
+                    state_key,
+                    transport: transport.clone(),
+                })),
+                ConstraintVariant::HardActivity(Arc::new(TransferHardActivityConstraint {
+                    code,
+                    state_key,
+                    activity: activity.clone(),
+                    transport: transport.clone(),
+                })),
+            ],
+            activity,
+            transport,
+        }
+    }
+
+    /// Delays departure of second echelon routes till goods of their jobs are available. The delay is
+    /// feasible as hard constraints do not allow goods to be ready after the latest departure of the route.
+    fn synchronize(&self, ctx: &mut SolutionContext) {
+        let times = get_transfer_times(ctx, self.transport.as_ref());
+        ctx.routes.iter_mut().for_each(|route_ctx| {
+            let ready = get_route_transfers(route_ctx, TransferEchelon::Second)
+                .filter_map(|(_, transfer)| times.get(&transfer.job_id).and_then(|(ready, _)| *ready))
+                .max_by(|a, b| compare_floats(*a, *b));
+            let departure = route_ctx.route.tour.start().map(|start| start.schedule.departure);
+
+            if let Some((ready, _)) = ready.zip(departure).filter(|(ready, departure)| ready > departure) {
+                route_ctx.route_mut().tour.get_mut(0).unwrap().schedule.departure = ready;
+                update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref());
+            }
+        });
+    }
+
+    /// Updates the latest arrival of activities of the first echelon route which keeps its transfers
+    /// not later than the latest departure of the corresponding second echelon routes.
+    fn update_latest_arrivals(&self, route_ctx: &mut RouteContext, times: &TransferTimes) {
+        let route = route_ctx.route.clone();
+        let actor = route.actor.as_ref();
+        let (route_mut, state) = route_ctx.as_mut();
+
+        // NOTE activity state is kept for all activities as insertion shifts them within the tour
+        route_mut.tour.all_activities().rev().fold(None, |next: Option<(Location, Timestamp)>, activity| {
+            let latest = next.map_or(f64::MAX, |(next_location, latest_next)| {
+                if latest_next == f64::MAX {
+                    return f64::MAX;
+                }
+
+                let latest_departure = latest_next
+                    - self.transport.duration(
+                        route.as_ref(),
+                        activity.place.location,
+                        next_location,
+                        TravelTime::Arrival(latest_next),
+                    );
+
+                self.activity.estimate_arrival(route.as_ref(), activity, latest_departure)
+            });
+
+            let latest = get_activity_transfer(activity)
+                .filter(|transfer| transfer.echelon == TransferEchelon::First)
+                .and_then(|transfer| times.get(&transfer.job_id))
+                .and_then(|(_, deadline)| *deadline)
+                .map_or(latest, |deadline| latest.min(deadline - get_actor_service_duration(actor, activity)));
+
+            state.put_activity_state(self.state_key, activity, latest);
+
+            Some((activity.place.location, latest))
+        });
+    }
+
+    /// Removes job parts which are assigned without their counterpart from another echelon.
+    fn remove_orphans(&self, ctx: &mut SolutionContext) {
+        let assigned = ctx
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route.tour.all_activities())
+            .filter_map(get_activity_transfer)
+            .fold(HashMap::<String, (bool, bool)>::new(), |mut acc, transfer| {
+                let entry = acc.entry(transfer.job_id.clone()).or_insert((false, false));
+                match transfer.echelon {
+                    TransferEchelon::First => entry.0 = true,
+                    TransferEchelon::Second => entry.1 = true,
+                }
+
+                acc
+            });
+
+        let orphans = assigned
+            .into_iter()
+            .filter(|(_, (has_first, has_second))| has_first != has_second)
+            .map(|(job_id, _)| job_id)
+            .collect::<HashSet<_>>();
+
+        if orphans.is_empty() {
+            return;
+        }
+
+        let mut removed = Vec::new();
+        ctx.routes.iter_mut().for_each(|route_ctx| {
+            let jobs = route_ctx
+                .route
+                .tour
+                .jobs()
+                .filter(|job| get_job_transfer(job).map_or(false, |transfer| orphans.contains(&transfer.job_id)))
+                .collect::<Vec<_>>();
+
+            if !jobs.is_empty() {
+                jobs.iter().for_each(|job| {
+                    route_ctx.route_mut().tour.remove(job);
+                });
+                update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref());
+                removed.extend(jobs);
+            }
+        });
+
+        // NOTE keep the part together with its counterpart: both are either inserted or unassigned
+        removed.into_iter().for_each(|job| {
+            let job_id = get_job_transfer(&job).map(|transfer| transfer.job_id.clone());
+            let is_required =
+                ctx.required.iter().any(|other| get_job_transfer(other).map(|t| &t.job_id) == job_id.as_ref());
+
+            if is_required {
+                ctx.required.push(job);
+            } else {
+                ctx.unassigned.insert(job, self.code);
+            }
+        });
+
+        let registry = &mut ctx.registry;
+        ctx.routes.retain(|route_ctx| {
+            if route_ctx.route.tour.has_jobs() {
+                true
+            } else {
+                registry.free_route(route_ctx);
+                false
+            }
+        });
+    }
+
+    fn update_transfer_times(&self, ctx: &mut SolutionContext, is_forced: bool) {
+        let times = get_transfer_times(ctx, self.transport.as_ref());
+        let is_changed = ctx
+            .state
+            .get(&self.state_key)
+            .and_then(|state| state.downcast_ref::<TransferTimes>())
+            .map_or(true, |previous| *previous != times);
+
+        let times = Arc::new(times);
+        ctx.state.insert(self.state_key, times.clone());
+        ctx.routes.iter_mut().for_each(|route_ctx| {
+            // NOTE route state is wiped out when route is changed, so it has to be restored
+            if is_changed || is_forced || route_ctx.state.get_route_state::<TransferTimes>(self.state_key).is_none() {
+                route_ctx.state_mut().put_route_state_raw(self.state_key, times.clone());
+            }
+
+            // NOTE any insertion can change latest arrivals of the first echelon route
+            self.update_latest_arrivals(route_ctx, times.as_ref());
+        });
+    }
+}
+
+impl ConstraintModule for TransferModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {
+        // NOTE any insertion can shift transfer time of the first echelon route or the latest departure
+        // of the second echelon route
+        self.synchronize(solution_ctx);
+        self.update_transfer_times(solution_ctx, false);
+    }
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        self.remove_orphans(ctx);
+        self.synchronize(ctx);
+        self.update_transfer_times(ctx, true);
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        match (get_job_transfer(&source), get_job_transfer(&candidate)) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct TransferHardRouteConstraint {
+    code: i32,
+    state_key: i32,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardRouteConstraint for TransferHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        let transfer = get_job_transfer(job)?;
+
+        let type_id = route_ctx.route.actor.vehicle.dimens.get_value::<String>("type_id");
+        let types = match transfer.echelon {
+            TransferEchelon::First => &transfer.policy.first_echelon,
+            TransferEchelon::Second => &transfer.policy.second_echelon,
+        };
+        if !type_id.map_or(false, |type_id| types.contains(type_id)) {
+            return Some(RouteConstraintViolation { code: self.code });
+        }
+
+        // NOTE route with transfer times is checked precisely on activity level
+        if route_ctx.state.get_route_state::<TransferTimes>(self.state_key).is_some() {
+            return None;
+        }
+
+        let (ready, deadline) = solution_ctx
+            .state
+            .get(&self.state_key)
+            .and_then(|state| state.downcast_ref::<TransferTimes>())
+            .and_then(|times| times.get(&transfer.job_id))?;
+
+        let is_feasible = match (transfer.echelon, ready, deadline) {
+            (TransferEchelon::First, _, Some(deadline)) => {
+                !route_ctx.route.tour.has_jobs() && self.can_transfer_in_empty_route(route_ctx, job, *deadline)
+            }
+            (TransferEchelon::Second, Some(ready), _) => {
+                !route_ctx.route.tour.has_jobs() && self.can_deliver_in_empty_route(route_ctx, job, *ready)
+            }
+            _ => true,
+        };
+
+        if is_feasible {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+impl TransferHardRouteConstraint {
+    fn can_transfer_in_empty_route(&self, route_ctx: &RouteContext, job: &Job, deadline: Timestamp) -> bool {
+        let start = route_ctx.route.tour.start().unwrap();
+
+        get_job_places(route_ctx, job).all(|(location, duration, time)| {
+            get_transfer_departure(route_ctx, self.transport.as_ref(), start, (location, duration, &time)) <= deadline
+        })
+    }
+
+    fn can_deliver_in_empty_route(&self, route_ctx: &RouteContext, job: &Job, ready: Timestamp) -> bool {
+        let tour = &route_ctx.route.tour;
+        let start = tour.start().unwrap();
+
+        get_job_places(route_ctx, job).all(|(location, duration, time)| {
+            can_depart_at(
+                route_ctx,
+                self.transport.as_ref(),
+                ready,
+                (0, start, tour.end()),
+                (location, duration, &time),
+            )
+        })
+    }
+}
+
+struct TransferHardActivityConstraint {
+    code: i32,
+    state_key: i32,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for TransferHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let transfer = get_activity_transfer(activity_ctx.target);
+
+        let times = match (route_ctx.state.get_route_state::<TransferTimes>(self.state_key), transfer) {
+            (Some(times), _) => times,
+            // NOTE route without transfer times is checked by route constraint
+            (None, Some(_)) => return None,
+            // NOTE route state is not restored yet (e.g. route copy used to evaluate multi job insertion),
+            // so do not allow to shift transfers of the first echelon route
+            (None, None) => {
+                let has_transfers_after =
+                    get_route_transfers(route_ctx, TransferEchelon::First).any(|(idx, _)| idx > activity_ctx.index);
+
+                return if has_transfers_after { self.violation() } else { None };
+            }
+        };
+
+        let route = route_ctx.route.as_ref();
+        let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+        let transport = self.transport.as_ref();
+
+        let arrival = prev.schedule.departure
+            + transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(prev.schedule.departure),
+            );
+        let departure = self.activity.estimate_departure(route, target, arrival);
+
+        let is_transfer_feasible = match transfer.and_then(|transfer| Some((transfer, times.get(&transfer.job_id)?))) {
+            Some((transfer, (_, Some(deadline)))) if transfer.echelon == TransferEchelon::First => {
+                departure <= *deadline
+            }
+            Some((transfer, (Some(ready), _))) if transfer.echelon == TransferEchelon::Second => {
+                let duration = get_actor_service_duration(route.actor.as_ref(), target);
+                let place = (target.place.location, duration, &target.place.time);
+                can_depart_at(route_ctx, transport, *ready, (activity_ctx.index, prev, next), place)
+            }
+            _ => true,
+        };
+
+        let is_next_feasible = || {
+            next.and_then(|next| {
+                route_ctx.state.get_activity_state::<Timestamp>(self.state_key, next).map(|latest| (next, *latest))
+            })
+            .map_or(true, |(next, latest)| {
+                departure
+                    + transport.duration(
+                        route,
+                        target.place.location,
+                        next.place.location,
+                        TravelTime::Departure(departure),
+                    )
+                    <= latest
+            })
+        };
+
+        if is_transfer_feasible && is_next_feasible() {
+            None
+        } else {
+            self.violation()
+        }
+    }
+}
+
+impl TransferHardActivityConstraint {
+    fn violation(&self) -> Option<ActivityConstraintViolation> {
+        Some(ActivityConstraintViolation { code: self.code, stopped: false })
+    }
+}
+
+/// Returns departure time from the hub when transfer is done right after given activity.
+fn get_transfer_departure(
+    route_ctx: &RouteContext,
+    transport: &(dyn TransportCost + Send + Sync),
+    prev: &Activity,
+    place: (Location, Duration, &TimeWindow),
+) -> Timestamp {
+    let (location, duration, time) = place;
+    let prev_departure = prev.schedule.departure;
+    let travel_time =
+        transport.duration(&route_ctx.route, prev.place.location, location, TravelTime::Departure(prev_departure));
+
+    (prev_departure + travel_time).max(time.start) + duration
+}
+
+/// Checks whether route can depart at given time, which is not earlier than its current departure,
+/// when given place is inserted between given activities. As existing activities can be shifted at most
+/// by the delay, feasibility of the route is checked using their latest arrival time.
+pub(crate) fn can_depart_at(
+    route_ctx: &RouteContext,
+    transport: &(dyn TransportCost + Send + Sync),
+    ready: Timestamp,
+    activities: (usize, &Activity, Option<&Activity>),
+    place: (Location, Duration, &TimeWindow),
+) -> bool {
+    let route = route_ctx.route.as_ref();
+    let (index, prev, next) = activities;
+    let (location, duration, time) = place;
+
+    let start = match route.tour.start() {
+        Some(start) => start,
+        None => return false,
+    };
+
+    let delay = ready - start.schedule.departure;
+    if compare_floats(delay, 0.) != Ordering::Greater {
+        return true;
+    }
+
+    let latest_start = route.actor.detail.start.as_ref().and_then(|start| start.time.latest).unwrap_or(f64::MAX);
+    if ready > latest_start {
+        return false;
+    }
+
+    let latest_arrival = |activity: &Activity| {
+        route_ctx
+            .state
+            .get_activity_state::<f64>(LATEST_ARRIVAL_KEY, activity)
+            .cloned()
+            .unwrap_or(activity.place.time.end)
+    };
+    let get_arrival = |from: Location, to: Location, departure: Timestamp| {
+        departure + transport.duration(route, from, to, TravelTime::Departure(departure))
+    };
+
+    let prev_departure = if index == 0 {
+        ready
+    } else {
+        let first = route.tour.get(1).unwrap();
+        if get_arrival(start.place.location, first.place.location, ready) > latest_arrival(first) {
+            return false;
+        }
+
+        prev.schedule.departure + delay
+    };
+
+    let arrival = get_arrival(prev.place.location, location, prev_departure);
+    if arrival > time.end {
+        return false;
+    }
+
+    let departure = arrival.max(time.start) + duration;
+
+    next.map_or(true, |next| get_arrival(location, next.place.location, departure) <= latest_arrival(next))
+}
+
+/// Returns all places of the job with their time windows.
+pub(crate) fn get_job_places<'a>(
+    route_ctx: &'a RouteContext,
+    job: &'a Job,
+) -> impl Iterator<Item = (Location, Duration, TimeWindow)> + 'a {
+    let start = route_ctx.route.tour.start().unwrap();
+
+    job.to_single().places.iter().flat_map(move |place| {
+        let location = place.location.unwrap_or(start.place.location);
+        let times = if place.times.is_empty() {
+            vec![TimeWindow::max()]
+        } else {
+            place.times.iter().map(|time| time.to_time_window(start.schedule.departure)).collect()
+        };
+
+        times.into_iter().map(move |time| (location, place.duration, time))
+    })
+}
+
+/// Returns transfers of given echelon with their activity indices.
+fn get_route_transfers(
+    route_ctx: &RouteContext,
+    echelon: TransferEchelon,
+) -> impl Iterator<Item = (usize, JobTransfer)> + '_ {
+    route_ctx.route.tour.all_activities().enumerate().filter_map(move |(idx, activity)| {
+        get_activity_transfer(activity).filter(|transfer| transfer.echelon == echelon).map(|t| (idx, t.clone()))
+    })
+}
+
+fn get_transfer_times(ctx: &SolutionContext, transport: &(dyn TransportCost + Send + Sync)) -> TransferTimes {
+    ctx.routes.iter().fold(TransferTimes::new(), |mut acc, route_ctx| {
+        let mut transfers = route_ctx.route.tour.all_activities().filter_map(get_activity_transfer).peekable();
+        if transfers.peek().is_none() {
+            return acc;
+        }
+
+        let latest_departure = get_latest_departure(route_ctx, transport);
+
+        route_ctx.route.tour.all_activities().for_each(|activity| {
+            if let Some(transfer) = get_activity_transfer(activity) {
+                let entry = acc.entry(transfer.job_id.clone()).or_insert((None, None));
+                match transfer.echelon {
+                    TransferEchelon::First => entry.0 = Some(activity.schedule.departure),
+                    TransferEchelon::Second => entry.1 = latest_departure,
+                }
+            }
+        });
+
+        acc
+    })
+}
+
+/// Returns the latest departure time of the route which keeps its schedule feasible.
+pub(crate) fn get_latest_departure(
+    route_ctx: &RouteContext,
+    transport: &(dyn TransportCost + Send + Sync),
+) -> Option<Timestamp> {
+    let route = route_ctx.route.as_ref();
+    let start = route.tour.start()?;
+    let latest_start = route.actor.detail.start.as_ref().and_then(|start| start.time.latest).unwrap_or(f64::MAX);
+
+    let latest_departure = route.tour.get(1).filter(|first| first.job.is_some()).map_or(f64::MAX, |first| {
+        let latest_arrival = route_ctx
+            .state
+            .get_activity_state::<f64>(LATEST_ARRIVAL_KEY, first)
+            .cloned()
+            .unwrap_or(first.place.time.end);
+
+        latest_arrival
+            - transport.duration(route, start.place.location, first.place.location, TravelTime::Arrival(latest_arrival))
+    });
+
+    Some(latest_departure.min(latest_start))
+}
+
+fn get_job_transfer(job: &Job) -> Option<&JobTransfer> {
+    job.dimens().get_value::<JobTransfer>("transfer")
+}
+
+fn get_activity_transfer(activity: &Activity) -> Option<&JobTransfer> {
+    activity.job.as_ref().and_then(|single| get_single_transfer(single))
+}
+
+/// Returns transfer of the job part.
+pub(crate) fn get_single_transfer(single: &Single) -> Option<&JobTransfer> {
+    single.dimens.get_value::<JobTransfer>("transfer")
+}
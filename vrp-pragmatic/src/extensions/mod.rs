@@ -3,6 +3,10 @@
 mod only_vehicle_activity_cost;
 pub use self::only_vehicle_activity_cost::OnlyVehicleActivityCost;
 
+mod service_duration_activity_cost;
+pub(crate) use self::service_duration_activity_cost::{get_actor_service_duration, get_service_duration};
+pub use self::service_duration_activity_cost::{ServiceDurationActivityCost, ServiceDurations};
+
 mod route_modifier;
 pub use self::route_modifier::get_route_modifier;
 
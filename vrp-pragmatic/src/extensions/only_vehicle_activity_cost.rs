@@ -1,6 +1,6 @@
 use crate::core::models::solution::Route;
 use vrp_core::models::common::{Cost, Timestamp};
-use vrp_core::models::problem::{ActivityCost, SimpleActivityCost};
+use vrp_core::models::problem::{estimate_lateness, ActivityCost, SimpleActivityCost};
 use vrp_core::models::solution::Activity;
 
 /// Uses costs only for a vehicle ignoring costs of a driver.
@@ -16,7 +16,9 @@ impl ActivityCost for OnlyVehicleActivityCost {
         let waiting = if activity.place.time.start > arrival { activity.place.time.start - arrival } else { 0.0 };
         let service = activity.place.duration;
 
-        waiting * actor.vehicle.costs.per_waiting_time + service * actor.vehicle.costs.per_service_time
+        waiting * actor.vehicle.costs.per_waiting_time
+            + service * actor.vehicle.costs.per_service_time
+            + estimate_lateness(activity, arrival).1
     }
 
     fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
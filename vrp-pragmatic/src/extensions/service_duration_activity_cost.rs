@@ -0,0 +1,83 @@
+use crate::core::models::solution::Route;
+use hashbrown::HashMap;
+use std::sync::Arc;
+use vrp_core::models::common::{Cost, Duration, Timestamp, ValueDimension};
+use vrp_core::models::problem::{ActivityCost, Actor, Single};
+use vrp_core::models::solution::{Activity, Place};
+
+/// Specifies service durations of job place which override default one for specific vehicle or driver types.
+#[derive(Clone, Debug, Default)]
+pub struct ServiceDurations {
+    /// Service durations per vehicle type id.
+    pub vehicles: HashMap<String, Duration>,
+    /// Service durations per driver type id.
+    pub drivers: HashMap<String, Duration>,
+}
+
+/// Uses actor dependent service durations of job places on top of inner activity costs.
+/// NOTE inner costs are expected to depend on service duration linearly.
+pub struct ServiceDurationActivityCost {
+    inner: Arc<dyn ActivityCost + Send + Sync>,
+}
+
+impl ServiceDurationActivityCost {
+    /// Creates a new instance of `ServiceDurationActivityCost`.
+    pub fn new(inner: Arc<dyn ActivityCost + Send + Sync>) -> Self {
+        Self { inner }
+    }
+}
+
+impl ActivityCost for ServiceDurationActivityCost {
+    fn cost(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Cost {
+        let actor = route.actor.as_ref();
+        let extra = get_extra_duration(actor, activity);
+
+        self.inner.cost(route, activity, arrival)
+            + extra * (actor.driver.costs.per_service_time + actor.vehicle.costs.per_service_time)
+    }
+
+    fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
+        self.inner.estimate_departure(route, activity, arrival) + get_extra_duration(route.actor.as_ref(), activity)
+    }
+
+    fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
+        self.inner.estimate_arrival(route, activity, departure - get_extra_duration(route.actor.as_ref(), activity))
+    }
+}
+
+/// Returns service duration of activity for given actor.
+pub(crate) fn get_actor_service_duration(actor: &Actor, activity: &Activity) -> Duration {
+    activity.job.as_ref().map_or(activity.place.duration, |single| {
+        get_service_duration(
+            single,
+            &activity.place,
+            actor.vehicle.dimens.get_value::<String>("type_id"),
+            actor.driver.dimens.get_value::<String>("type_id"),
+        )
+    })
+}
+
+/// Returns service duration of job place for given vehicle and driver types.
+pub(crate) fn get_service_duration(
+    single: &Single,
+    place: &Place,
+    vehicle_type: Option<&String>,
+    driver_type: Option<&String>,
+) -> Duration {
+    single
+        .dimens
+        .get_value::<Vec<ServiceDurations>>("service_durations")
+        .and_then(|durations| durations.get(place.idx))
+        .and_then(|durations| {
+            driver_type
+                .and_then(|driver_type| durations.drivers.get(driver_type))
+                .or_else(|| vehicle_type.and_then(|vehicle_type| durations.vehicles.get(vehicle_type)))
+                .cloned()
+        })
+        .unwrap_or(place.duration)
+}
+
+/// Returns a difference between actor dependent and default service duration of the activity.
+fn get_extra_duration(actor: &Actor, activity: &Activity) -> Duration {
+    get_actor_service_duration(actor, activity) - activity.place.duration
+}
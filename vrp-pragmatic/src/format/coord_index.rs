@@ -30,13 +30,19 @@ impl CoordIndex {
                 });
         });
 
+        if let Some(hubs) = &problem.plan.hubs {
+            hubs.iter().for_each(|hub| index.add(&hub.location));
+        }
+
         // process fleet
         problem.fleet.vehicles.iter().for_each(|vehicle| {
             vehicle.shifts.iter().for_each(|shift| {
-                index.add(&shift.start.location);
+                if let Some(location) = &shift.start.location {
+                    index.add(location);
+                }
 
-                if let Some(end) = &shift.end {
-                    index.add(&end.location);
+                if let Some(location) = shift.end.as_ref().and_then(|end| end.location.as_ref()) {
+                    index.add(location);
                 }
 
                 if let Some(dispatch) = &shift.dispatch {
@@ -58,9 +64,18 @@ impl CoordIndex {
                 if let Some(reloads) = &shift.reloads {
                     reloads.iter().for_each(|reload| index.add(&reload.location));
                 }
+
+                if let Some(stations) = &shift.charging_stations {
+                    stations.iter().for_each(|station| index.add(&station.location));
+                }
             });
         });
 
+        // process tour states
+        if let Some(tours) = &problem.plan.tours {
+            tours.iter().for_each(|tour| index.add(&tour.location));
+        }
+
         index
     }
 
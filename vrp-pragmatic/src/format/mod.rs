@@ -133,10 +133,45 @@ const TOUR_SIZE_CONSTRAINT_CODE: i32 = 11;
 const TOUR_ORDER_CONSTRAINT_CODE: i32 = 12;
 const GROUP_CONSTRAINT_CODE: i32 = 13;
 const COMPATIBILITY_CONSTRAINT_CODE: i32 = 14;
+const COMPARTMENT_CONSTRAINT_CODE: i32 = 15;
+const RIDE_TIME_CONSTRAINT_CODE: i32 = 16;
+const DRIVING_HOURS_CONSTRAINT_CODE: i32 = 17;
+const SHIFT_LINK_CONSTRAINT_CODE: i32 = 18;
+const PERIODIC_CONSTRAINT_CODE: i32 = 19;
+const BATTERY_CONSTRAINT_CODE: i32 = 20;
+const TRANSFER_CONSTRAINT_CODE: i32 = 21;
+const SYNC_CONSTRAINT_CODE: i32 = 22;
+const DOCK_CONSTRAINT_CODE: i32 = 23;
+const BACKHAUL_CONSTRAINT_CODE: i32 = 24;
 
 /// An job id to job index.
 pub type JobIndex = HashMap<String, CoreJob>;
 
+/// Returns an id of the periodic job visit used in job index.
+pub(crate) fn get_visit_id(job_id: &str, visit_index: usize) -> String {
+    format!("{}_visit_{}", job_id, visit_index)
+}
+
+/// Returns an id of the first echelon part of two-echelon job used in job index.
+pub(crate) fn get_transfer_id(job_id: &str) -> String {
+    format!("{}_transfer", job_id)
+}
+
+/// Returns an id of the synchronized job task used in job index.
+pub(crate) fn get_sync_id(job_id: &str, task_index: usize) -> String {
+    format!("{}_sync_{}", job_id, task_index)
+}
+
+/// Returns jobs with given id from job index: the job itself, all visits of periodic job or
+/// all tasks of synchronized job.
+pub(crate) fn get_jobs_by_id<'a>(job_index: &'a JobIndex, job_id: &str) -> Vec<&'a CoreJob> {
+    let get_jobs = |get_id: fn(&str, usize) -> String| {
+        (0..).map(move |index| job_index.get(&get_id(job_id, index))).take_while(|job| job.is_some()).flatten()
+    };
+
+    job_index.get(job_id).map_or_else(|| get_jobs(get_visit_id).chain(get_jobs(get_sync_id)).collect(), |job| vec![job])
+}
+
 /// Gets job index from core problem definition.
 pub fn get_job_index(problem: &CoreProblem) -> &JobIndex {
     problem
@@ -0,0 +1,221 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/dynamic_test.rs"]
+mod dynamic_test;
+
+use super::*;
+use crate::validation::is_reserved_job_id;
+use hashbrown::{HashMap, HashSet};
+
+/// Keeps completed activities per vehicle id and shift index.
+pub(crate) type CompletedActivities = HashMap<(String, usize), Vec<CompletedActivity>>;
+
+/// Specifies a completed activity of the job task.
+pub(crate) struct CompletedActivity {
+    /// Job id.
+    pub job_id: String,
+    /// An index of the task in the job.
+    pub task_index: usize,
+    /// An activity type of the task.
+    pub activity_type: String,
+    /// A tag of the completed task, if specified.
+    pub tag: Option<String>,
+}
+
+/// Applies current state of tours to the problem in order to re-optimize only the future part of them:
+/// * completed tasks are removed from jobs, jobs without remaining tasks are removed from the plan
+/// * partially completed jobs are locked to the vehicle which has started to serve them
+/// * completed jobs are removed from relations
+///
+/// Vehicle location, time and load from the tour state are applied when the problem is read. Completed
+/// activities are cleared from the returned problem, so the function can be safely applied more than once.
+pub fn apply_tour_states(problem: Problem) -> Problem {
+    let completed = get_completed_activities(&problem);
+    if completed.is_empty() {
+        return problem;
+    }
+
+    let mut job_tasks = completed.iter().flat_map(|(_, activities)| activities.iter()).fold(
+        HashMap::<String, HashSet<usize>>::new(),
+        |mut acc, activity| {
+            acc.entry(activity.job_id.clone()).or_default().insert(activity.task_index);
+            acc
+        },
+    );
+    let task_counts = job_tasks.iter().map(|(job_id, tasks)| (job_id.clone(), tasks.len())).collect::<HashMap<_, _>>();
+
+    let mut relations = problem
+        .plan
+        .relations
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|relation| {
+            // NOTE completed tasks are served before remaining ones, so the first job ids are removed
+            let mut counts = task_counts.clone();
+            let jobs = relation
+                .jobs
+                .into_iter()
+                .filter(|job_id| match counts.get_mut(job_id) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        false
+                    }
+                    _ => true,
+                })
+                .collect::<Vec<_>>();
+
+            if jobs.iter().any(|job_id| !is_reserved_job_id(job_id)) {
+                Some(Relation { jobs, ..relation })
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let related_jobs = relations.iter().flat_map(|relation| relation.jobs.iter().cloned()).collect::<HashSet<_>>();
+    let job_vehicles = completed
+        .iter()
+        .flat_map(|((vehicle_id, shift_index), activities)| {
+            activities.iter().map(move |activity| (activity.job_id.clone(), (vehicle_id.clone(), *shift_index)))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let jobs = problem
+        .plan
+        .jobs
+        .into_iter()
+        .filter_map(|job| {
+            let tasks = match job_tasks.remove(&job.id) {
+                Some(tasks) => tasks,
+                None => return Some(job),
+            };
+
+            let job = remove_completed_tasks(job, &tasks)?;
+
+            if !related_jobs.contains(&job.id) {
+                let (vehicle_id, shift_index) = job_vehicles.get(&job.id).cloned().unwrap();
+                relations.push(Relation {
+                    type_field: RelationType::Any,
+                    jobs: vec![job.id.clone(); get_job_tasks(&job).count()],
+                    vehicle_id,
+                    shift_index: Some(shift_index),
+                });
+            }
+
+            Some(job)
+        })
+        .collect();
+
+    let tours =
+        problem.plan.tours.map(|tours| tours.into_iter().map(|tour| TourState { completed: None, ..tour }).collect());
+
+    Problem {
+        plan: Plan {
+            jobs,
+            relations: if relations.is_empty() { None } else { Some(relations) },
+            tours,
+            ..problem.plan
+        },
+        ..problem
+    }
+}
+
+/// Returns completed activities defined in tour states. Completed tasks which cannot be matched with
+/// job tasks are ignored.
+pub(crate) fn get_completed_activities(problem: &Problem) -> CompletedActivities {
+    let jobs = problem.plan.jobs.iter().map(|job| (job.id.as_str(), job)).collect::<HashMap<_, _>>();
+
+    problem
+        .plan
+        .tours
+        .iter()
+        .flatten()
+        .filter_map(|tour| {
+            let activities = tour
+                .completed
+                .iter()
+                .flatten()
+                .filter_map(|completed| {
+                    let job = jobs.get(completed.job_id.as_str())?;
+                    let task_index = get_completed_task_index(job, completed)?;
+                    let (activity_type, _) = get_typed_job_tasks(job).nth(task_index)?;
+
+                    Some(CompletedActivity {
+                        job_id: completed.job_id.clone(),
+                        task_index,
+                        activity_type: activity_type.to_string(),
+                        tag: completed.tag.clone(),
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            if activities.is_empty() {
+                None
+            } else {
+                Some(((tour.vehicle_id.clone(), tour.shift_index.unwrap_or(0)), activities))
+            }
+        })
+        .collect()
+}
+
+/// Returns index of the completed task in the job's task list if it can be matched unambiguously: by
+/// task index, by tag or as the only job task.
+pub(crate) fn get_completed_task_index(job: &Job, completed: &CompletedTask) -> Option<usize> {
+    let has_tag = |task: &JobTask, tag: &String| task.places.iter().any(|place| place.tag.as_ref() == Some(tag));
+    let tasks = get_job_tasks(job).collect::<Vec<_>>();
+
+    match (completed.task_index, completed.tag.as_ref()) {
+        (Some(index), tag) => tasks.get(index).filter(|task| tag.map_or(true, |tag| has_tag(task, tag))).map(|_| index),
+        (None, Some(tag)) => {
+            let mut indices = tasks.iter().enumerate().filter(|(_, task)| has_tag(task, tag)).map(|(idx, _)| idx);
+            match (indices.next(), indices.next()) {
+                (Some(index), None) => Some(index),
+                _ => None,
+            }
+        }
+        (None, None) if tasks.len() == 1 => Some(0),
+        (None, None) => None,
+    }
+}
+
+/// Returns job tasks with their activity types in the order of `get_job_tasks`.
+fn get_typed_job_tasks(job: &Job) -> impl Iterator<Item = (&'static str, &JobTask)> {
+    fn typed<'a>(
+        tasks: &'a Option<Vec<JobTask>>,
+        activity_type: &'static str,
+    ) -> impl Iterator<Item = (&'static str, &'a JobTask)> {
+        tasks.iter().flatten().map(move |task| (activity_type, task))
+    }
+
+    typed(&job.pickups, "pickup")
+        .chain(typed(&job.deliveries, "delivery"))
+        .chain(typed(&job.services, "service"))
+        .chain(typed(&job.replacements, "replacement"))
+}
+
+/// Removes tasks with given indices in the job's task list.
+fn remove_completed_tasks(job: Job, completed: &HashSet<usize>) -> Option<Job> {
+    let mut offset = 0;
+    let mut remove_tasks = |tasks: Option<Vec<JobTask>>| {
+        let tasks = tasks?;
+        let start = offset;
+        offset += tasks.len();
+
+        let tasks = tasks.into_iter().enumerate().filter(|(idx, _)| !completed.contains(&(start + idx)));
+        Some(tasks.map(|(_, task)| task).collect::<Vec<_>>()).filter(|tasks| !tasks.is_empty())
+    };
+
+    // NOTE the order of tasks is the same as in `get_job_tasks`
+    let job = Job {
+        pickups: remove_tasks(job.pickups),
+        deliveries: remove_tasks(job.deliveries),
+        services: remove_tasks(job.services),
+        replacements: remove_tasks(job.replacements),
+        ..job
+    };
+
+    if get_job_tasks(&job).next().is_some() {
+        Some(job)
+    } else {
+        None
+    }
+}
@@ -2,14 +2,15 @@
 #[path = "../../../tests/unit/format/problem/fleet_reader_test.rs"]
 mod fleet_reader_test;
 
+use crate::constraints::{BatteryPolicy, DockPolicy, DockRate, DrivingHoursPolicy, VehicleDocks};
 use crate::extensions::create_typed_actor_groups;
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::reader::{ApiProblem, ProblemProperties};
-use crate::format::problem::Matrix;
+use crate::format::problem::{get_linked_shifts, DockDuration, Matrix, VehicleCompartment};
 use crate::parse_time;
 use hashbrown::{HashMap, HashSet};
 use std::sync::Arc;
-use vrp_core::construction::constraints::TravelLimitFunc;
+use vrp_core::construction::constraints::{LoadCosts, LoadCostsDimension, ServiceLevelFunc, TravelLimitFunc};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
 
@@ -74,7 +75,21 @@ pub(crate) fn create_transport_costs(
                 )
             };
 
-            MatrixData::new(profile, timestamp.map(|t| parse_time(&t)), durations, distances)
+            let timestamp = timestamp.map(|t| parse_time(&t));
+
+            // NOTE ignore values specified for unreachable locations
+            let get_reachable_values = |values: &Vec<f64>| {
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| if *durations.get(i).unwrap_or(&0.) < 0. { 0. } else { *value })
+                    .collect::<Vec<_>>()
+            };
+
+            let extra_costs = matrix.toll_costs.as_ref().map(get_reachable_values);
+            let duration_variances = matrix.travel_time_variances.as_ref().map(get_reachable_values);
+
+            MatrixData { extra_costs, duration_variances, ..MatrixData::new(profile, timestamp, durations, distances) }
         })
         .collect::<Vec<_>>();
 
@@ -94,6 +109,14 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         .iter()
         .flat_map(|areas| areas.iter().map(|area| (&area.id, area)))
         .collect::<HashMap<_, _>>();
+    let depots = get_depot_index(api_problem, coord_index);
+    let tour_states = api_problem
+        .plan
+        .tours
+        .iter()
+        .flatten()
+        .map(|tour| ((tour.vehicle_id.as_str(), tour.shift_index.unwrap_or(0)), tour))
+        .collect::<HashMap<_, _>>();
     let mut vehicles: Vec<Arc<Vehicle>> = Default::default();
 
     api_problem.fleet.vehicles.iter().for_each(|vehicle| {
@@ -109,6 +132,38 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         let profile = Profile::new(index, vehicle.profile.scale);
 
         let tour_size = vehicle.limits.as_ref().and_then(|l| l.tour_size);
+        let has_backhauls = vehicle.backhauls.unwrap_or(false);
+        let driving_hours =
+            vehicle.limits.as_ref().and_then(|l| l.driving_hours.as_ref()).map(|limit| DrivingHoursPolicy {
+                max_continuous_driving: limit.max_continuous_driving,
+                break_duration: limit.break_duration,
+                split_break: limit.split_break.as_ref().and_then(|parts| match parts.as_slice() {
+                    [first, second] => Some((*first, *second)),
+                    _ => None,
+                }),
+                max_daily_driving: limit.max_daily_driving,
+                daily_rest: limit.daily_rest,
+                max_daily_duty: limit.max_daily_duty,
+            });
+        let battery = vehicle.battery.as_ref().map(|battery| BatteryPolicy {
+            capacity: battery.capacity,
+            initial: battery.initial.unwrap_or(battery.capacity),
+            empty_consumption: battery.consumption,
+            full_consumption: battery.full_load_consumption.unwrap_or(battery.consumption),
+        });
+        let load_costs = if vehicle.costs.full_load_distance.is_some() || vehicle.emissions.is_some() {
+            Some(LoadCosts {
+                full_load_per_distance: vehicle.costs.full_load_distance.unwrap_or(vehicle.costs.distance)
+                    - vehicle.costs.distance,
+                empty_emissions: vehicle.emissions.as_ref().map_or(0., |emissions| emissions.distance),
+                full_emissions: vehicle
+                    .emissions
+                    .as_ref()
+                    .map_or(0., |emissions| emissions.full_load_distance.unwrap_or(emissions.distance)),
+            })
+        } else {
+            None
+        };
         let mut area_jobs = vehicle.limits.as_ref().and_then(|l| l.areas.as_ref()).map({
             let area_index = &area_index;
             move |areas| {
@@ -131,20 +186,33 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
             }
         });
 
-        for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
+        // NOTE a chain of linked shifts is joined into one vehicle detail with links between shifts
+        for (shift_index, shifts) in get_linked_shifts(&vehicle.shifts) {
+            let (first, last) = (shifts.first().unwrap(), shifts.last().unwrap());
             let start = {
-                let location = coord_index.get_by_loc(&shift.start.location).unwrap();
-                let earliest = parse_time(&shift.start.earliest);
-                let latest = shift.start.latest.as_ref().map(|time| parse_time(time));
+                let location = first.start.location.as_ref().and_then(|loc| coord_index.get_by_loc(loc)).unwrap();
+                let earliest = parse_time(&first.start.earliest);
+                let latest = first.start.latest.as_ref().map(|time| parse_time(time));
                 (location, earliest, latest)
             };
 
-            let end = shift.end.as_ref().map(|end| {
-                let location = coord_index.get_by_loc(&end.location).unwrap();
+            let end = last.end.as_ref().map(|end| {
+                let location = end.location.as_ref().and_then(|loc| coord_index.get_by_loc(loc)).unwrap();
                 let time = parse_time(&end.latest);
                 (location, time)
             });
 
+            let links = shifts
+                .windows(2)
+                .map(|pair| {
+                    let (prev_end, next_start) = (pair[0].end.as_ref().unwrap(), &pair[1].start);
+                    VehicleLink {
+                        location: prev_end.location.as_ref().and_then(|loc| coord_index.get_by_loc(loc)),
+                        time: TimeWindow::new(parse_time(&prev_end.latest), parse_time(&next_start.earliest)),
+                    }
+                })
+                .collect::<Vec<_>>();
+
             let details = vec![VehicleDetail {
                 start: Some(VehiclePlace {
                     location: start.0,
@@ -154,9 +222,43 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     location,
                     time: TimeInterval { earliest: None, latest: Some(time) },
                 }),
+                links,
             }];
 
+            let docks = VehicleDocks {
+                start: depots.get(&start.0).cloned(),
+                end: end.and_then(|(location, _)| depots.get(&location).cloned()),
+            };
+
             vehicle.vehicle_ids.iter().for_each(|vehicle_id| {
+                let tour_state = tour_states.get(&(vehicle_id.as_str(), shift_index));
+                // NOTE vehicle with tour in progress continues it from the current location at the current time
+                let (details, docks) = tour_state.map_or_else(
+                    || (details.clone(), docks.clone()),
+                    |tour_state| {
+                        let location = coord_index.get_by_loc(&tour_state.location).unwrap();
+                        let time = parse_time(&tour_state.time);
+                        let details = vec![VehicleDetail {
+                            start: Some(VehiclePlace {
+                                location,
+                                time: TimeInterval { earliest: Some(time), latest: Some(time) },
+                            }),
+                            end: details.first().and_then(|detail| detail.end.clone()),
+                            links: details
+                                .first()
+                                .iter()
+                                .flat_map(|detail| detail.links.iter())
+                                .filter(|link| link.time.end > time)
+                                .cloned()
+                                .collect(),
+                        }];
+
+                        (details, VehicleDocks { start: depots.get(&location).cloned(), end: docks.end.clone() })
+                    },
+                );
+                let capacity =
+                    get_remaining_capacity(&vehicle.capacity, tour_state.and_then(|state| state.load.as_ref()));
+
                 let mut dimens: Dimensions = Default::default();
                 dimens.set_value("type_id", vehicle.type_id.clone());
                 dimens.set_value("shift_index", shift_index);
@@ -170,38 +272,181 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     dimens.set_value("tour_size", tour_size);
                 }
 
+                if has_backhauls {
+                    dimens.set_value("backhauls", true);
+                }
+
+                if let Some(driving_hours) = driving_hours.clone() {
+                    dimens.set_value("driving_hours", driving_hours);
+                }
+
+                if let Some(battery) = battery.clone() {
+                    dimens.set_value("battery", battery);
+                }
+
+                if let Some(load_costs) = load_costs.clone() {
+                    dimens.set_load_costs(load_costs);
+                }
+
+                if docks.start.is_some() || docks.end.is_some() {
+                    dimens.set_value("docks", docks.clone());
+                }
+
                 if props.has_multi_dimen_capacity {
-                    dimens.set_capacity(MultiDimLoad::new(vehicle.capacity.clone()));
+                    dimens.set_capacity(MultiDimLoad::new(capacity));
                 } else {
-                    dimens.set_capacity(SingleDimLoad::new(*vehicle.capacity.first().unwrap()));
+                    dimens.set_capacity(SingleDimLoad::new(*capacity.first().unwrap()));
                 }
-                add_vehicle_skills(&mut dimens, &vehicle.skills);
+                add_compartments(&mut dimens, &vehicle.compartments, props.has_multi_dimen_capacity);
+                add_skills(&mut dimens, &vehicle.skills);
 
-                vehicles.push(Arc::new(Vehicle {
-                    profile: profile.clone(),
-                    costs: costs.clone(),
-                    dimens,
-                    details: details.clone(),
-                }));
+                vehicles.push(Arc::new(Vehicle { profile: profile.clone(), costs: costs.clone(), dimens, details }));
             });
         }
     });
 
-    let drivers = vec![Arc::new(Driver {
-        costs: Costs {
-            fixed: 0.0,
-            per_distance: 0.0,
-            per_driving_time: 0.0,
-            per_waiting_time: 0.0,
-            per_service_time: 0.0,
-        },
-        dimens: Default::default(),
-        details: vec![],
-    })];
+    let drivers = read_drivers(api_problem);
 
     Fleet::new(drivers, vehicles, Box::new(|actors| create_typed_actor_groups(actors)))
 }
 
+/// Returns vehicle capacity reduced by the load which is already on board.
+fn get_remaining_capacity(capacity: &[i32], load: Option<&Vec<i32>>) -> Vec<i32> {
+    capacity
+        .iter()
+        .enumerate()
+        .map(|(idx, capacity)| (capacity - load.and_then(|load| load.get(idx)).cloned().unwrap_or(0)).max(0))
+        .collect()
+}
+
+/// Returns depots with docks by their location.
+fn get_depot_index(api_problem: &ApiProblem, coord_index: &CoordIndex) -> HashMap<Location, Arc<DockPolicy>> {
+    let create_rate = |duration: &DockDuration| DockRate { fixed: duration.fixed, per_unit: duration.per_unit };
+
+    api_problem
+        .plan
+        .depots
+        .iter()
+        .flat_map(|depots| depots.iter())
+        .filter_map(|depot| {
+            coord_index.get_by_loc(&depot.location).map(|location| {
+                let policy = DockPolicy {
+                    depot_id: depot.id.clone(),
+                    docks: depot.docks,
+                    loading: depot.loading.as_ref().map(create_rate),
+                    unloading: depot.unloading.as_ref().map(create_rate),
+                };
+
+                (location, Arc::new(policy))
+            })
+        })
+        .collect()
+}
+
+fn add_compartments(dimens: &mut Dimensions, compartments: &Option<Vec<VehicleCompartment>>, has_multi_dimens: bool) {
+    fn create_compartments<T: LoadOps>(
+        compartments: &[VehicleCompartment],
+        create_load: impl Fn(&Vec<i32>) -> T,
+    ) -> Vec<Compartment<T>> {
+        compartments
+            .iter()
+            .map(|compartment| {
+                let mut dimens = Dimensions::default();
+                dimens.set_id(compartment.id.as_str());
+
+                Compartment {
+                    capacity: create_load(&compartment.capacity),
+                    products: compartment.products.as_ref().map(|products| products.iter().cloned().collect()),
+                    dimens,
+                }
+            })
+            .collect()
+    }
+
+    if let Some(compartments) = compartments.as_ref().filter(|compartments| !compartments.is_empty()) {
+        if has_multi_dimens {
+            dimens.set_compartments(create_compartments(compartments, |capacity| MultiDimLoad::new(capacity.clone())));
+        } else {
+            dimens.set_compartments(create_compartments(compartments, |capacity| {
+                SingleDimLoad::new(*capacity.first().unwrap())
+            }));
+        }
+    }
+}
+
+fn read_drivers(api_problem: &ApiProblem) -> Vec<Arc<Driver>> {
+    let drivers = api_problem
+        .fleet
+        .drivers
+        .iter()
+        .flat_map(|drivers| drivers.iter())
+        .flat_map(|driver| {
+            let costs = Costs {
+                fixed: driver.costs.fixed.unwrap_or(0.),
+                per_distance: driver.costs.distance,
+                per_driving_time: driver.costs.time,
+                per_waiting_time: driver.costs.time,
+                per_service_time: driver.costs.time,
+            };
+
+            let details = driver.shifts.as_ref().map_or_else(
+                || vec![DriverDetail { time: TimeWindow::max() }],
+                |shifts| {
+                    shifts
+                        .iter()
+                        .map(|shift| DriverDetail {
+                            time: TimeWindow::new(parse_time(&shift.earliest), parse_time(&shift.latest)),
+                        })
+                        .collect()
+                },
+            );
+
+            driver.driver_ids.iter().map(move |driver_id| {
+                let mut dimens: Dimensions = Default::default();
+                dimens.set_value("type_id", driver.type_id.clone());
+                dimens.set_id(driver_id);
+                add_skills(&mut dimens, &driver.skills);
+
+                Arc::new(Driver { costs: costs.clone(), dimens, details: details.clone() })
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if drivers.is_empty() {
+        vec![Arc::new(Driver {
+            costs: Costs {
+                fixed: 0.0,
+                per_distance: 0.0,
+                per_driving_time: 0.0,
+                per_waiting_time: 0.0,
+                per_service_time: 0.0,
+            },
+            dimens: Default::default(),
+            details: vec![],
+        })]
+    } else {
+        drivers
+    }
+}
+
+pub fn read_service_levels(api_problem: &ApiProblem) -> Option<ServiceLevelFunc> {
+    let profile_indices = get_profile_index_map(api_problem);
+    let service_levels = api_problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| {
+            profile.service_level.zip(profile_indices.get(&profile.name)).map(|(level, index)| (*index, level))
+        })
+        .collect::<HashMap<_, _>>();
+
+    if service_levels.is_empty() {
+        None
+    } else {
+        Some(Arc::new(move |actor: &Actor| service_levels.get(&actor.vehicle.profile.index).cloned()))
+    }
+}
+
 pub fn read_travel_limits(api_problem: &ApiProblem) -> Option<TravelLimitFunc> {
     let limits = api_problem.fleet.vehicles.iter().filter(|vehicle| vehicle.limits.is_some()).fold(
         HashMap::new(),
@@ -225,7 +470,7 @@ pub fn read_travel_limits(api_problem: &ApiProblem) -> Option<TravelLimitFunc> {
     }
 }
 
-fn add_vehicle_skills(dimens: &mut Dimensions, skills: &Option<Vec<String>>) {
+fn add_skills(dimens: &mut Dimensions, skills: &Option<Vec<String>>) {
     if let Some(skills) = skills {
         dimens.set_value("skills", skills.iter().cloned().collect::<HashSet<_>>());
     }
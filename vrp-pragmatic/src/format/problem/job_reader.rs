@@ -1,25 +1,36 @@
+use crate::extensions::ServiceDurations;
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::reader::{parse_time_window, ApiProblem, ProblemProperties};
 use crate::format::problem::*;
-use crate::format::{JobIndex, Location};
+use crate::format::{get_sync_id, get_transfer_id, get_visit_id, JobIndex, Location};
 use crate::utils::VariableJobPermutation;
 use std::sync::Arc;
+use vrp_core::construction::constraints::RideTimeDimension;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Actor, Fleet, Job, Jobs, Multi, Place, Single, TransportCost};
 use vrp_core::models::{Lock, LockDetail, LockOrder, LockPosition};
 use vrp_core::prelude::*;
 
-use crate::constraints::{BreakPolicy, JobSkills as ConstraintJobSkills};
+use crate::constraints::{get_day_index, get_weekday, SECONDS_IN_DAY};
+use crate::constraints::{BreakPolicy, JobVisit};
+use crate::constraints::{JobPreferredSkills as ConstraintJobPreferredSkills, JobSkills as ConstraintJobSkills};
+use crate::constraints::{JobSync, JobTransfer, SyncPolicy, TransferEchelon, TransferPolicy};
 use crate::format::problem::JobSkills as FormatJobSkills;
 use crate::parse_time;
 use hashbrown::HashMap;
 use std::cmp::Ordering;
 
+/// Specifies day windows when the job can be served and whether times should be split by days.
+type VisitDays = (Option<Vec<TimeWindow>>, bool);
+
 // TODO configure sample size
 const MULTI_JOB_SAMPLE_SIZE: usize = 3;
 
 type PlaceData = (Option<Location>, Duration, Vec<TimeSpan>, Option<String>);
+/// A time span with an optional soft time window it is originated from.
+type SoftTimeSpan = (TimeSpan, Option<SoftTimeWindow>);
 type ApiJob = crate::format::problem::Job;
+type CoreProduct = vrp_core::models::common::Product;
 
 pub(crate) fn read_jobs_with_extra_locks(
     api_problem: &ApiProblem,
@@ -40,22 +51,31 @@ pub(crate) fn read_jobs_with_extra_locks(
 }
 
 pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Lock>> {
-    if api_problem.plan.relations.as_ref().map_or(true, |r| r.is_empty()) {
-        return vec![];
-    }
+    read_relation_locks(api_problem, job_index, |relation| !matches!(relation.type_field, RelationType::Preferred))
+}
 
-    let relations = api_problem.plan.relations.as_ref().unwrap().iter().fold(HashMap::new(), |mut acc, r| {
-        let shift_index = r.shift_index.unwrap_or(0);
-        acc.entry((r.vehicle_id.clone(), shift_index)).or_insert_with(Vec::new).push(r.clone());
+/// Reads locks from preferred relations: they are not enforced and used only by assignment consistency objective.
+pub fn read_preferred_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Lock>> {
+    read_relation_locks(api_problem, job_index, |relation| matches!(relation.type_field, RelationType::Preferred))
+}
 
-        acc
-    });
+fn read_relation_locks<F>(api_problem: &ApiProblem, job_index: &JobIndex, filter: F) -> Vec<Arc<Lock>>
+where
+    F: Fn(&Relation) -> bool,
+{
+    let relations =
+        api_problem.plan.relations.iter().flatten().filter(|r| filter(r)).fold(HashMap::new(), |mut acc, r| {
+            let shift_index = r.shift_index.unwrap_or(0);
+            acc.entry((r.vehicle_id.clone(), shift_index)).or_insert_with(Vec::new).push(r.clone());
+
+            acc
+        });
 
     relations.into_iter().fold(vec![], |mut acc, ((vehicle_id, shift_index), rels)| {
         let condition = create_condition(vehicle_id.clone(), shift_index);
         let details = rels.iter().fold(vec![], |mut acc, rel| {
             let order = match rel.type_field {
-                RelationType::Any => LockOrder::Any,
+                RelationType::Any | RelationType::Preferred => LockOrder::Any,
                 RelationType::Sequence => LockOrder::Sequence,
                 RelationType::Strict => LockOrder::Strict,
             };
@@ -73,7 +93,7 @@ pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Loc
                 .filter(|job| job.as_str() != "departure" && job.as_str() != "arrival")
                 .fold((HashMap::<String, _>::default(), vec![]), |(mut indexer, mut jobs), job| {
                     let job_id = match job.as_str() {
-                        "break" | "dispatch" | "reload" => {
+                        "break" | "dispatch" | "reload" | "charging" => {
                             let entry = indexer.entry(job.clone()).or_insert(1_usize);
                             let job_index = *entry;
                             *entry += 1;
@@ -111,8 +131,11 @@ fn read_required_jobs(
 ) -> (Vec<Job>, Vec<Arc<Lock>>) {
     let mut jobs = vec![];
     let has_multi_dimens = props.has_multi_dimen_capacity;
+    let products = get_products(api_problem);
+    let hubs = get_transfer_hubs(api_problem);
+    let horizon = get_planning_horizon(api_problem);
 
-    let get_single_from_task = |task: &JobTask, activity_type: &str, is_static_demand: bool| {
+    let get_single_from_task = |task: &JobTask, activity_type: &str, is_static_demand: bool, visit: &VisitDays| {
         let absent = (empty(), empty());
         let capacity = task.demand.clone().map_or_else(empty, MultiDimLoad::new);
         let demand = if is_static_demand { (capacity, empty()) } else { (empty(), capacity) };
@@ -125,50 +148,152 @@ fn read_required_jobs(
             _ => panic!("Invalid activity type."),
         };
 
-        let places = task
+        let (places, soft_times): (Vec<_>, Vec<_>) = task
             .places
             .iter()
-            .map(|p| (Some(p.location.clone()), p.duration, parse_times(&p.times), p.tag.clone()))
-            .collect();
+            .map(|p| {
+                let times = parse_soft_times(p).map_or_else(
+                    || parse_times(&p.times).into_iter().map(|span| (span, None)).collect(),
+                    |soft_times| {
+                        soft_times.into_iter().map(|tw| (TimeSpan::Window(tw.to_hard_window()), Some(tw))).collect()
+                    },
+                );
+                let times = match visit {
+                    (Some(day_windows), false) => restrict_times_to_days(times, day_windows.as_slice()),
+                    (Some(day_windows), true) => split_times_by_days(times, day_windows.as_slice()),
+                    (None, _) => times,
+                };
+                let (times, soft_times): (Vec<_>, Vec<_>) = times.into_iter().unzip();
+
+                ((Some(p.location.clone()), p.duration, times, p.tag.clone()), soft_times)
+            })
+            .unzip();
+
+        let mut single =
+            get_single_with_extras(places, demand, &task.order, activity_type, has_multi_dimens, coord_index);
+        add_soft_times(&mut single.dimens, soft_times);
+        add_service_durations(&mut single.dimens, task.places.as_slice());
 
-        get_single_with_extras(places, demand, &task.order, activity_type, has_multi_dimens, coord_index)
+        single
     };
 
-    api_problem.plan.jobs.iter().for_each(|job| {
+    let get_singles = |job: &ApiJob, visit: &Option<JobVisit>, days: Option<(Vec<DayOfWeek>, (usize, usize))>| {
         let pickups = job.pickups.as_ref().map_or(0, |p| p.len());
         let deliveries = job.deliveries.as_ref().map_or(0, |p| p.len());
         let is_static_demand = pickups == 0 || deliveries == 0;
+        let day_windows = days.map(|(days, reserved)| {
+            // NOTE planning horizon might be too short for all visits, so they compete for the same days
+            let day_windows = get_day_windows(days.as_slice(), &horizon, reserved);
+            if day_windows.is_empty() {
+                get_day_windows(days.as_slice(), &horizon, (0, 0))
+            } else {
+                day_windows
+            }
+        });
+        let days = &(day_windows, visit.is_some());
 
-        let singles =
-            job.pickups
-                .iter()
-                .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "pickup", is_static_demand)))
-                .chain(job.deliveries.iter().flat_map(|tasks| {
-                    tasks.iter().map(|task| get_single_from_task(task, "delivery", is_static_demand))
-                }))
-                .chain(
-                    job.replacements
-                        .iter()
-                        .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "replacement", true))),
-                )
-                .chain(
-                    job.services
-                        .iter()
-                        .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "service", false))),
-                )
-                .collect::<Vec<_>>();
+        let mut singles = job
+            .pickups
+            .iter()
+            .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "pickup", is_static_demand, days)))
+            .chain(job.deliveries.iter().flat_map(|tasks| {
+                tasks.iter().map(|task| get_single_from_task(task, "delivery", is_static_demand, days))
+            }))
+            .chain(
+                job.replacements
+                    .iter()
+                    .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "replacement", true, days))),
+            )
+            .chain(
+                job.services
+                    .iter()
+                    .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "service", false, days))),
+            )
+            .collect::<Vec<_>>();
 
         assert!(!singles.is_empty());
+        add_product(&mut singles, &job.product, &products);
+        singles.iter_mut().for_each(|single| add_visit(&mut single.dimens, visit));
+        if let Some((policy, _)) = job.hub.as_ref().and_then(|hub| hubs.get(hub)) {
+            singles
+                .iter_mut()
+                .for_each(|single| add_transfer(&mut single.dimens, &job.id, policy, TransferEchelon::Second));
+        }
+
+        singles
+    };
 
-        let problem_job = if singles.len() > 1 {
+    let get_job = |job: &ApiJob, visit: Option<JobVisit>, days: Option<(Vec<DayOfWeek>, (usize, usize))>| {
+        let singles = get_singles(job, &visit, days);
+
+        if singles.len() > 1 {
             let deliveries_start_index = job.pickups.as_ref().map_or(0, |p| p.len());
-            get_multi_job(job, singles, deliveries_start_index, random)
+            get_multi_job(job, singles, deliveries_start_index, &visit, random)
         } else {
             get_single_job(job, singles.into_iter().next().unwrap())
-        };
+        }
+    };
 
-        job_index.insert(job.id.clone(), problem_job.clone());
-        jobs.push(problem_job);
+    // NOTE goods of two-echelon job are brought to the hub by the first echelon vehicle
+    let get_transfer_job = |job: &ApiJob, policy: &Arc<TransferPolicy>, hub: &TransferHub| {
+        let capacity = job
+            .deliveries
+            .iter()
+            .flat_map(|tasks| tasks.iter())
+            .find_map(|task| task.demand.clone())
+            .map_or_else(empty, MultiDimLoad::new);
+        let demand = Demand { pickup: (empty(), empty()), delivery: (capacity, empty()) };
+        let places = vec![(Some(hub.location.clone()), hub.duration, parse_times(&None), None)];
+
+        let mut single = get_single_with_extras(places, demand, &None, "transfer", has_multi_dimens, coord_index);
+        single.dimens.set_id(&job.id);
+        add_transfer(&mut single.dimens, &job.id, policy, TransferEchelon::First);
+
+        let mut singles = vec![single];
+        add_product(&mut singles, &job.product, &products);
+
+        Job::Single(Arc::new(singles.pop().unwrap()))
+    };
+
+    api_problem.plan.jobs.iter().for_each(|job| {
+        if let Some(visits) = job.visits.as_ref() {
+            let policy = Arc::new(get_periodic_policy(visits));
+
+            (0..visits.count).for_each(|index| {
+                let visit = JobVisit { job_id: job.id.clone(), index, policy: policy.clone() };
+                // NOTE reserve enough days in planning horizon for visits before and after this one
+                let interval = visits.min_days_between.unwrap_or(0) + 1;
+                let reserved = (index * interval, (visits.count - index - 1) * interval);
+                let problem_job = get_job(job, Some(visit), Some((get_visit_days(job, visits, index), reserved)));
+
+                job_index.insert(get_visit_id(&job.id, index), problem_job.clone());
+                jobs.push(problem_job);
+            });
+        } else if let Some(sync) = job.sync.as_ref() {
+            // NOTE each task of synchronized job is a separate job served by its own vehicle
+            let singles = get_singles(job, &None, job.days.clone().map(|days| (days, (0, 0))));
+            let policy = Arc::new(SyncPolicy { size: singles.len(), max_offset: sync.max_offset });
+
+            singles.into_iter().enumerate().for_each(|(index, mut single)| {
+                add_sync(&mut single.dimens, &job.id, index, &policy);
+                let problem_job = get_single_job(job, single);
+
+                job_index.insert(get_sync_id(&job.id, index), problem_job.clone());
+                jobs.push(problem_job);
+            });
+        } else {
+            let problem_job = get_job(job, None, job.days.clone().map(|days| (days, (0, 0))));
+
+            job_index.insert(job.id.clone(), problem_job.clone());
+            jobs.push(problem_job);
+
+            if let Some((policy, hub)) = job.hub.as_ref().and_then(|hub| hubs.get(hub)) {
+                let transfer_job = get_transfer_job(job, policy, hub);
+
+                job_index.insert(get_transfer_id(&job.id), transfer_job.clone());
+                jobs.push(transfer_job);
+            }
+        }
     });
 
     (jobs, vec![])
@@ -194,6 +319,10 @@ fn read_conditional_jobs(
             if let Some(reloads) = &shift.reloads {
                 read_reloads(coord_index, job_index, &mut jobs, vehicle, shift_index, reloads);
             }
+
+            if let Some(stations) = &shift.charging_stations {
+                read_charging_stations(coord_index, job_index, &mut jobs, vehicle, shift_index, stations);
+            }
         }
     });
 
@@ -333,6 +462,41 @@ fn read_reloads(
         .for_each(|(job_id, single)| add_conditional_job(job_index, jobs, job_id, single));
 }
 
+fn read_charging_stations(
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+    jobs: &mut Vec<Job>,
+    vehicle: &VehicleType,
+    shift_index: usize,
+    stations: &[VehicleChargingStation],
+) {
+    (1..)
+        .zip(stations.iter())
+        .flat_map(|(place_idx, station)| {
+            vehicle
+                .vehicle_ids
+                .iter()
+                .map(|vehicle_id| {
+                    let job_id = format!("{}_charging_{}_{}", vehicle_id, shift_index, place_idx);
+                    let times = parse_times(&station.times);
+
+                    let mut job = get_conditional_job(
+                        coord_index,
+                        vehicle_id.clone(),
+                        &job_id,
+                        "charging",
+                        shift_index,
+                        vec![(Some(station.location.clone()), station.duration, times, station.tag.clone())],
+                    );
+                    job.dimens.set_value("charging_rate", station.rate);
+
+                    (job_id, job)
+                })
+                .collect::<Vec<_>>()
+        })
+        .for_each(|(job_id, single)| add_conditional_job(job_index, jobs, job_id, single));
+}
+
 fn get_conditional_job(
     coord_index: &CoordIndex,
     vehicle_id: String,
@@ -421,14 +585,17 @@ fn get_multi_job(
     job: &ApiJob,
     singles: Vec<Single>,
     deliveries_start_index: usize,
+    visit: &Option<JobVisit>,
     random: &Arc<dyn Random + Send + Sync>,
 ) -> Job {
     let mut dimens: Dimensions = Default::default();
     dimens.set_id(&job.id);
+    add_visit(&mut dimens, visit);
     add_value(&mut dimens, &job.value);
     add_group(&mut dimens, &job.group);
     add_compatibility(&mut dimens, &job.compatibility);
     add_job_skills(&mut dimens, &job.skills);
+    add_max_ride_time(&mut dimens, &job.max_ride_time);
 
     let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
 
@@ -470,6 +637,32 @@ fn add_order(dimens: &mut Dimensions, order: &Option<i32>) {
     }
 }
 
+fn add_soft_times(dimens: &mut Dimensions, soft_times: SoftTimeWindows) {
+    if soft_times.iter().flatten().any(|window| window.is_some()) {
+        dimens.set_soft_time_windows(soft_times);
+    }
+}
+
+fn add_service_durations(dimens: &mut Dimensions, places: &[JobPlace]) {
+    if places.iter().any(|place| place.durations.is_some()) {
+        let get_durations = |durations: Option<&std::collections::HashMap<String, f64>>| {
+            durations.iter().flat_map(|durations| durations.iter()).map(|(id, value)| (id.clone(), *value)).collect()
+        };
+
+        let durations = places
+            .iter()
+            .map(|place| {
+                place.durations.as_ref().map_or_else(ServiceDurations::default, |durations| ServiceDurations {
+                    vehicles: get_durations(durations.vehicles.as_ref()),
+                    drivers: get_durations(durations.drivers.as_ref()),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        dimens.set_value("service_durations", durations);
+    }
+}
+
 fn add_value(dimens: &mut Dimensions, value: &Option<f64>) {
     if let Some(value) = *value {
         dimens.set_value("value", value);
@@ -488,6 +681,70 @@ fn add_compatibility(dimens: &mut Dimensions, compatibility: &Option<String>) {
     }
 }
 
+fn add_max_ride_time(dimens: &mut Dimensions, max_ride_time: &Option<f64>) {
+    if let Some(max_ride_time) = max_ride_time {
+        dimens.set_max_ride_time(*max_ride_time);
+    }
+}
+
+fn add_visit(dimens: &mut Dimensions, visit: &Option<JobVisit>) {
+    if let Some(visit) = visit {
+        dimens.set_value("visit", visit.clone());
+    }
+}
+
+fn add_product(singles: &mut [Single], product: &Option<String>, products: &HashMap<String, Arc<CoreProduct>>) {
+    if let Some(product) = product {
+        let product = products
+            .get(product)
+            .cloned()
+            .unwrap_or_else(|| Arc::new(CoreProduct::new(product.clone(), Default::default())));
+
+        singles.iter_mut().for_each(|single| {
+            single.dimens.set_product(product.clone());
+        });
+    }
+}
+
+fn get_products(api_problem: &ApiProblem) -> HashMap<String, Arc<CoreProduct>> {
+    api_problem
+        .plan
+        .products
+        .iter()
+        .flat_map(|products| products.iter())
+        .map(|product| {
+            let incompatible = product.incompatible.iter().cloned().collect();
+            (product.id.clone(), Arc::new(CoreProduct::new(product.id.clone(), incompatible)))
+        })
+        .collect()
+}
+
+fn add_transfer(dimens: &mut Dimensions, job_id: &str, policy: &Arc<TransferPolicy>, echelon: TransferEchelon) {
+    dimens.set_value("transfer", JobTransfer { job_id: job_id.to_string(), echelon, policy: policy.clone() });
+}
+
+fn add_sync(dimens: &mut Dimensions, job_id: &str, index: usize, policy: &Arc<SyncPolicy>) {
+    dimens.set_value("sync", JobSync { job_id: job_id.to_string(), index, policy: policy.clone() });
+}
+
+fn get_transfer_hubs(api_problem: &ApiProblem) -> HashMap<String, (Arc<TransferPolicy>, &TransferHub)> {
+    api_problem
+        .plan
+        .hubs
+        .iter()
+        .flat_map(|hubs| hubs.iter())
+        .map(|hub| {
+            let policy = TransferPolicy {
+                hub_id: hub.id.clone(),
+                first_echelon: hub.first_echelon.iter().cloned().collect(),
+                second_echelon: hub.second_echelon.iter().cloned().collect(),
+            };
+
+            (hub.id.clone(), (Arc::new(policy), hub))
+        })
+        .collect()
+}
+
 fn add_job_skills(dimens: &mut Dimensions, skills: &Option<FormatJobSkills>) {
     if let Some(skills) = skills {
         dimens.set_value(
@@ -496,6 +753,10 @@ fn add_job_skills(dimens: &mut Dimensions, skills: &Option<FormatJobSkills>) {
                 all_of: skills.all_of.as_ref().map(|all_of| all_of.iter().cloned().collect()),
                 one_of: skills.one_of.as_ref().map(|any_of| any_of.iter().cloned().collect()),
                 none_of: skills.none_of.as_ref().map(|none_of| none_of.iter().cloned().collect()),
+                preferred: skills.preferred.as_ref().map(|preferred| ConstraintJobPreferredSkills {
+                    one_of: preferred.one_of.iter().cloned().collect(),
+                    weight: preferred.weight.unwrap_or(1.),
+                }),
             },
         );
     }
@@ -510,3 +771,87 @@ fn parse_times(times: &Option<Vec<Vec<String>>>) -> Vec<TimeSpan> {
         tws.iter().map(|tw| TimeSpan::Window(parse_time_window(tw))).collect()
     })
 }
+
+/// Returns time windows of given days of week within planning horizon except reserved days at its start and end.
+fn get_day_windows(days: &[DayOfWeek], horizon: &(TimeWindow, i64), reserved: (usize, usize)) -> Vec<TimeWindow> {
+    let (horizon, offset) = (&horizon.0, horizon.1);
+    let first_day = get_day_index(horizon.start, offset) + reserved.0 as i64;
+    let last_day = ((horizon.end + offset as f64) / SECONDS_IN_DAY).ceil() as i64 - reserved.1 as i64;
+
+    (first_day..last_day)
+        .filter(|day| days.iter().any(|weekday| *weekday as usize == get_weekday(*day)))
+        .map(|day| {
+            let offset = offset as f64;
+            TimeWindow::new(day as f64 * SECONDS_IN_DAY - offset, (day + 1) as f64 * SECONDS_IN_DAY - offset)
+        })
+        .collect()
+}
+
+/// Restricts job times to given days: when job has no time windows, day windows are used,
+/// otherwise, only time windows which start within one of the days are kept.
+fn restrict_times_to_days(times: Vec<SoftTimeSpan>, day_windows: &[TimeWindow]) -> Vec<SoftTimeSpan> {
+    let is_unrestricted =
+        times.iter().all(|(span, _)| matches!(span, TimeSpan::Window(tw) if *tw == TimeWindow::max()));
+
+    if is_unrestricted {
+        day_windows.iter().cloned().map(|day| (TimeSpan::Window(day), None)).collect()
+    } else {
+        times
+            .into_iter()
+            .filter(|(span, _)| match span {
+                TimeSpan::Window(tw) => day_windows.iter().any(|day| tw.start >= day.start && tw.start < day.end),
+                TimeSpan::Offset(_) => true,
+            })
+            .collect()
+    }
+}
+
+/// Splits job times by given days, so each time window is limited by one day.
+fn split_times_by_days(times: Vec<SoftTimeSpan>, day_windows: &[TimeWindow]) -> Vec<SoftTimeSpan> {
+    times
+        .into_iter()
+        .flat_map(|(span, soft_time)| match span {
+            TimeSpan::Window(tw) => day_windows
+                .iter()
+                .filter_map(|day| tw.overlapping(day))
+                .filter(|tw| tw.duration() > 0.)
+                .map(|tw| (TimeSpan::Window(tw), soft_time.clone()))
+                .collect::<Vec<_>>(),
+            TimeSpan::Offset(_) => vec![(span, soft_time)],
+        })
+        .collect()
+}
+
+/// Returns days of week when given visit of periodic job can happen.
+fn get_visit_days(job: &ApiJob, visits: &JobVisits, index: usize) -> Vec<DayOfWeek> {
+    const ALL_DAYS: [DayOfWeek; 7] = [
+        DayOfWeek::Monday,
+        DayOfWeek::Tuesday,
+        DayOfWeek::Wednesday,
+        DayOfWeek::Thursday,
+        DayOfWeek::Friday,
+        DayOfWeek::Saturday,
+        DayOfWeek::Sunday,
+    ];
+
+    ALL_DAYS
+        .iter()
+        .filter(|day| job.days.as_ref().map_or(true, |days| days.contains(day)))
+        .filter(|day| {
+            visits.patterns.as_ref().map_or(true, |patterns| {
+                patterns.iter().any(|pattern| pattern.get(index).map_or(false, |pattern_day| pattern_day == *day))
+            })
+        })
+        .cloned()
+        .collect()
+}
+
+fn parse_soft_times(place: &JobPlace) -> Option<Vec<SoftTimeWindow>> {
+    place.times.as_ref().zip(place.lateness.as_ref()).map(|(times, lateness)| {
+        times
+            .iter()
+            .zip(lateness.iter())
+            .map(|(tw, lateness)| SoftTimeWindow::new(parse_time_window(tw), lateness.max_lateness, lateness.penalty))
+            .collect()
+    })
+}
@@ -1,6 +1,10 @@
 //! Specifies logic to read problem and routing matrix from json input.
 //!
 
+use crate::constraints::{PeriodicPolicy, SECONDS_IN_DAY};
+use crate::{parse_offset, parse_time};
+use vrp_core::models::common::TimeWindow;
+
 mod model;
 pub use self::model::*;
 
@@ -8,6 +12,50 @@ mod reader;
 pub use self::reader::create_approx_matrices;
 pub use self::reader::PragmaticProblem;
 
+mod dynamic;
+pub use self::dynamic::apply_tour_states;
+pub(crate) use self::dynamic::{
+    get_completed_activities, get_completed_task_index, CompletedActivities, CompletedActivity,
+};
+
+/// Returns planning horizon defined by vehicle shifts together with UTC offset of the earliest shift start.
+pub(crate) fn get_planning_horizon(problem: &Problem) -> (TimeWindow, i64) {
+    problem.fleet.vehicles.iter().flat_map(|vehicle| vehicle.shifts.iter()).fold(
+        (TimeWindow::new(f64::MAX, f64::MIN), 0),
+        |(horizon, offset), shift| {
+            let start = parse_time(&shift.start.earliest);
+            // NOTE use one week horizon for open shifts
+            let end = shift.end.as_ref().map_or(start + 7. * SECONDS_IN_DAY, |end| parse_time(&end.latest));
+            let offset = if start < horizon.start { parse_offset(&shift.start.earliest) } else { offset };
+
+            (TimeWindow::new(horizon.start.min(start), horizon.end.max(end)), offset)
+        },
+    )
+}
+
+/// Returns vehicle shifts grouped by chains of linked shifts together with index of the first shift in chain.
+pub(crate) fn get_linked_shifts(shifts: &[VehicleShift]) -> Vec<(usize, Vec<&VehicleShift>)> {
+    shifts.iter().enumerate().fold(Vec::new(), |mut acc, (shift_index, shift)| {
+        match acc.last_mut() {
+            Some((_, chain)) if shift.start.location.is_none() => chain.push(shift),
+            _ => acc.push((shift_index, vec![shift])),
+        }
+
+        acc
+    })
+}
+
+/// Returns visit policy of a periodic job.
+pub(crate) fn get_periodic_policy(visits: &JobVisits) -> PeriodicPolicy {
+    PeriodicPolicy {
+        min_days_between: visits.min_days_between.unwrap_or(0),
+        patterns: visits
+            .patterns
+            .as_ref()
+            .map(|patterns| patterns.iter().map(|pattern| pattern.iter().map(|day| *day as usize).collect()).collect()),
+    }
+}
+
 pub(crate) fn get_job_tasks(job: &Job) -> impl Iterator<Item = &JobTask> {
     job.pickups.iter().chain(job.deliveries.iter()).chain(job.services.iter()).chain(job.replacements.iter()).flatten()
 }
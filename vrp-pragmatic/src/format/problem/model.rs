@@ -6,6 +6,7 @@ extern crate serde_json;
 
 use crate::format::{FormatError, Location};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::io::{BufReader, BufWriter, Error, Read, Write};
 
 // region Plan
@@ -20,6 +21,9 @@ pub enum RelationType {
     Sequence,
     /// Relation type which locks jobs in strict order, no insertions in between are allowed.
     Strict,
+    /// Relation type which prefers jobs to be served by specific vehicle, e.g. based on historical
+    /// assignments. It is not enforced: reassignment is penalized by `minimize-reassignments` objective.
+    Preferred,
 }
 
 /// Relation is the way to lock specific jobs to specific vehicles.
@@ -47,6 +51,15 @@ pub struct Area {
     pub jobs: Vec<String>,
 }
 
+/// A product type of the job demand.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct Product {
+    /// An unique id of the product.
+    pub id: String,
+    /// List of product ids which cannot share the same compartment with this product.
+    pub incompatible: Vec<String>,
+}
+
 /// A job skills limitation for a vehicle.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -60,6 +73,20 @@ pub struct JobSkills {
     /// Vehicle should have none of these skills defined.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub none_of: Option<Vec<String>>,
+    /// Vehicle or driver should preferably have at least one of these skills defined.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred: Option<JobPreferredSkills>,
+}
+
+/// Specifies skills which are preferred, but not required to serve a job.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobPreferredSkills {
+    /// Vehicle or driver should have at least one of these skills defined.
+    pub one_of: Vec<String>,
+    /// A penalty of serving the job by vehicle without preferred skills. Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
 }
 
 /// Specifies a place for sub job.
@@ -76,6 +103,36 @@ pub struct JobPlace {
     /// You can use it to identify used place in solution.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
+    /// A list of lateness parameters which make time windows soft: each item corresponds to the
+    /// time window with the same index in `times`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lateness: Option<Vec<JobPlaceLateness>>,
+    /// Service durations which override default one for specific vehicle or driver types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub durations: Option<JobPlaceDurations>,
+}
+
+/// Specifies lateness parameters of soft time window: job can be started after time window end,
+/// but not later than max lateness. Lateness is penalized.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobPlaceLateness {
+    /// Max allowed lateness in seconds.
+    pub max_lateness: f64,
+    /// A penalty per second of lateness.
+    pub penalty: f64,
+}
+
+/// Specifies service durations which depend on actor: e.g. unloading takes longer for a big truck
+/// than for a van and experienced drivers are faster.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobPlaceDurations {
+    /// Service durations per vehicle type id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicles: Option<HashMap<String, f64>>,
+    /// Service durations per driver type id. Driver type duration has precedence over vehicle type one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drivers: Option<HashMap<String, f64>>,
 }
 
 /// Specifies a job task.
@@ -131,6 +188,161 @@ pub struct Job {
     /// A compatibility group: jobs with different compatibility cannot be assigned to the same tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compatibility: Option<String>,
+
+    /// A product type of the job demand: it is used to assign demand to vehicle compartments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<String>,
+
+    /// A maximum ride time: time between departure from the first pickup and arrival to the last delivery.
+    #[serde(rename(deserialize = "maxRideTime", serialize = "maxRideTime"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_ride_time: Option<f64>,
+
+    /// Days of week when the job can be served. No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days: Option<Vec<DayOfWeek>>,
+
+    /// Specifies periodic job visits: the job is expected to be visited several times within planning period.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visits: Option<JobVisits>,
+
+    /// Id of the transfer hub: goods are delivered to the hub by the first echelon vehicle and
+    /// then to the job location by the second echelon vehicle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hub: Option<String>,
+
+    /// Specifies that job tasks are served simultaneously by different vehicles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync: Option<JobSync>,
+}
+
+/// Specifies visits of a periodic job.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobVisits {
+    /// Amount of visits within planning period.
+    pub count: usize,
+
+    /// Minimum amount of days between two consecutive visits. Zero, if omitted: consecutive
+    /// visits can happen on consecutive days.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_days_between: Option<usize>,
+
+    /// Allowed visit day patterns: each pattern specifies a day of week for each visit.
+    /// When omitted, visits can happen on any day.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patterns: Option<Vec<Vec<DayOfWeek>>>,
+}
+
+/// Specifies synchronization of job tasks served by different vehicles.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobSync {
+    /// Maximum difference between service start times of job tasks in seconds.
+    pub max_offset: f64,
+}
+
+/// A transfer hub where goods are moved between vehicles of two echelons.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferHub {
+    /// An unique id of the hub.
+    pub id: String,
+    /// A hub location.
+    pub location: Location,
+    /// A duration of goods unloading at the hub.
+    pub duration: f64,
+    /// Vehicle type ids of the first echelon: they bring goods to the hub.
+    pub first_echelon: Vec<String>,
+    /// Vehicle type ids of the second echelon: they start at the hub and deliver goods to jobs.
+    pub second_echelon: Vec<String>,
+}
+
+/// A depot with limited amount of docks where vehicles are loaded before departure and unloaded after arrival.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Depot {
+    /// An unique id of the depot.
+    pub id: String,
+    /// A depot location. Vehicles which start or end their shifts here use depot docks.
+    pub location: Location,
+    /// Amount of docks.
+    pub docks: usize,
+    /// A loading duration before departure of the vehicle. No loading is done when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loading: Option<DockDuration>,
+    /// An unloading duration after arrival of the vehicle. No unloading is done when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unloading: Option<DockDuration>,
+}
+
+/// Specifies a duration of dock operation as a linear function of goods amount.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DockDuration {
+    /// A fixed duration of the operation.
+    pub fixed: f64,
+    /// A duration per unit of goods (sum of all demand dimensions).
+    pub per_unit: f64,
+}
+
+/// Specifies current state of the vehicle tour which is used to re-optimize plan dynamically:
+/// the executed part of the tour is frozen and only its future part is optimized.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TourState {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shift_index: Option<usize>,
+    /// A current vehicle location. The rest of the tour starts here.
+    pub location: Location,
+    /// A current time. The rest of the tour starts at this time.
+    pub time: String,
+    /// A load on board which stays in the vehicle till the end of the tour, e.g. collected by completed
+    /// pickups. It reduces available vehicle capacity. Goods picked up for deliveries which are not yet
+    /// completed should not be included: remaining delivery tasks are loaded at the current location of
+    /// the tour, so such goods are already counted by their demand.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load: Option<Vec<i32>>,
+    /// Already completed job tasks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed: Option<Vec<CompletedTask>>,
+}
+
+/// Specifies a completed job task of the tour in progress.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletedTask {
+    /// Job id.
+    pub job_id: String,
+    /// A tag of the task place. Either tag or task index is required when job has more than one task.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    /// An index of the task in the job: tasks are ordered as pickups, deliveries, services, replacements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_index: Option<usize>,
+}
+
+/// A day of week.
+#[derive(Clone, Copy, Deserialize, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DayOfWeek {
+    /// Monday.
+    Monday,
+    /// Tuesday.
+    Tuesday,
+    /// Wednesday.
+    Wednesday,
+    /// Thursday.
+    Thursday,
+    /// Friday.
+    Friday,
+    /// Saturday.
+    Saturday,
+    /// Sunday.
+    Sunday,
 }
 
 // region Clustering
@@ -238,6 +450,22 @@ pub struct Plan {
     /// Specifies clustering parameters.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub clustering: Option<Clustering>,
+
+    /// List of products with their compatibility rules.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub products: Option<Vec<Product>>,
+
+    /// List of transfer hubs used by two-echelon jobs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hubs: Option<Vec<TransferHub>>,
+
+    /// List of depots with limited amount of docks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depots: Option<Vec<Depot>>,
+
+    /// Current state of tours which are already in progress.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tours: Option<Vec<TourState>>,
 }
 
 // endregion
@@ -256,6 +484,12 @@ pub struct VehicleCosts {
 
     /// Cost per time unit.
     pub time: f64,
+
+    /// Cost per distance unit when vehicle is fully loaded. Distance cost is linearly interpolated
+    /// between empty and full load values. Load independent distance cost is used when omitted.
+    #[serde(rename = "fullLoadDistance")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_load_distance: Option<f64>,
 }
 
 /// Specifies vehicle shift start.
@@ -270,8 +504,10 @@ pub struct ShiftStart {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub latest: Option<String>,
 
-    /// Shift start location.
-    pub location: Location,
+    /// Shift start location. When omitted, the shift is linked to the previous shift of the vehicle:
+    /// vehicle continues its tour from the place where it spent time between shifts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
 }
 
 /// Specifies vehicle shift end.
@@ -285,8 +521,10 @@ pub struct ShiftEnd {
     /// Latest possible arrival date time in RFC3339 format.
     pub latest: String,
 
-    /// Shift end location.
-    pub location: Location,
+    /// Shift end location. Can be omitted only when the next shift is linked: then vehicle stays
+    /// where it stopped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
 }
 
 /// Specifies vehicle shift.
@@ -313,6 +551,11 @@ pub struct VehicleShift {
     /// unloaded during single tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reloads: Option<Vec<VehicleReload>>,
+
+    /// Vehicle charging stations which allows electric vehicle to recharge its battery during single tour.
+    #[serde(rename(deserialize = "chargingStations", serialize = "chargingStations"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charging_stations: Option<Vec<VehicleChargingStation>>,
 }
 
 /// Specifies a dispatch place where vehicle can load cargo and start the tour.
@@ -358,6 +601,27 @@ pub struct VehicleReload {
     pub tag: Option<String>,
 }
 
+/// Specifies a charging station where electric vehicle can recharge its battery.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleChargingStation {
+    /// A charging station location.
+    pub location: Location,
+
+    /// A charging duration (service time).
+    pub duration: f64,
+
+    /// A charging rate: amount of energy charged per second.
+    pub rate: f64,
+
+    /// A list of time windows with time specified in RFC3339 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub times: Option<Vec<Vec<String>>>,
+
+    /// A tag which will be propagated back within corresponding activity in solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
 /// Vehicle limits.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -381,6 +645,36 @@ pub struct VehicleLimits {
     /// No area restrictions when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub areas: Option<Vec<Vec<AreaLimit>>>,
+
+    /// Driving hours regulation rules, e.g. EU 561/2006.
+    /// No driving hours restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driving_hours: Option<DrivingHoursLimit>,
+}
+
+/// Driving hours limit: specifies how long driver can drive before mandatory rest has to be taken.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DrivingHoursLimit {
+    /// Max continuous driving time before a break, e.g. 4.5h.
+    pub max_continuous_driving: f64,
+
+    /// A break duration required after continuous driving, e.g. 45 min.
+    pub break_duration: f64,
+
+    /// Durations of two parts of the break if it can be split, e.g. 15 and 30 min.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_break: Option<Vec<f64>>,
+
+    /// Max driving time between two daily rests, e.g. 9h.
+    pub max_daily_driving: f64,
+
+    /// A daily rest duration, e.g. 11h.
+    pub daily_rest: f64,
+
+    /// Max duty time (driving, waiting and service time) between two daily rests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_daily_duty: Option<f64>,
 }
 
 /// An area limit.
@@ -489,6 +783,70 @@ pub struct VehicleType {
     /// Vehicle limits.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<VehicleLimits>,
+
+    /// Vehicle compartments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compartments: Option<Vec<VehicleCompartment>>,
+
+    /// Vehicle battery. Used only for electric vehicles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub battery: Option<VehicleBattery>,
+
+    /// Vehicle emissions. Used to report emissions and by `minimize-emissions` objective.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emissions: Option<VehicleEmissions>,
+
+    /// Specifies whether vehicle serves backhauls: when set, pickup jobs can be served only after
+    /// all delivery jobs of the tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backhauls: Option<bool>,
+}
+
+/// Specifies vehicle emissions, e.g. CO2 or fuel consumption.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleEmissions {
+    /// Emissions per distance unit when vehicle is empty.
+    pub distance: f64,
+
+    /// Emissions per distance unit when vehicle is fully loaded. Emissions are linearly interpolated
+    /// between empty and full load values. Load independent emissions are used when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_load_distance: Option<f64>,
+}
+
+/// Specifies a battery of electric vehicle.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleBattery {
+    /// Battery capacity.
+    pub capacity: f64,
+
+    /// Battery charge at the start of the shift. Full battery is assumed when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial: Option<f64>,
+
+    /// Energy consumption per distance unit when vehicle is empty.
+    pub consumption: f64,
+
+    /// Energy consumption per distance unit when vehicle is fully loaded. Consumption is linearly
+    /// interpolated between empty and full load values. Load independent consumption is used when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_load_consumption: Option<f64>,
+}
+
+/// Specifies a vehicle compartment: a separated part of vehicle with its own capacity.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleCompartment {
+    /// Compartment id.
+    pub id: String,
+
+    /// Compartment capacity.
+    pub capacity: Vec<i32>,
+
+    /// Products allowed to be loaded into compartment. All products are allowed when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub products: Option<Vec<String>>,
 }
 
 /// Specifies a vehicle profile.
@@ -505,6 +863,7 @@ pub struct VehicleProfile {
 
 /// Specifies routing matrix profile.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MatrixProfile {
     /// Profile name.
     pub name: String,
@@ -513,6 +872,57 @@ pub struct MatrixProfile {
     /// Default value is 10.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub speed: Option<f64>,
+
+    /// A required probability to arrive before time window end. Used only when routing matrix
+    /// has travel time variances.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_level: Option<f64>,
+}
+
+/// Specifies driver costs.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct DriverCosts {
+    /// Fixed is cost of driver usage per tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed: Option<f64>,
+
+    /// Cost per distance unit.
+    pub distance: f64,
+
+    /// Cost per time unit.
+    pub time: f64,
+}
+
+/// Specifies driver shift: a time window when driver is available.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct DriverShift {
+    /// Earliest time when driver can start to work.
+    pub earliest: String,
+
+    /// Latest time when driver has to finish work.
+    pub latest: String,
+}
+
+/// Specifies a driver type.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DriverType {
+    /// Driver type id.
+    pub type_id: String,
+
+    /// Concrete driver ids.
+    pub driver_ids: Vec<String>,
+
+    /// Driver costs.
+    pub costs: DriverCosts,
+
+    /// Driver shifts. If not specified, driver is available all the time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shifts: Option<Vec<DriverShift>>,
+
+    /// Driver skills.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skills: Option<Vec<String>>,
 }
 
 /// Specifies fleet.
@@ -520,6 +930,9 @@ pub struct MatrixProfile {
 pub struct Fleet {
     /// Vehicle types.
     pub vehicles: Vec<VehicleType>,
+    /// Driver types. If specified, each vehicle has to be driven by one of the drivers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drivers: Option<Vec<DriverType>>,
     /// Routing profiles.
     pub profiles: Vec<MatrixProfile>,
 }
@@ -544,6 +957,10 @@ pub enum Objective {
     #[serde(rename(deserialize = "minimize-duration", serialize = "minimize-duration"))]
     MinimizeDuration,
 
+    /// An objective to minimize total emissions.
+    #[serde(rename(deserialize = "minimize-emissions", serialize = "minimize-emissions"))]
+    MinimizeEmissions,
+
     /// An objective to minimize total tour amount.
     #[serde(rename(deserialize = "minimize-tours", serialize = "minimize-tours"))]
     MinimizeTours,
@@ -575,6 +992,15 @@ pub enum Objective {
         breaks: Option<f64>,
     },
 
+    /// An objective to minimize total penalty of jobs served by vehicles without preferred skills.
+    #[serde(rename(deserialize = "minimize-preference-penalty", serialize = "minimize-preference-penalty"))]
+    MinimizePreferencePenalty,
+
+    /// An objective to minimize amount of jobs served by vehicles different from ones specified
+    /// in preferred relations.
+    #[serde(rename(deserialize = "minimize-reassignments", serialize = "minimize-reassignments"))]
+    MinimizeReassignments,
+
     /// An objective to minimize sum of arrival times from all routes.
     #[serde(rename(deserialize = "minimize-arrival-time", serialize = "minimize-arrival-time"))]
     MinimizeArrivalTime,
@@ -685,6 +1111,14 @@ pub struct Matrix {
     /// Error codes to mark unreachable locations.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_codes: Option<Vec<i64>>,
+
+    /// Toll costs (or any other extra travel costs) which are added to transport cost as is.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub toll_costs: Option<Vec<f64>>,
+
+    /// Variances of travel times which specify their uncertainty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub travel_time_variances: Option<Vec<f64>>,
 }
 
 // endregion
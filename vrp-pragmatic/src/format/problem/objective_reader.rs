@@ -2,7 +2,7 @@
 #[path = "../../../tests/unit/format/problem/objective_reader_test.rs"]
 mod objective_reader_test;
 
-use crate::constraints::{AreaModule, TOTAL_VALUE_KEY, TOUR_ORDER_KEY};
+use crate::constraints::{AreaModule, PreferredSkillsModule, TOTAL_VALUE_KEY, TOUR_ORDER_KEY};
 use crate::core::models::common::IdDimension;
 use crate::format::problem::reader::{ApiProblem, ProblemProperties};
 use crate::format::problem::BalanceOptions;
@@ -17,6 +17,7 @@ use vrp_core::models::common::ValueDimension;
 use vrp_core::models::common::{MultiDimLoad, SingleDimLoad};
 use vrp_core::models::problem::Job;
 use vrp_core::models::problem::{ProblemObjective, Single, TargetConstraint, TargetObjective};
+use vrp_core::models::Lock;
 use vrp_core::solver::objectives::MinimizeArrivalTime as CoreMinimizeArrivalTime;
 use vrp_core::solver::objectives::TourOrder as CoreTourOrder;
 use vrp_core::solver::objectives::*;
@@ -25,6 +26,7 @@ pub fn create_objective(
     api_problem: &ApiProblem,
     constraint: &mut ConstraintPipeline,
     props: &ProblemProperties,
+    preferred_locks: &[Arc<Lock>],
 ) -> Arc<ProblemObjective> {
     Arc::new(match &api_problem.objectives {
         Some(objectives) => ProblemObjective::new(
@@ -36,6 +38,7 @@ pub fn create_objective(
                         MinimizeCost => core_objectives.push(TotalCost::minimize()),
                         MinimizeDistance => core_objectives.push(TotalDistance::minimize()),
                         MinimizeDuration => core_objectives.push(TotalDuration::minimize()),
+                        MinimizeEmissions => core_objectives.push(TotalEmissions::minimize()),
                         MinimizeTours => {
                             constraint.add_module(Arc::new(FleetUsageConstraintModule::new_minimized()));
                             core_objectives.push(Arc::new(TotalRoutes::new_minimized()))
@@ -59,6 +62,16 @@ pub fn create_objective(
                                 core_objectives.push(Arc::new(get_unassigned_objective(1.)))
                             }
                         }
+                        MinimizePreferencePenalty => {
+                            let (module, objective) = PreferredSkillsModule::new_objective();
+                            constraint.add_module(module);
+                            core_objectives.push(objective);
+                        }
+                        MinimizeReassignments => {
+                            let (module, objective) = AssignmentConsistency::new_minimized(preferred_locks);
+                            constraint.add_module(module);
+                            core_objectives.push(objective);
+                        }
                         MinimizeArrivalTime => {
                             constraint.add_module(Arc::new(FleetUsageConstraintModule::new_earliest()));
                             core_objectives.push(Arc::new(CoreMinimizeArrivalTime::default()))
@@ -124,6 +137,18 @@ pub fn create_objective(
                 objectives.insert(if props.max_job_value.is_some() { 2 } else { 1 }, vec![order_objective]);
             }
 
+            if props.has_preferred_skills {
+                let (preference_module, preference_objective) = PreferredSkillsModule::new_objective();
+                constraint.add_module(preference_module);
+                objectives.insert(objectives.len() - 1, vec![preference_objective]);
+            }
+
+            if !preferred_locks.is_empty() {
+                let (consistency_module, consistency_objective) = AssignmentConsistency::new_minimized(preferred_locks);
+                constraint.add_module(consistency_module);
+                objectives.insert(objectives.len() - 1, vec![consistency_objective]);
+            }
+
             ProblemObjective::new(objectives)
         }
     })
@@ -261,7 +286,7 @@ fn get_unassigned_job_estimate(job: &Job, break_value: f64, default_value: f64)
 
 fn get_default_order(single: &Single) -> OrderResult {
     match single.dimens.get_value::<String>("type").map(|v| v.as_str()) {
-        Some("break") | Some("reload") | Some("dispatch") => OrderResult::Ignored,
+        Some("break") | Some("reload") | Some("charging") | Some("dispatch") => OrderResult::Ignored,
         _ => OrderResult::Default,
     }
 }
@@ -15,11 +15,11 @@ mod objective_reader;
 mod clustering_reader;
 
 use self::clustering_reader::create_cluster_config;
-use self::fleet_reader::{create_transport_costs, read_fleet, read_travel_limits};
-use self::job_reader::{read_jobs_with_extra_locks, read_locks};
+use self::fleet_reader::{create_transport_costs, read_fleet, read_service_levels, read_travel_limits};
+use self::job_reader::{read_jobs_with_extra_locks, read_locks, read_preferred_locks};
 use self::objective_reader::create_objective;
 use crate::constraints::*;
-use crate::extensions::{get_route_modifier, OnlyVehicleActivityCost};
+use crate::extensions::{get_route_modifier, OnlyVehicleActivityCost, ServiceDurationActivityCost};
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::*;
 use crate::format::*;
@@ -115,13 +115,27 @@ pub struct ProblemProperties {
     has_multi_dimen_capacity: bool,
     has_breaks: bool,
     has_skills: bool,
+    has_preferred_skills: bool,
     has_unreachable_locations: bool,
     has_dispatch: bool,
     has_reloads: bool,
     has_order: bool,
     has_group: bool,
     has_compatibility: bool,
+    has_compartments: bool,
+    has_ride_time: bool,
     has_tour_size_limits: bool,
+    has_backhauls: bool,
+    has_driving_hours: bool,
+    has_linked_shifts: bool,
+    has_periodic_jobs: bool,
+    has_batteries: bool,
+    has_transfers: bool,
+    has_sync_jobs: bool,
+    has_depots: bool,
+    has_service_durations: bool,
+    has_load_costs: bool,
+    utc_offset: i64,
     max_job_value: Option<f64>,
     max_area_value: Option<f64>,
 }
@@ -157,6 +171,8 @@ pub fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
                 travel_times: approx_data[idx].0.clone(),
                 distances: approx_data[idx].1.clone(),
                 error_codes: None,
+                toll_costs: None,
+                travel_time_variances: None,
             }
         })
         .collect()
@@ -180,6 +196,8 @@ fn map_to_problem(
 ) -> Result<Problem, Vec<FormatError>> {
     ValidationContext::new(&api_problem, Some(&matrices), &coord_index).validate()?;
 
+    let completed_activities = get_completed_activities(&api_problem);
+    let api_problem = apply_tour_states(api_problem);
     let problem_props = get_problem_properties(&api_problem, &matrices);
 
     let coord_index = Arc::new(coord_index);
@@ -193,7 +211,11 @@ fn map_to_problem(
             format!("check matrix routing data: '{}'", err),
         )]
     })?;
-    let activity: Arc<dyn ActivityCost + Send + Sync> = Arc::new(OnlyVehicleActivityCost::default());
+    let activity: Arc<dyn ActivityCost + Send + Sync> = if api_problem.fleet.drivers.is_some() {
+        Arc::new(SimpleActivityCost::default())
+    } else {
+        Arc::new(OnlyVehicleActivityCost::default())
+    };
 
     let (transport, activity) = if reserved_times_index.is_empty() {
         (transport, activity)
@@ -214,6 +236,12 @@ fn map_to_problem(
             )?
     };
 
+    let activity: Arc<dyn ActivityCost + Send + Sync> = if problem_props.has_service_durations {
+        Arc::new(ServiceDurationActivityCost::new(activity))
+    } else {
+        activity
+    };
+
     // TODO pass random from outside as there might be need to have it initialized with seed
     //      at the moment, this random instance is used only by multi job permutation generator
     let random: Arc<dyn Random + Send + Sync> = Arc::new(DefaultRandom::default());
@@ -229,30 +257,42 @@ fn map_to_problem(
     );
     let locks = locks.into_iter().chain(read_locks(&api_problem, &job_index).into_iter()).collect::<Vec<_>>();
     let limits = read_travel_limits(&api_problem).unwrap_or_else(|| Arc::new(|_| (None, None)));
-    let mut constraint =
-        create_constraint_pipeline(&jobs, &fleet, transport.clone(), activity.clone(), &problem_props, &locks, limits);
+    let service_levels = read_service_levels(&api_problem).unwrap_or_else(|| Arc::new(|_| None));
+    let mut constraint = create_constraint_pipeline(
+        &jobs,
+        &fleet,
+        transport.clone(),
+        activity.clone(),
+        &problem_props,
+        &locks,
+        limits,
+        service_levels,
+    );
 
-    let objective = create_objective(&api_problem, &mut constraint, &problem_props);
+    let preferred_locks = read_preferred_locks(&api_problem, &job_index);
+    let objective = create_objective(&api_problem, &mut constraint, &problem_props, &preferred_locks);
     let constraint = Arc::new(constraint);
-    let extras = Arc::new(
-        create_extras(
-            &api_problem,
-            constraint.clone(),
-            random,
-            &problem_props,
-            job_index,
-            coord_index,
-            reserved_times_index,
-        )
-        .map_err(|err| {
-            // TODO make sure that error matches actual reason
-            vec![FormatError::new(
-                "E0002".to_string(),
-                "cannot create transport costs".to_string(),
-                format!("check clustering config: '{}'", err),
-            )]
-        })?,
-    );
+    let mut extras = create_extras(
+        &api_problem,
+        constraint.clone(),
+        random,
+        &problem_props,
+        job_index,
+        coord_index,
+        reserved_times_index,
+    )
+    .map_err(|err| {
+        // TODO make sure that error matches actual reason
+        vec![FormatError::new(
+            "E0002".to_string(),
+            "cannot create transport costs".to_string(),
+            format!("check clustering config: '{}'", err),
+        )]
+    })?;
+
+    if !completed_activities.is_empty() {
+        extras.insert("completed_activities".to_owned(), Arc::new(completed_activities));
+    }
 
     Ok(Problem {
         fleet: Arc::new(fleet),
@@ -262,7 +302,7 @@ fn map_to_problem(
         activity,
         transport,
         objective,
-        extras,
+        extras: Arc::new(extras),
     })
 }
 
@@ -314,6 +354,9 @@ fn read_reserved_times_index(api_problem: &ApiProblem, fleet: &CoreFleet) -> Res
         .collect()
 }
 
+/// Creates constraint pipeline. Modules are notified about state changes in the order they are added,
+/// so the order matters: dock, transfer and sync modules reschedule departures of routes, then rest
+/// module inserts rests into the final schedule and tightens latest arrival times of transport module.
 #[allow(clippy::too_many_arguments)]
 fn create_constraint_pipeline(
     jobs: &Jobs,
@@ -323,6 +366,7 @@ fn create_constraint_pipeline(
     props: &ProblemProperties,
     locks: &[Arc<Lock>],
     limits: TravelLimitFunc,
+    service_levels: ServiceLevelFunc,
 ) -> ConstraintPipeline {
     let mut constraint = ConstraintPipeline::default();
 
@@ -334,13 +378,36 @@ fn create_constraint_pipeline(
         transport.clone(),
         activity.clone(),
         limits,
+        service_levels,
         TIME_CONSTRAINT_CODE,
         DISTANCE_LIMIT_CONSTRAINT_CODE,
         DURATION_LIMIT_CONSTRAINT_CODE,
     )));
 
+    if props.has_ride_time {
+        constraint.add_module(Arc::new(RideTimeConstraintModule::new(
+            RIDE_TIME_CONSTRAINT_CODE,
+            activity.clone(),
+            transport.clone(),
+        )));
+    }
+
     add_capacity_module(&mut constraint, props, activity.clone(), transport.clone());
 
+    if props.has_compartments {
+        add_compartment_module(&mut constraint, props);
+    }
+
+    // NOTE charging module uses vehicle load, so it should be added after capacity module
+    if props.has_batteries {
+        add_charging_module(&mut constraint, props, activity.clone(), transport.clone());
+    }
+
+    // NOTE load cost module uses vehicle load, so it should be added after capacity module
+    if props.has_load_costs {
+        add_load_cost_module(&mut constraint, props, transport.clone());
+    }
+
     if props.has_breaks {
         constraint.add_module(Arc::new(BreakModule::new(activity.clone(), transport.clone(), BREAK_CONSTRAINT_CODE)));
     }
@@ -369,6 +436,52 @@ fn create_constraint_pipeline(
         add_tour_size_module(&mut constraint)
     }
 
+    if props.has_backhauls {
+        add_backhaul_module(&mut constraint, props);
+    }
+
+    if props.has_depots {
+        constraint.add_module(Arc::new(DockModule::new(
+            activity.clone(),
+            transport.clone(),
+            props.has_multi_dimen_capacity,
+            DOCK_CONSTRAINT_CODE,
+            DOCK_KEY,
+        )));
+    }
+
+    if props.has_transfers {
+        constraint.add_module(Arc::new(TransferModule::new(
+            activity.clone(),
+            transport.clone(),
+            TRANSFER_CONSTRAINT_CODE,
+            TRANSFER_KEY,
+        )));
+    }
+
+    if props.has_sync_jobs {
+        constraint.add_module(Arc::new(SyncModule::new(
+            activity.clone(),
+            transport.clone(),
+            SYNC_CONSTRAINT_CODE,
+            SYNC_KEY,
+        )));
+    }
+
+    if props.has_driving_hours || props.has_linked_shifts {
+        constraint.add_module(Arc::new(RestModule::new(
+            activity,
+            transport,
+            DRIVING_HOURS_CONSTRAINT_CODE,
+            SHIFT_LINK_CONSTRAINT_CODE,
+        )));
+    }
+
+    // NOTE periodic module updates state of all routes, so it is added last to avoid unnecessary recalculations
+    if props.has_periodic_jobs {
+        constraint.add_module(Arc::new(PeriodicModule::new(props.utc_offset, PERIODIC_CONSTRAINT_CODE, PERIODIC_KEY)));
+    }
+
     constraint
 }
 
@@ -402,6 +515,48 @@ fn add_capacity_module(
     });
 }
 
+fn add_charging_module(
+    constraint: &mut ConstraintPipeline,
+    props: &ProblemProperties,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+) {
+    let threshold = 0.2;
+    constraint.add_module(if props.has_multi_dimen_capacity {
+        Arc::new(ChargingModule::<MultiDimLoad>::new(activity, transport, threshold, BATTERY_CONSTRAINT_CODE))
+    } else {
+        Arc::new(ChargingModule::<SingleDimLoad>::new(activity, transport, threshold, BATTERY_CONSTRAINT_CODE))
+    });
+}
+
+fn add_load_cost_module(
+    constraint: &mut ConstraintPipeline,
+    props: &ProblemProperties,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+) {
+    constraint.add_module(if props.has_multi_dimen_capacity {
+        Arc::new(LoadCostModule::<MultiDimLoad>::new(transport))
+    } else {
+        Arc::new(LoadCostModule::<SingleDimLoad>::new(transport))
+    });
+}
+
+fn add_compartment_module(constraint: &mut ConstraintPipeline, props: &ProblemProperties) {
+    let threshold = 0.9;
+    constraint.add_module(match (props.has_reloads, props.has_multi_dimen_capacity) {
+        (true, true) => Arc::new(CompartmentConstraintModule::<MultiDimLoad>::new_with_multi_trip(
+            COMPARTMENT_CONSTRAINT_CODE,
+            Arc::new(ReloadMultiTrip::new(Box::new(move |capacity| *capacity * threshold))),
+        )),
+        (true, false) => Arc::new(CompartmentConstraintModule::<SingleDimLoad>::new_with_multi_trip(
+            COMPARTMENT_CONSTRAINT_CODE,
+            Arc::new(ReloadMultiTrip::new(Box::new(move |capacity| *capacity * threshold))),
+        )),
+        (false, true) => Arc::new(CompartmentConstraintModule::<MultiDimLoad>::new(COMPARTMENT_CONSTRAINT_CODE)),
+        (false, false) => Arc::new(CompartmentConstraintModule::<SingleDimLoad>::new(COMPARTMENT_CONSTRAINT_CODE)),
+    });
+}
+
 fn add_tour_size_module(constraint: &mut ConstraintPipeline) {
     constraint.add_module(Arc::new(TourSizeModule::new(
         Arc::new(|actor| actor.vehicle.dimens.get_value::<usize>("tour_size").cloned()),
@@ -409,6 +564,16 @@ fn add_tour_size_module(constraint: &mut ConstraintPipeline) {
     )));
 }
 
+fn add_backhaul_module(constraint: &mut ConstraintPipeline, props: &ProblemProperties) {
+    let resolver: BackhaulResolver = Arc::new(|actor| actor.vehicle.dimens.get_value::<bool>("backhauls").is_some());
+
+    constraint.add_module(if props.has_multi_dimen_capacity {
+        Arc::new(BackhaulModule::new::<MultiDimLoad>(resolver, BACKHAUL_CONSTRAINT_CODE))
+    } else {
+        Arc::new(BackhaulModule::new::<SingleDimLoad>(resolver, BACKHAUL_CONSTRAINT_CODE))
+    });
+}
+
 fn create_extras(
     api_problem: &ApiProblem,
     constraint: Arc<ConstraintPipeline>,
@@ -445,14 +610,16 @@ fn parse_time_window(tw: &[String]) -> TimeWindow {
 
 fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> ProblemProperties {
     let has_unreachable_locations = matrices.iter().any(|m| m.error_codes.is_some());
-    let has_multi_dimen_capacity = api_problem.fleet.vehicles.iter().any(|t| t.capacity.len() > 1)
-        || api_problem.plan.jobs.iter().any(|job| {
-            job.pickups
-                .iter()
-                .chain(job.deliveries.iter())
-                .flat_map(|tasks| tasks.iter())
-                .any(|task| task.demand.as_ref().map_or(false, |d| d.len() > 1))
-        });
+    let has_multi_dimen_capacity = api_problem.fleet.vehicles.iter().any(|t| {
+        t.capacity.len() > 1
+            || t.compartments.iter().flat_map(|compartments| compartments.iter()).any(|c| c.capacity.len() > 1)
+    }) || api_problem.plan.jobs.iter().any(|job| {
+        job.pickups
+            .iter()
+            .chain(job.deliveries.iter())
+            .flat_map(|tasks| tasks.iter())
+            .any(|task| task.demand.as_ref().map_or(false, |d| d.len() > 1))
+    });
     let has_breaks = api_problem
         .fleet
         .vehicles
@@ -461,6 +628,8 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .any(|shift| shift.breaks.as_ref().map_or(false, |b| !b.is_empty()));
 
     let has_skills = api_problem.plan.jobs.iter().any(|job| job.skills.is_some());
+    let has_preferred_skills =
+        api_problem.plan.jobs.iter().any(|job| job.skills.as_ref().map_or(false, |skills| skills.preferred.is_some()));
     let max_job_value = api_problem
         .plan
         .jobs
@@ -501,20 +670,64 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
 
     let has_group = api_problem.plan.jobs.iter().any(|job| job.group.is_some());
     let has_compatibility = api_problem.plan.jobs.iter().any(|job| job.compatibility.is_some());
+    let has_compartments = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .any(|t| t.compartments.as_ref().map_or(false, |compartments| !compartments.is_empty()));
+    let has_ride_time = api_problem.plan.jobs.iter().any(|job| job.max_ride_time.is_some());
     let has_tour_size_limits =
         api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.tour_size.is_some()));
+    let has_backhauls = api_problem.fleet.vehicles.iter().any(|vehicle| vehicle.backhauls.unwrap_or(false));
+    let has_driving_hours =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.driving_hours.is_some()));
+    let has_linked_shifts =
+        api_problem.fleet.vehicles.iter().any(|t| t.shifts.iter().any(|s| s.start.location.is_none()));
+    let has_batteries = api_problem.fleet.vehicles.iter().any(|vehicle| vehicle.battery.is_some());
+    let has_periodic_jobs = api_problem.plan.jobs.iter().any(|job| job.visits.is_some());
+    let has_transfers = api_problem.plan.hubs.as_ref().map_or(false, |hubs| !hubs.is_empty())
+        && api_problem.plan.jobs.iter().any(|job| job.hub.is_some());
+    let has_sync_jobs = api_problem.plan.jobs.iter().any(|job| job.sync.is_some());
+    let has_depots = api_problem.plan.depots.as_ref().map_or(false, |depots| !depots.is_empty());
+    let has_service_durations = api_problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(get_job_tasks)
+        .flat_map(|task| task.places.iter())
+        .any(|place| place.durations.is_some());
+    let has_load_costs = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .any(|vehicle| vehicle.costs.full_load_distance.is_some() || vehicle.emissions.is_some());
+    let (_, utc_offset) = get_planning_horizon(api_problem);
 
     ProblemProperties {
         has_multi_dimen_capacity,
         has_breaks,
         has_skills,
+        has_preferred_skills,
         has_unreachable_locations,
         has_dispatch,
         has_reloads,
         has_order,
         has_group,
         has_compatibility,
+        has_compartments,
+        has_ride_time,
         has_tour_size_limits,
+        has_backhauls,
+        has_driving_hours,
+        has_linked_shifts,
+        has_periodic_jobs,
+        has_batteries,
+        has_transfers,
+        has_sync_jobs,
+        has_depots,
+        has_service_durations,
+        has_load_costs,
+        utc_offset,
         max_job_value,
         max_area_value,
     }
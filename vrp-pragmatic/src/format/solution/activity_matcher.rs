@@ -2,11 +2,10 @@ use crate::format::problem::VehicleBreak;
 use crate::format::problem::{Problem as FormatProblem, VehicleRequiredBreakTime};
 use crate::format::solution::{Activity as FormatActivity, Schedule as FormatSchedule, Tour as FormatTour};
 use crate::format::solution::{PointStop, TransitStop};
-use crate::format::{CoordIndex, JobIndex};
+use crate::format::{get_sync_id, get_transfer_id, get_visit_id, CoordIndex, JobIndex};
 use crate::parse_time;
 use hashbrown::HashSet;
 use std::cmp::Ordering;
-use std::iter::once;
 use std::sync::Arc;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Job, Single};
@@ -17,7 +16,7 @@ use vrp_core::utils::compare_floats;
 pub(crate) struct JobInfo(pub Job, pub Arc<Single>, pub Place, pub TimeWindow);
 
 /// Tries to match given activity to core job models. None is returned in case of
-/// non-job activity (departure, arrival).
+/// non-job activity (departure, arrival, rest).
 pub(crate) fn try_match_point_job(
     tour: &FormatTour,
     stop: &PointStop,
@@ -37,37 +36,35 @@ pub(crate) fn try_match_point_job(
     };
 
     match activity.activity_type.as_str() {
-        "departure" | "arrival" => Ok(None),
+        "departure" | "arrival" | "rest" | "overnight" => Ok(None),
         "pickup" | "delivery" | "replacement" | "service" => {
-            let job =
-                job_index.get(&activity.job_id).ok_or_else(|| format!("unknown job id: '{}'", activity.job_id))?;
-            let singles: Box<dyn Iterator<Item = &Arc<_>>> = match job {
-                Job::Single(single) => Box::new(once(single)),
-                Job::Multi(multi) => {
-                    let tags = multi
-                        .jobs
-                        .iter()
-                        .filter_map(|single| single.dimens.get_value::<Vec<(usize, String)>>("tags"))
-                        .flat_map(|tags| tags.iter().map(|(_, tag)| tag))
-                        .collect::<HashSet<_>>();
-                    if tags.len() < multi.jobs.len() {
-                        return Err(format!(
-                            "cannot check multi job without unique tags, check '{}' job",
-                            activity.job_id
-                        ));
-                    }
-
-                    Box::new(multi.jobs.iter())
-                }
+            let job_id = activity
+                .visit_index
+                .map_or_else(|| activity.job_id.clone(), |visit_index| get_visit_id(&activity.job_id, visit_index));
+            let candidates = match job_index.get(&job_id) {
+                Some(job) => get_job_candidates(job, &activity.job_id)?,
+                None => get_sync_candidates(job_index, &job_id)?,
             };
-            let (single, place) = singles
-                .filter_map(|single| match_place(single, true, &ctx).map(|place| (single, place)))
+            let (job, single, place) = candidates
+                .into_iter()
+                .filter_map(|(job, single)| match_place(single, true, &ctx).map(|place| (job, single, place)))
                 .next()
                 .ok_or_else(|| format!("cannot match job '{}'", activity.job_id))?;
 
             Ok(Some(JobInfo(job.clone(), single.clone(), place, ctx.time)))
         }
-        "break" | "dispatch" | "reload" => Ok(Some(
+        "transfer" => {
+            let job_id = get_transfer_id(&activity.job_id);
+            let (job, single) = job_index
+                .get(&job_id)
+                .and_then(|job| job.as_single().map(|single| (job, single)))
+                .ok_or_else(|| format!("unknown transfer of job id: '{}'", activity.job_id))?;
+            let place =
+                match_place(single, true, &ctx).ok_or_else(|| format!("cannot match job '{}'", activity.job_id))?;
+
+            Ok(Some(JobInfo(job.clone(), single.clone(), place, ctx.time)))
+        }
+        "break" | "dispatch" | "reload" | "charging" => Ok(Some(
             (1..)
                 .map(|idx| format!("{}_{}_{}_{}", tour.vehicle_id, activity.activity_type, tour.shift_index, idx))
                 .map(|job_id| job_index.get(&job_id))
@@ -83,6 +80,48 @@ pub(crate) fn try_match_point_job(
     }
 }
 
+fn get_job_candidates<'a>(job: &'a Job, job_id: &str) -> Result<Vec<(&'a Job, &'a Arc<Single>)>, String> {
+    match job {
+        Job::Single(single) => Ok(vec![(job, single)]),
+        Job::Multi(multi) => {
+            ensure_unique_tags(multi.jobs.iter(), job_id)?;
+            Ok(multi.jobs.iter().map(|single| (job, single)).collect())
+        }
+    }
+}
+
+fn get_sync_candidates<'a>(job_index: &'a JobIndex, job_id: &str) -> Result<Vec<(&'a Job, &'a Arc<Single>)>, String> {
+    let candidates = (0..)
+        .map(|index| job_index.get(&get_sync_id(job_id, index)))
+        .take_while(|job| job.is_some())
+        .flatten()
+        .filter_map(|job| job.as_single().map(|single| (job, single)))
+        .collect::<Vec<_>>();
+
+    if candidates.is_empty() {
+        return Err(format!("unknown job id: '{}'", job_id));
+    }
+
+    ensure_unique_tags(candidates.iter().map(|(_, single)| *single), job_id)?;
+
+    Ok(candidates)
+}
+
+fn ensure_unique_tags<'a>(singles: impl Iterator<Item = &'a Arc<Single>>, job_id: &str) -> Result<(), String> {
+    let (size, tags) = singles.fold((0, HashSet::new()), |(size, mut tags), single| {
+        if let Some(single_tags) = single.dimens.get_value::<Vec<(usize, String)>>("tags") {
+            tags.extend(single_tags.iter().map(|(_, tag)| tag.clone()));
+        }
+        (size + 1, tags)
+    });
+
+    if tags.len() < size {
+        Err(format!("cannot check multi job without unique tags, check '{}' job", job_id))
+    } else {
+        Ok(())
+    }
+}
+
 /// Tries to return activity from transit stop to a break.
 pub(crate) fn try_match_transit_activity(
     problem: &FormatProblem,
@@ -153,29 +192,30 @@ fn match_place<'a>(single: &Arc<Single>, is_job_activity: bool, activity_ctx: &'
         (true, true, _) | (true, false, false) => single
             .places
             .iter()
-            .find(|place| {
+            .enumerate()
+            .find(|(_, place)| {
                 let is_same_location = place.location.map_or(true, |l| l == activity_ctx.location);
                 let is_proper_time =
                     place.times.iter().any(|time| time.intersects(activity_ctx.route_start_time, &activity_ctx.time));
 
                 is_same_location && is_proper_time
             })
-            .map(|place| {
+            .map(|(idx, place)| {
                 // NOTE search for the latest occurrence assuming that times are sorted
-                let time = place
+                let time_idx = place
                     .times
                     .iter()
-                    .rfind(|time| time.intersects(activity_ctx.route_start_time, &activity_ctx.time))
+                    .rposition(|time| time.intersects(activity_ctx.route_start_time, &activity_ctx.time))
                     .unwrap();
 
-                let time = match time {
+                let time = match &place.times[time_idx] {
                     TimeSpan::Window(tw) => tw.clone(),
                     TimeSpan::Offset(_) => {
                         TimeWindow::new(activity_ctx.time.end - place.duration, activity_ctx.time.end)
                     }
                 };
 
-                Place { location: activity_ctx.location, duration: place.duration, time }
+                Place { idx, time_idx, location: activity_ctx.location, duration: place.duration, time }
             }),
         _ => None,
     }
@@ -228,7 +268,7 @@ pub(crate) fn get_extra_time(stop: &PointStop, activity: &FormatActivity, place:
 
 fn get_job_id(single: &Arc<Single>) -> String {
     Activity {
-        place: Place { location: 0, duration: 0.0, time: TimeWindow::new(0., 0.) },
+        place: Place { idx: 0, time_idx: 0, location: 0, duration: 0.0, time: TimeWindow::new(0., 0.) },
         schedule: Schedule { arrival: 0.0, departure: 0.0 },
         job: Some(single.clone()),
         commute: None,
@@ -9,6 +9,14 @@ impl Add for Statistic {
             cost: self.cost + rhs.cost,
             distance: self.distance + rhs.distance,
             duration: self.duration + rhs.duration,
+            emissions: match (self.emissions, rhs.emissions) {
+                (None, None) => None,
+                (left, right) => Some(left.unwrap_or(0.) + right.unwrap_or(0.)),
+            },
+            tolls: match (self.tolls, rhs.tolls) {
+                (None, None) => None,
+                (left, right) => Some(left.unwrap_or(0.) + right.unwrap_or(0.)),
+            },
             times: Timing {
                 driving: self.times.driving + rhs.times.driving,
                 serving: self.times.serving + rhs.times.serving,
@@ -4,7 +4,7 @@ mod geo_serializer_test;
 
 use super::Solution;
 use crate::format::solution::{Activity, PointStop, Tour, UnassignedJob};
-use crate::format::{get_coord_index, get_job_index, CoordIndex, Location};
+use crate::format::{get_coord_index, get_job_index, get_jobs_by_id, CoordIndex, Location};
 use serde::Serialize;
 use std::cmp::Ordering;
 use std::collections::HashMap;
@@ -121,7 +121,7 @@ fn get_marker_symbol(stop: &PointStop) -> String {
     let contains_activity_type =
         |activity_type: &&str| stop.activities.iter().any(|activity| activity.activity_type == *activity_type);
     match (
-        ["departure", "dispatch", "reload", "arrival"].iter().any(contains_activity_type),
+        ["departure", "dispatch", "reload", "charging", "transfer", "arrival"].iter().any(contains_activity_type),
         contains_activity_type(&"break"),
     ) {
         (true, _) => "warehouse",
@@ -316,8 +316,9 @@ fn create_geojson_solution(problem: &Problem, solution: &Solution) -> Result<Fea
         .flat_map(|unassigned| unassigned.iter())
         .enumerate()
         .map(|(idx, unassigned_job)| {
-            let job = job_index
-                .get(&unassigned_job.job_id)
+            let job = get_jobs_by_id(job_index, &unassigned_job.job_id)
+                .first()
+                .cloned()
                 .ok_or_else(|| invalid_data(format!("cannot find job: {}", unassigned_job.job_id).as_str()))?;
             let color = get_color(idx);
             get_unassigned_points(coord_index, unassigned_job, job, color.as_str())
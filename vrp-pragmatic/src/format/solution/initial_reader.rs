@@ -2,12 +2,13 @@
 #[path = "../../../tests/unit/format/solution/initial_reader_test.rs"]
 mod initial_reader_test;
 
+use crate::format::problem::{CompletedActivities, CompletedActivity};
 use crate::format::solution::activity_matcher::{try_match_point_job, JobInfo};
 use crate::format::solution::Activity as FormatActivity;
 use crate::format::solution::Stop as FormatStop;
 use crate::format::solution::Tour as FormatTour;
 use crate::format::solution::{deserialize_solution, map_reason_code};
-use crate::format::{get_coord_index, get_job_index, CoordIndex, JobIndex};
+use crate::format::{get_coord_index, get_job_index, get_jobs_by_id, CoordIndex, JobIndex};
 use crate::parse_time;
 use hashbrown::{HashMap, HashSet};
 use std::io::{BufReader, Read};
@@ -17,11 +18,13 @@ use vrp_core::models::problem::{Actor, Job, Single};
 use vrp_core::models::solution::Tour as CoreTour;
 use vrp_core::models::solution::{Activity, Place, Registry, Route};
 use vrp_core::prelude::*;
+use vrp_core::rosomaxa::utils::CollectGroupBy;
 
-type ActorKey = (String, String, usize);
+type ActorKey = (String, String, usize, Option<String>);
 
 /// Reads initial solution from buffer.
-/// NOTE: Solution feasibility is not checked.
+/// NOTE: Solution feasibility is not checked. When problem has tours in progress, completed activities and
+/// activities of jobs which are not present in the plan anymore are skipped.
 pub fn read_init_solution<R: Read>(
     solution: BufReader<R>,
     problem: Arc<Problem>,
@@ -32,23 +35,38 @@ pub fn read_init_solution<R: Read>(
     let mut registry = Registry::new(&problem.fleet, random);
     let mut added_jobs = HashSet::default();
 
-    let actor_index = registry.all().map(|actor| (get_actor_key(actor.as_ref()), actor)).collect::<HashMap<_, _>>();
+    let actor_index = registry.all().map(|actor| (get_actor_key(actor.as_ref()), actor)).collect_group_by();
     let coord_index = get_coord_index(problem.as_ref());
     let job_index = get_job_index(problem.as_ref());
+    let completed_activities =
+        problem.extras.get("completed_activities").and_then(|s| s.downcast_ref::<CompletedActivities>());
 
     let routes =
         solution.tours.iter().try_fold::<_, _, Result<_, String>>(Vec::<_>::default(), |mut routes, tour| {
-            let actor_key = (tour.vehicle_id.clone(), tour.type_id.clone(), tour.shift_index);
-            let actor =
-                actor_index.get(&actor_key).ok_or_else(|| format!("cannot find vehicle for {:?}", actor_key))?.clone();
+            let actor_key = (tour.vehicle_id.clone(), tour.type_id.clone(), tour.shift_index, tour.driver_id.clone());
+            let actor = get_actor(&actor_index, &actor_key, tour)?;
             registry.use_actor(&actor);
 
             let mut core_route = create_core_route(actor, tour)?;
+            let mut completed = get_tour_completed_activities(completed_activities, tour);
 
             tour.stops.iter().try_for_each(|stop| {
-                stop.activities().iter().try_for_each::<_, Result<_, String>>(|activity| {
-                    try_insert_activity(&mut core_route, tour, stop, activity, job_index, coord_index, &mut added_jobs)
-                })
+                stop.activities()
+                    .iter()
+                    .filter(|activity| {
+                        completed_activities.is_none() || !is_skipped_activity(activity, job_index, &mut completed)
+                    })
+                    .try_for_each::<_, Result<_, String>>(|activity| {
+                        try_insert_activity(
+                            &mut core_route,
+                            tour,
+                            stop,
+                            activity,
+                            job_index,
+                            coord_index,
+                            &mut added_jobs,
+                        )
+                    })
             })?;
 
             routes.push(core_route);
@@ -59,18 +77,22 @@ pub fn read_init_solution<R: Read>(
     let mut unassigned = solution.unassigned.unwrap_or_default().iter().try_fold::<Vec<_>, _, Result<_, String>>(
         Default::default(),
         |mut acc, unassigned_job| {
-            let job = job_index
-                .get(&unassigned_job.job_id)
-                .cloned()
-                .ok_or_else(|| format!("cannot get job id for: {:?}", unassigned_job))?;
+            let jobs = get_jobs_by_id(job_index, &unassigned_job.job_id);
+            if jobs.is_empty() && completed_activities.is_some() {
+                return Ok(acc);
+            } else if jobs.is_empty() {
+                return Err(format!("cannot get job id for: {:?}", unassigned_job));
+            }
             let code = unassigned_job
                 .reasons
                 .first()
                 .map(|reason| map_reason_code(&reason.code))
                 .ok_or_else(|| format!("cannot get reason for: {:?}", unassigned_job))?;
 
-            added_jobs.insert(job.clone());
-            acc.push((job, code));
+            // NOTE periodic job can be partially assigned, so only its not added visits are unassigned
+            let jobs = jobs.into_iter().filter(|job| !added_jobs.contains(*job)).cloned().collect::<Vec<_>>();
+            added_jobs.extend(jobs.iter().cloned());
+            acc.extend(jobs.into_iter().map(|job| (job, code)));
 
             Ok(acc)
         },
@@ -94,6 +116,11 @@ fn try_insert_activity(
         return Err("commute property in initial solution is not supported".to_string());
     }
 
+    // NOTE mandatory rests and overnight stays are inserted by corresponding modules
+    if activity.activity_type == "rest" || activity.activity_type == "overnight" {
+        return Ok(());
+    }
+
     let stop = match stop {
         FormatStop::Transit(_) => return Err("transit property in initial solution is not yet supported".to_string()),
         FormatStop::Point(stop) => stop,
@@ -110,6 +137,38 @@ fn try_insert_activity(
     Ok(())
 }
 
+fn get_tour_completed_activities<'a>(
+    completed_activities: Option<&'a CompletedActivities>,
+    tour: &FormatTour,
+) -> Vec<&'a CompletedActivity> {
+    completed_activities
+        .and_then(|completed| completed.get(&(tour.vehicle_id.clone(), tour.shift_index)))
+        .iter()
+        .flat_map(|activities| activities.iter())
+        .collect()
+}
+
+/// Checks whether activity is already completed or belongs to the job which is not in the plan anymore.
+fn is_skipped_activity(
+    activity: &FormatActivity,
+    job_index: &JobIndex,
+    completed: &mut Vec<&CompletedActivity>,
+) -> bool {
+    let position = completed.iter().position(|completed| {
+        completed.job_id == activity.job_id
+            && completed.activity_type == activity.activity_type
+            && completed.tag.as_ref().map_or(true, |tag| activity.job_tag.as_ref() == Some(tag))
+    });
+
+    if let Some(position) = position {
+        completed.remove(position);
+        true
+    } else {
+        matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "replacement" | "service")
+            && get_jobs_by_id(job_index, &activity.job_id).is_empty()
+    }
+}
+
 fn get_actor_key(actor: &Actor) -> ActorKey {
     let dimens = &actor.vehicle.dimens;
 
@@ -117,7 +176,26 @@ fn get_actor_key(actor: &Actor) -> ActorKey {
     let type_id = dimens.get_value::<String>("type_id").cloned().expect("cannot get type id!");
     let shift_index = dimens.get_value::<usize>("shift_index").cloned().expect("cannot get shift index!");
 
-    (vehicle_id, type_id, shift_index)
+    let driver_id = actor.driver.dimens.get_id().cloned();
+
+    (vehicle_id, type_id, shift_index, driver_id)
+}
+
+fn get_actor(
+    actor_index: &HashMap<ActorKey, Vec<Arc<Actor>>>,
+    actor_key: &ActorKey,
+    tour: &FormatTour,
+) -> Result<Arc<Actor>, String> {
+    let actors = actor_index.get(actor_key).ok_or_else(|| format!("cannot find vehicle for {:?}", actor_key))?;
+
+    // NOTE the same vehicle and driver pair can be used within different driver shifts
+    let departure = tour.stops.first().map(|stop| parse_time(&stop.schedule().departure));
+    actors
+        .iter()
+        .find(|actor| departure.map_or(true, |departure| actor.detail.time.contains(departure)))
+        .or_else(|| actors.first())
+        .cloned()
+        .ok_or_else(|| format!("cannot find vehicle for {:?}", actor_key))
 }
 
 fn create_core_route(actor: Arc<Actor>, format_tour: &FormatTour) -> Result<Route, String> {
@@ -146,6 +224,11 @@ fn create_core_route(actor: Arc<Actor>, format_tour: &FormatTour) -> Result<Rout
 
     set_activity_time(start_stop, start_activity, core_start)?;
 
+    // NOTE tour in progress cannot be started before the current time
+    if let Some(earliest) = actor.detail.start.as_ref().and_then(|start| start.time.earliest) {
+        core_start.schedule.departure = core_start.schedule.departure.max(earliest);
+    }
+
     if core_tour.end().is_some() {
         let end_stop = format_tour.stops.last().unwrap();
         let end_activity = end_stop.activities().first().ok_or_else(|| "end stop has no activities".to_string())?;
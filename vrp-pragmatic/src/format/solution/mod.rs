@@ -43,6 +43,20 @@ fn map_code_reason(code: i32) -> (&'static str, &'static str) {
         COMPATIBILITY_CONSTRAINT_CODE => {
             ("COMPATIBILITY_CONSTRAINT", "cannot be assigned due to compatibility constraint")
         }
+        COMPARTMENT_CONSTRAINT_CODE => {
+            ("COMPARTMENT_CONSTRAINT", "cannot be assigned due to vehicle compartments constraint")
+        }
+        RIDE_TIME_CONSTRAINT_CODE => ("RIDE_TIME_CONSTRAINT", "cannot be assigned due to max ride time constraint"),
+        DRIVING_HOURS_CONSTRAINT_CODE => {
+            ("DRIVING_HOURS_CONSTRAINT", "cannot be assigned due to driving hours constraint of vehicle")
+        }
+        SHIFT_LINK_CONSTRAINT_CODE => ("SHIFT_LINK_CONSTRAINT", "cannot be assigned due to linked shifts of vehicle"),
+        PERIODIC_CONSTRAINT_CODE => ("PERIODIC_CONSTRAINT", "cannot be visited on allowed days with required spacing"),
+        BATTERY_CONSTRAINT_CODE => ("BATTERY_CONSTRAINT", "cannot be assigned due to battery capacity of vehicle"),
+        TRANSFER_CONSTRAINT_CODE => ("TRANSFER_CONSTRAINT", "cannot be transferred between echelons at the hub"),
+        SYNC_CONSTRAINT_CODE => ("SYNC_CONSTRAINT", "cannot be served by several vehicles simultaneously"),
+        DOCK_CONSTRAINT_CODE => ("DOCK_CONSTRAINT", "cannot be assigned due to depot docks availability"),
+        BACKHAUL_CONSTRAINT_CODE => ("BACKHAUL_CONSTRAINT", "cannot be assigned due to backhaul order of vehicle"),
         _ => ("NO_REASON_FOUND", "unknown"),
     }
 }
@@ -63,6 +77,16 @@ fn map_reason_code(reason: &str) -> i32 {
         "TOUR_ORDER_CONSTRAINT" => TOUR_ORDER_CONSTRAINT_CODE,
         "GROUP_CONSTRAINT" => GROUP_CONSTRAINT_CODE,
         "COMPATIBILITY_CONSTRAINT" => COMPATIBILITY_CONSTRAINT_CODE,
+        "COMPARTMENT_CONSTRAINT" => COMPARTMENT_CONSTRAINT_CODE,
+        "RIDE_TIME_CONSTRAINT" => RIDE_TIME_CONSTRAINT_CODE,
+        "DRIVING_HOURS_CONSTRAINT" => DRIVING_HOURS_CONSTRAINT_CODE,
+        "SHIFT_LINK_CONSTRAINT" => SHIFT_LINK_CONSTRAINT_CODE,
+        "PERIODIC_CONSTRAINT" => PERIODIC_CONSTRAINT_CODE,
+        "BATTERY_CONSTRAINT" => BATTERY_CONSTRAINT_CODE,
+        "TRANSFER_CONSTRAINT" => TRANSFER_CONSTRAINT_CODE,
+        "SYNC_CONSTRAINT" => SYNC_CONSTRAINT_CODE,
+        "DOCK_CONSTRAINT" => DOCK_CONSTRAINT_CODE,
+        "BACKHAUL_CONSTRAINT" => BACKHAUL_CONSTRAINT_CODE,
         _ => -1,
     }
 }
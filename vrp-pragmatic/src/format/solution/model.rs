@@ -35,6 +35,12 @@ pub struct Statistic {
     pub distance: i64,
     /// Total duration.
     pub duration: i64,
+    /// Total emissions. Present only when vehicles have emissions specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emissions: Option<f64>,
+    /// Total toll costs. Present only when routing matrix has toll costs which are used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tolls: Option<f64>,
     /// Timing statistic.
     pub times: Timing,
 }
@@ -100,6 +106,15 @@ pub struct Activity {
     /// Commute information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub commute: Option<Commute>,
+    /// Lateness in seconds: it is set when activity is started after its soft time window end.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lateness: Option<i64>,
+    /// Id of vehicle compartment used to load job demand.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compartment: Option<String>,
+    /// Visit index of periodic job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visit_index: Option<usize>,
 }
 
 /// A stop is a place where vehicle is supposed to do some work.
@@ -179,6 +194,14 @@ pub struct PointStop {
     /// Parking time.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parking: Option<Interval>,
+    /// Battery charge level after departure from this stop. Used only for electric vehicles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge: Option<f64>,
+    /// A probability to arrive at the stop before end of time windows of its activities. Present only
+    /// when routing matrix has travel time variances.
+    #[serde(rename = "onTimeProbability")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_time_probability: Option<f64>,
     /// Activities performed at the stop.
     pub activities: Vec<Activity>,
 }
@@ -194,6 +217,9 @@ pub struct Tour {
     /// Shift index.
     #[serde(default)]
     pub shift_index: usize,
+    /// Driver id. It is set only when fleet has drivers specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver_id: Option<String>,
     /// List of stops.
     pub stops: Vec<Stop>,
     /// Tour statistic.
@@ -234,6 +260,50 @@ pub enum Violation {
     },
 }
 
+/// Specifies a tour which takes part in transfer.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferTour {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Index of the shift.
+    pub shift_index: usize,
+}
+
+/// Represents a dependency between two tours created by a job transferred at the hub.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Transfer {
+    /// Id of the two-echelon job.
+    pub job_id: String,
+    /// Id of the transfer hub.
+    pub hub_id: String,
+    /// A first echelon tour which brings goods to the hub.
+    pub from: TransferTour,
+    /// A second echelon tour which delivers goods from the hub.
+    pub to: TransferTour,
+    /// A time when goods are available at the hub in RFC3339 format.
+    pub time: String,
+}
+
+/// Represents usage of the depot dock by the tour.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DockAssignment {
+    /// Id of the depot.
+    pub depot_id: String,
+    /// Index of the dock.
+    pub dock: usize,
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Index of the shift.
+    pub shift_index: usize,
+    /// Dock operation type: loading or unloading.
+    pub operation: String,
+    /// A time when the dock is occupied.
+    pub time: Interval,
+}
+
 /// Encapsulates different measurements regarding algorithm evaluation.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 pub struct Metrics {
@@ -309,6 +379,14 @@ pub struct Solution {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub violations: Option<Vec<Violation>>,
 
+    /// List of transfers between tours at hubs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfers: Option<Vec<Transfer>>,
+
+    /// List of depot dock assignments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub docks: Option<Vec<DockAssignment>>,
+
     /// An extra information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub extras: Option<Extras>,
@@ -2,24 +2,31 @@
 #[path = "../../../tests/unit/format/solution/writer_test.rs"]
 mod writer_test;
 
+use crate::constraints::{get_battery, get_charging_rate, get_driving_rests, get_single_transfer, DrivingRest};
+use crate::constraints::{get_dock_tours, schedule_docks, DockOperation, JobVisit, TransferEchelon};
+use crate::extensions::get_actor_service_duration;
 use crate::format::coord_index::CoordIndex;
 use crate::format::solution::activity_matcher::get_job_tag;
 use crate::format::solution::model::Timing;
 use crate::format::solution::*;
 use crate::format::*;
 use crate::{format_time, parse_time};
+use hashbrown::HashMap;
 use std::cmp::Ordering;
 use std::io::{BufWriter, Write};
-use vrp_core::construction::constraints::route_intervals;
+use std::iter::once;
+use vrp_core::construction::constraints::{assign_compartments, route_intervals, LoadCosts, LoadCostsDimension};
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Multi, TravelTime};
+use vrp_core::models::problem::{estimate_lateness, Multi, TransportCost, TravelTime};
 use vrp_core::models::solution::{Activity, Route};
 use vrp_core::models::{Problem, Solution};
 use vrp_core::prelude::compare_floats;
+use vrp_core::rosomaxa::algorithms::math::get_normal_cdf;
 use vrp_core::rosomaxa::evolution::TelemetryMetrics;
 use vrp_core::solver::processing::VicinityDimension;
 
 type ApiActivity = crate::format::solution::model::Activity;
+type ApiLocation = crate::format::Location;
 type ApiSolution = crate::format::solution::model::Solution;
 type ApiSchedule = crate::format::solution::model::Schedule;
 type ApiMetrics = crate::format::solution::model::Metrics;
@@ -92,6 +99,15 @@ impl Leg {
     }
 }
 
+/// Keeps overnight stay of vehicle between linked shifts.
+struct OvernightStay {
+    /// An index of activity after which vehicle stays.
+    pub leg_index: usize,
+    /// A separate stay location or none if vehicle stays at activity's stop.
+    pub location: Option<DomainLocation>,
+    pub schedule: DomainSchedule,
+}
+
 /// Creates solution.
 pub fn create_solution(problem: &Problem, solution: &Solution, metrics: Option<&TelemetryMetrics>) -> ApiSolution {
     let coord_index = get_coord_index(problem);
@@ -107,10 +123,12 @@ pub fn create_solution(problem: &Problem, solution: &Solution, metrics: Option<&
 
     let unassigned = create_unassigned(solution);
     let violations = create_violations(solution);
+    let transfers = create_transfers(solution);
+    let docks = create_docks(problem, solution);
 
     let extras = create_extras(solution, metrics);
 
-    ApiSolution { statistic, tours, unassigned, violations, extras }
+    ApiSolution { statistic, tours, unassigned, violations, transfers, docks, extras }
 }
 
 fn create_tour(
@@ -126,17 +144,38 @@ fn create_tour(
 
     let actor = route.actor.as_ref();
     let vehicle = actor.vehicle.as_ref();
+    let driver = actor.driver.as_ref();
     let transport = problem.transport.as_ref();
 
     let mut tour = Tour {
         vehicle_id: vehicle.dimens.get_id().unwrap().clone(),
         type_id: vehicle.dimens.get_value::<String>("type_id").unwrap().to_string(),
         shift_index: *vehicle.dimens.get_value::<usize>("shift_index").unwrap(),
+        driver_id: driver.dimens.get_id().cloned(),
         stops: vec![],
         statistic: Statistic::default(),
     };
 
     let intervals = route_intervals(route, Box::new(|a| get_activity_type(a).map_or(false, |t| t == "reload")));
+    let compartments = get_compartments(route, is_multi_dimen);
+    let on_time_probabilities = get_on_time_probabilities(route, transport);
+    let rests = get_driving_rests(route, problem.activity.as_ref(), transport);
+    let stays = get_overnight_stays(route);
+    let transit_rests =
+        |act_idx: usize| rests.iter().filter(move |rest| !rest.at_stop && rest.leg_index + 1 == act_idx);
+
+    // NOTE battery charge is estimated using distance and load reported between stops
+    let battery = get_battery(route);
+    let capacity = get_vehicle_capacity(&vehicle.dimens, is_multi_dimen);
+    let get_charge_on_arrival = |charge: Option<f64>, stops: &[Stop], distance: i64| {
+        let last = stops.iter().rev().find_map(|stop| stop.as_point())?;
+        let ratio = capacity.as_ref().map_or(0., |capacity| MultiDimLoad::new(last.load.clone()).ratio(capacity));
+
+        charge
+            .zip(battery)
+            .map(|(charge, battery)| charge - battery.get_consumption((distance - last.distance) as f64, ratio))
+    };
+    let mut charge = battery.map(|battery| battery.initial);
 
     let mut leg = intervals.into_iter().fold(Leg::empty(), |leg, (start_idx, end_idx)| {
         let (start_delivery, end_pickup) = route.tour.activities_slice(start_idx, end_idx).iter().fold(
@@ -183,17 +222,30 @@ fn create_tour(
                     },
                     job_tag: None,
                     commute: None,
+                    lateness: None,
+                    compartment: None,
+                    visit_index: None,
                 }],
                 parking: None,
+                charge,
+                on_time_probability: None,
             }));
+
+            // NOTE vehicle might stay overnight at start location
+            if let Some(Stop::Point(point)) = tour.stops.last_mut() {
+                stays.iter().filter(|stay| stay.leg_index == 0 && stay.location.is_none()).for_each(|stay| {
+                    point.activities.push(create_overnight_activity(&stay.schedule, Some(point.location.clone())));
+                });
+            }
+
             (start_idx + 1, start)
         } else {
             (start_idx, route.tour.get(start_idx - 1).unwrap())
         };
 
-        let mut leg = route.tour.activities_slice(start_idx, end_idx).iter().fold(
+        let mut leg = route.tour.activities_slice(start_idx, end_idx).iter().zip(start_idx..).fold(
             Leg::new(Some((start.place.location, start.schedule.departure)), Some(start_delivery), leg.statistic),
-            |leg, act| {
+            |leg, (act, act_idx)| {
                 let activity_type = get_activity_type(act).cloned();
                 let (prev_location, prev_departure) = leg.last_detail.unwrap();
                 let prev_load = if activity_type.is_some() {
@@ -204,6 +256,59 @@ fn create_tour(
                     MultiDimLoad::new(vec![0; dimen_size])
                 };
 
+                // NOTE insert overnight stays taken at separate locations as stops
+                let ((prev_location, travel_departure), stay_driving, stay_distance, stay_cost, stay_toll) = stays
+                    .iter()
+                    .filter(|stay| stay.leg_index + 1 == act_idx)
+                    .filter_map(|stay| stay.location.map(|location| (stay, location)))
+                    .fold(
+                        ((prev_location, prev_departure), 0., 0., 0., 0.),
+                        |((from, departure), driving, distance, cost, toll), (stay, location)| {
+                            let arrival = stay.schedule.arrival;
+                            push_transit_rests(
+                                &mut tour.stops,
+                                transit_rests(act_idx)
+                                    .filter(|rest| rest.time.start >= departure && rest.time.end <= arrival),
+                                &prev_load,
+                            );
+
+                            let travel_time = TravelTime::Departure(departure);
+                            let distance = distance + transport.distance(route, from, location, travel_time);
+                            let stop_distance = leg.statistic.distance + distance as i64;
+                            charge = get_charge_on_arrival(charge, tour.stops.as_slice(), stop_distance);
+
+                            tour.stops.push(Stop::Point(PointStop {
+                                location: coord_index.get_by_idx(location).unwrap(),
+                                time: format_schedule(&stay.schedule),
+                                load: prev_load.as_vec(),
+                                distance: stop_distance,
+                                parking: None,
+                                activities: vec![create_overnight_activity(
+                                    &stay.schedule,
+                                    coord_index.get_by_idx(location),
+                                )],
+                                charge,
+                                on_time_probability: None,
+                            }));
+
+                            (
+                                (location, stay.schedule.departure),
+                                driving + transport.duration(route, from, location, travel_time),
+                                distance,
+                                cost + transport.cost(route, from, location, travel_time),
+                                toll + transport.extra_cost(route, from, location, travel_time),
+                            )
+                        },
+                    );
+                let stay_time = stays
+                    .iter()
+                    .filter(|stay| match stay.location {
+                        Some(_) => stay.leg_index + 1 == act_idx,
+                        None => stay.leg_index == act_idx,
+                    })
+                    .map(|stay| stay.schedule.departure - stay.schedule.arrival)
+                    .sum::<f64>();
+
                 let activity_type = activity_type.unwrap_or_else(|| "arrival".to_string());
                 let is_break = activity_type == "break";
 
@@ -212,7 +317,7 @@ fn create_tour(
                         .cloned()
                 });
                 let job_id = match activity_type.as_str() {
-                    "pickup" | "delivery" | "replacement" | "service" => {
+                    "pickup" | "delivery" | "replacement" | "service" | "transfer" => {
                         let single = act.job.as_ref().unwrap();
                         let id = single.dimens.get_id().cloned();
                         id.unwrap_or_else(|| Multi::roots(single).unwrap().dimens.get_id().unwrap().clone())
@@ -223,15 +328,16 @@ fn create_tour(
                 let commute = act.commute.clone().unwrap_or_default();
                 let commuting = commute.duration();
 
-                let (driving, transport_cost) = if commute.is_zero_distance() {
+                let (driving, transport_cost, toll) = if commute.is_zero_distance() {
                     // NOTE: use original cost traits to adapt time-based costs (except waiting/commuting)
-                    let prev_departure = TravelTime::Departure(prev_departure);
+                    let prev_departure = TravelTime::Departure(travel_departure);
                     let duration = transport.duration(route, prev_location, act.place.location, prev_departure);
                     let transport_cost = transport.cost(route, prev_location, act.place.location, prev_departure);
-                    (duration, transport_cost)
+                    let toll = transport.extra_cost(route, prev_location, act.place.location, prev_departure);
+                    (duration + stay_driving, transport_cost + stay_cost, toll + stay_toll)
                 } else {
                     // NOTE: no need to drive in case of non-zero commute, this goes to commuting time
-                    (0., commuting * vehicle.costs.per_service_time)
+                    (0., commuting * (vehicle.costs.per_service_time + driver.costs.per_service_time), 0.)
                 };
 
                 // NOTE two clusters at the same stop location
@@ -244,18 +350,23 @@ fn create_tour(
                 let activity_arrival = parking + act.schedule.arrival + commute.forward.duration;
                 let service_start = activity_arrival.max(act.place.time.start);
                 let waiting = service_start - activity_arrival;
-                let serving = act.place.duration - parking;
+                let serving = get_actor_service_duration(route.actor.as_ref(), act) - parking;
                 let service_end = service_start + serving;
                 let activity_departure = service_end;
 
                 // TODO: add better support of time based activity costs
                 let serving_cost = problem.activity.cost(route, act, service_start);
-                let total_cost = serving_cost + transport_cost + waiting * vehicle.costs.per_waiting_time;
-
-                let location_distance =
-                    transport.distance(route, prev_location, act.place.location, TravelTime::Departure(prev_departure))
-                        as i64;
-                let distance = leg.statistic.distance + location_distance - commute.forward.distance as i64;
+                let total_cost = serving_cost
+                    + transport_cost
+                    + waiting * (vehicle.costs.per_waiting_time + driver.costs.per_waiting_time);
+
+                let location_distance = transport.distance(
+                    route,
+                    prev_location,
+                    act.place.location,
+                    TravelTime::Departure(travel_departure),
+                ) + stay_distance;
+                let distance = leg.statistic.distance + location_distance as i64 - commute.forward.distance as i64;
 
                 let is_new_stop = match (act.commute.as_ref(), prev_location == act.place.location) {
                     (Some(commute), false) if commute.is_zero_distance() => true,
@@ -263,7 +374,16 @@ fn create_tour(
                     (None, is_same_location) => !is_same_location,
                 };
 
+                // NOTE insert mandatory rests taken on the way as transit stops
+                let transit_rest_time = transit_rests(act_idx).map(|rest| rest.time.duration()).sum::<f64>();
+                push_transit_rests(
+                    &mut tour.stops,
+                    transit_rests(act_idx).filter(|rest| rest.time.start >= travel_departure),
+                    &prev_load,
+                );
+
                 if is_new_stop {
+                    charge = get_charge_on_arrival(charge, tour.stops.as_slice(), distance);
                     tour.stops.push(Stop::Point(PointStop {
                         location: coord_index.get_by_idx(act.place.location).unwrap(),
                         time: format_schedule(&act.schedule),
@@ -278,6 +398,8 @@ fn create_tour(
                             None
                         },
                         activities: vec![],
+                        charge,
+                        on_time_probability: None,
                     }));
                 }
 
@@ -289,8 +411,17 @@ fn create_tour(
                     Stop::Transit(_) => unreachable!(),
                 };
 
+                charge = charge.zip(battery).map(|(charge, battery)| {
+                    get_charging_rate(act).map_or(charge, |rate| battery.charge(charge, rate, act.place.duration))
+                });
+
                 last.time.departure = format_time(act.schedule.departure);
                 last.load = load.as_vec();
+                last.charge = charge;
+                if let Some(probability) = on_time_probabilities.get(act_idx).cloned().flatten() {
+                    last.on_time_probability =
+                        Some(last.on_time_probability.map_or(probability, |current| current.min(probability)));
+                }
                 last.activities.push(ApiActivity {
                     job_id,
                     activity_type: activity_type.clone(),
@@ -305,8 +436,30 @@ fn create_tour(
                         .commute
                         .as_ref()
                         .map(|commute| Commute::new(commute, act.schedule.arrival, activity_departure, coord_index)),
+                    lateness: Some(estimate_lateness(act, activity_arrival).0 as i64).filter(|&lateness| lateness > 0),
+                    compartment: compartments.get(act_idx).cloned().flatten(),
+                    visit_index: act
+                        .job
+                        .as_ref()
+                        .and_then(|single| single.dimens.get_value::<JobVisit>("visit"))
+                        .map(|visit| visit.index),
+                });
+
+                let stop_rests = rests.iter().filter(|rest| rest.at_stop && rest.leg_index == act_idx);
+                let stop_rest_time = stop_rests.clone().map(|rest| rest.time.duration()).sum::<f64>();
+                stop_rests.for_each(|rest| {
+                    let location = coord_index.get_by_idx(act.place.location);
+                    last.activities.push(create_rest_activity(&rest.time, location));
+                });
+
+                stays.iter().filter(|stay| stay.leg_index == act_idx && stay.location.is_none()).for_each(|stay| {
+                    let location = coord_index.get_by_idx(act.place.location);
+                    last.activities.push(create_overnight_activity(&stay.schedule, location));
                 });
 
+                let rest_time = transit_rest_time + stop_rest_time + stay_time;
+                let rest_cost = rest_time * (vehicle.costs.per_service_time + driver.costs.per_service_time);
+
                 // NOTE detect when vehicle returns after activity to stop point
                 let end_location = if commute.backward.is_zero_distance() {
                     act.place.location
@@ -321,14 +474,22 @@ fn create_tour(
                 Leg {
                     last_detail: Some((end_location, act.schedule.departure)),
                     statistic: Statistic {
-                        cost: leg.statistic.cost + total_cost,
+                        cost: leg.statistic.cost + total_cost + rest_cost,
                         distance,
                         duration: leg.statistic.duration + act.schedule.departure as i64 - prev_departure as i64,
+                        emissions: leg.statistic.emissions,
+                        tolls: if toll > 0. {
+                            Some(leg.statistic.tolls.unwrap_or(0.) + toll)
+                        } else {
+                            leg.statistic.tolls
+                        },
                         times: Timing {
                             driving: leg.statistic.times.driving + driving as i64,
                             serving: leg.statistic.times.serving + (if is_break { 0 } else { serving as i64 }),
                             waiting: leg.statistic.times.waiting + waiting as i64,
-                            break_time: leg.statistic.times.break_time + (if is_break { serving as i64 } else { 0 }),
+                            break_time: leg.statistic.times.break_time
+                                + (if is_break { serving as i64 } else { 0 })
+                                + rest_time as i64,
                             commuting: leg.statistic.times.commuting + commuting as i64,
                             parking: leg.statistic.times.parking + parking as i64,
                         },
@@ -343,9 +504,18 @@ fn create_tour(
         leg
     });
 
-    leg.statistic.cost += vehicle.costs.fixed;
+    leg.statistic.cost += vehicle.costs.fixed + driver.costs.fixed;
     tour.statistic = leg.statistic;
 
+    // NOTE load dependent cost and emissions are estimated using distance and load reported between stops
+    if let Some(load_costs) = vehicle.dimens.get_load_costs() {
+        let (load_cost, emissions) = get_load_cost_and_emissions(&tour.stops, load_costs, capacity.as_ref());
+        let has_emissions = load_costs.empty_emissions > 0. || load_costs.full_emissions > 0.;
+
+        tour.statistic.cost += load_cost;
+        tour.statistic.emissions = if has_emissions { Some(emissions) } else { None };
+    }
+
     insert_reserved_times(route, &mut tour, reserved_times_index);
 
     // NOTE remove redundant info
@@ -367,6 +537,61 @@ fn create_tour(
     tour
 }
 
+fn create_rest_activity(time: &TimeWindow, location: Option<ApiLocation>) -> ApiActivity {
+    ApiActivity {
+        job_id: "rest".to_string(),
+        activity_type: "rest".to_string(),
+        location,
+        time: Some(Interval { start: format_time(time.start), end: format_time(time.end) }),
+        job_tag: None,
+        commute: None,
+        lateness: None,
+        compartment: None,
+        visit_index: None,
+    }
+}
+
+fn push_transit_rests<'a>(stops: &mut Vec<Stop>, rests: impl Iterator<Item = &'a DrivingRest>, load: &MultiDimLoad) {
+    rests.for_each(|rest| {
+        stops.push(Stop::Transit(TransitStop {
+            time: ApiSchedule { arrival: format_time(rest.time.start), departure: format_time(rest.time.end) },
+            load: load.as_vec(),
+            activities: vec![create_rest_activity(&rest.time, None)],
+        }))
+    });
+}
+
+/// Returns overnight stays of vehicle with linked shifts.
+fn get_overnight_stays(route: &Route) -> Vec<OvernightStay> {
+    route
+        .tour
+        .links()
+        .map(|link| {
+            let is_at_stop = matches!(route.tour.get(link.leg_index), Some(act) if act.place.location == link.location);
+
+            OvernightStay {
+                leg_index: link.leg_index,
+                location: if is_at_stop { None } else { Some(link.location) },
+                schedule: link.schedule.clone(),
+            }
+        })
+        .collect()
+}
+
+fn create_overnight_activity(schedule: &DomainSchedule, location: Option<ApiLocation>) -> ApiActivity {
+    ApiActivity {
+        job_id: "overnight".to_string(),
+        activity_type: "overnight".to_string(),
+        location,
+        time: Some(Interval { start: format_time(schedule.arrival), end: format_time(schedule.departure) }),
+        job_tag: None,
+        commute: None,
+        lateness: None,
+        compartment: None,
+        visit_index: None,
+    }
+}
+
 fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &ReservedTimesIndex) {
     let shift_time = route
         .tour
@@ -449,7 +674,9 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
                     // TODO costs may not match?
                     let activities = match stop {
                         Stop::Point(point) => {
-                            tour.statistic.cost += break_time as f64 * route.actor.vehicle.costs.per_service_time;
+                            tour.statistic.cost += break_time as f64
+                                * (route.actor.vehicle.costs.per_service_time
+                                    + route.actor.driver.costs.per_service_time);
                             &mut point.activities
                         }
                         Stop::Transit(transit) => {
@@ -470,6 +697,9 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
                             }),
                             job_tag: None,
                             commute: None,
+                            lateness: None,
+                            compartment: None,
+                            visit_index: None,
                         },
                     );
 
@@ -505,18 +735,28 @@ fn calculate_load(current: MultiDimLoad, act: &Activity, is_multi_dimen: bool) -
 }
 
 fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
+    // NOTE visits of periodic job share the same job id, so they are grouped together
     let unassigned = solution
         .unassigned
         .iter()
         .filter(|(job, _)| job.dimens().get_value::<String>("vehicle_id").is_none())
-        .map(|(job, code)| {
+        .fold((Vec::<UnassignedJob>::new(), HashMap::<String, usize>::new()), |(mut acc, mut index), (job, code)| {
+            let job_id = job.dimens().get_id().expect("job id expected");
             let (code, reason) = map_code_reason(*code);
-            UnassignedJob {
-                job_id: job.dimens().get_id().expect("job id expected").clone(),
-                reasons: vec![UnassignedJobReason { code: code.to_string(), description: reason.to_string() }],
+            let reason = UnassignedJobReason { code: code.to_string(), description: reason.to_string() };
+
+            match index.get(job_id).and_then(|&idx| acc.get_mut(idx)) {
+                Some(unassigned) if !unassigned.reasons.contains(&reason) => unassigned.reasons.push(reason),
+                Some(_) => {}
+                None => {
+                    index.insert(job_id.clone(), acc.len());
+                    acc.push(UnassignedJob { job_id: job_id.clone(), reasons: vec![reason] });
+                }
             }
+
+            (acc, index)
         })
-        .collect::<Vec<_>>();
+        .0;
 
     if unassigned.is_empty() {
         None
@@ -544,10 +784,178 @@ fn create_violations(solution: &Solution) -> Option<Vec<Violation>> {
     }
 }
 
+fn create_transfers(solution: &Solution) -> Option<Vec<Transfer>> {
+    let get_tour = |route: &Route| TransferTour {
+        vehicle_id: route.actor.vehicle.dimens.get_id().unwrap().clone(),
+        shift_index: *route.actor.vehicle.dimens.get_value::<usize>("shift_index").unwrap(),
+    };
+
+    let (first, second) = solution
+        .routes
+        .iter()
+        .flat_map(|route| route.tour.all_activities().map(move |activity| (route, activity)))
+        .filter_map(|(route, activity)| {
+            activity
+                .job
+                .as_ref()
+                .and_then(|single| get_single_transfer(single))
+                .map(|transfer| (route, activity, transfer))
+        })
+        .fold((Vec::new(), HashMap::new()), |(mut first, mut second), (route, activity, transfer)| {
+            match transfer.echelon {
+                TransferEchelon::First => first.push((route, activity, transfer)),
+                TransferEchelon::Second => {
+                    second.insert(transfer.job_id.clone(), route);
+                }
+            }
+
+            (first, second)
+        });
+
+    let transfers = first
+        .into_iter()
+        .filter_map(|(from, activity, transfer)| {
+            second.get(&transfer.job_id).map(|to| Transfer {
+                job_id: transfer.job_id.clone(),
+                hub_id: transfer.policy.hub_id.clone(),
+                from: get_tour(from),
+                to: get_tour(to),
+                time: format_time(activity.schedule.departure),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if transfers.is_empty() {
+        None
+    } else {
+        Some(transfers)
+    }
+}
+
+fn create_docks(problem: &Problem, solution: &Solution) -> Option<Vec<DockAssignment>> {
+    let routes = solution.routes.iter().collect::<Vec<_>>();
+    let is_multi_dimen = has_multi_dimensional_capacity(problem.extras.as_ref());
+    let tours = get_dock_tours(routes.as_slice(), is_multi_dimen);
+
+    if tours.is_empty() {
+        return None;
+    }
+
+    // NOTE routes are already scheduled, so arrival is returned as is
+    let docks = schedule_docks(tours, |route_index, _| routes[route_index].tour.end().map(|end| end.schedule.arrival))
+        .into_iter()
+        .map(|usage| {
+            let vehicle = &routes[usage.route_index].actor.vehicle;
+
+            DockAssignment {
+                depot_id: usage.policy.depot_id.clone(),
+                dock: usage.dock,
+                vehicle_id: vehicle.dimens.get_id().unwrap().clone(),
+                shift_index: *vehicle.dimens.get_value::<usize>("shift_index").unwrap(),
+                operation: match usage.operation {
+                    DockOperation::Loading => "loading",
+                    DockOperation::Unloading => "unloading",
+                }
+                .to_string(),
+                time: Interval { start: format_time(usage.time.start), end: format_time(usage.time.end) },
+            }
+        })
+        .collect();
+
+    Some(docks)
+}
+
 fn get_activity_type(activity: &Activity) -> Option<&String> {
     activity.job.as_ref().and_then(|single| single.dimens.get_value::<String>("type"))
 }
 
+fn get_compartments(route: &Route, is_multi_dimen: bool) -> Vec<Option<String>> {
+    fn get_compartment_ids<T: LoadOps>(route: &Route) -> Option<Vec<Option<String>>> {
+        let compartments: &Vec<Compartment<T>> = route.actor.vehicle.dimens.get_compartments()?;
+        let activities = route.tour.all_activities().collect::<Vec<_>>();
+        let is_reload = |a: &Activity| get_activity_type(a).map_or(false, |t| t == "reload");
+
+        assign_compartments(activities.as_slice(), compartments.as_slice(), &is_reload).map(|assignment| {
+            assignment.into_iter().map(|idx| idx.and_then(|idx| compartments[idx].dimens.get_id().cloned())).collect()
+        })
+    }
+
+    if is_multi_dimen {
+        get_compartment_ids::<MultiDimLoad>(route)
+    } else {
+        get_compartment_ids::<SingleDimLoad>(route)
+    }
+    .unwrap_or_default()
+}
+
+/// Returns probabilities to arrive at job activities before their time window end assuming that
+/// arrival time is normally distributed with variance accumulated along the route.
+fn get_on_time_probabilities(route: &Route, transport: &(dyn TransportCost + Send + Sync)) -> Vec<Option<f64>> {
+    let start = route.tour.start().unwrap();
+    let init = (start.place.location, start.schedule.departure, 0.);
+
+    let variances = route
+        .tour
+        .all_activities()
+        .skip(1)
+        .scan(init, |(location, departure, variance), activity| {
+            *variance += transport.duration_variance(
+                route,
+                *location,
+                activity.place.location,
+                TravelTime::Departure(*departure),
+            );
+            *location = activity.place.location;
+            *departure = activity.schedule.departure;
+
+            Some(*variance)
+        })
+        .collect::<Vec<_>>();
+
+    if variances.iter().all(|variance| compare_floats(*variance, 0.) == Ordering::Equal) {
+        return vec![];
+    }
+
+    once(None)
+        .chain(route.tour.all_activities().skip(1).zip(variances).map(|(activity, variance)| {
+            activity.job.as_ref()?;
+
+            let slack = activity.place.time.end - activity.schedule.arrival;
+
+            Some(if compare_floats(variance, 0.) == Ordering::Equal {
+                if slack < 0. {
+                    0.
+                } else {
+                    1.
+                }
+            } else {
+                get_normal_cdf(slack / variance.sqrt())
+            })
+        }))
+        .collect()
+}
+
+fn get_load_cost_and_emissions(stops: &[Stop], load_costs: &LoadCosts, capacity: Option<&MultiDimLoad>) -> (Cost, f64) {
+    let points = stops.iter().filter_map(|stop| stop.as_point()).collect::<Vec<_>>();
+
+    points.windows(2).fold((Cost::default(), 0.), |(cost, emissions), points| {
+        let (from, to) = (points[0], points[1]);
+        let distance = (to.distance - from.distance) as f64;
+        let ratio = capacity.map_or(0., |capacity| MultiDimLoad::new(from.load.clone()).ratio(capacity));
+        let ratio = if ratio.is_finite() { ratio } else { 0. };
+
+        (cost + load_costs.get_cost(distance, ratio), emissions + load_costs.get_emissions(distance, ratio))
+    })
+}
+
+fn get_vehicle_capacity(dimens: &Dimensions, is_multi_dimen: bool) -> Option<MultiDimLoad> {
+    if is_multi_dimen {
+        dimens.get_capacity().cloned()
+    } else {
+        dimens.get_capacity().map(|capacity: &SingleDimLoad| MultiDimLoad::new(vec![capacity.value]))
+    }
+}
+
 fn get_capacity(dimens: &Dimensions, is_multi_dimen: bool) -> Option<Demand<MultiDimLoad>> {
     if is_multi_dimen {
         dimens.get_demand().cloned()
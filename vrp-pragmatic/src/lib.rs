@@ -53,6 +53,10 @@ fn parse_time(time: &str) -> f64 {
     parse_time_safe(time).unwrap()
 }
 
+fn parse_offset(time: &str) -> i64 {
+    OffsetDateTime::parse(time, &Rfc3339).map(|time| time.offset().whole_seconds() as i64).unwrap()
+}
+
 fn parse_time_safe(time: &str) -> Result<f64, String> {
     OffsetDateTime::parse(time, &Rfc3339)
         .map(|time| time.unix_timestamp() as f64)
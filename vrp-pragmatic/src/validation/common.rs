@@ -1,3 +1,4 @@
+use crate::format::problem::VehicleType;
 use crate::parse_time_safe;
 use hashbrown::HashSet;
 use std::cmp::Ordering::Less;
@@ -69,3 +70,10 @@ pub fn get_duplicates<'a>(items: impl Iterator<Item = &'a String>) -> Option<Vec
         Some(duplicates)
     }
 }
+
+/// Checks whether vehicle takes rests which shift its schedule: driving hours or linked shifts.
+pub fn has_vehicle_rests(vehicle: &VehicleType) -> bool {
+    let has_driving_hours = matches!(&vehicle.limits, Some(limits) if limits.driving_hours.is_some());
+
+    has_driving_hours || vehicle.shifts.iter().any(|shift| shift.start.location.is_none())
+}
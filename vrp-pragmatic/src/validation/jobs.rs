@@ -4,6 +4,8 @@ mod jobs_test;
 
 use super::*;
 use crate::utils::combine_error_results;
+use hashbrown::HashSet;
+use std::iter::once;
 use vrp_core::models::common::MultiDimLoad;
 
 /// Checks that plan has no jobs with duplicate ids.
@@ -139,7 +141,14 @@ fn check_e1106_negative_duration(ctx: &ValidationContext) -> Result<(), FormatEr
         .filter(|job| {
             ctx.tasks(job)
                 .iter()
-                .flat_map(|task| task.places.iter().map(|place| place.duration))
+                .flat_map(|task| task.places.iter())
+                .flat_map(|place| {
+                    let durations = place.durations.iter();
+                    let overrides =
+                        durations.flat_map(|durations| durations.vehicles.iter().chain(durations.drivers.iter()));
+
+                    once(place.duration).chain(overrides.flat_map(|overrides| overrides.values().cloned()))
+                })
                 .any(|duration| duration.is_sign_negative())
         })
         .map(|job| job.id.clone())
@@ -179,6 +188,380 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that job's soft time windows are correct.
+fn check_e1108_soft_time_window_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job).iter().flat_map(|task| task.places.iter()).any(|place| {
+                match (&place.times, &place.lateness) {
+                    (_, None) => false,
+                    (None, Some(_)) => true,
+                    (Some(times), Some(lateness)) => {
+                        times.len() != lateness.len()
+                            || lateness
+                                .iter()
+                                .any(|l| l.max_lateness.is_sign_negative() || l.penalty.is_sign_negative())
+                    }
+                }
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "invalid soft time windows in jobs".to_string(),
+            format!(
+                "specify non negative lateness settings per each time window of job place, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that max ride time is used only with pickup and delivery jobs and has a non negative value.
+fn check_e1109_max_ride_time_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.max_ride_time.map_or(false, |max_ride_time| {
+                let has_pickups = job.pickups.as_ref().map_or(false, |pickups| !pickups.is_empty());
+                let has_deliveries = job.deliveries.as_ref().map_or(false, |deliveries| !deliveries.is_empty());
+
+                max_ride_time.is_sign_negative() || !has_pickups || !has_deliveries
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1109".to_string(),
+            "invalid max ride time in jobs".to_string(),
+            format!(
+                "specify non negative max ride time only for jobs with pickups and deliveries, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that periodic job visits are specified correctly.
+fn check_e1110_job_visits_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let relation_ids = ctx
+        .problem
+        .plan
+        .relations
+        .iter()
+        .flat_map(|relations| relations.iter())
+        .flat_map(|relation| relation.jobs.iter())
+        .collect::<HashSet<_>>();
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.visits.as_ref().map_or(false, |visits| {
+                let has_invalid_patterns = visits.patterns.as_ref().map_or(false, |patterns| {
+                    patterns.is_empty()
+                        || patterns.iter().any(|pattern| {
+                            pattern.len() != visits.count
+                                || pattern.iter().map(|day| *day as usize).collect::<HashSet<_>>().len()
+                                    != pattern.len()
+                        })
+                });
+
+                visits.count == 0 || has_invalid_patterns || relation_ids.contains(&job.id)
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1110".to_string(),
+            "invalid job visits".to_string(),
+            format!(
+                "specify positive visit count, patterns with unique day per visit and do not use periodic jobs in \
+                 relations, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that two-echelon jobs refer to a known hub and have a single delivery task only.
+fn check_e1111_job_hub_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let hub_ids = ctx.problem.plan.hubs.iter().flat_map(|hubs| hubs.iter()).map(|hub| &hub.id).collect::<HashSet<_>>();
+    let relation_ids = ctx
+        .problem
+        .plan
+        .relations
+        .iter()
+        .flat_map(|relations| relations.iter())
+        .flat_map(|relation| relation.jobs.iter())
+        .collect::<HashSet<_>>();
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.hub.as_ref().map_or(false, |hub_id| {
+                let task_size = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().map_or(0, |tasks| tasks.len());
+                let is_single_delivery = task_size(&job.deliveries) == 1
+                    && task_size(&job.pickups) + task_size(&job.replacements) + task_size(&job.services) == 0;
+
+                !hub_ids.contains(hub_id)
+                    || !is_single_delivery
+                    || job.visits.is_some()
+                    || relation_ids.contains(&job.id)
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1111".to_string(),
+            "invalid job hub".to_string(),
+            format!(
+                "use known hub only for jobs with a single delivery, no visits and not in relations, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that transfer hubs are unique and have proper echelon vehicle types.
+fn check_e1112_transfer_hubs_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let hubs = ctx.problem.plan.hubs.iter().flat_map(|hubs| hubs.iter()).collect::<Vec<_>>();
+    let duplicates = get_duplicates(hubs.iter().map(|hub| &hub.id)).unwrap_or_default();
+
+    let ids = hubs
+        .iter()
+        .filter(|hub| {
+            let first = hub.first_echelon.iter().collect::<HashSet<_>>();
+            let second = hub.second_echelon.iter().collect::<HashSet<_>>();
+
+            let has_unknown_types = first
+                .iter()
+                .chain(second.iter())
+                .any(|type_id| ctx.vehicles().all(|vehicle| vehicle.type_id != **type_id));
+            let has_wrong_start = ctx
+                .vehicles()
+                .filter(|vehicle| second.contains(&vehicle.type_id))
+                .flat_map(|vehicle| vehicle.shifts.iter())
+                .any(|shift| shift.start.location.as_ref() != Some(&hub.location));
+            // NOTE transfer module reschedules routes ignoring rests
+            let has_rests = ctx
+                .vehicles()
+                .filter(|vehicle| first.contains(&vehicle.type_id) || second.contains(&vehicle.type_id))
+                .any(has_vehicle_rests);
+
+            duplicates.contains(&hub.id)
+                || hub.duration.is_sign_negative()
+                || first.is_empty()
+                || second.is_empty()
+                || !first.is_disjoint(&second)
+                || has_unknown_types
+                || has_wrong_start
+                || has_rests
+        })
+        .map(|hub| hub.id.clone())
+        .collect::<HashSet<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        let mut ids = ids.into_iter().collect::<Vec<_>>();
+        ids.sort();
+
+        Err(FormatError::new(
+            "E1112".to_string(),
+            "invalid transfer hubs".to_string(),
+            format!(
+                "ensure that hub ids are unique, duration is not negative, echelons have different known vehicle \
+                 types without driving hours or linked shifts and second echelon vehicles start at the hub, \
+                 hubs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that synchronized jobs have proper tasks and are not combined with other job features.
+fn check_e1113_job_sync_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let relation_ids = ctx
+        .problem
+        .plan
+        .relations
+        .iter()
+        .flat_map(|relations| relations.iter())
+        .flat_map(|relation| relation.jobs.iter())
+        .collect::<HashSet<_>>();
+    // NOTE sync module reschedules routes ignoring rests
+    let has_rests = ctx.vehicles().any(has_vehicle_rests);
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.sync.as_ref().map_or(false, |sync| {
+                let tasks = ctx.tasks(job);
+                let has_mixed_demand = job.pickups.is_some() && job.deliveries.is_some();
+
+                let places = tasks.iter().flat_map(|task| task.places.iter()).collect::<Vec<_>>();
+                let tags = places.iter().filter_map(|place| place.tag.as_ref()).collect::<HashSet<_>>();
+                let has_unique_tags = tags.len() == places.len();
+
+                tasks.len() < 2
+                    || has_mixed_demand
+                    || !has_unique_tags
+                    || sync.max_offset < 0.
+                    || job.visits.is_some()
+                    || job.hub.is_some()
+                    || job.group.is_some()
+                    || relation_ids.contains(&job.id)
+                    || has_rests
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1113".to_string(),
+            "invalid job sync".to_string(),
+            format!(
+                "use sync only for jobs with at least two tasks which are not mix of pickups and deliveries, have \
+                 places with unique tags, not negative max offset, no visits, hub or group, not in relations and \
+                 vehicles have no driving hours or linked shifts, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job place durations refer to known vehicle and driver types.
+fn check_e1114_job_place_durations_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let vehicle_types = ctx.vehicles().map(|vehicle| &vehicle.type_id).collect::<HashSet<_>>();
+    let driver_types = ctx
+        .problem
+        .fleet
+        .drivers
+        .iter()
+        .flat_map(|drivers| drivers.iter())
+        .map(|driver| &driver.type_id)
+        .collect::<HashSet<_>>();
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job).iter().flat_map(|task| task.places.iter()).filter_map(|place| place.durations.as_ref()).any(
+                |durations| {
+                    durations.vehicles.iter().flat_map(|vehicles| vehicles.keys()).any(|id| !vehicle_types.contains(id))
+                        || durations
+                            .drivers
+                            .iter()
+                            .flat_map(|drivers| drivers.keys())
+                            .any(|id| !driver_types.contains(id))
+                },
+            )
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1114".to_string(),
+            "invalid job place durations".to_string(),
+            format!("use only known vehicle and driver type ids in job place durations, jobs: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that depots are unique and have proper docks and their durations.
+fn check_e1115_depots_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let depots = ctx.problem.plan.depots.iter().flat_map(|depots| depots.iter()).collect::<Vec<_>>();
+    let duplicates = get_duplicates(depots.iter().map(|depot| &depot.id)).unwrap_or_default();
+    // NOTE dock module reschedules routes ignoring rests
+    let has_rests = ctx.vehicles().any(has_vehicle_rests);
+
+    let ids = depots
+        .iter()
+        .filter(|depot| {
+            let is_invalid_duration = |duration: &Option<DockDuration>| {
+                duration
+                    .as_ref()
+                    .map_or(false, |duration| duration.fixed.is_sign_negative() || duration.per_unit.is_sign_negative())
+            };
+            let has_same_location = depots.iter().any(|other| other.id != depot.id && other.location == depot.location);
+
+            duplicates.contains(&depot.id)
+                || depot.docks == 0
+                || is_invalid_duration(&depot.loading)
+                || is_invalid_duration(&depot.unloading)
+                || has_same_location
+                || has_rests
+        })
+        .map(|depot| depot.id.clone())
+        .collect::<HashSet<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        let mut ids = ids.into_iter().collect::<Vec<_>>();
+        ids.sort();
+
+        Err(FormatError::new(
+            "E1115".to_string(),
+            "invalid depots".to_string(),
+            format!(
+                "ensure that depot ids and locations are unique, amount of docks is positive, durations are \
+                 not negative and vehicles have no driving hours or linked shifts, depots: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that preferred skills of the job are not empty and have non negative weight.
+fn check_e1116_job_preferred_skills_correctness(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.skills.as_ref().and_then(|skills| skills.preferred.as_ref()).map_or(false, |preferred| {
+                preferred.one_of.is_empty() || preferred.weight.map_or(false, |weight| weight.is_sign_negative())
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1116".to_string(),
+            "invalid job preferred skills".to_string(),
+            format!(
+                "ensure that preferred skills are not empty and weight is not negative, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     combine_error_results(&[
@@ -190,5 +573,14 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_soft_time_window_correctness(ctx),
+        check_e1109_max_ride_time_correctness(ctx),
+        check_e1110_job_visits_correctness(ctx),
+        check_e1111_job_hub_correctness(ctx),
+        check_e1112_transfer_hubs_correctness(ctx),
+        check_e1113_job_sync_correctness(ctx),
+        check_e1114_job_place_durations_correctness(ctx),
+        check_e1115_depots_correctness(ctx),
+        check_e1116_job_preferred_skills_correctness(ctx),
     ])
 }
@@ -26,6 +26,9 @@ use self::objectives::validate_objectives;
 mod vehicles;
 use self::vehicles::validate_vehicles;
 
+mod drivers;
+use self::drivers::validate_drivers;
+
 mod relations;
 use self::relations::validate_relations;
 
@@ -50,6 +53,7 @@ impl<'a> ValidationContext<'a> {
             .err()
             .into_iter()
             .chain(validate_vehicles(self).err().into_iter())
+            .chain(validate_drivers(self).err().into_iter())
             .chain(validate_objectives(self).err().into_iter())
             .chain(validate_routing(self).err().into_iter())
             .chain(validate_relations(self).err().into_iter())
@@ -73,6 +77,11 @@ impl<'a> ValidationContext<'a> {
         self.problem.fleet.vehicles.iter()
     }
 
+    /// Gets list of drivers from the problem.
+    fn drivers(&self) -> impl Iterator<Item = &DriverType> {
+        self.problem.fleet.drivers.iter().flat_map(|drivers| drivers.iter())
+    }
+
     /// Gets a flat list of job tasks from the job.
     fn tasks(&self, job: &'a Job) -> Vec<&'a JobTask> {
         job.pickups
@@ -86,6 +95,6 @@ impl<'a> ValidationContext<'a> {
     }
 }
 
-fn is_reserved_job_id(job_id: &str) -> bool {
-    job_id == "departure" || job_id == "arrival" || job_id == "break" || job_id == "reload" || job_id == "dispatch"
+pub(crate) fn is_reserved_job_id(job_id: &str) -> bool {
+    ["departure", "arrival", "break", "reload", "charging", "dispatch", "transfer"].contains(&job_id)
 }
@@ -28,10 +28,13 @@ fn check_e1601_duplicate_objectives(objectives: &[&Objective]) -> Result<(), For
                 MinimizeCost => acc.entry("minimize-cost"),
                 MinimizeDistance => acc.entry("minimize-distance"),
                 MinimizeDuration => acc.entry("minimize-duration"),
+                MinimizeEmissions => acc.entry("minimize-emissions"),
                 MinimizeTours => acc.entry("minimize-tours"),
                 MaximizeTours => acc.entry("maximize-tours"),
                 MaximizeValue { .. } => acc.entry("maximize-value"),
                 MinimizeUnassignedJobs { .. } => acc.entry("minimize-unassigned"),
+                MinimizePreferencePenalty => acc.entry("minimize-preference-penalty"),
+                MinimizeReassignments => acc.entry("minimize-reassignments"),
                 MinimizeArrivalTime => acc.entry("minimize-arrival-time"),
                 BalanceMaxLoad { .. } => acc.entry("balance-max-load"),
                 BalanceActivities { .. } => acc.entry("balance-activities"),
@@ -206,6 +209,74 @@ fn check_e1608_areas_but_no_objective(ctx: &ValidationContext, objectives: &[&Ob
     }
 }
 
+/// Checks that emissions objective can be specified only when vehicle with emissions is used.
+fn check_e1609_no_vehicles_with_emissions_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_emissions_objective = objectives.iter().any(|objective| matches!(objective, MinimizeEmissions));
+    let has_no_vehicles_with_emissions = !ctx.vehicles().any(|vehicle| vehicle.emissions.is_some());
+
+    if has_emissions_objective && has_no_vehicles_with_emissions {
+        Err(FormatError::new(
+            "E1609".to_string(),
+            "redundant emissions objective".to_string(),
+            "specify emissions for at least one vehicle type or delete 'minimize-emissions' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that preference objective is specified when jobs have preferred skills.
+fn check_e1610_jobs_with_preferred_skills_but_no_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_no_preference_objective =
+        !objectives.iter().any(|objective| matches!(objective, MinimizePreferencePenalty));
+    let has_jobs_with_preferences =
+        ctx.jobs().any(|job| job.skills.as_ref().map_or(false, |skills| skills.preferred.is_some()));
+
+    if !objectives.is_empty() && has_no_preference_objective && has_jobs_with_preferences {
+        Err(FormatError::new(
+            "E1610".to_string(),
+            "missing preference penalty objective".to_string(),
+            "specify 'minimize-preference-penalty' objective, remove objectives property or remove preferred skills \
+             from jobs"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that reassignment objective is specified when preferred relations are used.
+fn check_e1611_preferred_relations_but_no_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_no_reassignment_objective = !objectives.iter().any(|objective| matches!(objective, MinimizeReassignments));
+    let has_preferred_relations = ctx
+        .problem
+        .plan
+        .relations
+        .iter()
+        .flatten()
+        .any(|relation| matches!(relation.type_field, RelationType::Preferred));
+
+    if !objectives.is_empty() && has_no_reassignment_objective && has_preferred_relations {
+        Err(FormatError::new(
+            "E1611".to_string(),
+            "missing reassignment objective".to_string(),
+            "specify 'minimize-reassignments' objective, remove objectives property or remove preferred relations"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
     ctx.problem.objectives.as_ref().map(|objectives| objectives.iter().flatten().collect())
 }
@@ -222,6 +293,9 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), Vec<FormatErro
             check_e1606_jobs_with_order_but_no_objective(ctx, &objectives),
             check_e1607_jobs_with_value_but_no_objective(ctx, &objectives),
             check_e1608_areas_but_no_objective(ctx, &objectives),
+            check_e1609_no_vehicles_with_emissions_objective(ctx, &objectives),
+            check_e1610_jobs_with_preferred_skills_but_no_objective(ctx, &objectives),
+            check_e1611_preferred_relations_but_no_objective(ctx, &objectives),
         ])
     } else {
         Ok(())
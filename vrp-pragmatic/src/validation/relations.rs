@@ -69,10 +69,11 @@ fn check_e1202_empty_job_list(relations: &[Relation]) -> Result<(), FormatError>
     }
 }
 
-/// Checks that relation has no jobs with multiple places or time windows.
+/// Checks that strict or sequence relation has no jobs with multiple places or time windows.
 fn check_e1203_no_multiple_places_times(ctx: &ValidationContext, relations: &[Relation]) -> Result<(), FormatError> {
     let mut job_ids = relations
         .iter()
+        .filter(|relation| matches!(relation.type_field, RelationType::Strict | RelationType::Sequence))
         .flat_map(|relation| {
             relation
                 .jobs
@@ -107,11 +108,12 @@ fn check_e1203_no_multiple_places_times(ctx: &ValidationContext, relations: &[Re
     }
 }
 
-/// Checks that relation job is assigned to one vehicle.
+/// Checks that relation job is assigned to one vehicle. Preferred relations are soft, so they are ignored.
 fn check_e1204_job_assigned_to_multiple_vehicles(relations: &[Relation]) -> Result<(), FormatError> {
     let mut job_vehicle_map = HashMap::<String, String>::new();
     let job_ids: Vec<String> = relations
         .iter()
+        .filter(|relation| !matches!(relation.type_field, RelationType::Preferred))
         .flat_map(|relation| {
             relation
                 .jobs
@@ -145,7 +147,10 @@ fn check_e1205_relation_has_correct_shift_index(
     let vehicle_ids: Vec<String> = relations
         .iter()
         .filter_map(|relation| vehicle_map.get(&relation.vehicle_id).map(|vehicle| (vehicle, relation)))
-        .filter(|(vehicle, relation)| vehicle.shifts.get(relation.shift_index.unwrap_or(0)).is_none())
+        .filter(|(vehicle, relation)| {
+            // NOTE relation refers to the first shift of linked shifts
+            !matches!(vehicle.shifts.get(relation.shift_index.unwrap_or(0)), Some(shift) if shift.start.location.is_some())
+        })
         .map(|(_, relation)| relation.vehicle_id.clone())
         .collect::<Vec<_>>();
 
@@ -156,7 +161,8 @@ fn check_e1205_relation_has_correct_shift_index(
             "E1205".to_string(),
             "relation has invalid shift index".to_string(),
             format!(
-                "check that vehicle has enough shifts defined or correct relation, vehicle ids: '{}'",
+                "check that vehicle has enough shifts defined or correct relation, linked shifts are referenced by \
+                 the first shift index, vehicle ids: '{}'",
                 vehicle_ids.join(", ")
             ),
         ))
@@ -181,6 +187,7 @@ fn check_e1206_relation_has_no_missing_shift_properties(
                 "break" => vehicle_shift.breaks.is_none(),
                 "dispatch" => vehicle_shift.dispatch.is_none(),
                 "reload" => vehicle_shift.reloads.is_none(),
+                "charging" => vehicle_shift.charging_stations.is_none(),
                 "arrival" => vehicle_shift.end.is_none(),
                 _ => false,
             })
@@ -254,6 +261,29 @@ fn check_e1207_no_incomplete_relation(ctx: &ValidationContext, relations: &[Rela
     }
 }
 
+/// Checks that preferred relation has no reserved job ids.
+fn check_e1208_preferred_relation_has_no_reserved_ids(relations: &[Relation]) -> Result<(), FormatError> {
+    let vehicle_ids = relations
+        .iter()
+        .filter(|relation| matches!(relation.type_field, RelationType::Preferred))
+        .filter(|relation| relation.jobs.iter().any(|job_id| is_reserved_job_id(job_id)))
+        .map(|relation| relation.vehicle_id.clone())
+        .collect::<Vec<_>>();
+
+    if vehicle_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1208".to_string(),
+            "preferred relation has special job id".to_string(),
+            format!(
+                "remove special job ids (e.g. departure, break, arrival) from preferred relation, vehicle ids: '{}'",
+                vehicle_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates relations in the plan.
 pub fn validate_relations(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     let vehicle_map = ctx
@@ -271,6 +301,7 @@ pub fn validate_relations(ctx: &ValidationContext) -> Result<(), Vec<FormatError
             check_e1205_relation_has_correct_shift_index(relations, &vehicle_map),
             check_e1206_relation_has_no_missing_shift_properties(relations, &vehicle_map),
             check_e1207_no_incomplete_relation(ctx, relations),
+            check_e1208_preferred_relation_has_no_reserved_ids(relations),
         ])
     } else {
         Ok(())
@@ -121,6 +121,89 @@ fn check_e1505_profiles_exist(ctx: &ValidationContext) -> Result<(), FormatError
     }
 }
 
+/// Checks that toll costs have the same size as distances and are not negative.
+fn check_e1506_toll_costs_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let invalid_matrices = ctx
+        .matrices
+        .iter()
+        .flat_map(|matrices| matrices.iter().enumerate())
+        .filter(|(_, matrix)| {
+            matrix.toll_costs.as_ref().map_or(false, |toll_costs| {
+                toll_costs.len() != matrix.distances.len() || toll_costs.iter().any(|toll| *toll < 0.)
+            })
+        })
+        .map(|(idx, matrix)| matrix.profile.clone().unwrap_or_else(|| idx.to_string()))
+        .collect::<Vec<_>>();
+
+    if invalid_matrices.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1506".to_string(),
+            "invalid toll costs in routing matrix".to_string(),
+            format!(
+                "ensure that toll costs have the same size as distances and have no negative values \
+                 in matrices: '{}'",
+                invalid_matrices.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that travel time variances have the same size as travel times and are not negative.
+fn check_e1507_travel_time_variances_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let invalid_matrices = ctx
+        .matrices
+        .iter()
+        .flat_map(|matrices| matrices.iter().enumerate())
+        .filter(|(_, matrix)| {
+            matrix.travel_time_variances.as_ref().map_or(false, |variances| {
+                variances.len() != matrix.travel_times.len() || variances.iter().any(|variance| *variance < 0.)
+            })
+        })
+        .map(|(idx, matrix)| matrix.profile.clone().unwrap_or_else(|| idx.to_string()))
+        .collect::<Vec<_>>();
+
+    if invalid_matrices.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1507".to_string(),
+            "invalid travel time variances in routing matrix".to_string(),
+            format!(
+                "ensure that travel time variances have the same size as travel times and have no negative \
+                 values in matrices: '{}'",
+                invalid_matrices.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that service level of profiles is a valid probability.
+fn check_e1508_service_level_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let invalid_profiles = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| profile.service_level.map_or(false, |level| !(0.5..1.).contains(&level)))
+        .map(|profile| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if invalid_profiles.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1508".to_string(),
+            "invalid service level in profile".to_string(),
+            format!(
+                "ensure that service level is not less than 0.5 and less than 1 in profiles: '{}'",
+                invalid_profiles.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates routing rules.
 pub fn validate_routing(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     let location_types = ctx.coord_index.get_used_types();
@@ -132,5 +215,8 @@ pub fn validate_routing(ctx: &ValidationContext) -> Result<(), Vec<FormatError>>
         check_e1503_no_matrix_when_indices_used(ctx, location_types),
         check_e1504_index_size_mismatch(ctx),
         check_e1505_profiles_exist(ctx),
+        check_e1506_toll_costs_are_correct(ctx),
+        check_e1507_travel_time_variances_are_correct(ctx),
+        check_e1508_service_level_is_correct(ctx),
     ])
 }
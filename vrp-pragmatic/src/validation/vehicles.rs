@@ -4,7 +4,7 @@ mod vehicles_test;
 
 use super::*;
 use crate::utils::combine_error_results;
-use crate::validation::common::get_time_windows;
+use crate::validation::common::{get_time_window_from_vec, get_time_windows};
 use crate::{parse_time, parse_time_safe};
 use hashbrown::HashSet;
 use std::cmp::Ordering;
@@ -298,6 +298,291 @@ fn check_e1308_vehicle_required_break_rescheduling(ctx: &ValidationContext) -> R
     }
 }
 
+/// Checks that vehicle compartments are defined correctly.
+fn check_e1309_vehicle_compartments_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.compartments.as_ref().map_or(false, |compartments| {
+                let has_duplicates = get_duplicates(compartments.iter().map(|compartment| &compartment.id)).is_some();
+                let has_invalid_capacity = compartments.iter().any(|compartment| {
+                    compartment.capacity.len() != vehicle.capacity.len()
+                        || compartment.capacity.iter().any(|value| *value < 0)
+                });
+
+                compartments.is_empty() || has_duplicates || has_invalid_capacity
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "invalid vehicle compartments".to_string(),
+            format!(
+                "ensure that compartments are not empty, have unique ids and non-negative capacity with the same \
+                 dimension as vehicle capacity, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle driving hours limits are defined correctly.
+fn check_e1310_vehicle_driving_hours_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.limits.as_ref().and_then(|limits| limits.driving_hours.as_ref()).map_or(false, |limit| {
+                let has_invalid_durations = [
+                    limit.max_continuous_driving,
+                    limit.break_duration,
+                    limit.max_daily_driving,
+                    limit.daily_rest,
+                    limit.max_daily_duty.unwrap_or(1.),
+                ]
+                .iter()
+                .any(|value| *value <= 0.);
+                let has_invalid_split = limit.split_break.as_ref().map_or(false, |parts| {
+                    parts.len() != 2
+                        || parts.iter().any(|part| *part <= 0.)
+                        || parts.iter().sum::<f64>() < limit.break_duration
+                });
+
+                has_invalid_durations || has_invalid_split
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid vehicle driving hours limit".to_string(),
+            format!(
+                "ensure that driving hours limit has positive durations and split break has two positive parts \
+                 which are not shorter than break duration in total, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that linked vehicle shifts are defined correctly.
+fn check_e1311_vehicle_linked_shifts_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let is_linked = |shift: &VehicleShift| shift.start.location.is_none();
+    let is_link_correct = |prev: &VehicleShift, next: &VehicleShift| match (&prev.end, is_linked(next)) {
+        (Some(end), true) => {
+            let is_time_correct = match (parse_time_safe(&end.latest), parse_time_safe(&next.start.earliest)) {
+                (Ok(end), Ok(start)) => end < start,
+                _ => false,
+            };
+            let has_no_extra_places = next.breaks.is_none()
+                && next.reloads.is_none()
+                && next.dispatch.is_none()
+                && next.charging_stations.is_none();
+
+            is_time_correct && has_no_extra_places
+        }
+        (None, true) => false,
+        (end, false) => !matches!(end, Some(end) if end.location.is_none()),
+    };
+
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            let shifts = vehicle.shifts.as_slice();
+            let is_first_correct = !matches!(shifts.first(), Some(first) if is_linked(first));
+            let is_last_correct =
+                !matches!(shifts.last().and_then(|last| last.end.as_ref()), Some(end) if end.location.is_none());
+            let are_links_correct = shifts.windows(2).all(|pair| match pair {
+                [prev, next] => is_link_correct(prev, next),
+                _ => unreachable!(),
+            });
+
+            !(is_first_correct && is_last_correct && are_links_correct)
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1311".to_string(),
+            "invalid linked vehicle shifts".to_string(),
+            format!(
+                "ensure that first shift has start location, linked shift starts after previous shift end, has no \
+                 breaks, reloads, dispatch or charging stations, and end location is omitted only when next shift \
+                 is linked, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle battery and charging stations are defined correctly.
+fn check_e1312_vehicle_battery_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|vehicle, shift, _| {
+            let is_battery_correct = vehicle.battery.as_ref().map_or(true, |battery| {
+                let initial = battery.initial.unwrap_or(battery.capacity);
+                let full_load_consumption = battery.full_load_consumption.unwrap_or(battery.consumption);
+
+                battery.capacity > 0.
+                    && initial >= 0.
+                    && initial <= battery.capacity
+                    && battery.consumption >= 0.
+                    && full_load_consumption >= 0.
+            });
+
+            let are_stations_correct = shift.charging_stations.as_ref().map_or(true, |stations| {
+                vehicle.battery.is_some() && stations.iter().all(|station| station.rate > 0. && station.duration >= 0.)
+            });
+
+            is_battery_correct && are_stations_correct
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1312".to_string(),
+            "invalid vehicle battery".to_string(),
+            format!(
+                "ensure that battery capacity is positive, initial charge is within capacity, consumption is not \
+                 negative and charging stations are used with battery and have positive rate, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle load dependent costs and emissions are defined correctly.
+fn check_e1313_vehicle_load_costs_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            let is_cost_correct = vehicle.costs.full_load_distance.map_or(true, |cost| cost >= 0.);
+            let are_emissions_correct = vehicle.emissions.as_ref().map_or(true, |emissions| {
+                emissions.distance >= 0. && emissions.full_load_distance.map_or(true, |value| value >= 0.)
+            });
+
+            !is_cost_correct || !are_emissions_correct
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1313".to_string(),
+            "invalid vehicle load dependent costs or emissions".to_string(),
+            format!(
+                "ensure that full load distance cost and emissions are not negative, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that tour states refer to existing vehicle shifts and have correct time and load.
+fn check_e1314_tour_states_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let vehicle_map = ctx
+        .vehicles()
+        .flat_map(|vehicle| vehicle.vehicle_ids.iter().map(move |vehicle_id| (vehicle_id, vehicle)))
+        .collect::<HashMap<_, _>>();
+    let mut used = HashSet::new();
+
+    let vehicle_ids = ctx
+        .problem
+        .plan
+        .tours
+        .iter()
+        .flatten()
+        .filter(|tour| {
+            let shift_index = tour.shift_index.unwrap_or(0);
+            // NOTE tour state refers to the first shift of linked shifts and can be within any of them
+            let shift_time = vehicle_map.get(&tour.vehicle_id).and_then(|vehicle| {
+                get_linked_shifts(&vehicle.shifts).into_iter().find(|(index, _)| *index == shift_index).and_then(
+                    |(_, shifts)| {
+                        get_shift_time_window(shifts.first().unwrap())
+                            .zip(get_shift_time_window(shifts.last().unwrap()))
+                            .map(|(first, last)| TimeWindow::new(first.start, last.end))
+                    },
+                )
+            });
+            let is_time_correct = match (shift_time, parse_time_safe(&tour.time)) {
+                (Some(shift_time), Ok(time)) => shift_time.contains(time),
+                _ => false,
+            };
+            let is_load_correct = tour.load.as_ref().map_or(true, |load| load.iter().all(|value| *value >= 0));
+
+            !used.insert((tour.vehicle_id.clone(), shift_index)) || !is_time_correct || !is_load_correct
+        })
+        .map(|tour| tour.vehicle_id.clone())
+        .collect::<Vec<_>>();
+
+    if vehicle_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1314".to_string(),
+            "invalid tour state".to_string(),
+            format!(
+                "ensure that tour state is unique and refers to existing vehicle shift which is not linked, its time \
+                 is within shift time and load is not negative, vehicle ids: '{}'",
+                vehicle_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that completed tasks of tour states refer to existing job tasks unambiguously and only once.
+fn check_e1315_tour_states_completed_activities_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let mut used = HashSet::new();
+    let mut job_ids = ctx
+        .problem
+        .plan
+        .tours
+        .iter()
+        .flatten()
+        .flat_map(|tour| tour.completed.iter().flatten())
+        .filter(|completed| {
+            let task_index =
+                ctx.job_index.get(&completed.job_id).and_then(|job| get_completed_task_index(job, completed));
+
+            task_index.map_or(true, |task_index| !used.insert((completed.job_id.clone(), task_index)))
+        })
+        .map(|completed| completed.job_id.clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    job_ids.sort();
+
+    if job_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1315".to_string(),
+            "invalid completed activities in tour state".to_string(),
+            format!(
+                "ensure that completed tasks refer to jobs present in the plan, specify tag or task index when \
+                 job has multiple tasks and do not repeat them, job ids: '{}'",
+                job_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 fn get_invalid_type_ids(
     ctx: &ValidationContext,
     check_shift: Box<dyn Fn(&VehicleType, &VehicleShift, Option<TimeWindow>) -> bool>,
@@ -347,5 +632,12 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), Vec<FormatError>
         check_e1306_vehicle_dispatch_is_correct(ctx),
         check_e1307_vehicle_has_no_zero_costs(ctx),
         check_e1308_vehicle_required_break_rescheduling(ctx),
+        check_e1309_vehicle_compartments_are_correct(ctx),
+        check_e1310_vehicle_driving_hours_are_correct(ctx),
+        check_e1311_vehicle_linked_shifts_are_correct(ctx),
+        check_e1312_vehicle_battery_is_correct(ctx),
+        check_e1313_vehicle_load_costs_are_correct(ctx),
+        check_e1314_tour_states_are_correct(ctx),
+        check_e1315_tour_states_completed_activities_are_correct(ctx),
     ])
 }
@@ -17,7 +17,11 @@ fn vehicle_type_prototype() -> impl Strategy<Value = VehicleType> {
             generate_shift(
                 generate_location(&DEFAULT_BOUNDING_BOX).prop_flat_map(|location| {
                     Just((
-                        ShiftStart { earliest: default_time_plus_offset(9), latest: None, location: location.clone() },
+                        ShiftStart {
+                            earliest: default_time_plus_offset(9),
+                            latest: None,
+                            location: Some(location.clone()),
+                        },
                         None,
                     ))
                 }),
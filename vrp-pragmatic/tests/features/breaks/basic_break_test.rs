@@ -27,6 +27,7 @@ fn can_assign_break_between_jobs() {
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -42,12 +43,15 @@ fn can_assign_break_between_jobs() {
                 cost: 54.,
                 distance: 20,
                 duration: 24,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -95,6 +99,8 @@ fn can_assign_break_between_jobs() {
                     cost: 54.,
                     distance: 20,
                     duration: 24,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
                 },
             }],
@@ -17,7 +17,7 @@ fn can_assign_break_using_second_place() {
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
-                        location: (30., 0.).to_loc(),
+                        location: Some((30., 0.).to_loc()),
                     }),
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(10.), format_time(30.)]),
@@ -39,6 +39,7 @@ fn can_assign_break_using_second_place() {
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -54,12 +55,15 @@ fn can_assign_break_using_second_place() {
                 cost: 74.,
                 distance: 30,
                 duration: 34,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 30, serving: 2, break_time: 2, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -107,6 +111,8 @@ fn can_assign_break_using_second_place() {
                     cost: 74.,
                     distance: 30,
                     duration: 34,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 30, serving: 2, break_time: 2, ..Timing::default() },
                 },
             }],
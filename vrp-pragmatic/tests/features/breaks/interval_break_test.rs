@@ -27,6 +27,7 @@ fn can_assign_interval_break_between_jobs() {
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         objectives: create_test_objectives(),
@@ -43,12 +44,15 @@ fn can_assign_interval_break_between_jobs() {
                 cost: 74.,
                 distance: 30,
                 duration: 34,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 30, serving: 2, break_time: 2, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -77,7 +81,10 @@ fn can_assign_interval_break_between_jobs() {
                                     end: "1970-01-01T00:00:06Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                lateness: None,
+                                compartment: None,
+                                visit_index: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -88,9 +95,14 @@ fn can_assign_interval_break_between_jobs() {
                                     end: "1970-01-01T00:00:08Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                lateness: None,
+                                compartment: None,
+                                visit_index: None,
                             }
                         ],
+                        charge: None,
+                        on_time_probability: None,
                     }),
                     create_stop_with_activity(
                         "job2",
@@ -113,6 +125,8 @@ fn can_assign_interval_break_between_jobs() {
                     cost: 74.,
                     distance: 30,
                     duration: 34,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 30, serving: 2, break_time: 2, ..Timing::default() },
                 },
             }],
@@ -139,12 +153,12 @@ fn can_assign_interval_break_with_reload() {
                     start: ShiftStart {
                         earliest: format_time(0.),
                         latest: Some(format_time(0.)),
-                        location: (0., 0.).to_loc(),
+                        location: Some((0., 0.).to_loc()),
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
-                        location: (30., 0.).to_loc(),
+                        location: Some((30., 0.).to_loc()),
                     }),
                     dispatch: None,
                     breaks: Some(vec![VehicleBreak::Optional {
@@ -158,10 +172,12 @@ fn can_assign_interval_break_with_reload() {
                         duration: 3.0,
                         tag: None,
                     }]),
+                    charging_stations: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         objectives: create_test_objectives(),
@@ -178,12 +194,15 @@ fn can_assign_interval_break_with_reload() {
                 cost: 139.,
                 distance: 60,
                 duration: 69,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 60, serving: 7, break_time: 2, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -212,7 +231,10 @@ fn can_assign_interval_break_with_reload() {
                                     end: "1970-01-01T00:00:11Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                lateness: None,
+                                compartment: None,
+                                visit_index: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -223,9 +245,14 @@ fn can_assign_interval_break_with_reload() {
                                     end: "1970-01-01T00:00:13Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                lateness: None,
+                                compartment: None,
+                                visit_index: None,
                             }
                         ],
+                        charge: None,
+                        on_time_probability: None,
                     }),
                     create_stop_with_activity(
                         "job2",
@@ -272,6 +299,8 @@ fn can_assign_interval_break_with_reload() {
                     cost: 139.,
                     distance: 60,
                     duration: 69,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 60, serving: 7, break_time: 2, ..Timing::default() },
                 },
             }],
@@ -303,6 +332,7 @@ fn can_consider_departure_rescheduling() {
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         objectives: create_test_objectives(),
@@ -16,7 +16,7 @@ fn can_use_two_breaks() {
                     start: ShiftStart {
                         earliest: format_time(0.),
                         latest: Some(format_time(0.)),
-                        location: (0., 0.).to_loc(),
+                        location: Some((0., 0.).to_loc()),
                     },
                     breaks: Some(vec![
                         VehicleBreak::Optional {
@@ -38,6 +38,7 @@ fn can_use_two_breaks() {
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -53,12 +54,15 @@ fn can_use_two_breaks() {
                 cost: 412.,
                 distance: 198,
                 duration: 204,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 198, serving: 2, break_time: 4, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -103,7 +107,10 @@ fn can_use_two_breaks() {
                                     end: "1970-01-01T00:01:43Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                lateness: None,
+                                compartment: None,
+                                visit_index: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -114,9 +121,14 @@ fn can_use_two_breaks() {
                                     end: "1970-01-01T00:01:45Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                lateness: None,
+                                compartment: None,
+                                visit_index: None,
                             }
                         ],
+                        charge: None,
+                        on_time_probability: None,
                     }),
                     create_stop_with_activity(
                         "arrival",
@@ -131,6 +143,8 @@ fn can_use_two_breaks() {
                     cost: 412.,
                     distance: 198,
                     duration: 204,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 198, serving: 2, break_time: 4, ..Timing::default() },
                 },
             }],
@@ -23,11 +23,15 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
             vehicles: vec![
                 VehicleType {
                     shifts: vec![VehicleShift {
-                        start: ShiftStart { earliest: format_time(0.), latest: None, location: (100., 0.).to_loc() },
+                        start: ShiftStart {
+                            earliest: format_time(0.),
+                            latest: None,
+                            location: Some((100., 0.).to_loc()),
+                        },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: format_time(1000.).to_string(),
-                            location: (100., 0.).to_loc(),
+                            location: Some((100., 0.).to_loc()),
                         }),
                         dispatch: None,
                         breaks: Some(vec![VehicleBreak::Optional {
@@ -40,11 +44,13 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
                             policy,
                         }]),
                         reloads: None,
+                        charging_stations: None,
                     }],
                     ..create_default_vehicle_type()
                 },
                 create_default_vehicle("vehicle_without_break"),
             ],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -60,12 +66,15 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
                 cost: 52.,
                 distance: 20,
                 duration: 22,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 20, serving: 2, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "vehicle_without_break_1".to_string(),
                 type_id: "vehicle_without_break".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -104,6 +113,8 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
                     cost: 52.,
                     distance: 20,
                     duration: 22,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 20, serving: 2, ..Timing::default() },
                 },
             }],
@@ -141,6 +152,7 @@ fn can_skip_break_when_jobs_completed_impl(policy: Option<VehicleOptionalBreakPo
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -156,12 +168,15 @@ fn can_skip_break_when_jobs_completed_impl(policy: Option<VehicleOptionalBreakPo
                 cost: 24.,
                 distance: 2,
                 duration: 12,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 2, serving: 10, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -192,6 +207,8 @@ fn can_skip_break_when_jobs_completed_impl(policy: Option<VehicleOptionalBreakPo
                     cost: 24.,
                     distance: 2,
                     duration: 12,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 2, serving: 10, ..Timing::default() },
                 },
             }],
@@ -239,6 +256,7 @@ fn can_skip_second_break_when_jobs_completed_impl(policy: Option<VehicleOptional
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -254,12 +272,15 @@ fn can_skip_second_break_when_jobs_completed_impl(policy: Option<VehicleOptional
                 cost: 54.,
                 distance: 20,
                 duration: 24,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -306,6 +327,8 @@ fn can_skip_second_break_when_jobs_completed_impl(policy: Option<VehicleOptional
                     cost: 54.,
                     distance: 20,
                     duration: 24,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
                 },
             }],
@@ -346,6 +369,7 @@ fn can_skip_break_depending_on_policy_impl(
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -46,6 +46,7 @@ fn get_solution(
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -78,12 +79,15 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                 cost: 26.,
                 distance: 6,
                 duration: 10,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 6, serving: 2, break_time: 2, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -130,6 +134,8 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                     cost: 26.,
                     distance: 6,
                     duration: 10,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 6, serving: 2, break_time: 2, ..Timing::default() },
                 },
             }],
@@ -157,12 +163,15 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                 cost: 26.,
                 distance: 6,
                 duration: 10,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 6, serving: 2, break_time: 2, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -209,6 +218,8 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                     cost: 26.,
                     distance: 6,
                     duration: 10,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 6, serving: 2, break_time: 2, ..Timing::default() },
                 },
             }],
@@ -4,7 +4,7 @@ use crate::format_time;
 use crate::helpers::*;
 
 fn create_shift_start() -> ShiftStart {
-    ShiftStart { earliest: format_time(0.), latest: Some(format_time(0.)), location: (0., 0.).to_loc() }
+    ShiftStart { earliest: format_time(0.), latest: Some(format_time(0.)), location: Some((0., 0.).to_loc()) }
 }
 
 #[test]
@@ -27,6 +27,7 @@ fn can_assign_break_during_travel() {
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -42,12 +43,15 @@ fn can_assign_break_during_travel() {
                 cost: 54.,
                 distance: 20,
                 duration: 24,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -77,7 +81,10 @@ fn can_assign_break_during_travel() {
                             location: None,
                             time: None,
                             job_tag: None,
-                            commute: None
+                            commute: None,
+                            lateness: None,
+                            compartment: None,
+                            visit_index: None,
                         }],
                     }),
                     create_stop_with_activity(
@@ -101,6 +108,8 @@ fn can_assign_break_during_travel() {
                     cost: 54.,
                     distance: 20,
                     duration: 24,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
                 },
             }],
@@ -126,6 +135,7 @@ fn can_assign_break_during_activity() {
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -141,12 +151,15 @@ fn can_assign_break_during_activity() {
                 cost: 35.,
                 distance: 10,
                 duration: 15,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 10, serving: 3, break_time: 2, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -175,7 +188,10 @@ fn can_assign_break_during_activity() {
                                     end: "1970-01-01T00:00:10Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                lateness: None,
+                                compartment: None,
+                                visit_index: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -186,9 +202,14 @@ fn can_assign_break_during_activity() {
                                     end: "1970-01-01T00:00:09Z".to_string(),
                                 }),
                                 job_tag: None,
-                                commute: None
+                                commute: None,
+                                lateness: None,
+                                compartment: None,
+                                visit_index: None,
                             }
                         ],
+                        charge: None,
+                        on_time_probability: None,
                     }),
                     create_stop_with_activity(
                         "arrival",
@@ -203,6 +224,8 @@ fn can_assign_break_during_activity() {
                     cost: 35.,
                     distance: 10,
                     duration: 15,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 10, serving: 3, break_time: 2, ..Timing::default() },
                 },
             }],
@@ -85,6 +85,7 @@ fn can_cluster_simple_jobs_impl(
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -226,6 +227,7 @@ fn can_handle_two_clusters_impl(
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: once(create_stop_with_activity(
                     "departure",
                     "departure",
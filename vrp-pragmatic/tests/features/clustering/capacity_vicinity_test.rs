@@ -48,6 +48,7 @@ fn can_mix_pickup_delivery_jobs() {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -143,6 +144,7 @@ fn can_vary_cluster_size_based_on_capacity_impl(
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: once(create_stop_with_activity(
                     "departure",
                     "departure",
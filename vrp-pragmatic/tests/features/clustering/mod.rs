@@ -44,6 +44,9 @@ impl From<ActivityData> for Activity {
                 forward: convert_expected_commute_info(fwd),
                 backward: convert_expected_commute_info(bak),
             }),
+            lateness: None,
+            compartment: None,
+            visit_index: None,
         }
     }
 }
@@ -83,6 +86,8 @@ impl From<StopData> for Stop {
             },
             load: vec![stop.load],
             activities: stop.activities.into_iter().map(ActivityData::into).collect(),
+            charge: None,
+            on_time_probability: None,
         })
     }
 }
@@ -92,6 +97,8 @@ fn create_statistic(data: (f64, i64, i64, (i64, i64, i64, i64))) -> Statistic {
         cost: data.0,
         distance: data.1,
         duration: data.2,
+        emissions: None,
+        tolls: None,
         times: Timing {
             driving: data.3 .0,
             serving: data.3 .1,
@@ -122,7 +129,8 @@ fn create_test_problem(jobs_data: &[(f64, &str)], capacity: i32, clustering: Clu
                 shifts: vec![create_default_open_vehicle_shift()],
                 ..create_vehicle_with_capacity("my_vehicle", vec![capacity])
             }],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            drivers: None,
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, service_level: None }],
         },
         ..create_empty_problem()
     }
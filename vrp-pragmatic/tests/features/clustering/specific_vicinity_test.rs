@@ -29,18 +29,19 @@ fn can_handle_parking_with_no_clusters_and_job_time_windows() {
                     start: ShiftStart {
                         earliest: "1970-01-01T09:00:00Z".to_string(),
                         latest: None,
-                        location: Location::Coordinate { lat: 52.497, lng: 13.547 },
+                        location: Some(Location::Coordinate { lat: 52.497, lng: 13.547 }),
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: "1970-01-01T18:00:00Z".to_string(),
-                        location: Location::Coordinate { lat: 52.497, lng: 13.547 },
+                        location: Some(Location::Coordinate { lat: 52.497, lng: 13.547 }),
                     }),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle("vehicle1")
             }],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            drivers: None,
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, service_level: None }],
         },
         ..create_empty_problem()
     };
@@ -103,18 +104,19 @@ fn can_handle_waiting_time_with_parking_impl(
                     start: ShiftStart {
                         earliest: "1970-01-01T09:00:00Z".to_string(),
                         latest: None,
-                        location: vehicle_location.clone(),
+                        location: Some(vehicle_location.clone()),
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: "1970-01-01T18:00:00Z".to_string(),
-                        location: vehicle_location,
+                        location: Some(vehicle_location),
                     }),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle("vehicle1")
             }],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            drivers: None,
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, service_level: None }],
         },
         objectives: None,
     };
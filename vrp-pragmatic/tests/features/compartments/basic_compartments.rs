@@ -0,0 +1,184 @@
+use crate::format::problem::*;
+use crate::format::solution::{Activity, UnassignedJobReason};
+use crate::helpers::*;
+
+fn create_vehicle_with_compartments(compartments: Vec<(&str, i32, Option<Vec<&str>>)>) -> VehicleType {
+    VehicleType {
+        capacity: vec![compartments.iter().map(|(_, capacity, _)| *capacity).sum()],
+        compartments: Some(
+            compartments
+                .into_iter()
+                .map(|(id, capacity, products)| VehicleCompartment {
+                    id: id.to_string(),
+                    capacity: vec![capacity],
+                    products: products.map(|products| products.into_iter().map(|p| p.to_string()).collect()),
+                })
+                .collect(),
+        ),
+        ..create_default_vehicle_type()
+    }
+}
+
+fn create_products(incompatible: Vec<(&str, &str)>) -> Option<Vec<Product>> {
+    Some(
+        incompatible
+            .into_iter()
+            .map(|(id, other)| Product { id: id.to_string(), incompatible: vec![other.to_string()] })
+            .collect(),
+    )
+}
+
+fn get_job_compartments(solution: &crate::format::solution::Solution) -> Vec<(String, Option<String>)> {
+    let mut compartments = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity: &&Activity| activity.job_id.starts_with("job"))
+        .map(|activity| (activity.job_id.clone(), activity.compartment.clone()))
+        .collect::<Vec<_>>();
+    compartments.sort();
+
+    compartments
+}
+
+#[test]
+fn can_load_incompatible_products_into_different_compartments() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_product("job1", (1., 0.), vec![4], "fuel"),
+                create_delivery_job_with_product("job2", (2., 0.), vec![3], "diesel"),
+                create_delivery_job_with_product("job3", (3., 0.), vec![1], "fuel"),
+            ],
+            products: create_products(vec![("fuel", "diesel")]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_compartments(vec![("c1", 5, None), ("c2", 5, None)])],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let compartments = get_job_compartments(&solution);
+    assert_eq!(compartments.len(), 3);
+    assert!(compartments.iter().all(|(_, compartment)| compartment.is_some()));
+    assert_eq!(compartments[0].1, compartments[2].1);
+    assert_ne!(compartments[0].1, compartments[1].1);
+}
+
+#[test]
+fn can_unassign_job_when_no_compartment_is_available() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_product("job1", (1., 0.), vec![2], "fuel"),
+                create_delivery_job_with_product("job2", (2., 0.), vec![2], "diesel"),
+                create_delivery_job_with_product("job3", (3., 0.), vec![2], "gas"),
+            ],
+            products: create_products(vec![("fuel", "diesel"), ("gas", "fuel"), ("diesel", "gas")]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_compartments(vec![("c1", 5, None), ("c2", 5, None)])],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.as_ref().map_or(0, |u| u.len()), 1);
+    let reasons = solution.unassigned.iter().flatten().flat_map(|u| u.reasons.iter().cloned()).collect::<Vec<_>>();
+    assert_eq!(
+        reasons,
+        vec![UnassignedJobReason {
+            code: "COMPARTMENT_CONSTRAINT".to_string(),
+            description: "cannot be assigned due to vehicle compartments constraint".to_string()
+        }]
+    );
+}
+
+#[test]
+fn can_load_product_only_into_allowed_compartment() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_product("job1", (1., 0.), vec![2], "chilled"),
+                create_delivery_job_with_product("job2", (2., 0.), vec![2], "dry"),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_compartments(vec![
+                ("ambient", 5, None),
+                ("fridge", 5, Some(vec!["chilled"])),
+            ])],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_job_compartments(&solution),
+        vec![("job1".to_string(), Some("ambient".to_string())), ("job2".to_string(), Some("ambient".to_string()))]
+    );
+}
+
+#[test]
+fn can_use_compartments_with_pickup_and_delivery() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    product: Some("fuel".to_string()),
+                    ..create_pickup_delivery_job_with_params(
+                        "job1",
+                        vec![5],
+                        ((1., 0.), 1., vec![]),
+                        ((2., 0.), 1., vec![]),
+                    )
+                },
+                Job {
+                    product: Some("diesel".to_string()),
+                    ..create_pickup_delivery_job_with_params(
+                        "job2",
+                        vec![5],
+                        ((3., 0.), 1., vec![]),
+                        ((4., 0.), 1., vec![]),
+                    )
+                },
+            ],
+            products: create_products(vec![("fuel", "diesel")]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_compartments(vec![("c1", 5, None), ("c2", 2, None)])],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert!(get_job_compartments(&solution).iter().all(|(_, compartment)| *compartment == Some("c1".to_string())));
+}
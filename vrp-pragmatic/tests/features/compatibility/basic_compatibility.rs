@@ -31,6 +31,7 @@ fn can_separate_jobs_based_on_compatibility() {
                     ..create_default_vehicle_type()
                 },
             ],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -59,6 +60,7 @@ fn can_unassign_job_due_to_compatibility() {
         },
         fleet: Fleet {
             vehicles: vec![VehicleType { capacity: vec![2], ..create_default_vehicle_type() }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
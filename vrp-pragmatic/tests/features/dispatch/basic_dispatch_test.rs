@@ -14,6 +14,7 @@ fn create_problem_with_dispatch(dispatch: Option<Vec<VehicleDispatch>>) -> Probl
                 shifts: vec![VehicleShift { dispatch, ..create_default_vehicle_shift() }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -38,12 +39,15 @@ fn can_assign_single_dispatch() {
                 cost: 42.,
                 distance: 14,
                 duration: 18,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 14, serving: 4, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -90,6 +94,8 @@ fn can_assign_single_dispatch() {
                     cost: 42.,
                     distance: 14,
                     duration: 18,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 14, serving: 4, ..Timing::default() },
                 },
             }],
@@ -201,6 +207,7 @@ fn create_problem_with_dispatch_5jobs(vehicle_ids: Vec<&str>, dispatch: Option<V
                 capacity: vec![1],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -37,6 +37,7 @@ fn can_use_init_solution_with_dispatch() {
                 capacity: vec![1],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -46,6 +47,8 @@ fn can_use_init_solution_with_dispatch() {
             cost: 34.,
             distance: 4,
             duration: 10,
+            emissions: None,
+            tolls: None,
             times: Timing { driving: 4, serving: 6, ..Timing::default() },
         },
         tours: vec![
@@ -53,6 +56,7 @@ fn can_use_init_solution_with_dispatch() {
                 vehicle_id: "v1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     Stop::Point(PointStop {
                         location: (0., 0.).to_loc(),
@@ -74,6 +78,9 @@ fn can_use_init_solution_with_dispatch() {
                                 }),
                                 job_tag: None,
                                 commute: None,
+                                lateness: None,
+                                compartment: None,
+                                visit_index: None,
                             },
                             Activity {
                                 job_id: "dispatch".to_string(),
@@ -85,8 +92,13 @@ fn can_use_init_solution_with_dispatch() {
                                 }),
                                 job_tag: None,
                                 commute: None,
+                                lateness: None,
+                                compartment: None,
+                                visit_index: None,
                             },
                         ],
+                        charge: None,
+                        on_time_probability: None,
                     }),
                     create_stop_with_activity(
                         "job1",
@@ -109,6 +121,8 @@ fn can_use_init_solution_with_dispatch() {
                     cost: 17.,
                     distance: 2,
                     duration: 5,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 2, serving: 3, ..Timing::default() },
                 },
             },
@@ -116,6 +130,7 @@ fn can_use_init_solution_with_dispatch() {
                 vehicle_id: "v2".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     Stop::Point(PointStop {
                         location: (0., 0.).to_loc(),
@@ -137,6 +152,9 @@ fn can_use_init_solution_with_dispatch() {
                                 }),
                                 job_tag: None,
                                 commute: None,
+                                lateness: None,
+                                compartment: None,
+                                visit_index: None,
                             },
                             Activity {
                                 job_id: "dispatch".to_string(),
@@ -148,8 +166,13 @@ fn can_use_init_solution_with_dispatch() {
                                 }),
                                 job_tag: None,
                                 commute: None,
+                                lateness: None,
+                                compartment: None,
+                                visit_index: None,
                             },
                         ],
+                        charge: None,
+                        on_time_probability: None,
                     }),
                     create_stop_with_activity(
                         "job2",
@@ -172,6 +195,8 @@ fn can_use_init_solution_with_dispatch() {
                     cost: 17.,
                     distance: 2,
                     duration: 5,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 2, serving: 3, ..Timing::default() },
                 },
             },
@@ -0,0 +1,78 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_backhaul_problem(jobs: Vec<Job>, backhauls: Option<bool>) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { backhauls, ..create_vehicle_with_capacity("my_vehicle", vec![3]) }],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_pickup_job_with_times(id: &str, location: (f64, f64), times: Vec<(i32, i32)>) -> Job {
+    let job = create_delivery_job_with_times(id, location, times, 1.);
+
+    Job { pickups: job.deliveries.clone(), deliveries: None, ..job }
+}
+
+fn get_activity_types(solution: &Solution) -> Vec<String> {
+    solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "pickup" || activity.activity_type == "delivery")
+        .map(|activity| activity.activity_type.clone())
+        .collect()
+}
+
+#[test]
+fn can_serve_backhauls_after_linehauls() {
+    let problem = create_backhaul_problem(
+        vec![
+            create_delivery_job("job1", (1., 0.)),
+            create_pickup_job("job2", (2., 0.)),
+            create_delivery_job("job3", (3., 0.)),
+            create_pickup_job("job4", (4., 0.)),
+            create_delivery_job("job5", (5., 0.)),
+        ],
+        Some(true),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_activity_types(&solution), vec!["delivery", "delivery", "delivery", "pickup", "pickup"]);
+}
+
+parameterized_test! {can_unassign_backhaul_with_early_time_window, (backhauls, expected_unassigned), {
+    can_unassign_backhaul_with_early_time_window_impl(backhauls, expected_unassigned);
+}}
+
+can_unassign_backhaul_with_early_time_window! {
+    case01_with_backhauls: (Some(true), Some(1)),
+    case02_without_backhauls: (Some(false), None),
+    case03_default: (None, None),
+}
+
+fn can_unassign_backhaul_with_early_time_window_impl(backhauls: Option<bool>, expected_unassigned: Option<usize>) {
+    let problem = create_backhaul_problem(
+        vec![
+            create_pickup_job_with_times("job1", (1., 0.), vec![(0, 2)]),
+            create_delivery_job_with_times("job2", (5., 0.), vec![(0, 6)], 1.),
+        ],
+        backhauls,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.map(|unassigned| unassigned.len()), expected_unassigned);
+}
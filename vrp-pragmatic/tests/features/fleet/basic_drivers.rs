@@ -0,0 +1,126 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_vehicle_at(id: &str, location: (f64, f64)) -> VehicleType {
+    VehicleType {
+        shifts: vec![create_default_vehicle_shift_with_locations(location, location)],
+        ..create_default_vehicle(id)
+    }
+}
+
+#[test]
+fn can_limit_amount_of_tours_by_amount_of_drivers() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (10., 0.), vec![(0, 20)], 1.),
+                create_delivery_job_with_times("job2", (-10., 0.), vec![(0, 20)], 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_at("vehicle1", (0., 0.)), create_vehicle_at("vehicle2", (0., 0.))],
+            drivers: Some(vec![create_default_driver("driver")]),
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].driver_id, Some("driver_1".to_string()));
+    assert_eq!(solution.unassigned.map(|jobs| jobs.len()), Some(1));
+}
+
+#[test]
+fn can_use_driver_skills() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_skills("job1", (1., 0.), all_of_skills(vec!["license".to_string()]))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("vehicle")],
+            drivers: Some(vec![
+                DriverType {
+                    costs: DriverCosts { fixed: Some(100.), distance: 0., time: 0. },
+                    skills: Some(vec!["license".to_string()]),
+                    ..create_default_driver("skilled")
+                },
+                create_default_driver("unskilled"),
+            ]),
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].driver_id, Some("skilled_1".to_string()));
+    assert_eq!(solution.statistic.cost, 10. + 2. + 2. + 1. + 100.);
+}
+
+#[test]
+fn can_prefer_cheaper_driver() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (5., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("vehicle")],
+            drivers: Some(vec![
+                DriverType {
+                    costs: DriverCosts { fixed: Some(10.), distance: 2., time: 0. },
+                    ..create_default_driver("expensive")
+                },
+                DriverType {
+                    costs: DriverCosts { fixed: Some(10.), distance: 1., time: 0. },
+                    ..create_default_driver("cheap")
+                },
+            ]),
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].driver_id, Some("cheap_1".to_string()));
+}
+
+#[test]
+fn can_respect_driver_shift_time() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (5., 0.), vec![(0, 100)], 1.),
+                create_delivery_job_with_times("job2", (5., 0.), vec![(200, 300)], 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("vehicle")],
+            drivers: Some(vec![DriverType {
+                shifts: Some(vec![DriverShift { earliest: format_time(0.), latest: format_time(150.) }]),
+                ..create_default_driver("driver")
+            }]),
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.unassigned.map(|jobs| jobs.iter().map(|job| job.job_id.clone()).collect::<Vec<_>>()),
+        Some(vec!["job2".to_string()])
+    );
+}
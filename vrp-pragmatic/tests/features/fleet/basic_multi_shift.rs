@@ -18,20 +18,28 @@ fn can_use_multiple_times_from_vehicle_and_job() {
             vehicles: vec![VehicleType {
                 shifts: vec![
                     VehicleShift {
-                        start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                        start: ShiftStart {
+                            earliest: format_time(0.),
+                            latest: None,
+                            location: Some((0., 0.).to_loc()),
+                        },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: format_time(99.).to_string(),
-                            location: (0., 0.).to_loc(),
+                            location: Some((0., 0.).to_loc()),
                         }),
                         ..create_default_vehicle_shift()
                     },
                     VehicleShift {
-                        start: ShiftStart { earliest: format_time(100.), latest: None, location: (0., 0.).to_loc() },
+                        start: ShiftStart {
+                            earliest: format_time(100.),
+                            latest: None,
+                            location: Some((0., 0.).to_loc()),
+                        },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: format_time(200.).to_string(),
-                            location: (0., 0.).to_loc(),
+                            location: Some((0., 0.).to_loc()),
                         }),
                         ..create_default_vehicle_shift()
                     },
@@ -39,6 +47,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                 capacity: vec![1],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -54,6 +63,8 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                 cost: 102.,
                 distance: 40,
                 duration: 42,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 40, serving: 2, ..Timing::default() },
             },
             tours: vec![
@@ -61,6 +72,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                     vehicle_id: "my_vehicle_1".to_string(),
                     type_id: "my_vehicle".to_string(),
                     shift_index: 0,
+                    driver_id: None,
                     stops: vec![
                         create_stop_with_activity(
                             "departure",
@@ -91,6 +103,8 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         cost: 51.,
                         distance: 20,
                         duration: 21,
+                        emissions: None,
+                        tolls: None,
                         times: Timing { driving: 20, serving: 1, ..Timing::default() },
                     },
                 },
@@ -98,6 +112,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                     vehicle_id: "my_vehicle_1".to_string(),
                     type_id: "my_vehicle".to_string(),
                     shift_index: 1,
+                    driver_id: None,
                     stops: vec![
                         create_stop_with_activity(
                             "departure",
@@ -128,6 +143,8 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         cost: 51.,
                         distance: 20,
                         duration: 21,
+                        emissions: None,
+                        tolls: None,
                         times: Timing { driving: 20, serving: 1, ..Timing::default() },
                     },
                 },
@@ -157,7 +174,7 @@ fn can_prefer_first_days_with_minimize_arrival_time_objective() {
                         start: ShiftStart {
                             earliest: format_time(*earliest),
                             latest: None,
-                            location: (0., 0.).to_loc(),
+                            location: Some((0., 0.).to_loc()),
                         },
                         end: None,
                         ..create_default_vehicle_shift()
@@ -166,6 +183,7 @@ fn can_prefer_first_days_with_minimize_arrival_time_objective() {
                 capacity: vec![1],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -11,6 +11,7 @@ fn can_use_vehicle_with_open_end() {
                 shifts: vec![create_default_open_vehicle_shift()],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -26,12 +27,15 @@ fn can_use_vehicle_with_open_end() {
                 cost: 13.,
                 distance: 1,
                 duration: 2,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 1, serving: 1, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -54,6 +58,8 @@ fn can_use_vehicle_with_open_end() {
                     cost: 13.,
                     distance: 1,
                     duration: 2,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 1, serving: 1, ..Timing::default() },
                 },
             }],
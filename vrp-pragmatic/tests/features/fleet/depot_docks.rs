@@ -0,0 +1,179 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_depot_problem(jobs: Vec<Job>, docks: usize, unloading: Option<DockDuration>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs,
+            depots: Some(vec![Depot {
+                id: "depot1".to_string(),
+                location: (0., 0.).to_loc(),
+                docks,
+                loading: Some(DockDuration { fixed: 10., per_unit: 1. }),
+                unloading,
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["truck_1".to_string(), "truck_2".to_string()],
+                ..create_vehicle_with_capacity("truck", vec![1])
+            }],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_dock_assignment(dock: usize, vehicle_id: &str, operation: &str, time: (f64, f64)) -> DockAssignment {
+    DockAssignment {
+        depot_id: "depot1".to_string(),
+        dock,
+        vehicle_id: vehicle_id.to_string(),
+        shift_index: 0,
+        operation: operation.to_string(),
+        time: Interval { start: format_time(time.0), end: format_time(time.1) },
+    }
+}
+
+fn get_departures(solution: &Solution) -> Vec<(String, String)> {
+    let mut departures = solution
+        .tours
+        .iter()
+        .map(|tour| (tour.vehicle_id.clone(), tour.stops.first().unwrap().schedule().departure.clone()))
+        .collect::<Vec<_>>();
+    departures.sort();
+
+    departures
+}
+
+parameterized_test! {can_delay_departure_when_docks_are_busy, (docks, expected), {
+    can_delay_departure_when_docks_are_busy_impl(docks, expected);
+}}
+
+can_delay_departure_when_docks_are_busy! {
+    case01_single_dock: (1, vec![(0, "truck_1", (0., 11.)), (0, "truck_2", (11., 22.))]),
+    case02_two_docks: (2, vec![(0, "truck_1", (0., 11.)), (1, "truck_2", (0., 11.))]),
+}
+
+fn can_delay_departure_when_docks_are_busy_impl(docks: usize, expected: Vec<(usize, &str, (f64, f64))>) {
+    let problem = create_depot_problem(
+        vec![create_delivery_job("job1", (5., 0.)), create_delivery_job("job2", (5., 0.))],
+        docks,
+        None,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_departures(&solution),
+        expected
+            .iter()
+            .map(|(_, vehicle_id, (_, end))| (vehicle_id.to_string(), format_time(*end)))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        solution.docks,
+        Some(
+            expected
+                .into_iter()
+                .map(|(dock, vehicle_id, time)| create_dock_assignment(dock, vehicle_id, "loading", time))
+                .collect()
+        )
+    );
+}
+
+#[test]
+fn can_unload_vehicle_after_arrival() {
+    let problem = create_depot_problem(
+        vec![create_pickup_job("job1", (5., 0.))],
+        1,
+        Some(DockDuration { fixed: 2., per_unit: 3. }),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let vehicle_id = solution.tours[0].vehicle_id.as_str();
+    assert_eq!(
+        solution.docks,
+        Some(vec![
+            create_dock_assignment(0, vehicle_id, "loading", (0., 10.)),
+            create_dock_assignment(0, vehicle_id, "unloading", (21., 26.)),
+        ])
+    );
+}
+
+#[test]
+fn can_unassign_job_when_loading_is_too_long() {
+    let problem = create_depot_problem(
+        vec![
+            create_delivery_job_with_times("job1", (5., 0.), vec![(0, 20)], 1.),
+            create_delivery_job_with_times("job2", (5., 0.), vec![(0, 20)], 1.),
+        ],
+        1,
+        None,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.map(|unassigned| unassigned.len()), Some(1));
+    assert_eq!(solution.docks.map(|docks| docks.len()), Some(1));
+}
+
+#[test]
+fn can_unassign_job_when_unloading_ends_after_shift_end() {
+    let mut problem = create_depot_problem(
+        vec![create_pickup_job("job1", (5., 0.))],
+        1,
+        Some(DockDuration { fixed: 2., per_unit: 3. }),
+    );
+    problem.fleet.vehicles[0].shifts[0].end.iter_mut().for_each(|end| end.latest = format_time(24.));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(solution.unassigned.map(|unassigned| unassigned.len()), Some(1));
+}
+
+parameterized_test! {can_reject_docks_with_vehicle_rests, vehicle, {
+    can_reject_docks_with_vehicle_rests_impl(vehicle);
+}}
+
+can_reject_docks_with_vehicle_rests! {
+    case01_driving_hours: create_vehicle_with_driving_hours("truck"),
+    case02_linked_shifts: VehicleType {
+        shifts: vec![
+            VehicleShift {
+                end: Some(ShiftEnd { earliest: None, latest: format_time(10.), location: Some((0., 0.).to_loc()) }),
+                ..create_default_vehicle_shift()
+            },
+            VehicleShift {
+                start: ShiftStart { earliest: format_time(50.), latest: None, location: None },
+                ..create_default_vehicle_shift()
+            },
+        ],
+        ..create_default_vehicle("truck")
+    },
+}
+
+fn can_reject_docks_with_vehicle_rests_impl(vehicle: VehicleType) {
+    let mut problem = create_depot_problem(vec![create_delivery_job("job1", (5., 0.))], 1, None);
+    problem.fleet.vehicles = vec![vehicle];
+    let matrix = create_matrix_from_problem(&problem);
+
+    let result = (problem, vec![matrix]).read_pragmatic();
+
+    let codes = result.err().map(|errors| errors.into_iter().map(|error| error.code).collect::<Vec<_>>());
+    assert_eq!(codes, Some(vec!["E1115".to_string()]));
+}
@@ -0,0 +1,113 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_electric_vehicle(battery: VehicleBattery, stations: Option<Vec<(f64, f64)>>) -> VehicleType {
+    VehicleType {
+        shifts: vec![VehicleShift {
+            charging_stations: stations.map(|stations| {
+                stations
+                    .into_iter()
+                    .map(|location| VehicleChargingStation {
+                        location: location.to_loc(),
+                        duration: 10.,
+                        rate: 1.,
+                        times: None,
+                        tag: None,
+                    })
+                    .collect()
+            }),
+            ..create_default_vehicle_shift()
+        }],
+        capacity: vec![1],
+        battery: Some(battery),
+        ..create_default_vehicle_type()
+    }
+}
+
+fn create_problem(vehicle: VehicleType) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![vehicle], drivers: None, profiles: create_default_matrix_profiles() },
+        ..create_empty_problem()
+    }
+}
+
+fn get_charging_activities(solution: &Solution) -> usize {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "charging")
+        .count()
+}
+
+fn get_charges(solution: &Solution) -> Vec<f64> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .filter_map(|stop| stop.as_point())
+        .map(|stop| stop.charge.expect("charge should be reported"))
+        .collect()
+}
+
+#[test]
+fn can_charge_battery_on_the_way() {
+    let battery = VehicleBattery { capacity: 15., initial: None, consumption: 1., full_load_consumption: None };
+    let problem = create_problem(create_electric_vehicle(battery, Some(vec![(8., 0.)])));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_charging_activities(&solution), 1);
+    assert!(get_charges(&solution).into_iter().all(|charge| charge >= 0.));
+}
+
+#[test]
+fn can_skip_charging_when_battery_is_enough() {
+    let battery = VehicleBattery { capacity: 25., initial: None, consumption: 1., full_load_consumption: None };
+    let problem = create_problem(create_electric_vehicle(battery, Some(vec![(8., 0.)])));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_charging_activities(&solution), 0);
+    assert_eq!(get_charges(&solution), vec![25., 15., 5.]);
+}
+
+#[test]
+fn can_consider_load_in_battery_consumption() {
+    let battery = VehicleBattery { capacity: 25., initial: None, consumption: 1., full_load_consumption: Some(2.) };
+    let problem = create_problem(create_electric_vehicle(battery, None));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "BATTERY_CONSTRAINT".to_string(),
+                description: "cannot be assigned due to battery capacity of vehicle".to_string()
+            }]
+        }])
+    );
+}
+
+#[test]
+fn can_skip_charging_when_job_is_unreachable() {
+    let battery = VehicleBattery { capacity: 5., initial: None, consumption: 1., full_load_consumption: None };
+    let problem = create_problem(create_electric_vehicle(battery, Some(vec![(2., 0.)])));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(solution.unassigned.iter().flatten().count(), 1);
+}
@@ -0,0 +1,71 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_vehicle_with_emissions(id: &str, distance_cost: f64, emissions: VehicleEmissions) -> VehicleType {
+    VehicleType {
+        costs: VehicleCosts { distance: distance_cost, ..create_default_vehicle_costs() },
+        emissions: Some(emissions),
+        ..create_vehicle_with_capacity(id, vec![1])
+    }
+}
+
+fn create_problem(vehicles: Vec<VehicleType>, objectives: Option<Vec<Vec<Objective>>>) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: Fleet { vehicles, drivers: None, profiles: create_default_matrix_profiles() },
+        objectives,
+    }
+}
+
+#[test]
+fn can_report_load_dependent_cost_and_emissions() {
+    let vehicle = VehicleType {
+        costs: VehicleCosts { full_load_distance: Some(3.), ..create_default_vehicle_costs() },
+        ..create_vehicle_with_emissions(
+            "my_vehicle",
+            1.,
+            VehicleEmissions { distance: 1., full_load_distance: Some(3.) },
+        )
+    };
+    let problem = create_problem(vec![vehicle], None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].statistic.emissions, Some(40.));
+    assert_eq!(
+        solution.statistic,
+        Statistic {
+            cost: 71.,
+            distance: 20,
+            duration: 21,
+            emissions: Some(40.),
+            tolls: None,
+            times: Timing { driving: 20, serving: 1, ..Timing::default() },
+        }
+    );
+}
+
+#[test]
+fn can_prefer_vehicle_with_less_emissions() {
+    let objectives = vec![vec![MinimizeUnassignedJobs { breaks: None }], vec![MinimizeEmissions], vec![MinimizeCost]];
+    let problem = create_problem(
+        vec![
+            create_vehicle_with_emissions("diesel", 1., VehicleEmissions { distance: 3., full_load_distance: None }),
+            create_vehicle_with_emissions("hybrid", 2., VehicleEmissions { distance: 1., full_load_distance: None }),
+        ],
+        Some(objectives),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].type_id, "hybrid");
+    assert_eq!(solution.statistic.emissions, Some(20.));
+}
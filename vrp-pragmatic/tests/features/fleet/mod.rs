@@ -1,5 +1,13 @@
+mod backhauls;
+mod basic_drivers;
 mod basic_multi_shift;
 mod basic_open_end;
+mod depot_docks;
+mod electric_vehicles;
+mod load_costs;
 mod multi_dimens;
 mod profile_variation;
+mod sync_vehicles;
+mod tours_in_progress;
+mod two_echelon;
 mod unreachable_jobs;
@@ -18,6 +18,7 @@ fn can_use_two_dimensions() {
                 capacity: vec![1, 1],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -33,12 +34,15 @@ fn can_use_two_dimensions() {
                 cost: 16.,
                 distance: 2,
                 duration: 4,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 2, serving: 2, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity_md(
                         "departure",
@@ -69,6 +73,8 @@ fn can_use_two_dimensions() {
                     cost: 16.,
                     distance: 2,
                     duration: 4,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 2, serving: 2, ..Timing::default() },
                 },
             }],
@@ -87,6 +93,7 @@ fn can_unassign_due_to_dimension_mismatch() {
                 capacity: vec![1],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -0,0 +1,88 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+fn create_sync_job(id: &str, location: (f64, f64), max_offset: f64) -> Job {
+    let create_service = |tag: &str| JobTask { demand: None, ..create_task(location, Some(tag.to_string())) };
+
+    Job {
+        services: Some(vec![create_service("s1"), create_service("s2")]),
+        sync: Some(JobSync { max_offset }),
+        ..create_job(id)
+    }
+}
+
+fn create_sync_problem(job: Job, vehicle_starts: Vec<(f64, f64)>) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vehicle_starts
+                .into_iter()
+                .enumerate()
+                .map(|(idx, start)| VehicleType {
+                    shifts: vec![create_default_vehicle_shift_with_locations(start, start)],
+                    ..create_default_vehicle(format!("v{}", idx + 1).as_str())
+                })
+                .collect(),
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_service_starts(solution: &Solution, job_id: &str) -> Vec<(String, f64)> {
+    let mut starts = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter().map(move |stop| (tour, stop)))
+        .filter(|(_, stop)| stop.activities().iter().any(|activity| activity.job_id == job_id))
+        .map(|(tour, stop)| (tour.vehicle_id.clone(), parse_time(&stop.schedule().departure) - 1.))
+        .collect::<Vec<_>>();
+    starts.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    starts
+}
+
+#[test]
+fn can_serve_sync_job_by_two_vehicles_at_the_same_time() {
+    let problem = create_sync_problem(create_sync_job("job1", (5., 0.), 0.), vec![(0., 0.), (20., 0.)]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_service_starts(&solution, "job1"), vec![("v1_1".to_string(), 15.), ("v2_1".to_string(), 15.)]);
+}
+
+#[test]
+fn can_serve_sync_job_within_max_offset() {
+    let problem = create_sync_problem(create_sync_job("job1", (5., 0.), 4.), vec![(0., 0.), (20., 0.)]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_service_starts(&solution, "job1"), vec![("v1_1".to_string(), 11.), ("v2_1".to_string(), 15.)]);
+}
+
+#[test]
+fn can_unassign_sync_job_when_there_is_only_one_vehicle() {
+    let problem = create_sync_problem(create_sync_job("job1", (5., 0.), 0.), vec![(0., 0.)]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "SYNC_CONSTRAINT".to_string(),
+                description: "cannot be served by several vehicles simultaneously".to_string(),
+            }]
+        }])
+    );
+}
@@ -0,0 +1,69 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_problem(jobs: Vec<Job>, capacity: i32, tour: TourState) -> Problem {
+    Problem {
+        plan: Plan { jobs, tours: Some(vec![tour]), ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![capacity])],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_tour_state(load: Option<Vec<i32>>, completed: Vec<(&str, Option<&str>)>) -> TourState {
+    TourState {
+        vehicle_id: "my_vehicle_1".to_string(),
+        shift_index: None,
+        location: (2., 0.).to_loc(),
+        time: format_time(5.),
+        load,
+        completed: Some(completed.into_iter().map(|(job_id, tag)| create_completed_task(job_id, tag)).collect()),
+    }
+}
+
+fn get_activity_ids(tour: &Tour) -> Vec<String> {
+    tour.stops.iter().flat_map(|stop| stop.activities().iter().map(|activity| activity.job_id.clone())).collect()
+}
+
+#[test]
+fn can_continue_tour_from_current_state() {
+    let problem = create_problem(
+        vec![
+            create_delivery_job("job1", (1., 0.)),
+            create_pickup_delivery_job("job2", (2., 0.), (4., 0.)),
+            create_delivery_job("job3", (3., 0.)),
+        ],
+        2,
+        create_tour_state(None, vec![("job1", None), ("job2", Some("p1"))]),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let tour = &solution.tours[0];
+    assert_eq!(get_activity_ids(tour), vec!["departure", "job3", "job2", "arrival"]);
+    assert_eq!(tour.stops[0].as_point().map(|stop| stop.location.clone()), Some((2., 0.).to_loc()));
+    assert_eq!(tour.stops[0].schedule().departure, format_time(5.));
+}
+
+#[test]
+fn can_reduce_capacity_by_load_on_board() {
+    let problem = create_problem(
+        vec![create_pickup_job("job1", (1., 0.)), create_delivery_job("job2", (3., 0.))],
+        1,
+        create_tour_state(Some(vec![1]), vec![("job1", None)]),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(solution.unassigned.map(|unassigned| unassigned.len()), Some(1));
+}
@@ -0,0 +1,102 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_two_echelon_problem(jobs: Vec<Job>, bike_capacity: i32) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: jobs.into_iter().map(|job| Job { hub: Some("hub1".to_string()), ..job }).collect(),
+            hubs: Some(vec![TransferHub {
+                id: "hub1".to_string(),
+                location: (10., 0.).to_loc(),
+                duration: 5.,
+                first_echelon: vec!["truck".to_string()],
+                second_echelon: vec!["bike".to_string()],
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_capacity("truck", vec![10]),
+                VehicleType {
+                    shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
+                    ..create_vehicle_with_capacity("bike", vec![bike_capacity])
+                },
+            ],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_activity_types(solution: &Solution, vehicle_id: &str) -> Vec<String> {
+    solution
+        .tours
+        .iter()
+        .filter(|tour| tour.vehicle_id == vehicle_id)
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| activity.activity_type.clone())
+        .collect()
+}
+
+#[test]
+fn can_deliver_job_through_transfer_hub() {
+    let problem = create_two_echelon_problem(vec![create_delivery_job("job1", (15., 0.))], 1);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_activity_types(&solution, "truck_1"), vec!["departure", "transfer", "arrival"]);
+    assert_eq!(get_activity_types(&solution, "bike_1"), vec!["departure", "delivery", "arrival"]);
+    assert_eq!(
+        solution.transfers,
+        Some(vec![Transfer {
+            job_id: "job1".to_string(),
+            hub_id: "hub1".to_string(),
+            from: TransferTour { vehicle_id: "truck_1".to_string(), shift_index: 0 },
+            to: TransferTour { vehicle_id: "bike_1".to_string(), shift_index: 0 },
+            time: format_time(15.),
+        }])
+    );
+    let bike_departure = solution
+        .tours
+        .iter()
+        .find(|tour| tour.vehicle_id == "bike_1")
+        .and_then(|tour| tour.stops.first())
+        .map(|stop| stop.schedule().departure.clone());
+    assert_eq!(bike_departure, Some(format_time(15.)));
+}
+
+#[test]
+fn can_unassign_both_parts_of_job_when_second_echelon_is_not_possible() {
+    let problem = create_two_echelon_problem(vec![create_delivery_job("job1", (15., 0.))], 0);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert!(solution.transfers.is_none());
+    assert_eq!(solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job1"]);
+}
+
+#[test]
+fn can_serve_multiple_two_echelon_jobs() {
+    let problem = create_two_echelon_problem(
+        vec![
+            create_delivery_job("job1", (15., 0.)),
+            create_delivery_job("job2", (12., 0.)),
+            create_delivery_job("job3", (18., 0.)),
+        ],
+        3,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.transfers.iter().flatten().count(), 3);
+}
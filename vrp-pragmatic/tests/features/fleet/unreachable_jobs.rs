@@ -6,7 +6,11 @@ use crate::helpers::*;
 fn can_use_vehicle_with_open_end() {
     let problem = Problem {
         plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
         ..create_empty_problem()
     };
     let matrix = Matrix {
@@ -15,6 +19,8 @@ fn can_use_vehicle_with_open_end() {
         travel_times: vec![0, 1, 1, 0],
         distances: vec![0, 1, 1, 0],
         error_codes: Some(vec![0, 1, 1, 1]),
+        toll_costs: None,
+        travel_time_variances: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
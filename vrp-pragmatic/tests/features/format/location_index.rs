@@ -16,12 +16,13 @@ fn create_test_problem() -> Problem {
                     start: ShiftStart {
                         earliest: format_time(0.),
                         latest: None,
-                        location: Location::Reference { index: 2 },
+                        location: Some(Location::Reference { index: 2 }),
                     },
                     ..create_default_open_vehicle_shift()
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -35,6 +36,8 @@ fn create_test_matrix() -> Matrix {
         travel_times: vec![0, 3, 3, 1, 0, 3, 3, 2, 0],
         distances: vec![0, 3, 3, 1, 0, 3, 3, 2, 0],
         error_codes: None,
+        toll_costs: None,
+        travel_time_variances: None,
     }
 }
 
@@ -52,12 +55,15 @@ fn can_use_location_index() {
                 cost: 18.,
                 distance: 3,
                 duration: 5,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 3, serving: 2, ..Timing::default() }
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     Stop::Point(PointStop {
                         location: Location::Reference { index: 2 },
@@ -100,11 +106,15 @@ fn can_use_location_index() {
                     cost: 18.,
                     distance: 3,
                     duration: 5,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 3, serving: 2, ..Timing::default() }
-                }
+                },
             }],
             unassigned: None,
             violations: None,
+            transfers: None,
+            docks: None,
             extras: None
         }
     );
@@ -1 +1,2 @@
 mod location_index;
+mod toll_costs;
@@ -0,0 +1,71 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format::Location;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_vehicle_at(id: &str, index: usize) -> VehicleType {
+    VehicleType {
+        shifts: vec![VehicleShift {
+            start: ShiftStart {
+                earliest: format_time(0.),
+                latest: None,
+                location: Some(Location::Reference { index }),
+            },
+            ..create_default_open_vehicle_shift()
+        }],
+        ..create_vehicle_with_capacity(id, vec![1])
+    }
+}
+
+fn create_test_problem(vehicles: Vec<VehicleType>) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_index("job1", 0)], ..create_empty_plan() },
+        fleet: Fleet { vehicles, drivers: None, profiles: create_default_matrix_profiles() },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_matrix(toll_costs: Vec<f64>) -> Matrix {
+    let size = (toll_costs.len() as f64).sqrt() as usize;
+    let data = (0..toll_costs.len()).map(|idx| if idx % (size + 1) == 0 { 0 } else { 1 }).collect();
+
+    Matrix { toll_costs: Some(toll_costs), ..create_matrix(data) }
+}
+
+#[test]
+fn can_report_toll_costs() {
+    let problem = create_test_problem(vec![create_vehicle_at("tolled", 1)]);
+
+    let matrix = create_test_matrix(vec![0., 0., 100., 0.]);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours[0].statistic.tolls, Some(100.));
+    assert_eq!(
+        solution.statistic,
+        Statistic {
+            cost: 113.,
+            distance: 1,
+            duration: 2,
+            emissions: None,
+            tolls: Some(100.),
+            times: Timing { driving: 1, serving: 1, ..Timing::default() }
+        }
+    );
+}
+
+#[test]
+fn can_avoid_tolled_legs() {
+    let problem = create_test_problem(vec![create_vehicle_at("tolled", 1), create_vehicle_at("free", 2)]);
+
+    let matrix = create_test_matrix(vec![0., 0., 0., 100., 0., 0., 0., 0., 0.]);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].type_id, "free");
+    assert_eq!(solution.statistic.tolls, None);
+}
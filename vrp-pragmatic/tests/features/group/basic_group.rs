@@ -31,6 +31,7 @@ fn can_group_jobs() {
                     ..create_default_vehicle_type()
                 },
             ],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
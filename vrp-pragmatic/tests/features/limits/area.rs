@@ -35,9 +35,11 @@ fn can_use_constrained_areas() {
                         vec![AreaLimit { area_id: "area1".to_string(), job_value: 10. }],
                         vec![AreaLimit { area_id: "area2".to_string(), job_value: 1. }],
                     ]),
+                    driving_hours: None,
                 }),
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -92,9 +94,11 @@ fn can_use_unconstrained_areas_impl(area1_job_value: f64, expected_job_ids: Vec<
                         vec![AreaLimit { area_id: "area1".to_string(), job_value: area1_job_value }],
                         vec![AreaLimit { area_id: "area2".to_string(), job_value: 1. }],
                     ]),
+                    driving_hours: None,
                 }),
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
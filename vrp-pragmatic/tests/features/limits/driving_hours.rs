@@ -0,0 +1,182 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_vehicle_type_with_driving_hours(split_break: Option<Vec<f64>>) -> VehicleType {
+    VehicleType {
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            shift_time: None,
+            tour_size: None,
+            areas: None,
+            driving_hours: Some(DrivingHoursLimit {
+                max_continuous_driving: 10.,
+                break_duration: 3.,
+                split_break,
+                max_daily_driving: 18.,
+                daily_rest: 20.,
+                max_daily_duty: None,
+            }),
+        }),
+        ..create_default_vehicle_type()
+    }
+}
+
+fn create_rest_stop(time: (&str, &str), load: i32) -> Stop {
+    Stop::Transit(TransitStop {
+        time: Schedule { arrival: time.0.to_string(), departure: time.1.to_string() },
+        load: vec![load],
+        activities: vec![Activity {
+            job_id: "rest".to_string(),
+            activity_type: "rest".to_string(),
+            location: None,
+            time: None,
+            job_tag: None,
+            commute: None,
+            lateness: None,
+            compartment: None,
+            visit_index: None,
+        }],
+    })
+}
+
+#[test]
+fn can_insert_break_and_daily_rest() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (16., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type_with_driving_hours(None)],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 101.,
+                distance: 32,
+                duration: 59,
+                emissions: None,
+                tolls: None,
+                times: Timing { driving: 32, serving: 1, break_time: 26, ..Timing::default() },
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                driver_id: None,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        1,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0,
+                    ),
+                    create_rest_stop(("1970-01-01T00:00:10Z", "1970-01-01T00:00:13Z"), 1),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (16., 0.),
+                        0,
+                        ("1970-01-01T00:00:19Z", "1970-01-01T00:00:20Z"),
+                        16,
+                    ),
+                    create_rest_stop(("1970-01-01T00:00:22Z", "1970-01-01T00:00:42Z"), 0),
+                    create_rest_stop(("1970-01-01T00:00:52Z", "1970-01-01T00:00:55Z"), 0),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:59Z", "1970-01-01T00:00:59Z"),
+                        32,
+                    ),
+                ],
+                statistic: Statistic {
+                    cost: 101.,
+                    distance: 32,
+                    duration: 59,
+                    emissions: None,
+                    tolls: None,
+                    times: Timing { driving: 32, serving: 1, break_time: 26, ..Timing::default() },
+                },
+            }],
+            ..create_empty_solution()
+        }
+    );
+}
+
+#[test]
+fn can_take_first_part_of_split_break_at_stop() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (8., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_vehicle_shift_with_locations((0., 0.), (16., 0.))],
+                ..create_vehicle_type_with_driving_hours(Some(vec![1., 2.]))
+            }],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let tour = solution.tours.first().expect("no tour");
+    let activities = tour
+        .stops
+        .iter()
+        .map(|stop| (stop.schedule().departure.clone(), stop.activities().iter().map(|a| a.job_id.clone()).collect()))
+        .collect::<Vec<(String, Vec<String>)>>();
+    assert_eq!(
+        activities,
+        vec![
+            ("1970-01-01T00:00:00Z".to_string(), vec!["departure".to_string()]),
+            ("1970-01-01T00:00:10Z".to_string(), vec!["job1".to_string(), "rest".to_string()]),
+            ("1970-01-01T00:00:14Z".to_string(), vec!["rest".to_string()]),
+            ("1970-01-01T00:00:20Z".to_string(), vec!["arrival".to_string()]),
+        ]
+    );
+    assert_eq!(tour.statistic.times.break_time, 3);
+}
+
+#[test]
+fn can_skip_job_when_rest_delays_arrival() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", (15., 0.), vec![(0, 17)], 1.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type_with_driving_hours(None)],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "DRIVING_HOURS_CONSTRAINT".to_string(),
+                description: "cannot be assigned due to driving hours constraint of vehicle".to_string()
+            }]
+        }])
+    );
+}
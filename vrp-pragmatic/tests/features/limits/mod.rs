@@ -1,4 +1,5 @@
 mod area;
+mod driving_hours;
 mod max_distance;
 mod shift_time;
 mod tour_size;
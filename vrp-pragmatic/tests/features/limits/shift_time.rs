@@ -4,7 +4,13 @@ use crate::helpers::*;
 
 fn create_vehicle_type_with_shift_time_limit(shift_time: f64) -> VehicleType {
     VehicleType {
-        limits: Some(VehicleLimits { max_distance: None, shift_time: Some(shift_time), tour_size: None, areas: None }),
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            shift_time: Some(shift_time),
+            tour_size: None,
+            areas: None,
+            driving_hours: None,
+        }),
         ..create_default_vehicle_type()
     }
 }
@@ -15,6 +21,7 @@ fn can_limit_one_job_by_shift_time() {
         plan: Plan { jobs: vec![create_delivery_job("job1", (100., 0.))], ..create_empty_plan() },
         fleet: Fleet {
             vehicles: vec![create_vehicle_type_with_shift_time_limit(99.)],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -25,6 +32,8 @@ fn can_limit_one_job_by_shift_time() {
         travel_times: vec![1, 100, 100, 1],
         distances: vec![1, 1, 1, 1],
         error_codes: Option::None,
+        toll_costs: None,
+        travel_time_variances: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -61,6 +70,7 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
         },
         fleet: Fleet {
             vehicles: vec![create_vehicle_type_with_shift_time_limit(40.)],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -76,12 +86,15 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                 cost: 52.,
                 distance: 6,
                 duration: 36,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 6, serving: 30, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -128,6 +141,8 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                     cost: 52.,
                     distance: 6,
                     duration: 36,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 6, serving: 30, ..Timing::default() },
                 },
             }],
@@ -163,6 +178,7 @@ fn can_serve_job_when_it_starts_late() {
         },
         fleet: Fleet {
             vehicles: vec![create_vehicle_type_with_shift_time_limit(50.)],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
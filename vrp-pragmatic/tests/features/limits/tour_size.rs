@@ -16,9 +16,16 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
-                limits: Some(VehicleLimits { max_distance: None, shift_time: None, areas: None, tour_size: Some(2) }),
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    areas: None,
+                    tour_size: Some(2),
+                    driving_hours: None,
+                }),
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -34,12 +41,15 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
                 cost: 16.,
                 distance: 2,
                 duration: 4,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 2, serving: 2, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -70,6 +80,8 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
                     cost: 16.,
                     distance: 2,
                     duration: 4,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 2, serving: 2, ..Timing::default() },
                 },
             }],
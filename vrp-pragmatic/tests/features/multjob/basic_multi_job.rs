@@ -18,6 +18,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
         },
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -33,12 +34,15 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                 cost: 46.,
                 distance: 16,
                 duration: 20,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 16, serving: 4, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -96,6 +100,8 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                     cost: 46.,
                     distance: 16,
                     duration: 20,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 16, serving: 4, ..Timing::default() },
                 },
             }],
@@ -117,6 +123,7 @@ fn can_assign_multi_job_in_pickup_effective_way() {
         },
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -132,12 +139,15 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                 cost: 37.,
                 distance: 12,
                 duration: 15,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 12, serving: 3, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -187,6 +197,8 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                     cost: 37.,
                     distance: 12,
                     duration: 15,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 12, serving: 3, ..Timing::default() },
                 },
             }],
@@ -20,12 +20,13 @@ fn can_assign_replacement_job() {
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
-                        location: (4., 0.).to_loc(),
+                        location: Some((4., 0.).to_loc()),
                     }),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -41,12 +42,15 @@ fn can_assign_replacement_job() {
                 cost: 21.,
                 distance: 4,
                 duration: 7,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 4, serving: 3, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -93,6 +97,8 @@ fn can_assign_replacement_job() {
                     cost: 21.,
                     distance: 4,
                     duration: 7,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 4, serving: 3, ..Timing::default() },
                 },
             }],
@@ -22,6 +22,7 @@ fn can_handle_limited_capacity() {
         },
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -36,6 +37,8 @@ fn can_handle_limited_capacity() {
             cost: 88.,
             distance: 36,
             duration: 42,
+            emissions: None,
+            tolls: None,
             times: Timing { driving: 36, serving: 6, ..Timing::default() },
         }
     );
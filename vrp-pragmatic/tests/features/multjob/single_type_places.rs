@@ -15,6 +15,7 @@ fn can_use_only_deliveries_as_static_demand() {
                 shifts: vec![create_default_open_vehicle_shift()],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -30,12 +31,15 @@ fn can_use_only_deliveries_as_static_demand() {
                 cost: 29.,
                 distance: 8,
                 duration: 11,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 8, serving: 3, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -68,6 +72,8 @@ fn can_use_only_deliveries_as_static_demand() {
                     cost: 29.,
                     distance: 8,
                     duration: 11,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 8, serving: 3, ..Timing::default() },
                 },
             }],
@@ -89,12 +95,13 @@ fn can_use_only_pickups_as_static_demand() {
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
-                        location: (10., 0.).to_loc(),
+                        location: Some((10., 0.).to_loc()),
                     }),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -110,12 +117,15 @@ fn can_use_only_pickups_as_static_demand() {
                 cost: 33.,
                 distance: 10,
                 duration: 13,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 10, serving: 3, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -156,6 +166,8 @@ fn can_use_only_pickups_as_static_demand() {
                     cost: 33.,
                     distance: 10,
                     duration: 13,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 10, serving: 3, ..Timing::default() },
                 },
             }],
@@ -15,6 +15,7 @@ fn can_unassign_multi_job_due_to_capacity() {
         },
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
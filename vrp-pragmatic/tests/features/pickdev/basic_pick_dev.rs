@@ -8,6 +8,7 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
         plan: Plan { jobs: vec![create_pickup_delivery_job("job1", (1., 0.), (2., 0.))], ..create_empty_plan() },
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("my_vehicle")],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -23,12 +24,15 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                 cost: 20.,
                 distance: 4,
                 duration: 6,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 4, serving: 2, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -69,6 +73,8 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                     cost: 20.,
                     distance: 4,
                     duration: 6,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 4, serving: 2, ..Timing::default() },
                 },
             }],
@@ -15,6 +15,7 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
         },
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("my_vehicle")],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -30,12 +31,15 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                 cost: 30.,
                 distance: 8,
                 duration: 12,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 8, serving: 4, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -92,8 +96,10 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                     cost: 30.,
                     distance: 8,
                     duration: 12,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 8, serving: 4, ..Timing::default() },
-                }
+                },
             }],
             ..create_empty_solution()
         }
@@ -23,6 +23,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                 shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -38,12 +39,15 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                 cost: 114.,
                 distance: 50,
                 duration: 54,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 50, serving: 4, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -102,6 +106,8 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                     cost: 114.,
                     distance: 50,
                     duration: 54,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 50, serving: 4, ..Timing::default() },
                 },
             }],
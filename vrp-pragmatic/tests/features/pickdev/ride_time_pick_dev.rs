@@ -0,0 +1,79 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_pickup_delivery_job_with_ride_time(
+    id: &str,
+    pickup_location: (f64, f64),
+    delivery_location: (f64, f64),
+    max_ride_time: Option<f64>,
+) -> Job {
+    Job { max_ride_time, ..create_pickup_delivery_job(id, pickup_location, delivery_location) }
+}
+
+fn get_job_ids(solution: &Solution) -> Vec<String> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.job_id.starts_with("job"))
+        .map(|activity| activity.job_id.clone())
+        .collect()
+}
+
+#[test]
+fn can_avoid_detour_when_ride_time_is_limited() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job_with_ride_time("job1", (1., 0.), (10., 0.), Some(9.)),
+                create_pickup_delivery_job_with_ride_time("job2", (5., 0.), (6., 0.), None),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { capacity: vec![2], ..create_default_vehicle_type() }],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_job_ids(&solution), vec!["job1", "job1", "job2", "job2"]);
+}
+
+#[test]
+fn can_unassign_job_when_ride_time_cannot_be_met() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_pickup_delivery_job_with_ride_time("job1", (1., 0.), (10., 0.), Some(5.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "RIDE_TIME_CONSTRAINT".to_string(),
+                description: "cannot be assigned due to max ride time constraint".to_string()
+            }]
+        }])
+    );
+}
@@ -15,7 +15,7 @@ fn create_test_plan_with_three_jobs() -> Plan {
 }
 
 fn create_test_limit() -> Option<VehicleLimits> {
-    Some(VehicleLimits { max_distance: Some(15.), shift_time: None, tour_size: None, areas: None })
+    Some(VehicleLimits { max_distance: Some(15.), shift_time: None, tour_size: None, areas: None, driving_hours: None })
 }
 
 fn create_prioritized_objective() -> Vec<Vec<Objective>> {
@@ -31,7 +31,11 @@ fn create_prioritized_objective() -> Vec<Vec<Objective>> {
 fn can_follow_orders() {
     let problem = Problem {
         plan: create_test_plan_with_three_jobs(),
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
         objectives: Some(create_prioritized_objective()),
         ..create_empty_problem()
     };
@@ -46,12 +50,15 @@ fn can_follow_orders() {
                 cost: 53.,
                 distance: 20,
                 duration: 23,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 20, serving: 3, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -98,6 +105,8 @@ fn can_follow_orders() {
                     cost: 53.,
                     distance: 20,
                     duration: 23,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 20, serving: 3, ..Timing::default() },
                 },
             }],
@@ -112,6 +121,7 @@ fn can_assign_more_jobs_ignoring_order_with_default_objective() {
         plan: create_test_plan_with_three_jobs(),
         fleet: Fleet {
             vehicles: vec![VehicleType { limits: create_test_limit(), ..create_default_vehicle_type() }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -131,6 +141,7 @@ fn can_follow_order_when_prioritized_property_set() {
         plan: create_test_plan_with_three_jobs(),
         fleet: Fleet {
             vehicles: vec![VehicleType { limits: create_test_limit(), ..create_default_vehicle_type() }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         objectives: Some(create_prioritized_objective()),
@@ -157,7 +168,14 @@ fn can_follow_order_when_prioritized_property_set() {
 fn can_handle_order_between_special_activities() {
     let create_test_job = |id: &str, location: (f64, f64), order: i32| Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: location.to_loc(), duration: 100., tag: None }],
+            places: vec![JobPlace {
+                times: None,
+                location: location.to_loc(),
+                duration: 100.,
+                tag: None,
+                lateness: None,
+                durations: None,
+            }],
             demand: Some(vec![1]),
             order: Some(order),
         }]),
@@ -174,7 +192,7 @@ fn can_handle_order_between_special_activities() {
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
-                        location: (10., 0.).to_loc(),
+                        location: Some((10., 0.).to_loc()),
                     }),
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(100.), format_time(200.)]),
@@ -189,6 +207,7 @@ fn can_handle_order_between_special_activities() {
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -24,6 +24,7 @@ fn can_prefer_jobs_with_more_value_impl(objectives: Option<Vec<Vec<Objective>>>)
         },
         fleet: Fleet {
             vehicles: vec![VehicleType { capacity: vec![1], ..create_default_vehicle_type() }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         objectives,
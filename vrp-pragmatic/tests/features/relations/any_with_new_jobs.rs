@@ -24,6 +24,7 @@ fn create_and_solve_problem_with_three_jobs(any_relation_jobs: Vec<String>) -> S
                 capacity: vec![3],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -44,12 +45,15 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                 cost: 19.,
                 distance: 3,
                 duration: 6,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 3, serving: 3, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -88,6 +92,8 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                     cost: 19.,
                     distance: 3,
                     duration: 6,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 3, serving: 3, ..Timing::default() },
                 },
             }],
@@ -31,7 +31,11 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
             ]),
             ..create_empty_plan()
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -45,12 +49,15 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                 cost: 53.,
                 distance: 18,
                 duration: 25,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 18, serving: 7, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -129,6 +136,8 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                     cost: 53.,
                     distance: 18,
                     duration: 25,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 18, serving: 7, ..Timing::default() },
                 },
             }],
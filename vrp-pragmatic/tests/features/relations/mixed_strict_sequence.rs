@@ -31,7 +31,11 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
             ]),
             ..create_empty_plan()
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -45,12 +49,15 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                 cost: 61.,
                 distance: 22,
                 duration: 29,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 22, serving: 7, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -129,6 +136,8 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                     cost: 61.,
                     distance: 22,
                     duration: 29,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 22, serving: 7, ..Timing::default() },
                 },
             }],
@@ -186,6 +195,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                 capacity: vec![5],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -201,6 +211,8 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                 cost: 80.,
                 distance: 26,
                 duration: 34,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 26, serving: 8, ..Timing::default() },
             },
             tours: vec![
@@ -208,6 +220,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                     vehicle_id: "my_vehicle_1".to_string(),
                     type_id: "my_vehicle".to_string(),
                     shift_index: 0,
+                    driver_id: None,
                     stops: vec![
                         create_stop_with_activity(
                             "departure",
@@ -254,6 +267,8 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                         cost: 40.,
                         distance: 13,
                         duration: 17,
+                        emissions: None,
+                        tolls: None,
                         times: Timing { driving: 13, serving: 4, ..Timing::default() },
                     },
                 },
@@ -261,6 +276,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                     vehicle_id: "my_vehicle_2".to_string(),
                     type_id: "my_vehicle".to_string(),
                     shift_index: 0,
+                    driver_id: None,
                     stops: vec![
                         create_stop_with_activity(
                             "departure",
@@ -307,6 +323,8 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                         cost: 40.,
                         distance: 13,
                         duration: 17,
+                        emissions: None,
+                        tolls: None,
                         times: Timing { driving: 13, serving: 4, ..Timing::default() },
                     },
                 }
@@ -2,6 +2,7 @@ mod any_basic;
 mod any_with_new_jobs;
 mod mixed_strict_any;
 mod mixed_strict_sequence;
+mod preferred_basic;
 mod sequence_with_new_jobs;
 mod strict_with_new_jobs;
 mod strict_with_old_jobs;
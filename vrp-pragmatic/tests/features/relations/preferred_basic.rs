@@ -0,0 +1,58 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_problem(objectives: Option<Vec<Vec<Objective>>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            relations: Some(vec![Relation {
+                type_field: RelationType::Preferred,
+                jobs: to_strings(vec!["job1", "job2"]),
+                vehicle_id: "familiar_1".to_string(),
+                shift_index: None,
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("regular"),
+                VehicleType {
+                    shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
+                    ..create_default_vehicle("familiar")
+                },
+            ],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        objectives,
+    }
+}
+
+parameterized_test! {can_keep_jobs_assigned_to_preferred_vehicle, (objectives, expected), {
+    can_keep_jobs_assigned_to_preferred_vehicle_impl(objectives, expected);
+}}
+
+can_keep_jobs_assigned_to_preferred_vehicle! {
+    case01_default_objectives: (None, "familiar_1"),
+    case02_reassignment_objective: (Some(vec![
+        vec![MinimizeUnassignedJobs { breaks: None }],
+        vec![MinimizeReassignments],
+        vec![MinimizeCost],
+    ]), "familiar_1"),
+    case03_cost_is_preferred: (Some(vec![
+        vec![MinimizeUnassignedJobs { breaks: None }],
+        vec![MinimizeCost],
+        vec![MinimizeReassignments],
+    ]), "regular_1"),
+}
+
+fn can_keep_jobs_assigned_to_preferred_vehicle_impl(objectives: Option<Vec<Vec<Objective>>>, expected: &str) {
+    let problem = create_problem(objectives);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.iter().map(|tour| tour.vehicle_id.clone()).collect::<Vec<_>>(), vec![expected]);
+}
@@ -27,6 +27,7 @@ fn can_use_sequence_relation_with_strict_time_windows() {
                 capacity: vec![10],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -42,12 +43,15 @@ fn can_use_sequence_relation_with_strict_time_windows() {
                 cost: 270.,
                 distance: 100,
                 duration: 160,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 100, serving: 50, waiting: 10, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -110,6 +114,8 @@ fn can_use_sequence_relation_with_strict_time_windows() {
                     cost: 270.,
                     distance: 100,
                     duration: 160,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 100, serving: 50, waiting: 10, ..Timing::default() },
                 },
             }],
@@ -40,6 +40,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                 capacity: vec![5],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -55,6 +56,8 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                 cost: 114.,
                 distance: 42,
                 duration: 52,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 42, serving: 10, ..Timing::default() },
             },
             tours: vec![
@@ -62,6 +65,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                     vehicle_id: "my_vehicle_1".to_string(),
                     type_id: "my_vehicle".to_string(),
                     shift_index: 0,
+                    driver_id: None,
                     stops: vec![
                         create_stop_with_activity(
                             "departure",
@@ -124,6 +128,8 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                         cost: 59.,
                         distance: 22,
                         duration: 27,
+                        emissions: None,
+                        tolls: None,
                         times: Timing { driving: 22, serving: 5, ..Timing::default() },
                     },
                 },
@@ -131,6 +137,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                     vehicle_id: "my_vehicle_2".to_string(),
                     type_id: "my_vehicle".to_string(),
                     shift_index: 0,
+                    driver_id: None,
                     stops: vec![
                         create_stop_with_activity(
                             "departure",
@@ -193,6 +200,8 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                         cost: 55.,
                         distance: 20,
                         duration: 25,
+                        emissions: None,
+                        tolls: None,
                         times: Timing { driving: 20, serving: 5, ..Timing::default() },
                     },
                 }
@@ -38,6 +38,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                 capacity: vec![5],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -53,6 +54,8 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                 cost: 96.,
                 distance: 34,
                 duration: 42,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 34, serving: 8, ..Timing::default() },
             },
             tours: vec![
@@ -60,6 +63,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                     vehicle_id: "my_vehicle_1".to_string(),
                     type_id: "my_vehicle".to_string(),
                     shift_index: 0,
+                    driver_id: None,
                     stops: vec![
                         create_stop_with_activity(
                             "departure",
@@ -114,6 +118,8 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                         cost: 54.,
                         distance: 20,
                         duration: 24,
+                        emissions: None,
+                        tolls: None,
                         times: Timing { driving: 20, serving: 4, ..Timing::default() },
                     },
                 },
@@ -121,6 +127,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                     vehicle_id: "my_vehicle_2".to_string(),
                     type_id: "my_vehicle".to_string(),
                     shift_index: 0,
+                    driver_id: None,
                     stops: vec![
                         create_stop_with_activity(
                             "departure",
@@ -175,6 +182,8 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                         cost: 42.,
                         distance: 14,
                         duration: 18,
+                        emissions: None,
+                        tolls: None,
                         times: Timing { driving: 14, serving: 4, ..Timing::default() },
                     },
                 }
@@ -28,11 +28,11 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart { earliest: format_time(0.), latest: None, location: Some((0., 0.).to_loc()) },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
-                        location: (0., 0.).to_loc(),
+                        location: Some((0., 0.).to_loc()),
                     }),
                     dispatch: None,
                     breaks: None,
@@ -42,10 +42,12 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                         duration: 2.0,
                         tag: None,
                     }]),
+                    charging_stations: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -61,12 +63,15 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                 cost: 46.,
                 distance: 16,
                 duration: 20,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 16, serving: 4, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -124,6 +129,8 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                     cost: 46.,
                     distance: 16,
                     duration: 20,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 16, serving: 4, ..Timing::default() },
                 },
             }],
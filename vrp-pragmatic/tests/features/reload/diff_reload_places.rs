@@ -18,8 +18,12 @@ fn can_use_reloads_with_different_locations() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (32., 0.).to_loc() }),
+                    start: ShiftStart { earliest: format_time(0.), latest: None, location: Some((0., 0.).to_loc()) },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: Some((32., 0.).to_loc()),
+                    }),
                     dispatch: None,
                     breaks: None,
                     reloads: Some(vec![
@@ -36,10 +40,12 @@ fn can_use_reloads_with_different_locations() {
                             tag: Some("far".to_string()),
                         },
                     ]),
+                    charging_stations: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
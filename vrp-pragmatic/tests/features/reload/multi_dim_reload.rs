@@ -16,11 +16,11 @@ fn can_use_multi_dim_capacity() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart { earliest: format_time(0.), latest: None, location: Some((0., 0.).to_loc()) },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
-                        location: (0., 0.).to_loc(),
+                        location: Some((0., 0.).to_loc()),
                     }),
                     dispatch: None,
                     breaks: None,
@@ -30,10 +30,12 @@ fn can_use_multi_dim_capacity() {
                         duration: 2.0,
                         tag: None,
                     }]),
+                    charging_stations: None,
                 }],
                 capacity: vec![1, 1],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -49,12 +51,15 @@ fn can_use_multi_dim_capacity() {
                 cost: 26.,
                 distance: 6,
                 duration: 10,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 6, serving: 4, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity_md(
                         "departure",
@@ -101,6 +106,8 @@ fn can_use_multi_dim_capacity() {
                     cost: 26.,
                     distance: 6,
                     duration: 10,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 6, serving: 4, ..Timing::default() },
                 },
             }],
@@ -23,11 +23,11 @@ fn can_serve_multi_job_and_delivery_with_reload() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart { earliest: format_time(0.), latest: None, location: Some((0., 0.).to_loc()) },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
-                        location: (10., 0.).to_loc(),
+                        location: Some((10., 0.).to_loc()),
                     }),
                     dispatch: None,
                     breaks: None,
@@ -37,10 +37,12 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                         duration: 2.0,
                         tag: None,
                     }]),
+                    charging_stations: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -56,12 +58,15 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                 cost: 46.,
                 distance: 14,
                 duration: 22,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 14, serving: 8, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -143,6 +148,8 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                     cost: 46.,
                     distance: 14,
                     duration: 22,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 14, serving: 8, ..Timing::default() },
                 },
             }],
@@ -168,7 +175,7 @@ fn can_properly_handle_load_without_capacity_violation() {
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: Some(20.0), distance: 0.002, time: 0.003 },
+                costs: VehicleCosts { fixed: Some(20.0), distance: 0.002, time: 0.003, full_load_distance: None },
                 shifts: vec![VehicleShift {
                     reloads: Some(vec![
                         VehicleReload {
@@ -189,6 +196,7 @@ fn can_properly_handle_load_without_capacity_violation() {
                 capacity: vec![2],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
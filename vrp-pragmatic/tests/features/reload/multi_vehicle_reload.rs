@@ -14,11 +14,11 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
             vehicles: vec![VehicleType {
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart { earliest: format_time(0.), latest: None, location: Some((0., 0.).to_loc()) },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
-                        location: (0., 0.).to_loc(),
+                        location: Some((0., 0.).to_loc()),
                     }),
                     dispatch: None,
                     breaks: None,
@@ -28,10 +28,12 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                         duration: 2.0,
                         tag: None,
                     }]),
+                    charging_stations: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -47,12 +49,15 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                 cost: 26.,
                 distance: 6,
                 duration: 10,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 6, serving: 4, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -99,6 +104,8 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                     cost: 26.,
                     distance: 6,
                     duration: 10,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 6, serving: 4, ..Timing::default() },
                 },
             }],
@@ -19,11 +19,11 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart { earliest: format_time(0.), latest: None, location: Some((0., 0.).to_loc()) },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
-                        location: (6., 0.).to_loc(),
+                        location: Some((6., 0.).to_loc()),
                     }),
                     dispatch: None,
                     breaks: None,
@@ -33,10 +33,12 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                         duration: 2.0,
                         tag: None,
                     }]),
+                    charging_stations: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -51,6 +53,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
             vehicle_id: "my_vehicle_1".to_string(),
             type_id: "my_vehicle".to_string(),
             shift_index: 0,
+            driver_id: None,
             stops: vec![
                 create_stop_with_activity(
                     "departure",
@@ -113,6 +116,8 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                 cost: 28.,
                 distance: 6,
                 duration: 12,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 6, serving: 6, ..Timing::default() },
             },
         }]
@@ -123,6 +128,8 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
             cost: 28.,
             distance: 6,
             duration: 12,
+            emissions: None,
+            tolls: None,
             times: Timing { driving: 6, serving: 6, ..Timing::default() },
         }
     );
@@ -24,6 +24,7 @@ fn can_wait_for_job_start() {
                     ..create_default_vehicle_type()
                 },
             ],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -39,12 +40,15 @@ fn can_wait_for_job_start() {
                 cost: 47.,
                 distance: 18,
                 duration: 19,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 18, serving: 1, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "vehicle_with_skill_1".to_string(),
                 type_id: "vehicle_with_skill".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -75,6 +79,8 @@ fn can_wait_for_job_start() {
                     cost: 47.,
                     distance: 18,
                     duration: 19,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 18, serving: 1, ..Timing::default() },
                 },
             }],
@@ -0,0 +1,73 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_problem(preferred: JobPreferredSkills, objectives: Option<Vec<Vec<Objective>>>) -> Problem {
+    let skills = JobSkills { all_of: None, one_of: None, none_of: None, preferred: Some(preferred) };
+
+    Problem {
+        plan: Plan {
+            jobs: vec![Job { skills: Some(skills), ..create_delivery_job("job1", (1., 0.)) }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("regular"),
+                VehicleType {
+                    shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
+                    skills: Some(vec!["familiar_driver".to_string()]),
+                    ..create_default_vehicle("familiar")
+                },
+            ],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        objectives,
+    }
+}
+
+fn get_type_ids(problem: Problem) -> Vec<String> {
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    solution.tours.iter().map(|tour| tour.type_id.clone()).collect()
+}
+
+parameterized_test! {can_prefer_vehicle_with_preferred_skills, (objectives, expected), {
+    can_prefer_vehicle_with_preferred_skills_impl(objectives, expected);
+}}
+
+can_prefer_vehicle_with_preferred_skills! {
+    case01_default_objectives: (None, "familiar"),
+    case02_preference_objective: (Some(vec![
+        vec![MinimizeUnassignedJobs { breaks: None }],
+        vec![MinimizePreferencePenalty],
+        vec![MinimizeCost],
+    ]), "familiar"),
+    case03_cost_is_preferred: (Some(vec![
+        vec![MinimizeUnassignedJobs { breaks: None }],
+        vec![MinimizeCost],
+        vec![MinimizePreferencePenalty],
+    ]), "regular"),
+}
+
+fn can_prefer_vehicle_with_preferred_skills_impl(objectives: Option<Vec<Vec<Objective>>>, expected: &str) {
+    let preferred = JobPreferredSkills { one_of: vec!["familiar_driver".to_string()], weight: Some(100.) };
+    let problem = create_problem(preferred, objectives);
+
+    let type_ids = get_type_ids(problem);
+
+    assert_eq!(type_ids, vec![expected.to_string()]);
+}
+
+#[test]
+fn can_serve_job_without_vehicle_with_preferred_skills() {
+    let preferred = JobPreferredSkills { one_of: vec!["unknown_driver".to_string()], weight: None };
+    let problem = create_problem(preferred, None);
+
+    let type_ids = get_type_ids(problem);
+
+    assert_eq!(type_ids, vec!["regular".to_string()]);
+}
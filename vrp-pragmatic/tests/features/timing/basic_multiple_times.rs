@@ -17,6 +17,7 @@ fn can_use_multiple_times() {
         },
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("my_vehicle")],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -32,12 +33,15 @@ fn can_use_multiple_times() {
                 cost: 240.,
                 distance: 100,
                 duration: 130,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 100, serving: 0, waiting: 30, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -100,6 +104,8 @@ fn can_use_multiple_times() {
                     cost: 240.,
                     distance: 100,
                     duration: 130,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 100, serving: 0, waiting: 30, ..Timing::default() },
                 },
             }],
@@ -15,6 +15,7 @@ fn can_wait_for_job_start() {
         },
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("my_vehicle")],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -30,12 +31,15 @@ fn can_wait_for_job_start() {
                 cost: 26.,
                 distance: 4,
                 duration: 12,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 4, serving: 0, waiting: 8, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -74,6 +78,8 @@ fn can_wait_for_job_start() {
                     cost: 26.,
                     distance: 4,
                     duration: 12,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 4, serving: 0, waiting: 8, ..Timing::default() },
                 },
             }],
@@ -91,6 +97,7 @@ fn can_skip_initial_waiting() {
         },
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("my_vehicle")],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -106,12 +113,15 @@ fn can_skip_initial_waiting() {
                 cost: 24.,
                 distance: 2,
                 duration: 12,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 2, serving: 10, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -142,6 +152,8 @@ fn can_skip_initial_waiting() {
                     cost: 24.,
                     distance: 2,
                     duration: 12,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 2, serving: 10, ..Timing::default() },
                 },
             }],
@@ -163,12 +175,13 @@ fn can_consider_latest_departure_time() {
                     start: ShiftStart {
                         earliest: "1970-01-01T00:00:00Z".to_string(),
                         latest: Some("1970-01-01T00:00:05Z".to_string()),
-                        location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+                        location: Some(Location::Coordinate { lat: 0.0, lng: 0.0 }),
                     },
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle("my_vehicle")
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -184,12 +197,15 @@ fn can_consider_latest_departure_time() {
                 cost: 28.,
                 distance: 2,
                 duration: 16,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 2, serving: 10, waiting: 4, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -220,6 +236,8 @@ fn can_consider_latest_departure_time() {
                     cost: 28.,
                     distance: 2,
                     duration: 16,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 2, serving: 10, waiting: 4, ..Timing::default() },
                 },
             }],
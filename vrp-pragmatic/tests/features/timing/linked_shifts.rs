@@ -0,0 +1,450 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_vehicle_type_with_linked_shifts(location: Option<(f64, f64)>) -> VehicleType {
+    VehicleType {
+        shifts: vec![
+            VehicleShift {
+                end: Some(ShiftEnd {
+                    earliest: None,
+                    latest: format_time(10.),
+                    location: location.map(|location| location.to_loc()),
+                }),
+                ..create_default_vehicle_shift()
+            },
+            VehicleShift {
+                start: ShiftStart { earliest: format_time(50.), latest: None, location: None },
+                ..create_default_vehicle_shift()
+            },
+        ],
+        ..create_default_vehicle_type()
+    }
+}
+
+fn create_problem(vehicle: VehicleType, jobs: Vec<Job>) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![vehicle], drivers: None, profiles: create_default_matrix_profiles() },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_stay_overnight_between_linked_shifts_at_job_location() {
+    let problem = create_problem(
+        create_vehicle_type_with_linked_shifts(None),
+        vec![
+            create_delivery_job_with_times("job1", (5., 0.), vec![(5, 8)], 1.),
+            create_delivery_job("job2", (10., 0.)),
+        ],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 96.,
+                distance: 20,
+                duration: 66,
+                emissions: None,
+                tolls: None,
+                times: Timing { driving: 20, serving: 2, break_time: 44, ..Timing::default() },
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                driver_id: None,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        2,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0,
+                    ),
+                    Stop::Point(PointStop {
+                        location: (5., 0.).to_loc(),
+                        time: Schedule {
+                            arrival: "1970-01-01T00:00:05Z".to_string(),
+                            departure: "1970-01-01T00:00:50Z".to_string(),
+                        },
+                        distance: 5,
+                        load: vec![1],
+                        parking: None,
+                        activities: vec![
+                            Activity {
+                                job_id: "job1".to_string(),
+                                activity_type: "delivery".to_string(),
+                                location: Some((5., 0.).to_loc()),
+                                time: Some(Interval {
+                                    start: "1970-01-01T00:00:05Z".to_string(),
+                                    end: "1970-01-01T00:00:06Z".to_string(),
+                                }),
+                                job_tag: None,
+                                commute: None,
+                                lateness: None,
+                                compartment: None,
+                                visit_index: None,
+                            },
+                            Activity {
+                                job_id: "overnight".to_string(),
+                                activity_type: "overnight".to_string(),
+                                location: Some((5., 0.).to_loc()),
+                                time: Some(Interval {
+                                    start: "1970-01-01T00:00:06Z".to_string(),
+                                    end: "1970-01-01T00:00:50Z".to_string(),
+                                }),
+                                job_tag: None,
+                                commute: None,
+                                lateness: None,
+                                compartment: None,
+                                visit_index: None,
+                            },
+                        ],
+                        charge: None,
+                        on_time_probability: None,
+                    }),
+                    create_stop_with_activity(
+                        "job2",
+                        "delivery",
+                        (10., 0.),
+                        0,
+                        ("1970-01-01T00:00:55Z", "1970-01-01T00:00:56Z"),
+                        10,
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:01:06Z", "1970-01-01T00:01:06Z"),
+                        20,
+                    ),
+                ],
+                statistic: Statistic {
+                    cost: 96.,
+                    distance: 20,
+                    duration: 66,
+                    emissions: None,
+                    tolls: None,
+                    times: Timing { driving: 20, serving: 2, break_time: 44, ..Timing::default() },
+                },
+            }],
+            ..create_empty_solution()
+        }
+    );
+}
+
+#[test]
+fn can_stay_overnight_between_linked_shifts_at_shift_end_location() {
+    let problem = create_problem(
+        create_vehicle_type_with_linked_shifts(Some((7., 0.))),
+        vec![
+            create_delivery_job_with_times("job1", (5., 0.), vec![(5, 8)], 1.),
+            create_delivery_job("job2", (10., 0.)),
+        ],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 94.,
+                distance: 20,
+                duration: 64,
+                emissions: None,
+                tolls: None,
+                times: Timing { driving: 20, serving: 2, break_time: 42, ..Timing::default() },
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                driver_id: None,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        2,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0,
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (5., 0.),
+                        1,
+                        ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
+                        5,
+                    ),
+                    create_stop_with_activity(
+                        "overnight",
+                        "overnight",
+                        (7., 0.),
+                        1,
+                        ("1970-01-01T00:00:08Z", "1970-01-01T00:00:50Z"),
+                        7,
+                    ),
+                    create_stop_with_activity(
+                        "job2",
+                        "delivery",
+                        (10., 0.),
+                        0,
+                        ("1970-01-01T00:00:53Z", "1970-01-01T00:00:54Z"),
+                        10,
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:01:04Z", "1970-01-01T00:01:04Z"),
+                        20,
+                    ),
+                ],
+                statistic: Statistic {
+                    cost: 94.,
+                    distance: 20,
+                    duration: 64,
+                    emissions: None,
+                    tolls: None,
+                    times: Timing { driving: 20, serving: 2, break_time: 42, ..Timing::default() },
+                },
+            }],
+            ..create_empty_solution()
+        }
+    );
+}
+
+#[test]
+fn can_use_linked_shifts_with_driving_hours() {
+    let problem = create_problem(
+        VehicleType {
+            limits: Some(VehicleLimits {
+                max_distance: None,
+                shift_time: None,
+                tour_size: None,
+                areas: None,
+                driving_hours: Some(DrivingHoursLimit {
+                    max_continuous_driving: 8.,
+                    break_duration: 2.,
+                    split_break: None,
+                    max_daily_driving: 100.,
+                    daily_rest: 40.,
+                    max_daily_duty: None,
+                }),
+            }),
+            ..create_vehicle_type_with_linked_shifts(None)
+        },
+        vec![
+            create_delivery_job_with_times("job1", (5., 0.), vec![(5, 8)], 1.),
+            create_delivery_job("job2", (10., 0.)),
+        ],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 98.,
+                distance: 20,
+                duration: 68,
+                emissions: None,
+                tolls: None,
+                times: Timing { driving: 20, serving: 2, break_time: 46, ..Timing::default() },
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                driver_id: None,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        2,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0,
+                    ),
+                    Stop::Point(PointStop {
+                        location: (5., 0.).to_loc(),
+                        time: Schedule {
+                            arrival: "1970-01-01T00:00:05Z".to_string(),
+                            departure: "1970-01-01T00:00:50Z".to_string(),
+                        },
+                        distance: 5,
+                        load: vec![1],
+                        parking: None,
+                        activities: vec![
+                            Activity {
+                                job_id: "job1".to_string(),
+                                activity_type: "delivery".to_string(),
+                                location: Some((5., 0.).to_loc()),
+                                time: Some(Interval {
+                                    start: "1970-01-01T00:00:05Z".to_string(),
+                                    end: "1970-01-01T00:00:06Z".to_string(),
+                                }),
+                                job_tag: None,
+                                commute: None,
+                                lateness: None,
+                                compartment: None,
+                                visit_index: None,
+                            },
+                            Activity {
+                                job_id: "overnight".to_string(),
+                                activity_type: "overnight".to_string(),
+                                location: Some((5., 0.).to_loc()),
+                                time: Some(Interval {
+                                    start: "1970-01-01T00:00:06Z".to_string(),
+                                    end: "1970-01-01T00:00:50Z".to_string(),
+                                }),
+                                job_tag: None,
+                                commute: None,
+                                lateness: None,
+                                compartment: None,
+                                visit_index: None,
+                            },
+                        ],
+                        charge: None,
+                        on_time_probability: None,
+                    }),
+                    create_stop_with_activity(
+                        "job2",
+                        "delivery",
+                        (10., 0.),
+                        0,
+                        ("1970-01-01T00:00:55Z", "1970-01-01T00:00:56Z"),
+                        10,
+                    ),
+                    Stop::Transit(TransitStop {
+                        time: Schedule {
+                            arrival: "1970-01-01T00:00:59Z".to_string(),
+                            departure: "1970-01-01T00:01:01Z".to_string(),
+                        },
+                        load: vec![0],
+                        activities: vec![Activity {
+                            job_id: "rest".to_string(),
+                            activity_type: "rest".to_string(),
+                            location: None,
+                            time: None,
+                            job_tag: None,
+                            commute: None,
+                            lateness: None,
+                            compartment: None,
+                            visit_index: None,
+                        }],
+                    }),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:01:08Z", "1970-01-01T00:01:08Z"),
+                        20,
+                    ),
+                ],
+                statistic: Statistic {
+                    cost: 98.,
+                    distance: 20,
+                    duration: 68,
+                    emissions: None,
+                    tolls: None,
+                    times: Timing { driving: 20, serving: 2, break_time: 46, ..Timing::default() },
+                },
+            }],
+            ..create_empty_solution()
+        }
+    );
+}
+
+#[test]
+fn can_serve_job_on_allowed_day() {
+    let problem = create_problem(
+        VehicleType {
+            shifts: vec![VehicleShift {
+                start: ShiftStart { earliest: format_time(0.), latest: None, location: Some((0., 0.).to_loc()) },
+                end: Some(ShiftEnd {
+                    earliest: None,
+                    latest: format_time(3. * 86400.),
+                    location: Some((0., 0.).to_loc()),
+                }),
+                ..create_default_vehicle_shift()
+            }],
+            ..create_default_vehicle_type()
+        },
+        vec![Job { days: Some(vec![DayOfWeek::Friday]), ..create_delivery_job("job1", (1., 0.)) }],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 15.,
+                distance: 2,
+                duration: 3,
+                emissions: None,
+                tolls: None,
+                times: Timing { driving: 2, serving: 1, ..Timing::default() },
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                driver_id: None,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        1,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T23:59:59Z"),
+                        0,
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (1., 0.),
+                        0,
+                        ("1970-01-02T00:00:00Z", "1970-01-02T00:00:01Z"),
+                        1,
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-02T00:00:02Z", "1970-01-02T00:00:02Z"),
+                        2,
+                    ),
+                ],
+                statistic: Statistic {
+                    cost: 15.,
+                    distance: 2,
+                    duration: 3,
+                    emissions: None,
+                    tolls: None,
+                    times: Timing { driving: 2, serving: 1, ..Timing::default() },
+                },
+            }],
+            ..create_empty_solution()
+        }
+    );
+}
@@ -1,4 +1,9 @@
 mod basic_multiple_times;
 mod basic_waiting_time;
+mod linked_shifts;
+mod periodic_visits;
+mod robust_time_windows;
+mod service_durations;
+mod soft_time_window;
 mod strict_leads_to_unassigned;
 mod strict_split_into_two_tours;
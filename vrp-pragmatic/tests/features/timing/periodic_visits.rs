@@ -0,0 +1,117 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+const DAY: f64 = 86400.;
+
+fn create_vehicle_with_daily_shifts(days: usize) -> VehicleType {
+    VehicleType {
+        shifts: (0..days)
+            .map(|day| {
+                let start = day as f64 * DAY;
+                VehicleShift {
+                    start: ShiftStart { earliest: format_time(start), latest: None, location: Some((0., 0.).to_loc()) },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(start + 1000.),
+                        location: Some((0., 0.).to_loc()),
+                    }),
+                    ..create_default_vehicle_shift()
+                }
+            })
+            .collect(),
+        ..create_default_vehicle_type()
+    }
+}
+
+fn create_periodic_job(id: &str, location: (f64, f64), visits: JobVisits) -> Job {
+    Job { visits: Some(visits), ..create_delivery_job(id, location) }
+}
+
+fn create_problem(days: usize, jobs: Vec<Job>) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_daily_shifts(days)],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_visits(solution: &Solution, job_id: &str) -> Vec<(usize, Option<usize>)> {
+    let mut visits = solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter())
+                .filter(|activity| activity.job_id == job_id)
+                .map(move |activity| (tour.shift_index, activity.visit_index))
+        })
+        .collect::<Vec<_>>();
+    visits.sort();
+
+    visits
+}
+
+#[test]
+fn can_space_visits_over_days() {
+    let problem = create_problem(
+        3,
+        vec![
+            create_periodic_job("job1", (5., 0.), JobVisits { count: 2, min_days_between: Some(1), patterns: None }),
+            create_delivery_job("job2", (10., 0.)),
+        ],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_visits(&solution, "job1"), vec![(0, Some(0)), (2, Some(1))]);
+    assert_eq!(get_visits(&solution, "job2").len(), 1);
+}
+
+#[test]
+fn can_follow_visit_day_pattern() {
+    // NOTE planning period starts on Thursday
+    let problem = create_problem(
+        4,
+        vec![create_periodic_job(
+            "job1",
+            (5., 0.),
+            JobVisits {
+                count: 2,
+                min_days_between: None,
+                patterns: Some(vec![vec![DayOfWeek::Friday, DayOfWeek::Sunday]]),
+            },
+        )],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_visits(&solution, "job1"), vec![(1, Some(0)), (3, Some(1))]);
+}
+
+#[test]
+fn can_unassign_visit_without_enough_days() {
+    let problem = create_problem(
+        2,
+        vec![create_periodic_job("job1", (5., 0.), JobVisits { count: 2, min_days_between: Some(1), patterns: None })],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(get_visits(&solution, "job1").len(), 1);
+    assert_eq!(
+        solution.unassigned.iter().flat_map(|jobs| jobs.iter()).map(|job| job.job_id.as_str()).collect::<Vec<_>>(),
+        vec!["job1"]
+    );
+}
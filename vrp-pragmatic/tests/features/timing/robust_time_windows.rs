@@ -0,0 +1,79 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_test_problem(job_end: i32, service_level: Option<f64>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", (5., 0.), vec![(0, job_end)], 1.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            drivers: None,
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, service_level }],
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_matrix(problem: &Problem) -> Matrix {
+    let matrix = create_matrix_from_problem(problem);
+    let variances = matrix.travel_times.iter().map(|&duration| if duration > 0 { 4. } else { 0. }).collect();
+
+    Matrix { travel_time_variances: Some(variances), ..matrix }
+}
+
+fn get_on_time_probabilities(solution: &Solution) -> Vec<Option<f64>> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .filter_map(|stop| stop.as_point())
+        .map(|stop| stop.on_time_probability)
+        .collect()
+}
+
+parameterized_test! {can_use_service_level_for_time_windows, (job_end, service_level, expected_probability), {
+    can_use_service_level_for_time_windows_impl(job_end, service_level, expected_probability);
+}}
+
+can_use_service_level_for_time_windows! {
+    case01_deterministic: (7, None, Some(0.841345)),
+    case02_robust_unassigned: (7, Some(0.9), None),
+    case03_robust_assigned: (10, Some(0.9), Some(0.993790)),
+}
+
+fn can_use_service_level_for_time_windows_impl(
+    job_end: i32,
+    service_level: Option<f64>,
+    expected_probability: Option<f64>,
+) {
+    let problem = create_test_problem(job_end, service_level);
+    let matrix = create_test_matrix(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    if let Some(expected_probability) = expected_probability {
+        assert!(solution.unassigned.is_none());
+        let probabilities = get_on_time_probabilities(&solution);
+        assert_eq!(probabilities.len(), 3);
+        assert_eq!(probabilities[0], None);
+        assert!((probabilities[1].unwrap() - expected_probability).abs() < 1E-5);
+        assert_eq!(probabilities[2], None);
+    } else {
+        assert!(solution.tours.is_empty());
+        assert_eq!(solution.unassigned.map(|unassigned| unassigned.len()), Some(1));
+    }
+}
+
+#[test]
+fn can_skip_on_time_probability_without_variances() {
+    let problem = create_test_problem(7, Some(0.9));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert!(get_on_time_probabilities(&solution).iter().all(|probability| probability.is_none()));
+}
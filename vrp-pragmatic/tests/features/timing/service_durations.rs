@@ -0,0 +1,72 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_problem(job: Job, vehicles: Vec<&str>, drivers: Option<Vec<&str>>) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vehicles.into_iter().map(create_default_vehicle).collect(),
+            drivers: drivers.map(|drivers| drivers.into_iter().map(create_default_driver).collect()),
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_job_schedule(solution: &Solution) -> (String, Schedule) {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter().map(move |stop| (tour, stop)))
+        .find(|(_, stop)| stop.activities().iter().any(|activity| activity.job_id == "job1"))
+        .map(|(tour, stop)| (tour.type_id.clone(), stop.schedule().clone()))
+        .unwrap()
+}
+
+#[test]
+fn can_use_vehicle_dependent_service_duration() {
+    let job = create_delivery_job_with_durations("job1", (10., 0.), vec![("truck", 100.)], vec![]);
+    let problem = create_problem(job, vec!["truck"], None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_job_schedule(&solution),
+        ("truck".to_string(), Schedule { arrival: format_time(10.), departure: format_time(110.) })
+    );
+    assert_eq!(solution.statistic.times.serving, 100);
+}
+
+#[test]
+fn can_prefer_vehicle_with_shorter_service_duration() {
+    let job = create_delivery_job_with_durations("job1", (10., 0.), vec![("truck", 1000.)], vec![]);
+    let problem = create_problem(job, vec!["truck", "van"], None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        get_job_schedule(&solution),
+        ("van".to_string(), Schedule { arrival: format_time(10.), departure: format_time(11.) })
+    );
+}
+
+#[test]
+fn can_use_driver_dependent_service_duration() {
+    let job = create_delivery_job_with_durations("job1", (10., 0.), vec![("truck", 100.)], vec![("expert", 50.)]);
+    let problem = create_problem(job, vec!["truck"], Some(vec!["expert"]));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_job_schedule(&solution),
+        ("truck".to_string(), Schedule { arrival: format_time(10.), departure: format_time(60.) })
+    );
+    assert_eq!(solution.statistic.times.serving, 50);
+}
@@ -0,0 +1,62 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_test_problem(lateness: Vec<(f64, f64)>) -> Problem {
+    create_test_problem_with_times(vec![(0, 5)], lateness)
+}
+
+fn create_test_problem_with_times(times: Vec<(i32, i32)>, lateness: Vec<(f64, f64)>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_soft_times("job1", (10., 0.), times, lateness, 0.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_serve_job_late_within_soft_time_window() {
+    let problem = create_test_problem(vec![(10., 2.)]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let job_activity = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .find(|activity| activity.job_id == "job1")
+        .expect("cannot find job activity");
+    assert_eq!(job_activity.lateness, Some(5));
+    assert_eq!(solution.statistic.cost, 10. + 20. + 20. + 5. * 2.);
+}
+
+#[test]
+fn can_use_penalty_of_selected_soft_time_window() {
+    let problem = create_test_problem_with_times(vec![(0, 2), (3, 5)], vec![(10., 1.), (10., 3.)]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.statistic.cost, 10. + 20. + 20. + 8. * 1.);
+}
+
+#[test]
+fn can_have_unassigned_job_when_max_lateness_is_exceeded() {
+    let problem = create_test_problem(vec![(4., 2.)]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(solution.unassigned.map(|jobs| jobs.len()), Some(1));
+}
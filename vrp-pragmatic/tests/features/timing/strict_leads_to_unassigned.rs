@@ -17,6 +17,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
         },
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("my_vehicle")],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -32,12 +33,15 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                 cost: 170.,
                 distance: 80,
                 duration: 80,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 80, serving: 0, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -92,6 +96,8 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                     cost: 170.,
                     distance: 80,
                     duration: 80,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 80, serving: 0, ..Timing::default() },
                 },
             }],
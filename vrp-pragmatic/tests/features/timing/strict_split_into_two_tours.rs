@@ -19,6 +19,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         objectives: create_min_jobs_cost_objective(),
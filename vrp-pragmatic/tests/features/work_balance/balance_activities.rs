@@ -49,6 +49,7 @@ fn can_balance_activities_with_threshold_impl(threshold: Option<f64>, expected_l
                     ..create_default_vehicle_type()
                 },
             ],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         objectives: Some(vec![
@@ -23,6 +23,7 @@ fn can_balance_max_load() {
                 capacity: vec![5],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         objectives: Some(vec![
@@ -21,6 +21,7 @@ fn can_balance_duration() {
                 capacity: vec![3],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         objectives: Some(vec![
@@ -89,7 +89,13 @@ prop_compose! {
             skills,
             value,
             group,
-            compatibility
+            compatibility,
+            product: None,
+            max_ride_time: None,
+            days: None,
+            visits: None,
+            hub: None,
+            sync: None,
         }
     }
 }
@@ -135,6 +141,12 @@ prop_compose! {
             value,
             group,
             compatibility,
+            product: None,
+            max_ride_time: None,
+            days: None,
+            visits: None,
+            hub: None,
+            sync: None,
         }
     }
 }
@@ -167,7 +179,7 @@ prop_compose! {
      times in time_windows,
      tag in tags
     ) -> JobPlace {
-      JobPlace { times, location, duration, tag }
+      JobPlace { times, location, duration, tag, lateness: None, durations: None }
     }
 }
 
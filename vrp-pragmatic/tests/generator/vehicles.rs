@@ -33,6 +33,10 @@ prop_compose! {
             capacity,
             skills,
             limits,
+            compartments: None,
+            battery: None,
+            emissions: None,
+            backhauls: None,
         }
     }
 }
@@ -85,7 +89,8 @@ prop_compose! {
           end: places.1,
           dispatch,
           breaks,
-          reloads
+          reloads,
+          charging_stations: None,
         }
     }
 }
@@ -106,7 +111,7 @@ prop_compose! {
      vehicles in vehicles_proto,
      profiles in profiles_proto
     ) -> Fleet {
-        Fleet { vehicles, profiles }
+        Fleet { vehicles, drivers: None, profiles }
     }
 }
 
@@ -34,6 +34,7 @@ pub fn test_vehicle(id: &str) -> Vehicle {
         details: vec![VehicleDetail {
             start: Some(VehiclePlace { location: 0, time: Default::default() }),
             end: Some(VehiclePlace { location: 0, time: Default::default() }),
+            links: vec![],
         }],
     }
 }
@@ -60,6 +61,8 @@ pub fn create_route_with_activities(fleet: &Fleet, vehicle: &str, activities: Ve
 pub fn create_activity_at_location(location: Location) -> Activity {
     Activity {
         place: vrp_core::models::solution::Place {
+            idx: 0,
+            time_idx: 0,
             location,
             duration: DEFAULT_JOB_DURATION,
             time: DEFAULT_ACTIVITY_TIME_WINDOW,
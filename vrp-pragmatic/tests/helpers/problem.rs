@@ -11,13 +11,17 @@ use vrp_core::models::common::Profile as CoreProfile;
 use vrp_core::models::problem::{ActivityCost, SimpleActivityCost, TransportCost};
 
 pub fn create_job_place(location: (f64, f64), tag: Option<String>) -> JobPlace {
-    JobPlace { times: None, location: location.to_loc(), duration: 1., tag }
+    JobPlace { times: None, location: location.to_loc(), duration: 1., tag, lateness: None, durations: None }
 }
 
 pub fn create_task(location: (f64, f64), tag: Option<String>) -> JobTask {
     JobTask { places: vec![create_job_place(location, tag)], demand: Some(vec![1]), order: None }
 }
 
+pub fn create_completed_task(job_id: &str, tag: Option<&str>) -> CompletedTask {
+    CompletedTask { job_id: job_id.to_string(), tag: tag.map(|tag| tag.to_string()), task_index: None }
+}
+
 pub fn create_job(id: &str) -> Job {
     Job {
         id: id.to_string(),
@@ -29,6 +33,12 @@ pub fn create_job(id: &str) -> Job {
         value: None,
         group: None,
         compatibility: None,
+        product: None,
+        max_ride_time: None,
+        days: None,
+        visits: None,
+        hub: None,
+        sync: None,
     }
 }
 
@@ -79,6 +89,40 @@ pub fn create_delivery_job_with_demand(id: &str, location: (f64, f64), demand: V
     Job { deliveries: Some(vec![JobTask { demand: Some(demand), ..create_task(location, None) }]), ..create_job(id) }
 }
 
+pub fn create_delivery_job_with_product(id: &str, location: (f64, f64), demand: Vec<i32>, product: &str) -> Job {
+    Job { product: Some(product.to_string()), ..create_delivery_job_with_demand(id, location, demand) }
+}
+
+pub fn create_delivery_job_with_durations(
+    id: &str,
+    location: (f64, f64),
+    vehicles: Vec<(&str, f64)>,
+    drivers: Vec<(&str, f64)>,
+) -> Job {
+    let create_durations = |durations: Vec<(&str, f64)>| {
+        if durations.is_empty() {
+            None
+        } else {
+            Some(durations.into_iter().map(|(id, duration)| (id.to_string(), duration)).collect())
+        }
+    };
+
+    Job {
+        deliveries: Some(vec![JobTask {
+            places: vec![JobPlace {
+                durations: Some(JobPlaceDurations {
+                    vehicles: create_durations(vehicles),
+                    drivers: create_durations(drivers),
+                }),
+                ..create_job_place(location, None)
+            }],
+            demand: Some(vec![1]),
+            order: None,
+        }]),
+        ..create_job(id)
+    }
+}
+
 pub fn create_delivery_job_with_duration(id: &str, location: (f64, f64), duration: f64) -> Job {
     Job {
         deliveries: Some(vec![JobTask {
@@ -101,6 +145,33 @@ pub fn create_delivery_job_with_times(id: &str, location: (f64, f64), times: Vec
     }
 }
 
+pub fn create_delivery_job_with_soft_times(
+    id: &str,
+    location: (f64, f64),
+    times: Vec<(i32, i32)>,
+    lateness: Vec<(f64, f64)>,
+    duration: f64,
+) -> Job {
+    Job {
+        deliveries: Some(vec![JobTask {
+            places: vec![JobPlace {
+                duration,
+                times: convert_times(&times),
+                lateness: Some(
+                    lateness
+                        .into_iter()
+                        .map(|(max_lateness, penalty)| JobPlaceLateness { max_lateness, penalty })
+                        .collect(),
+                ),
+                ..create_job_place(location, None)
+            }],
+            demand: Some(vec![1]),
+            order: None,
+        }]),
+        ..create_job(id)
+    }
+}
+
 pub fn create_delivery_job_with_value(id: &str, location: (f64, f64), value: f64) -> Job {
     Job { deliveries: Some(vec![create_task(location, None)]), value: Some(value), ..create_job(id) }
 }
@@ -162,7 +233,14 @@ pub fn create_pickup_delivery_job_with_params(
 pub fn create_delivery_job_with_index(id: &str, index: usize) -> Job {
     Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: Location::Reference { index }, duration: 1., tag: None }],
+            places: vec![JobPlace {
+                times: None,
+                location: Location::Reference { index },
+                duration: 1.,
+                tag: None,
+                lateness: None,
+                durations: None,
+            }],
             demand: Some(vec![1]),
             order: None,
         }]),
@@ -205,30 +283,32 @@ pub fn create_default_vehicle_shift() -> VehicleShift {
 
 pub fn create_default_open_vehicle_shift() -> VehicleShift {
     VehicleShift {
-        start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+        start: ShiftStart { earliest: format_time(0.), latest: None, location: Some((0., 0.).to_loc()) },
         end: None,
         dispatch: None,
         breaks: None,
         reloads: None,
+        charging_stations: None,
     }
 }
 
 pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64, f64)) -> VehicleShift {
     VehicleShift {
-        start: ShiftStart { earliest: format_time(0.), latest: None, location: (start.0, start.1).to_loc() },
+        start: ShiftStart { earliest: format_time(0.), latest: None, location: Some((start.0, start.1).to_loc()) },
         end: Some(ShiftEnd {
             earliest: None,
             latest: format_time(1000.).to_string(),
-            location: (end.0, end.1).to_loc(),
+            location: Some((end.0, end.1).to_loc()),
         }),
         dispatch: None,
         breaks: None,
         reloads: None,
+        charging_stations: None,
     }
 }
 
 pub fn create_default_vehicle_costs() -> VehicleCosts {
-    VehicleCosts { fixed: Some(10.), distance: 1., time: 1. }
+    VehicleCosts { fixed: Some(10.), distance: 1., time: 1., full_load_distance: None }
 }
 
 pub fn create_default_vehicle_profile() -> VehicleProfile {
@@ -257,11 +337,45 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
         capacity,
         skills: None,
         limits: None,
+        compartments: None,
+        battery: None,
+        emissions: None,
+        backhauls: None,
+    }
+}
+
+pub fn create_vehicle_with_driving_hours(id: &str) -> VehicleType {
+    VehicleType {
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            shift_time: None,
+            tour_size: None,
+            areas: None,
+            driving_hours: Some(DrivingHoursLimit {
+                max_continuous_driving: 8.,
+                break_duration: 2.,
+                split_break: None,
+                max_daily_driving: 100.,
+                daily_rest: 40.,
+                max_daily_duty: None,
+            }),
+        }),
+        ..create_default_vehicle(id)
+    }
+}
+
+pub fn create_default_driver(id: &str) -> DriverType {
+    DriverType {
+        type_id: id.to_string(),
+        driver_ids: vec![format!("{}_1", id)],
+        costs: DriverCosts { fixed: None, distance: 0., time: 0. },
+        shifts: None,
+        skills: None,
     }
 }
 
 pub fn create_default_matrix_profiles() -> Vec<MatrixProfile> {
-    vec![MatrixProfile { name: "car".to_string(), speed: None }]
+    vec![MatrixProfile { name: "car".to_string(), speed: None, service_level: None }]
 }
 
 pub fn create_min_jobs_cost_objective() -> Option<Vec<Vec<Objective>>> {
@@ -269,11 +383,24 @@ pub fn create_min_jobs_cost_objective() -> Option<Vec<Vec<Objective>>> {
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, areas: None, clustering: None }
+    Plan {
+        jobs: vec![],
+        relations: None,
+        areas: None,
+        clustering: None,
+        products: None,
+        hubs: None,
+        depots: None,
+        tours: None,
+    }
 }
 
 pub fn create_empty_problem() -> Problem {
-    Problem { plan: create_empty_plan(), fleet: Fleet { vehicles: vec![], profiles: vec![] }, objectives: None }
+    Problem {
+        plan: create_empty_plan(),
+        fleet: Fleet { vehicles: vec![], drivers: None, profiles: vec![] },
+        objectives: None,
+    }
 }
 
 pub fn get_costs() -> (Arc<dyn TransportCost + Send + Sync>, Arc<dyn ActivityCost + Send + Sync>) {
@@ -311,6 +438,8 @@ pub fn create_matrix(data: Vec<i64>) -> Matrix {
         travel_times: data.clone(),
         distances: data.clone(),
         error_codes: None,
+        toll_costs: None,
+        travel_time_variances: None,
     }
 }
 
@@ -337,7 +466,7 @@ pub fn to_strings(data: Vec<&str>) -> Vec<String> {
 }
 
 pub fn all_of_skills(skills: Vec<String>) -> JobSkills {
-    JobSkills { all_of: Some(skills), one_of: None, none_of: None }
+    JobSkills { all_of: Some(skills), one_of: None, none_of: None, preferred: None }
 }
 
 fn convert_times(times: &Vec<(i32, i32)>) -> Option<Vec<Vec<String>>> {
@@ -63,8 +63,13 @@ fn create_stop_with_activity_impl(
             time: None,
             job_tag,
             commute: None,
+            lateness: None,
+            compartment: None,
+            visit_index: None,
         }],
         parking: None,
+        charge: None,
+        on_time_probability: None,
     })
 }
 
@@ -102,13 +107,22 @@ pub fn create_empty_tour() -> Tour {
         vehicle_id: "".to_string(),
         type_id: "".to_string(),
         shift_index: 0,
+        driver_id: None,
         stops: vec![],
         statistic: Default::default(),
     }
 }
 
 pub fn create_empty_solution() -> Solution {
-    Solution { statistic: Default::default(), tours: vec![], unassigned: None, violations: None, extras: None }
+    Solution {
+        statistic: Default::default(),
+        tours: vec![],
+        unassigned: None,
+        violations: None,
+        transfers: None,
+        docks: None,
+        extras: None,
+    }
 }
 
 pub fn get_ids_from_tour(tour: &Tour) -> Vec<Vec<String>> {
@@ -22,6 +22,8 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T13:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                lateness: None,
+                                durations: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
@@ -38,6 +40,8 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T11:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                lateness: None,
+                                durations: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
@@ -54,6 +58,8 @@ fn can_handle_properly_invalid_break_removal() {
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                lateness: None,
+                                durations: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
@@ -70,6 +76,8 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T16:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                lateness: None,
+                                durations: None,
                             }],
                             demand: Some(vec![2]),
                             order: None,
@@ -86,6 +94,8 @@ fn can_handle_properly_invalid_break_removal() {
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                lateness: None,
+                                durations: None,
                             }],
                             demand: Some(vec![3]),
                             order: None,
@@ -102,6 +112,8 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T18:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                lateness: None,
+                                durations: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
@@ -116,17 +128,17 @@ fn can_handle_properly_invalid_break_removal() {
                     type_id: "vehicle1".to_string(),
                     vehicle_ids: vec!["vehicle1_1".to_string()],
                     profile: VehicleProfile { matrix: "car".to_string(), scale: None },
-                    costs: VehicleCosts { fixed: Some(20.), distance: 0.002, time: 0.003 },
+                    costs: VehicleCosts { fixed: Some(20.), distance: 0.002, time: 0.003, full_load_distance: None },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: "2020-07-04T09:00:00Z".to_string(),
                             latest: None,
-                            location: Location::Coordinate { lat: 52.44105158292253, lng: 13.424429791168873 },
+                            location: Some(Location::Coordinate { lat: 52.44105158292253, lng: 13.424429791168873 }),
                         },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: "2020-07-04T18:00:00Z".to_string(),
-                            location: Location::Coordinate { lat: 52.44105158292253, lng: 13.424429791168873 },
+                            location: Some(Location::Coordinate { lat: 52.44105158292253, lng: 13.424429791168873 }),
                         }),
                         dispatch: None,
                         breaks: Some(vec![VehicleBreak::Optional {
@@ -138,12 +150,18 @@ fn can_handle_properly_invalid_break_removal() {
                             policy: None,
                         }]),
                         reloads: None,
+                        charging_stations: None,
                     }],
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    compartments: None,
+                    battery: None,
+                    emissions: None,
+                    backhauls: None,
                 }],
-                profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+                drivers: None,
+                profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, service_level: None }],
             },
             ..create_empty_problem()
         };
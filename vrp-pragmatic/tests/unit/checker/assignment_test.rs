@@ -21,6 +21,7 @@ fn check_vehicles_impl(known_ids: Vec<&str>, tours: Vec<(&str, usize)>, expected
                 vehicle_ids: known_ids.into_iter().map(|id| id.to_string()).collect(),
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -35,6 +36,7 @@ fn check_vehicles_impl(known_ids: Vec<&str>, tours: Vec<(&str, usize)>, expected
                 shift_index,
                 stops: vec![],
                 statistic: Statistic::default(),
+                driver_id: None,
             })
             .collect(),
         ..create_empty_solution()
@@ -46,6 +48,72 @@ fn check_vehicles_impl(known_ids: Vec<&str>, tours: Vec<(&str, usize)>, expected
     assert_eq!(result.map_err(|_| ()), expected_result);
 }
 
+parameterized_test! {check_drivers, (tours, expected_result), {
+    check_drivers_impl(tours, expected_result);
+}}
+
+check_drivers! {
+    case_01: (vec![("vehicle_1", Some("driver_1"), (0, 10))], Ok(())),
+    case_02: (vec![("vehicle_1", None, (0, 10))], Err("tour 'vehicle_1' has no driver assigned".to_string())),
+    case_03: (vec![("vehicle_1", Some("driver_2"), (0, 10))], Err("used driver with unknown id: 'driver_2' in tour 'vehicle_1'".to_string())),
+    case_04: (vec![("vehicle_1", Some("driver_1"), (0, 10)), ("vehicle_2", Some("driver_1"), (20, 30))], Ok(())),
+    case_05: (
+        vec![("vehicle_1", Some("driver_1"), (0, 10)), ("vehicle_2", Some("driver_1"), (5, 30))],
+        Err("driver 'driver_1' is used in more than one tour at the same time".to_string())
+    ),
+}
+
+fn check_drivers_impl(tours: Vec<(&str, Option<&str>, (i32, i32))>, expected_result: Result<(), String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["vehicle_1".to_string(), "vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            drivers: Some(vec![create_default_driver("driver")]),
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        statistic: Statistic::default(),
+        tours: tours
+            .into_iter()
+            .map(|(id, driver_id, (start, end))| Tour {
+                vehicle_id: id.to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                driver_id: driver_id.map(|id| id.to_string()),
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        0,
+                        (format_time(start as f64).as_str(), format_time(start as f64).as_str()),
+                        0,
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        (format_time(end as f64).as_str(), format_time(end as f64).as_str()),
+                        0,
+                    ),
+                ],
+                statistic: Statistic::default(),
+            })
+            .collect(),
+        ..create_empty_solution()
+    };
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_drivers(&ctx);
+
+    assert_eq!(result, expected_result);
+}
+
 parameterized_test! {check_jobs, (jobs, tours, unassigned, expected_result), {
     check_jobs_impl(jobs, tours, unassigned, expected_result);
 }}
@@ -132,6 +200,8 @@ fn check_jobs_impl(
                     duration: 0.0,
                     times: None,
                     tag: Some(format!("{}{}", tgt, idx)),
+                    lateness: None,
+                    durations: None,
                 }],
                 demand: if tgt != "service" { Some(vec![1]) } else { None },
                 order: None,
@@ -155,7 +225,11 @@ fn check_jobs_impl(
                 .collect(),
             ..create_empty_plan()
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
         ..create_empty_problem()
     };
     let solution = Solution {
@@ -168,6 +242,7 @@ fn check_jobs_impl(
                 shift_index,
                 stops: stops.into_iter().map(create_stop).collect(),
                 statistic: Statistic::default(),
+                driver_id: None,
             })
             .collect(),
         unassigned: Some(
@@ -189,7 +264,11 @@ fn can_detect_time_window_violation() {
             jobs: vec![create_delivery_job_with_times("job1", (1., 0.), vec![(1, 2)], 1.)],
             ..create_empty_plan()
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
         ..create_empty_problem()
     };
     let solution = Solution {
@@ -197,12 +276,15 @@ fn can_detect_time_window_violation() {
             cost: 15.,
             distance: 2,
             duration: 3,
+            emissions: None,
+            tolls: None,
             times: Timing { driving: 2, serving: 1, ..Timing::default() },
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
             type_id: "my_vehicle".to_string(),
             shift_index: 0,
+            driver_id: None,
             stops: vec![
                 create_stop_with_activity(
                     "departure",
@@ -233,6 +315,8 @@ fn can_detect_time_window_violation() {
                 cost: 15.,
                 distance: 2,
                 duration: 3,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 2, serving: 1, ..Timing::default() },
             },
         }],
@@ -253,7 +337,11 @@ fn can_detect_job_duration_violation() {
             jobs: vec![create_delivery_job_with_times("job1", (1., 0.), vec![(5, 10)], 1.)],
             ..create_empty_plan()
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
         ..create_empty_problem()
     };
     let solution = Solution {
@@ -261,12 +349,15 @@ fn can_detect_job_duration_violation() {
             cost: 18.,
             distance: 2,
             duration: 6,
+            emissions: None,
+            tolls: None,
             times: Timing { driving: 2, serving: 2, waiting: 2, ..Timing::default() },
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
             type_id: "my_vehicle".to_string(),
             shift_index: 0,
+            driver_id: None,
             stops: vec![
                 create_stop_with_activity(
                     "departure",
@@ -297,6 +388,8 @@ fn can_detect_job_duration_violation() {
                 cost: 18.,
                 distance: 2,
                 duration: 6,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 2, serving: 2, waiting: 2, ..Timing::default() },
             },
         }],
@@ -326,6 +419,7 @@ fn can_detect_dispatch_violations() {
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -387,6 +481,7 @@ fn can_detect_group_violations() {
                 vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -431,3 +526,79 @@ fn can_detect_group_violations() {
 
     assert_eq!(result, Err("job groups are not respected: 'group1'".to_owned()));
 }
+
+parameterized_test! {can_detect_ride_time_violation, (max_ride_time, expected_result), {
+    can_detect_ride_time_violation_impl(max_ride_time, expected_result);
+}}
+
+can_detect_ride_time_violation! {
+    case_01: (None, Ok(())),
+    case_02: (Some(3.), Ok(())),
+    case_03: (Some(2.), Ok(())),
+    case_04: (Some(1.), Err("max ride time is violated for jobs: 'job1'".to_owned())),
+}
+
+fn can_detect_ride_time_violation_impl(max_ride_time: Option<f64>, expected_result: Result<(), String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { max_ride_time, ..create_pickup_delivery_job("job1", (1., 0.), (3., 0.)) }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity_with_tag(
+                    "job1",
+                    "pickup",
+                    (1., 0.),
+                    1,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                    "p1",
+                ),
+                create_stop_with_activity_with_tag(
+                    "job1",
+                    "delivery",
+                    (3., 0.),
+                    0,
+                    ("1970-01-01T00:00:04Z", "1970-01-01T00:00:05Z"),
+                    3,
+                    "d1",
+                ),
+                create_stop_with_activity(
+                    "arrival",
+                    "arrival",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:00:08Z", "1970-01-01T00:00:08Z"),
+                    6,
+                ),
+            ],
+            ..create_empty_tour()
+        }],
+        ..create_empty_solution()
+    };
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_ride_time(&ctx);
+
+    assert_eq!(result, expected_result);
+}
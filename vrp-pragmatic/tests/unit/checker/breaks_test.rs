@@ -1,6 +1,7 @@
 use super::*;
 use crate::format_time;
 use crate::helpers::*;
+use std::iter::once;
 use vrp_core::models::examples::create_example_problem;
 
 fn test_violations() -> Option<Vec<Violation>> {
@@ -78,11 +79,11 @@ fn can_check_breaks_impl(
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart { earliest: format_time(0.), latest: None, location: Some((0., 0.).to_loc()) },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
-                        location: (0., 0.).to_loc(),
+                        location: Some((0., 0.).to_loc()),
                     }),
                     dispatch: None,
                     breaks: Some(vec![VehicleBreak::Optional {
@@ -91,10 +92,12 @@ fn can_check_breaks_impl(
                         policy: None,
                     }]),
                     reloads: None,
+                    charging_stations: None,
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -107,6 +110,9 @@ fn can_check_breaks_impl(
         time: Some(Interval { start: "1970-01-01T00:00:03Z".to_string(), end: "1970-01-01T00:00:04Z".to_string() }),
         job_tag: None,
         commute: None,
+        lateness: None,
+        compartment: None,
+        visit_index: None,
     }];
     if has_break {
         activities.push(Activity {
@@ -116,6 +122,9 @@ fn can_check_breaks_impl(
             time: Some(Interval { start: "1970-01-01T00:00:04Z".to_string(), end: "1970-01-01T00:00:06Z".to_string() }),
             job_tag: None,
             commute: None,
+            lateness: None,
+            compartment: None,
+            visit_index: None,
         });
     }
 
@@ -124,12 +133,15 @@ fn can_check_breaks_impl(
             cost: 22.,
             distance: 4,
             duration: 8,
+            emissions: None,
+            tolls: None,
             times: Timing { driving: 4, serving: 2, break_time: 2, ..Timing::default() },
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
             type_id: "my_vehicle".to_string(),
             shift_index: 0,
+            driver_id: None,
             stops: vec![
                 create_stop_with_activity(
                     "departure",
@@ -157,6 +169,8 @@ fn can_check_breaks_impl(
                     parking: None,
                     load: vec![0],
                     activities,
+                    charge: None,
+                    on_time_probability: None,
                 }),
                 create_stop_with_activity(
                     "arrival",
@@ -171,6 +185,8 @@ fn can_check_breaks_impl(
                 cost: 22.,
                 distance: 4,
                 duration: 8,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 4, serving: 2, break_time: 2, ..Timing::default() },
             },
         }],
@@ -183,3 +199,106 @@ fn can_check_breaks_impl(
 
     assert_eq!(result, expected_result);
 }
+
+fn get_driving_hours_error_msg(name: &str, expected: usize, actual: usize) -> Result<(), Vec<String>> {
+    Err(vec![format!(
+        "{} limit violation, expected: not more than {}, got: {}, vehicle id 'my_vehicle_1', shift index: 0",
+        name, expected, actual
+    )])
+}
+
+parameterized_test! {can_check_driving_hours, (split_break, rests, expected_result), {
+    can_check_driving_hours_impl(split_break, rests, expected_result);
+}}
+
+can_check_driving_hours! {
+    case01_break: (None, vec![(10, 13)], Ok(())),
+    case02_no_break: (None, vec![], get_driving_hours_error_msg("continuous driving", 10, 16)),
+    case03_short_break: (None, vec![(10, 12)], get_driving_hours_error_msg("continuous driving", 10, 16)),
+    case04_split_break: (Some(vec![1., 2.]), vec![(5, 6), (11, 13)], Ok(())),
+    case05_short_second_part: (Some(vec![1., 2.]), vec![(5, 7), (12, 13)], get_driving_hours_error_msg("continuous driving", 10, 16)),
+}
+
+fn can_check_driving_hours_impl(
+    split_break: Option<Vec<f64>>,
+    rests: Vec<(i64, i64)>,
+    expected_result: Result<(), Vec<String>>,
+) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (16., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    tour_size: None,
+                    areas: None,
+                    driving_hours: Some(DrivingHoursLimit {
+                        max_continuous_driving: 10.,
+                        break_duration: 3.,
+                        split_break,
+                        max_daily_driving: 18.,
+                        daily_rest: 20.,
+                        max_daily_duty: None,
+                    }),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let rest_time = rests.iter().map(|(start, end)| end - start).sum::<i64>();
+    let stops = once(create_stop_with_activity(
+        "departure",
+        "departure",
+        (0., 0.),
+        1,
+        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+        0,
+    ))
+    .chain(rests.iter().map(|&(start, end)| {
+        Stop::Transit(TransitStop {
+            time: Schedule { arrival: format_time(start as f64), departure: format_time(end as f64) },
+            load: vec![1],
+            activities: vec![Activity {
+                job_id: "rest".to_string(),
+                activity_type: "rest".to_string(),
+                location: None,
+                time: None,
+                job_tag: None,
+                commute: None,
+                lateness: None,
+                compartment: None,
+                visit_index: None,
+            }],
+        })
+    }))
+    .chain(once(create_stop_with_activity(
+        "job1",
+        "delivery",
+        (16., 0.),
+        0,
+        (&format_time((16 + rest_time) as f64), &format_time((17 + rest_time) as f64)),
+        16,
+    )))
+    .collect();
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            driver_id: None,
+            stops,
+            statistic: Statistic::default(),
+        }],
+        ..create_empty_solution()
+    };
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_breaks(&ctx);
+
+    assert_eq!(result, expected_result);
+}
@@ -36,11 +36,11 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart { earliest: format_time(0.), latest: None, location: Some((0., 0.).to_loc()) },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
-                        location: (0., 0.).to_loc(),
+                        location: Some((0., 0.).to_loc()),
                     }),
                     dispatch: None,
                     breaks: None,
@@ -50,10 +50,12 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                         duration: 2.0,
                         tag: None,
                     }]),
+                    charging_stations: None,
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -63,12 +65,15 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
             cost: 13.,
             distance: 1,
             duration: 2,
+            emissions: None,
+            tolls: None,
             times: Timing { driving: 1, serving: 1, ..Timing::default() },
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
             type_id: "my_vehicle".to_string(),
             shift_index: 0,
+            driver_id: None,
             stops: vec![
                 create_stop_with_activity(
                     "departure",
@@ -95,6 +100,9 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                             time: None,
                             job_tag: None,
                             commute: None,
+                            lateness: None,
+                            compartment: None,
+                            visit_index: None,
                         },
                         Activity {
                             job_id: "job5".to_string(),
@@ -103,8 +111,13 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                             time: None,
                             job_tag: Some("p1".to_string()),
                             commute: None,
+                            lateness: None,
+                            compartment: None,
+                            visit_index: None,
                         },
                     ],
+                    charge: None,
+                    on_time_probability: None,
                 }),
                 Stop::Point(PointStop {
                     location: (0., 0.).to_loc(),
@@ -122,7 +135,12 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                         time: None,
                         job_tag: None,
                         commute: None,
+                        lateness: None,
+                        compartment: None,
+                        visit_index: None,
                     }],
+                    charge: None,
+                    on_time_probability: None,
                 }),
                 Stop::Point(PointStop {
                     location: (2., 0.).to_loc(),
@@ -144,6 +162,9 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                             }),
                             job_tag: None,
                             commute: None,
+                            lateness: None,
+                            compartment: None,
+                            visit_index: None,
                         },
                         Activity {
                             job_id: "job3".to_string(),
@@ -155,8 +176,13 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                             }),
                             job_tag: None,
                             commute: None,
+                            lateness: None,
+                            compartment: None,
+                            visit_index: None,
                         },
                     ],
+                    charge: None,
+                    on_time_probability: None,
                 }),
                 create_stop_with_activity(
                     "job4",
@@ -188,6 +214,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                 cost: 13.,
                 distance: 1,
                 duration: 2,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 1, serving: 1, ..Timing::default() },
             },
         }],
@@ -207,6 +235,7 @@ fn can_check_load_when_departure_has_other_activity() {
         plan: Plan { jobs: vec![create_pickup_delivery_job("job1", (0., 0.), (1., 0.))], ..create_empty_plan() },
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -216,12 +245,15 @@ fn can_check_load_when_departure_has_other_activity() {
             cost: 6.,
             distance: 2,
             duration: 4,
+            emissions: None,
+            tolls: None,
             times: Timing { driving: 2, serving: 2, ..Timing::default() },
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
             type_id: "my_vehicle".to_string(),
             shift_index: 0,
+            driver_id: None,
             stops: vec![
                 Stop::Point(PointStop {
                     location: (0., 0.).to_loc(),
@@ -240,6 +272,9 @@ fn can_check_load_when_departure_has_other_activity() {
                             time: None,
                             job_tag: None,
                             commute: None,
+                            lateness: None,
+                            compartment: None,
+                            visit_index: None,
                         },
                         Activity {
                             job_id: "job1".to_string(),
@@ -248,8 +283,13 @@ fn can_check_load_when_departure_has_other_activity() {
                             time: None,
                             job_tag: Some("p1".to_string()),
                             commute: None,
+                            lateness: None,
+                            compartment: None,
+                            visit_index: None,
                         },
                     ],
+                    charge: None,
+                    on_time_probability: None,
                 }),
                 create_stop_with_activity_with_tag(
                     "job1",
@@ -273,6 +313,8 @@ fn can_check_load_when_departure_has_other_activity() {
                 cost: 6.,
                 distance: 2,
                 duration: 4,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 2, serving: 2, ..Timing::default() },
             },
         }],
@@ -284,3 +326,84 @@ fn can_check_load_when_departure_has_other_activity() {
 
     assert_eq!(result, Ok(()));
 }
+
+parameterized_test! {can_check_backhauls, (backhauls, is_pickup_first, expected_result), {
+    can_check_backhauls_impl(backhauls, is_pickup_first, expected_result);
+}}
+
+can_check_backhauls! {
+    case01_linehaul_first: (Some(true), false, Ok(())),
+    case02_backhaul_first: (Some(true), true, Err(vec![
+        "linehaul job 'job1' is served after backhaul in tour 'my_vehicle_1'".to_owned()
+    ])),
+    case03_backhaul_first_without_backhauls: (None, true, Ok(())),
+}
+
+fn can_check_backhauls_impl(backhauls: Option<bool>, is_pickup_first: bool, expected_result: Result<(), Vec<String>>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_pickup_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { backhauls, ..create_vehicle_with_capacity("my_vehicle", vec![2]) }],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let (first, second) = if is_pickup_first {
+        (("job2", "pickup", (2., 0.), 2), ("job1", "delivery", (1., 0.), 1))
+    } else {
+        (("job1", "delivery", (1., 0.), 0), ("job2", "pickup", (2., 0.), 1))
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            driver_id: None,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    1,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    first.0,
+                    first.1,
+                    first.2,
+                    first.3,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+                create_stop_with_activity(
+                    second.0,
+                    second.1,
+                    second.2,
+                    second.3,
+                    ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
+                    2,
+                ),
+                create_stop_with_activity(
+                    "arrival",
+                    "arrival",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:00:06Z", "1970-01-01T00:00:06Z"),
+                    4,
+                ),
+            ],
+            statistic: Statistic::default(),
+        }],
+        ..create_empty_solution()
+    };
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_vehicle_load(&ctx);
+
+    assert_eq!(result, expected_result);
+}
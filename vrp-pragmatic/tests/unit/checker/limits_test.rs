@@ -11,6 +11,7 @@ fn create_test_problem(limits: Option<VehicleLimits>) -> Problem {
                 limits,
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -61,7 +62,13 @@ pub fn can_check_shift_and_distance_limit_impl(
     actual: i64,
     expected: Result<(), String>,
 ) {
-    let problem = create_test_problem(Some(VehicleLimits { max_distance, shift_time, tour_size: None, areas: None }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance,
+        shift_time,
+        tour_size: None,
+        areas: None,
+        driving_hours: None,
+    }));
     let solution =
         create_test_solution(Statistic { distance: actual, duration: actual, ..Statistic::default() }, vec![]);
     let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
@@ -78,6 +85,7 @@ pub fn can_check_tour_size_limit() {
         shift_time: None,
         tour_size: Some(2),
         areas: None,
+        driving_hours: None,
     }));
     let solution = create_test_solution(
         Statistic::default(),
@@ -145,16 +153,17 @@ fn can_check_shift_time() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart { earliest: format_time(0.), latest: None, location: Some((0., 0.).to_loc()) },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(5.).to_string(),
-                        location: (0., 0.).to_loc(),
+                        location: Some((0., 0.).to_loc()),
                     }),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -164,12 +173,15 @@ fn can_check_shift_time() {
             cost: 17.,
             distance: 2,
             duration: 5,
+            emissions: None,
+            tolls: None,
             times: Timing { driving: 2, serving: 1, waiting: 2, ..Timing::default() },
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
             type_id: "my_vehicle".to_string(),
             shift_index: 0,
+            driver_id: None,
             stops: vec![
                 create_stop_with_activity(
                     "departure",
@@ -200,6 +212,8 @@ fn can_check_shift_time() {
                 cost: 17.,
                 distance: 2,
                 duration: 5,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 2, serving: 1, waiting: 2, ..Timing::default() },
             },
         }],
@@ -87,11 +87,15 @@ mod single {
                     profile: create_default_vehicle_profile(),
                     costs: create_default_vehicle_costs(),
                     shifts: vec![VehicleShift {
-                        start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                        start: ShiftStart {
+                            earliest: format_time(0.),
+                            latest: None,
+                            location: Some((0., 0.).to_loc()),
+                        },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: format_time(1000.).to_string(),
-                            location: (0., 0.).to_loc(),
+                            location: Some((0., 0.).to_loc()),
                         }),
                         dispatch: None,
                         breaks: Some(vec![VehicleBreak::Optional {
@@ -105,11 +109,17 @@ mod single {
                             duration: 2.0,
                             tag: None,
                         }]),
+                        charging_stations: None,
                     }],
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    compartments: None,
+                    battery: None,
+                    emissions: None,
+                    backhauls: None,
                 }],
+                drivers: None,
                 profiles: create_default_matrix_profiles(),
             },
             ..create_empty_problem()
@@ -119,6 +129,8 @@ mod single {
                 cost: 51.,
                 distance: 16,
                 duration: 25,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 16, serving: 9, break_time: 2, ..Timing::default() },
             },
             tours: vec![
@@ -126,6 +138,7 @@ mod single {
                     vehicle_id: "my_vehicle_1".to_string(),
                     type_id: "my_vehicle".to_string(),
                     shift_index: 0,
+                    driver_id: None,
                     stops: vec![
                         create_stop_with_activity(
                             "departure",
@@ -160,6 +173,9 @@ mod single {
                                     time: None,
                                     job_tag: None,
                                     commute: None,
+                                    lateness: None,
+                                    compartment: None,
+                                    visit_index: None,
                                 },
                                 Activity {
                                     job_id: "break".to_string(),
@@ -168,8 +184,13 @@ mod single {
                                     time: None,
                                     job_tag: None,
                                     commute: None,
+                                    lateness: None,
+                                    compartment: None,
+                                    visit_index: None,
                                 },
                             ],
+                            charge: None,
+                            on_time_probability: None,
                         }),
                         create_stop_with_activity(
                             "job3",
@@ -216,6 +237,8 @@ mod single {
                         cost: 51.,
                         distance: 16,
                         duration: 25,
+                        emissions: None,
+                        tolls: None,
                         times: Timing { driving: 16, serving: 9, break_time: 2, ..Timing::default() },
                     },
                 },
@@ -223,6 +246,7 @@ mod single {
                     vehicle_id: "my_vehicle_2".to_string(),
                     type_id: "my_vehicle".to_string(),
                     shift_index: 0,
+                    driver_id: None,
                     stops: vec![],
                     statistic: Default::default(),
                 },
@@ -9,13 +9,24 @@ fn create_test_problem() -> Problem {
             jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
             ..create_empty_plan()
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
         ..create_empty_problem()
     }
 }
 
 fn create_test_statistic() -> Statistic {
-    Statistic { cost: 10., distance: 4, duration: 6, times: Timing { driving: 4, serving: 2, ..Timing::default() } }
+    Statistic {
+        cost: 10.,
+        distance: 4,
+        duration: 6,
+        emissions: None,
+        tolls: None,
+        times: Timing { driving: 4, serving: 2, ..Timing::default() },
+    }
 }
 
 fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> Solution {
@@ -26,6 +37,7 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
             vehicle_id: "my_vehicle_1".to_string(),
             type_id: "my_vehicle".to_string(),
             shift_index: 0,
+            driver_id: None,
             stops: vec![
                 create_stop_with_activity(
                     "departure",
@@ -48,7 +60,12 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
                         time: None,
                         job_tag: None,
                         commute: None,
+                        lateness: None,
+                        compartment: None,
+                        visit_index: None,
                     }],
+                    charge: None,
+                    on_time_probability: None,
                 }),
                 Stop::Point(PointStop {
                     location: (2., 0.).to_loc(),
@@ -63,7 +80,12 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
                         time: None,
                         job_tag: None,
                         commute: None,
+                        lateness: None,
+                        compartment: None,
+                        visit_index: None,
                     }],
+                    charge: None,
+                    on_time_probability: None,
                 }),
                 create_stop_with_activity(
                     "arrival",
@@ -0,0 +1,126 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::problem::Fleet;
+
+fn create_battery(capacity: f64, initial: f64) -> BatteryPolicy {
+    BatteryPolicy { capacity, initial, empty_consumption: 1., full_consumption: 2. }
+}
+
+fn create_charging_station(rate: f64) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_id("charging");
+    single.dimens.set_value("type", "charging".to_string());
+    single.dimens.set_value("charging_rate", rate);
+
+    Arc::new(single)
+}
+
+parameterized_test! {can_get_consumption, (distance, load_ratio, expected), {
+    can_get_consumption_impl(distance, load_ratio, expected);
+}}
+
+can_get_consumption! {
+    case01_empty: (10., 0., 10.),
+    case02_half_loaded: (10., 0.5, 15.),
+    case03_fully_loaded: (10., 1., 20.),
+    case04_overloaded: (10., 2., 20.),
+    case05_zero_distance: (0., 1., 0.),
+}
+
+fn can_get_consumption_impl(distance: Distance, load_ratio: f64, expected: f64) {
+    let battery = create_battery(100., 100.);
+
+    assert_eq!(battery.get_consumption(distance, load_ratio), expected);
+}
+
+parameterized_test! {can_charge_battery, (current, rate, duration, expected), {
+    can_charge_battery_impl(current, rate, duration, expected);
+}}
+
+can_charge_battery! {
+    case01_partial: (10., 2., 20., 50.),
+    case02_full: (10., 2., 45., 100.),
+    case03_over_capacity: (10., 2., 100., 100.),
+}
+
+fn can_charge_battery_impl(current: f64, rate: f64, duration: Duration, expected: f64) {
+    let battery = create_battery(100., 100.);
+
+    assert_eq!(battery.charge(current, rate, duration), expected);
+}
+
+parameterized_test! {can_evaluate_activity_with_battery, (initial, charging_rate, capacity, expected), {
+    can_evaluate_activity_with_battery_impl(initial, charging_rate, capacity, expected);
+}}
+
+can_evaluate_activity_with_battery! {
+    case01_enough_charge: (130., None, None, None),
+    case02_not_enough_charge: (100., None, None, Some(ActivityConstraintViolation { code: 1, stopped: false })),
+    case03_charging_on_the_way: (100., Some(1.), None, None),
+    case04_not_enough_charge_to_reach_station: (80., Some(1.), None, Some(ActivityConstraintViolation { code: 1, stopped: false })),
+    case05_zero_capacity: (100., None, Some(0), Some(ActivityConstraintViolation { code: 1, stopped: false })),
+}
+
+fn can_evaluate_activity_with_battery_impl(
+    initial: f64,
+    charging_rate: Option<f64>,
+    capacity: Option<i32>,
+    expected: Option<ActivityConstraintViolation>,
+) {
+    let (transport, activity) = get_costs();
+    let mut vehicle = test_vehicle("v1");
+    vehicle.dimens.set_value("battery", create_battery(200., initial));
+    if let Some(capacity) = capacity {
+        vehicle.dimens.set_capacity(SingleDimLoad::new(capacity));
+    }
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let mut route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(
+            &fleet,
+            "v1",
+            vec![create_activity_with_job_at_location(create_single("job1"), 1)],
+        )),
+        Arc::new(RouteState::default()),
+    );
+    let module = ChargingModule::<SingleDimLoad>::new(activity, transport, 0.2, 1);
+    module.accept_route_state(&mut route_ctx);
+    let mut target = match charging_rate {
+        Some(rate) => create_activity_with_job_at_location(create_charging_station(rate), 2),
+        None => create_activity_at_location(2),
+    };
+    target.place.duration = 50.;
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route.tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(2),
+    };
+
+    let result = module
+        .get_constraints()
+        .filter_map(|constraint| match constraint {
+            ConstraintVariant::HardActivity(constraint) => constraint.evaluate_activity(&route_ctx, &activity_ctx),
+            _ => None,
+        })
+        .next();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_reject_merge_of_charging_stations() {
+    let (transport, activity) = get_costs();
+    let module = ChargingModule::<SingleDimLoad>::new(activity, transport, 0.2, 1);
+    let station = Job::Single(create_charging_station(1.));
+    let job = Job::Single(create_single("job2"));
+
+    assert_eq!(module.merge(job.clone(), job.clone()).map(|_| ()), Ok(()));
+    assert_eq!(module.merge(job.clone(), station.clone()).map(|_| ()), Err(1));
+    assert_eq!(module.merge(station, job).map(|_| ()), Err(1));
+}
@@ -0,0 +1,126 @@
+use super::*;
+
+fn create_policy(docks: usize) -> Arc<DockPolicy> {
+    Arc::new(DockPolicy {
+        depot_id: "depot1".to_string(),
+        docks,
+        loading: Some(DockRate { fixed: 10., per_unit: 1. }),
+        unloading: Some(DockRate { fixed: 5., per_unit: 0. }),
+    })
+}
+
+fn create_dock_tour(
+    route_index: usize,
+    arrival: Timestamp,
+    loading: Option<(&Arc<DockPolicy>, Duration)>,
+    unloading: Option<(&Arc<DockPolicy>, Duration)>,
+) -> DockTour {
+    DockTour {
+        route_index,
+        vehicle_id: format!("v{}", route_index + 1),
+        earliest: 0.,
+        departure: 0.,
+        arrival: Some(arrival),
+        latest_departure: f64::MAX,
+        latest_end: f64::MAX,
+        loading: loading.map(|(policy, duration)| (policy.clone(), duration)),
+        unloading: unloading.map(|(policy, duration)| (policy.clone(), duration)),
+    }
+}
+
+fn get_usages(usages: &[DockUsage]) -> Vec<(usize, usize, DockOperation, Timestamp, Timestamp)> {
+    usages
+        .iter()
+        .map(|usage| (usage.route_index, usage.dock, usage.operation, usage.time.start, usage.time.end))
+        .collect()
+}
+
+#[test]
+fn can_calculate_dock_duration() {
+    let rate = DockRate { fixed: 10., per_unit: 2. };
+
+    assert_eq!(rate.duration(0.), 10.);
+    assert_eq!(rate.duration(5.), 20.);
+}
+
+parameterized_test! {can_schedule_loading_on_limited_docks, (docks, expected_departures), {
+    can_schedule_loading_on_limited_docks_impl(docks, expected_departures);
+}}
+
+can_schedule_loading_on_limited_docks! {
+    case01_single_dock: (1, vec![(0, 10.), (1, 20.), (2, 30.)]),
+    case02_two_docks: (2, vec![(0, 10.), (1, 10.), (2, 20.)]),
+    case03_three_docks: (3, vec![(0, 10.), (1, 10.), (2, 10.)]),
+}
+
+fn can_schedule_loading_on_limited_docks_impl(docks: usize, expected_departures: Vec<(usize, Timestamp)>) {
+    let policy = create_policy(docks);
+    let tours = (0..3).map(|idx| create_dock_tour(idx, 100., Some((&policy, 10.)), None)).collect();
+    let mut departures = Vec::new();
+
+    let usages = schedule_docks(tours, |route_index, departure| {
+        departures.push((route_index, departure));
+        Some(100.)
+    });
+
+    assert_eq!(departures, expected_departures);
+    assert_eq!(usages.len(), 3);
+    assert!(usages.iter().all(|usage| usage.operation == DockOperation::Loading && usage.dock < docks));
+}
+
+#[test]
+fn can_schedule_unloading_after_arrival() {
+    let policy = create_policy(1);
+    let tours = vec![
+        create_dock_tour(0, 50., Some((&policy, 10.)), Some((&policy, 5.))),
+        create_dock_tour(1, 15., None, Some((&policy, 5.))),
+        create_dock_tour(2, 100., Some((&policy, 10.)), None),
+    ];
+
+    let usages =
+        schedule_docks(tours, |route_index, departure| Some(if route_index == 0 { departure + 40. } else { 100. }));
+
+    assert_eq!(
+        get_usages(usages.as_slice()),
+        vec![
+            (0, 0, DockOperation::Loading, 0., 10.),
+            (2, 0, DockOperation::Loading, 10., 20.),
+            (1, 0, DockOperation::Unloading, 20., 25.),
+            (0, 0, DockOperation::Unloading, 50., 55.),
+        ]
+    );
+}
+
+parameterized_test! {can_check_feasibility_of_dock_schedule, (latest_departure, latest_end, expected), {
+    can_check_feasibility_of_dock_schedule_impl(latest_departure, latest_end, expected);
+}}
+
+can_check_feasibility_of_dock_schedule! {
+    case01_feasible: (20., 100., Some(vec![(0, 10.), (1, 20.)])),
+    case02_late_departure: (15., 100., None),
+    case03_late_unloading: (20., 40., None),
+}
+
+fn can_check_feasibility_of_dock_schedule_impl(
+    latest_departure: Timestamp,
+    latest_end: Timestamp,
+    expected: Option<Vec<(usize, Timestamp)>>,
+) {
+    let policy = create_policy(1);
+    let tours = vec![
+        create_dock_tour(0, 50., Some((&policy, 10.)), None),
+        DockTour {
+            latest_departure,
+            latest_end,
+            ..create_dock_tour(1, 30., Some((&policy, 10.)), Some((&policy, 5.)))
+        },
+    ];
+
+    let result = get_feasible_departures(tours).map(|departures| {
+        let mut departures = departures.into_iter().collect::<Vec<_>>();
+        departures.sort_by_key(|(route_index, _)| *route_index);
+        departures
+    });
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,43 @@
+use super::*;
+
+fn create_policy(split_break: Option<(Duration, Duration)>) -> DrivingHoursPolicy {
+    DrivingHoursPolicy {
+        max_continuous_driving: 20.,
+        break_duration: 5.,
+        split_break,
+        max_daily_driving: 25.,
+        daily_rest: 50.,
+        max_daily_duty: None,
+    }
+}
+
+type RestData = (bool, (f64, f64));
+
+parameterized_test! {can_drive_with_rests, (split_break, continuous_driving, driving, expected), {
+    can_drive_with_rests_impl(split_break, continuous_driving, driving, expected);
+}}
+
+can_drive_with_rests! {
+    case01_no_rests: (None, 0., 10., (0., 10., vec![])),
+    case02_break_on_the_way: (None, 0., 22., (0., 27., vec![(false, (20., 25.))])),
+    case03_break_at_stop: (None, 20., 2., (5., 7., vec![(true, (0., 5.))])),
+    case04_split_break: (Some((2., 3.)), 10., 12., (2., 17., vec![(true, (0., 2.)), (false, (12., 15.))])),
+    case05_no_split_at_start: (Some((2., 3.)), 0., 22., (0., 27., vec![(false, (20., 25.))])),
+    case06_daily_rest: (None, 0., 30., (0., 85., vec![(false, (20., 25.)), (false, (30., 80.))])),
+}
+
+fn can_drive_with_rests_impl(
+    split_break: Option<(Duration, Duration)>,
+    continuous_driving: Duration,
+    driving: Duration,
+    expected: (Timestamp, Timestamp, Vec<RestData>),
+) {
+    let policy = create_policy(split_break);
+    let mut state = DrivingState { continuous_driving, daily_driving: continuous_driving, ..DrivingState::default() };
+    let mut rests = vec![];
+
+    let (departure, arrival) =
+        drive(&policy, &mut state, 0., driving, &mut |at_stop, time| rests.push((at_stop, (time.start, time.end))));
+
+    assert_eq!((departure, arrival, rests), expected);
+}
@@ -0,0 +1,129 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{IdDimension, TimeWindow};
+use vrp_core::models::problem::Single;
+
+const DAY: f64 = SECONDS_IN_DAY;
+
+fn create_policy(min_days_between: usize, patterns: Option<Vec<Vec<usize>>>) -> Arc<PeriodicPolicy> {
+    Arc::new(PeriodicPolicy { min_days_between, patterns })
+}
+
+fn create_visit(index: usize, policy: Arc<PeriodicPolicy>) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_id("job1");
+    single.dimens.set_value("visit", JobVisit { job_id: "job1".to_string(), index, policy });
+
+    Arc::new(single)
+}
+
+parameterized_test! {can_get_day_index_and_weekday, (time, offset, expected), {
+    can_get_day_index_and_weekday_impl(time, offset, expected);
+}}
+
+can_get_day_index_and_weekday! {
+    case01_epoch_start: (0., 0, (0, 3)),
+    case02_next_day: (DAY + 1., 0, (1, 4)),
+    case03_positive_offset: (DAY - 3600., 7200, (1, 4)),
+    case04_negative_offset: (3600., -7200, (-1, 2)),
+    case05_monday: (4. * DAY, 0, (4, 0)),
+}
+
+fn can_get_day_index_and_weekday_impl(time: Timestamp, offset: i64, expected: (i64, usize)) {
+    let day = get_day_index(time, offset);
+
+    assert_eq!((day, get_weekday(day)), expected);
+}
+
+parameterized_test! {can_check_visit_allowed, (min_days_between, patterns, visit, others, expected), {
+    can_check_visit_allowed_impl(min_days_between, patterns, visit, others, expected);
+}}
+
+can_check_visit_allowed! {
+    case01_no_others: (1, None, (0, 4), vec![], true),
+    case02_spaced_after: (1, None, (1, 6), vec![(0, 4)], true),
+    case03_not_spaced_after: (1, None, (1, 5), vec![(0, 4)], false),
+    case04_spaced_before: (1, None, (0, 4), vec![(1, 6)], true),
+    case05_not_spaced_before: (1, None, (0, 5), vec![(1, 6)], false),
+    case06_wrong_order: (0, None, (1, 3), vec![(0, 4)], false),
+    case07_same_day_no_spacing: (0, None, (1, 4), vec![(0, 4)], false),
+    case08_reserves_days_in_between: (1, None, (2, 8), vec![(0, 4)], true),
+    case09_not_reserves_days_in_between: (1, None, (2, 7), vec![(0, 4)], false),
+    case10_ignores_same_index: (1, None, (0, 4), vec![(0, 5)], true),
+
+    case11_matches_pattern: (0, Some(vec![vec![0, 3]]), (1, 7), vec![(0, 4)], true),
+    case12_not_matches_pattern: (0, Some(vec![vec![0, 2]]), (1, 7), vec![(0, 4)], false),
+    case13_matches_second_pattern: (0, Some(vec![vec![0, 2], vec![0, 3]]), (1, 7), vec![(0, 4)], true),
+    case14_mixes_patterns: (0, Some(vec![vec![0, 2], vec![1, 3]]), (1, 7), vec![(0, 4)], false),
+}
+
+fn can_check_visit_allowed_impl(
+    min_days_between: usize,
+    patterns: Option<Vec<Vec<usize>>>,
+    visit: (usize, i64),
+    others: Vec<(usize, i64)>,
+    expected: bool,
+) {
+    let policy = create_policy(min_days_between, patterns);
+
+    let result = is_visit_allowed(policy.as_ref(), visit, others.as_slice());
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_visit_activity, (visit_start, expected), {
+    can_evaluate_visit_activity_impl(visit_start, expected);
+}}
+
+can_evaluate_visit_activity! {
+    case01_same_day: (10., Some(ActivityConstraintViolation { code: 1, stopped: false })),
+    case02_next_day: (DAY + 10., Some(ActivityConstraintViolation { code: 1, stopped: false })),
+    case03_two_days_later: (2. * DAY + 10., None),
+}
+
+fn can_evaluate_visit_activity_impl(visit_start: Timestamp, expected: Option<ActivityConstraintViolation>) {
+    let fleet = test_fleet();
+    let policy = create_policy(1, None);
+    let mut solution_ctx = create_solution_context_for_fleet(&fleet);
+    solution_ctx.routes.push(RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(
+            &fleet,
+            "v1",
+            vec![create_activity_with_job_at_location(create_visit(0, policy.clone()), 1)],
+        )),
+        Arc::new(RouteState::default()),
+    ));
+    let module = PeriodicModule::new(0, 1, 2);
+    module.accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx.routes.first().unwrap();
+    let mut target = create_activity_with_job_at_location(create_visit(1, policy), 2);
+    target.place.time = TimeWindow::new(visit_start, visit_start + 100.);
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route.tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(2),
+    };
+
+    let result = module
+        .get_constraints()
+        .filter_map(|constraint| match constraint {
+            ConstraintVariant::HardActivity(constraint) => constraint.evaluate_activity(route_ctx, &activity_ctx),
+            _ => None,
+        })
+        .next();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_reject_merge_of_visits() {
+    let module = PeriodicModule::new(0, 1, 2);
+    let visit = Job::Single(create_visit(0, create_policy(0, None)));
+    let job = Job::Single(create_single("job2"));
+
+    assert_eq!(module.merge(job.clone(), job.clone()).map(|_| ()), Ok(()));
+    assert_eq!(module.merge(job.clone(), visit.clone()).map(|_| ()), Err(1));
+    assert_eq!(module.merge(visit, job).map(|_| ()), Err(1));
+}
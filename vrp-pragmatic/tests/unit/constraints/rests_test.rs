@@ -0,0 +1,247 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::problem::{Fleet, Vehicle};
+
+fn create_policy() -> DrivingHoursPolicy {
+    DrivingHoursPolicy {
+        max_continuous_driving: 50.,
+        break_duration: 10.,
+        split_break: None,
+        max_daily_driving: 100.,
+        daily_rest: 1000.,
+        max_daily_duty: None,
+    }
+}
+
+fn create_links(links: Vec<(f64, f64, Option<Location>)>) -> Vec<VehicleLink> {
+    links
+        .into_iter()
+        .map(|(start, end, location)| VehicleLink { location, time: TimeWindow::new(start, end) })
+        .collect()
+}
+
+fn create_fleet(policy: Option<DrivingHoursPolicy>, links: Vec<VehicleLink>) -> Fleet {
+    let mut vehicle = test_vehicle("v1");
+    if let Some(policy) = policy {
+        vehicle.dimens.set_value("driving_hours", policy);
+    }
+    vehicle.details.iter_mut().for_each(|detail| detail.links = links.clone());
+
+    create_fleet_with_vehicle(vehicle)
+}
+
+fn create_fleet_with_vehicle(vehicle: Vehicle) -> Fleet {
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+fn create_route_ctx(fleet: &Fleet, locations: Vec<Location>) -> RouteContext {
+    let activities = locations
+        .into_iter()
+        .enumerate()
+        .map(|(idx, location)| {
+            create_activity_with_job_at_location(create_single(&format!("job{}", idx + 1)), location)
+        })
+        .collect();
+
+    RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(fleet, "v1", activities)),
+        Arc::new(RouteState::default()),
+    )
+}
+
+fn evaluate_target(
+    module: &RestModule,
+    route_ctx: &RouteContext,
+    time_end: Timestamp,
+) -> Option<ActivityConstraintViolation> {
+    let mut target = create_activity_at_location(2);
+    target.place.time = TimeWindow::new(0., time_end);
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route.tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(2),
+    };
+
+    module
+        .get_constraints()
+        .filter_map(|constraint| match constraint {
+            ConstraintVariant::HardActivity(constraint) => constraint.evaluate_activity(route_ctx, &activity_ctx),
+            _ => None,
+        })
+        .next()
+}
+
+type LinkData = (Location, (f64, f64));
+
+parameterized_test! {can_travel_with_links, (links, expected), {
+    can_travel_with_links_impl(create_links(links), expected);
+}}
+
+can_travel_with_links! {
+    case01_no_link_needed: (vec![(60., 100., None)], (0., 42., 42., true, vec![])),
+    case02_link_at_stop: (vec![(30., 100., None)], (100., 142., 42., true, vec![(0, (0., 100.))])),
+    case03_link_at_location: (vec![(50., 100., Some(5))], (0., 142., 84., true, vec![(5, (42., 100.))])),
+    case04_link_at_location_too_late: (vec![(30., 100., Some(5))], (0., 142., 84., false, vec![(5, (42., 100.))])),
+    case05_two_links_at_stop: (
+        vec![(30., 100., None), (130., 200., None)],
+        (200., 242., 42., true, vec![(0, (0., 100.)), (0, (100., 200.))])
+    ),
+    case06_past_link_ignored: (vec![(-100., -50., None)], (0., 42., 42., true, vec![])),
+}
+
+fn can_travel_with_links_impl(
+    links: Vec<VehicleLink>,
+    expected: (Timestamp, Timestamp, Distance, bool, Vec<LinkData>),
+) {
+    let (transport, activity) = get_costs();
+    let fleet = create_fleet(None, links);
+    let route = create_route_with_activities(&fleet, "v1", vec![]);
+    let mut target = create_activity_at_location(1);
+    target.place.duration = 10.;
+    let scheduler = RestScheduler::new(&route, activity.as_ref(), transport.as_ref()).unwrap();
+    let mut scheduled = vec![];
+
+    let leg = scheduler.travel(0, 0, &mut DrivingState::default(), 0., &target, &mut |_| {}, &mut |link| {
+        scheduled.push((link.location, (link.schedule.arrival, link.schedule.departure)))
+    });
+
+    assert_eq!((leg.departure, leg.arrival, leg.distance, leg.is_in_time, scheduled), expected);
+}
+
+parameterized_test! {can_evaluate_activity_with_rests, (time_end, expected), {
+    can_evaluate_activity_with_rests_impl(time_end, expected);
+}}
+
+can_evaluate_activity_with_rests! {
+    case01_can_arrive_after_break: (94., None),
+    case02_cannot_arrive_after_break: (93., Some(ActivityConstraintViolation { code: 1, stopped: false })),
+}
+
+fn can_evaluate_activity_with_rests_impl(time_end: Timestamp, expected: Option<ActivityConstraintViolation>) {
+    let (transport, activity) = get_costs();
+    let fleet = create_fleet(Some(create_policy()), vec![]);
+    let mut route_ctx = create_route_ctx(&fleet, vec![1]);
+    let module = RestModule::new(activity, transport, 1, 2);
+    module.accept_route_state(&mut route_ctx);
+
+    let result = evaluate_target(&module, &route_ctx, time_end);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_activity_with_links, (time_end, expected), {
+    can_evaluate_activity_with_links_impl(time_end, expected);
+}}
+
+can_evaluate_activity_with_links! {
+    case01_can_arrive_after_link: (142., None),
+    case02_cannot_arrive_after_link: (141., Some(ActivityConstraintViolation { code: 2, stopped: false })),
+}
+
+fn can_evaluate_activity_with_links_impl(time_end: Timestamp, expected: Option<ActivityConstraintViolation>) {
+    let (transport, activity) = get_costs();
+    let fleet = create_fleet(None, create_links(vec![(50., 100., None)]));
+    let mut route_ctx = create_route_ctx(&fleet, vec![1]);
+    let module = RestModule::new(activity, transport, 1, 2);
+    module.accept_route_state(&mut route_ctx);
+
+    let result = evaluate_target(&module, &route_ctx, time_end);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_schedule_route_with_links() {
+    let (transport, activity) = get_costs();
+    let fleet = create_fleet(None, create_links(vec![(50., 100., None)]));
+    let mut route_ctx = create_route_ctx(&fleet, vec![1, 2]);
+    let module = RestModule::new(activity, transport, 1, 2);
+
+    module.accept_route_state(&mut route_ctx);
+
+    let route = route_ctx.route.as_ref();
+    assert_eq!(
+        route.tour.links().cloned().collect::<Vec<_>>(),
+        vec![TourLink { leg_index: 1, location: 1, schedule: Schedule::new(42., 100.) }]
+    );
+    assert_eq!(route.tour.get(2).unwrap().schedule, Schedule::new(142., 142.));
+}
+
+#[test]
+fn can_schedule_route_with_rests_and_links() {
+    let (transport, activity) = get_costs();
+    let fleet = create_fleet(Some(create_policy()), create_links(vec![(50., 100., None)]));
+    let mut route_ctx = create_route_ctx(&fleet, vec![1, 2]);
+    let module = RestModule::new(activity.clone(), transport.clone(), 1, 2);
+
+    module.accept_route_state(&mut route_ctx);
+
+    let route = route_ctx.route.as_ref();
+    // NOTE link time is counted as a break, but not as a daily rest, so daily rest is needed on the way back
+    let rests = get_driving_rests(route, activity.as_ref(), transport.as_ref());
+    assert_eq!(
+        rests.iter().map(|rest| (rest.leg_index, rest.time.start, rest.time.end)).collect::<Vec<_>>(),
+        vec![(2, 150., 160.), (2, 168., 1168.)]
+    );
+    assert_eq!(route.tour.links().count(), 1);
+    assert_eq!(route.tour.get(2).unwrap().schedule, Schedule::new(142., 142.));
+}
+
+#[test]
+fn can_update_latest_arrival_with_rests() {
+    let (transport, activity) = get_costs();
+    let fleet = create_fleet(Some(create_policy()), vec![]);
+    let mut route_ctx = create_route_ctx(&fleet, vec![1, 2]);
+    route_ctx.as_mut().0.tour.get_mut(2).unwrap().place.time = TimeWindow::new(0., 200.);
+    let module = RestModule::new(activity, transport, 1, 2);
+
+    module.accept_route_state(&mut route_ctx);
+
+    let get_latest_arrival = |index: usize| {
+        route_ctx.state.get_activity_state::<f64>(LATEST_ARRIVAL_KEY, route_ctx.route.tour.get(index).unwrap()).cloned()
+    };
+    // NOTE break is needed on the way to the second job, so it has to be taken into account
+    assert_eq!(get_latest_arrival(1), Some(148.));
+    assert_eq!(get_latest_arrival(2), Some(200.));
+}
+
+type StateData = (Duration, Duration, Duration, bool);
+
+fn create_state(data: StateData) -> DrivingState {
+    let (continuous_driving, daily_driving, daily_duty, has_first_part) = data;
+    DrivingState { continuous_driving, daily_driving, daily_duty, has_first_part }
+}
+
+parameterized_test! {can_estimate_rest_penalty, (old, new, slacks, expected), {
+    can_estimate_rest_penalty_impl(create_state(old), create_state(new), slacks, expected);
+}}
+
+can_estimate_rest_penalty! {
+    case01_same_state: ((20., 20., 20., false), (20., 20., 20., false), (30., 80.), 0.),
+    case02_within_slacks: ((20., 20., 20., false), (40., 40., 40., false), (30., 80.), 0.),
+    case03_exceeds_continuous_slack: ((20., 20., 20., false), (40., 40., 40., false), (10., 80.), 10.),
+    case04_exceeds_daily_slack: ((20., 20., 20., false), (40., 40., 40., false), (30., 10.), 1000.),
+    case05_exceeds_both_slacks: ((20., 20., 20., false), (40., 40., 40., false), (10., 10.), 1010.),
+    case06_loses_first_part: ((20., 20., 20., true), (20., 20., 20., false), (30., 80.), 10.),
+}
+
+fn can_estimate_rest_penalty_impl(
+    old: DrivingState,
+    new: DrivingState,
+    slacks: (Duration, Duration),
+    expected: Duration,
+) {
+    let (continuous_slack, daily_slack) = slacks;
+    let checkpoint = RestCheckpoint { state: old, ready: 0., continuous_slack, daily_slack };
+
+    let result = get_rest_penalty(&create_policy(), &new, 0., &checkpoint);
+
+    assert_eq!(result, expected);
+}
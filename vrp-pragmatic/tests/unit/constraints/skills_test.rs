@@ -1,4 +1,4 @@
-use crate::constraints::{JobSkills, SkillsModule};
+use crate::constraints::{JobPreferredSkills, JobSkills, PreferredSkillsModule, SkillsModule};
 use crate::extensions::create_typed_actor_groups;
 use crate::helpers::*;
 use hashbrown::HashSet;
@@ -18,6 +18,7 @@ fn create_job_with_skills(all_of: Option<Vec<&str>>, one_of: Option<Vec<&str>>,
             all_of: all_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
             one_of: one_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
             none_of: none_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
+            preferred: None,
         },
     );
 
@@ -129,3 +130,58 @@ fn can_merge_skills_impl(source: Job, candidate: Job, expected: Result<(), i32>)
 
     assert_eq!(result, expected);
 }
+
+parameterized_test! {can_estimate_preferred_skills, (preferred, vehicle_skills, driver_skills, expected), {
+    can_estimate_preferred_skills_impl(preferred, vehicle_skills, driver_skills, expected);
+}}
+
+can_estimate_preferred_skills! {
+    case01_no_preferences: (None, Some(vec!["s1"]), None, 0.),
+    case02_vehicle_has_skill: (Some(vec!["s1", "s2"]), Some(vec!["s2"]), None, 0.),
+    case03_driver_has_skill: (Some(vec!["s1"]), None, Some(vec!["s1"]), 0.),
+    case04_no_skills: (Some(vec!["s1"]), None, None, 10.),
+    case05_other_skills: (Some(vec!["s1"]), Some(vec!["s2"]), Some(vec!["s3"]), 10.),
+}
+
+fn can_estimate_preferred_skills_impl(
+    preferred: Option<Vec<&str>>,
+    vehicle_skills: Option<Vec<&str>>,
+    driver_skills: Option<Vec<&str>>,
+    expected: f64,
+) {
+    let mut driver = test_driver();
+    if let Some(skills) = driver_skills {
+        driver.dimens.set_value("skills", HashSet::<String>::from_iter(skills.iter().map(|s| s.to_string())));
+    }
+    let fleet = Fleet::new(
+        vec![Arc::new(driver)],
+        vec![Arc::new(create_vehicle_with_skills(vehicle_skills))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&fleet, "v1", vec![])),
+        Arc::new(RouteState::default()),
+    );
+    let mut single = create_single_with_location(None);
+    single.dimens.set_value(
+        "skills",
+        JobSkills {
+            all_of: None,
+            one_of: None,
+            none_of: None,
+            preferred: preferred.map(|skills| JobPreferredSkills {
+                one_of: skills.iter().map(|s| s.to_string()).collect(),
+                weight: 10.,
+            }),
+        },
+    );
+    let (module, _) = PreferredSkillsModule::new_objective();
+
+    let actual = ConstraintPipeline::default().add_module(module).evaluate_soft_route(
+        &create_solution_context_for_fleet(&fleet),
+        &route_ctx,
+        &Job::Single(Arc::new(single)),
+    );
+
+    assert_eq!(actual, expected)
+}
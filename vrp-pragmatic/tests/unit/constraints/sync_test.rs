@@ -0,0 +1,132 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{IdDimension, TimeSpan};
+use vrp_core::models::problem::Fleet;
+
+const VIOLATION_CODE: i32 = 1;
+const STATE_KEY: i32 = 2;
+
+fn create_sync_single(job_id: &str, index: usize, time: TimeWindow) -> Arc<Single> {
+    let policy = Arc::new(SyncPolicy { size: 2, max_offset: 10. });
+
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.places.first_mut().unwrap().times = vec![TimeSpan::Window(time)];
+    single.dimens.set_id(job_id);
+    single.dimens.set_value("sync", JobSync { job_id: job_id.to_string(), index, policy });
+
+    Arc::new(single)
+}
+
+fn create_fleet() -> Fleet {
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(test_vehicle("v1")), Arc::new(test_vehicle("v2"))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+parameterized_test! {can_reject_task_of_the_same_job_in_route, (job_id, index, expected), {
+    can_reject_task_of_the_same_job_in_route_impl(job_id, index, expected);
+}}
+
+can_reject_task_of_the_same_job_in_route! {
+    case01_same_job_other_task: ("job1", 1, Some(RouteConstraintViolation { code: VIOLATION_CODE })),
+    case02_other_job: ("job2", 1, None),
+}
+
+fn can_reject_task_of_the_same_job_in_route_impl(
+    job_id: &str,
+    index: usize,
+    expected: Option<RouteConstraintViolation>,
+) {
+    let (transport, activity) = get_costs();
+    let fleet = create_fleet();
+    let solution_ctx = create_solution_context_for_fleet(&fleet);
+    let assigned = create_sync_single("job1", 0, DEFAULT_ACTIVITY_TIME_WINDOW);
+    let route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(
+            &fleet,
+            "v1",
+            vec![create_activity_with_job_at_location(assigned, DEFAULT_JOB_LOCATION)],
+        )),
+        Arc::new(RouteState::default()),
+    );
+    let module = SyncModule::new(activity, transport, VIOLATION_CODE, STATE_KEY);
+    let job = Job::Single(create_sync_single(job_id, index, DEFAULT_ACTIVITY_TIME_WINDOW));
+
+    let result = module
+        .get_constraints()
+        .filter_map(|constraint| match constraint {
+            ConstraintVariant::HardRoute(constraint) => constraint.evaluate_job(&solution_ctx, &route_ctx, &job),
+            _ => None,
+        })
+        .next();
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_service_start_within_offset, (other_start, time, expected), {
+    can_check_service_start_within_offset_impl(other_start, TimeWindow::new(time.0, time.1), expected);
+}}
+
+can_check_service_start_within_offset! {
+    case01_can_wait_for_other: (500., (0., 1000.), None),
+    case02_cannot_wait_for_other: (500., (0., 100.), Some(ActivityConstraintViolation { code: VIOLATION_CODE, stopped: false })),
+    case03_within_offset: (105., (0., 100.), None),
+    case04_other_is_too_early: (0., (100., 200.), Some(ActivityConstraintViolation { code: VIOLATION_CODE, stopped: false })),
+}
+
+fn can_check_service_start_within_offset_impl(
+    other_start: Timestamp,
+    time: TimeWindow,
+    expected: Option<ActivityConstraintViolation>,
+) {
+    let (transport, activity) = get_costs();
+    let fleet = create_fleet();
+    let mut state = RouteState::default();
+    let times: SyncTimes = vec![("job1".to_string(), vec![(0, other_start)])].into_iter().collect();
+    state.put_route_state(STATE_KEY, times);
+    let route_ctx =
+        RouteContext::new_with_state(Arc::new(create_route_with_activities(&fleet, "v2", vec![])), Arc::new(state));
+    let module = SyncModule::new(activity, transport, VIOLATION_CODE, STATE_KEY);
+    let target = Activity {
+        place: vrp_core::models::solution::Place {
+            idx: 0,
+            time_idx: 0,
+            location: DEFAULT_JOB_LOCATION,
+            duration: 0.,
+            time: time.clone(),
+        },
+        ..create_activity_with_job_at_location(create_sync_single("job1", 1, time), DEFAULT_JOB_LOCATION)
+    };
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route.tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(1),
+    };
+
+    let result = module
+        .get_constraints()
+        .filter_map(|constraint| match constraint {
+            ConstraintVariant::HardActivity(constraint) => constraint.evaluate_activity(&route_ctx, &activity_ctx),
+            _ => None,
+        })
+        .next();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_reject_merge_of_sync_jobs() {
+    let (transport, activity) = get_costs();
+    let module = SyncModule::new(activity, transport, VIOLATION_CODE, STATE_KEY);
+    let sync = Job::Single(create_sync_single("job1", 0, DEFAULT_ACTIVITY_TIME_WINDOW));
+    let job = Job::Single(create_single("job2"));
+
+    assert_eq!(module.merge(job.clone(), job.clone()).map(|_| ()), Ok(()));
+    assert_eq!(module.merge(job.clone(), sync.clone()).map(|_| ()), Err(VIOLATION_CODE));
+    assert_eq!(module.merge(sync, job).map(|_| ()), Err(VIOLATION_CODE));
+}
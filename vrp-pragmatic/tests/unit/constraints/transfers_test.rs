@@ -0,0 +1,127 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{IdDimension, Schedule};
+use vrp_core::models::problem::Fleet;
+
+const VIOLATION_CODE: i32 = 1;
+const STATE_KEY: i32 = 2;
+
+fn create_transfer_job(job_id: &str, echelon: TransferEchelon) -> Job {
+    let policy = Arc::new(TransferPolicy {
+        hub_id: "hub1".to_string(),
+        first_echelon: vec!["v1".to_string()].into_iter().collect(),
+        second_echelon: vec!["v2".to_string()].into_iter().collect(),
+    });
+
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_id(job_id);
+    single.dimens.set_value("transfer", JobTransfer { job_id: job_id.to_string(), echelon, policy });
+
+    Job::Single(Arc::new(single))
+}
+
+parameterized_test! {can_check_echelon_vehicle_type, (echelon, vehicle_id, expected), {
+    can_check_echelon_vehicle_type_impl(echelon, vehicle_id, expected);
+}}
+
+can_check_echelon_vehicle_type! {
+    case01_first_echelon_vehicle: (TransferEchelon::First, "v1", None),
+    case02_first_echelon_wrong_vehicle: (TransferEchelon::First, "v2", Some(RouteConstraintViolation { code: VIOLATION_CODE })),
+    case03_second_echelon_vehicle: (TransferEchelon::Second, "v2", None),
+    case04_second_echelon_wrong_vehicle: (TransferEchelon::Second, "v1", Some(RouteConstraintViolation { code: VIOLATION_CODE })),
+}
+
+fn can_check_echelon_vehicle_type_impl(
+    echelon: TransferEchelon,
+    vehicle_id: &str,
+    expected: Option<RouteConstraintViolation>,
+) {
+    let (transport, activity) = get_costs();
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(test_vehicle("v1")), Arc::new(test_vehicle("v2"))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let solution_ctx = create_solution_context_for_fleet(&fleet);
+    let route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&fleet, vehicle_id, vec![])),
+        Arc::new(RouteState::default()),
+    );
+    let module = TransferModule::new(activity, transport, VIOLATION_CODE, STATE_KEY);
+    let job = create_transfer_job("job1", echelon);
+
+    let result = module
+        .get_constraints()
+        .filter_map(|constraint| match constraint {
+            ConstraintVariant::HardRoute(constraint) => constraint.evaluate_job(&solution_ctx, &route_ctx, &job),
+            _ => None,
+        })
+        .next();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_reject_merge_of_transfer_jobs() {
+    let (transport, activity) = get_costs();
+    let module = TransferModule::new(activity, transport, VIOLATION_CODE, STATE_KEY);
+    let transfer = create_transfer_job("job1", TransferEchelon::Second);
+    let job = Job::Single(create_single("job2"));
+
+    assert_eq!(module.merge(job.clone(), job.clone()).map(|_| ()), Ok(()));
+    assert_eq!(module.merge(job.clone(), transfer.clone()).map(|_| ()), Err(VIOLATION_CODE));
+    assert_eq!(module.merge(transfer, job).map(|_| ()), Err(VIOLATION_CODE));
+}
+
+parameterized_test! {can_check_transfer_deadline_of_first_echelon_route, (deadline, index, expected), {
+    can_check_transfer_deadline_of_first_echelon_route_impl(deadline, index, expected);
+}}
+
+can_check_transfer_deadline_of_first_echelon_route! {
+    case01_before_transfer_in_time: (100., 0, None),
+    case02_before_transfer_late: (60., 0, Some(ActivityConstraintViolation { code: VIOLATION_CODE, stopped: false })),
+    case03_after_transfer: (60., 1, None),
+}
+
+fn can_check_transfer_deadline_of_first_echelon_route_impl(
+    deadline: Timestamp,
+    index: usize,
+    expected: Option<ActivityConstraintViolation>,
+) {
+    let (transport, activity) = get_costs();
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(test_vehicle("v1")), Arc::new(test_vehicle("v2"))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let transfer = create_transfer_job("job1", TransferEchelon::First).to_single().clone();
+    let mut transfer_activity = create_activity_with_job_at_location(transfer, 5);
+    transfer_activity.schedule = Schedule::new(42., 42.);
+    let mut route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&fleet, "v1", vec![transfer_activity])),
+        Arc::new(RouteState::default()),
+    );
+    let times: TransferTimes = vec![("job1".to_string(), (Some(42.), Some(deadline)))].into_iter().collect();
+    route_ctx.state_mut().put_route_state(STATE_KEY, times.clone());
+    let module = TransferModule::new(activity, transport, VIOLATION_CODE, STATE_KEY);
+    module.update_latest_arrivals(&mut route_ctx, &times);
+    let target = create_activity_at_location(10);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = module
+        .get_constraints()
+        .filter_map(|constraint| match constraint {
+            ConstraintVariant::HardActivity(constraint) => constraint.evaluate_activity(&route_ctx, &activity_ctx),
+            _ => None,
+        })
+        .next();
+
+    assert_eq!(result, expected);
+}
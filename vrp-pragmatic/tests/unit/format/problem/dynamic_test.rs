@@ -0,0 +1,118 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_tour_state(vehicle_id: &str, completed: Vec<(&str, Option<&str>)>) -> TourState {
+    TourState {
+        vehicle_id: vehicle_id.to_string(),
+        shift_index: None,
+        location: (1., 0.).to_loc(),
+        time: format_time(10.),
+        load: None,
+        completed: Some(completed.into_iter().map(|(job_id, tag)| create_completed_task(job_id, tag)).collect()),
+    }
+}
+
+fn create_problem(tours: Vec<TourState>, relations: Option<Vec<Relation>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_pickup_delivery_job("job2", (1., 0.), (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+            ],
+            relations,
+            tours: Some(tours),
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_job_tasks_count(problem: &Problem, job_id: &str) -> Option<(usize, usize)> {
+    problem.plan.jobs.iter().find(|job| job.id == job_id).map(|job| {
+        (job.pickups.as_ref().map_or(0, |tasks| tasks.len()), job.deliveries.as_ref().map_or(0, |tasks| tasks.len()))
+    })
+}
+
+fn get_relations(problem: &Problem) -> Vec<(String, Vec<String>)> {
+    problem
+        .plan
+        .relations
+        .iter()
+        .flatten()
+        .map(|relation| (relation.vehicle_id.clone(), relation.jobs.clone()))
+        .collect()
+}
+
+#[test]
+fn can_remove_completed_tasks_and_lock_partially_completed_jobs() {
+    let problem =
+        create_problem(vec![create_tour_state("my_vehicle_1", vec![("job1", None), ("job2", Some("p1"))])], None);
+
+    let problem = apply_tour_states(problem);
+
+    assert_eq!(get_job_tasks_count(&problem, "job1"), None);
+    assert_eq!(get_job_tasks_count(&problem, "job2"), Some((0, 1)));
+    assert_eq!(get_job_tasks_count(&problem, "job3"), Some((0, 1)));
+    assert_eq!(get_relations(&problem), vec![("my_vehicle_1".to_string(), vec!["job2".to_string()])]);
+    assert!(problem.plan.tours.iter().flatten().all(|tour| tour.completed.is_none()));
+
+    let problem = apply_tour_states(problem);
+
+    assert_eq!(get_job_tasks_count(&problem, "job2"), Some((0, 1)));
+    assert_eq!(get_relations(&problem).len(), 1);
+}
+
+parameterized_test! {can_remove_completed_jobs_from_relations, (relation_jobs, expected), {
+    can_remove_completed_jobs_from_relations_impl(relation_jobs, expected);
+}}
+
+can_remove_completed_jobs_from_relations! {
+    case01_partially_completed: (vec!["departure", "job1", "job3"], Some(vec!["departure", "job3"])),
+    case02_fully_completed: (vec!["departure", "job1"], None),
+}
+
+fn can_remove_completed_jobs_from_relations_impl(relation_jobs: Vec<&str>, expected: Option<Vec<&str>>) {
+    let relation = Relation {
+        type_field: RelationType::Strict,
+        jobs: relation_jobs.into_iter().map(|job_id| job_id.to_string()).collect(),
+        vehicle_id: "my_vehicle_1".to_string(),
+        shift_index: None,
+    };
+    let problem = create_problem(vec![create_tour_state("my_vehicle_1", vec![("job1", None)])], Some(vec![relation]));
+
+    let problem = apply_tour_states(problem);
+
+    assert_eq!(
+        get_relations(&problem),
+        expected
+            .map(|jobs| vec![("my_vehicle_1".to_string(), jobs.into_iter().map(|job| job.to_string()).collect())])
+            .unwrap_or_default()
+    );
+}
+
+parameterized_test! {can_remove_completed_task_by_tag_or_index, (tag, task_index, expected), {
+    can_remove_completed_task_by_tag_or_index_impl(tag, task_index, expected);
+}}
+
+can_remove_completed_task_by_tag_or_index! {
+    case01_pickup_by_tag: (Some("p1"), None, Some((0, 1))),
+    case02_delivery_by_tag: (Some("d1"), None, Some((1, 0))),
+    case03_pickup_by_index: (None, Some(0), Some((0, 1))),
+    case04_delivery_by_index: (None, Some(1), Some((1, 0))),
+}
+
+fn can_remove_completed_task_by_tag_or_index_impl(
+    tag: Option<&str>,
+    task_index: Option<usize>,
+    expected: Option<(usize, usize)>,
+) {
+    let mut tour = create_tour_state("my_vehicle_1", vec![]);
+    tour.completed = Some(vec![CompletedTask { task_index, ..create_completed_task("job2", tag) }]);
+    let problem = create_problem(vec![tour], None);
+
+    let problem = apply_tour_states(problem);
+
+    assert_eq!(get_job_tasks_count(&problem, "job2"), expected);
+}
@@ -15,6 +15,8 @@ fn matrix(profile: Option<&str>, timestamp: Option<f64>, fill_value: i64, size:
         travel_times: vec![fill_value; size],
         distances: vec![fill_value; size],
         error_codes: None,
+        toll_costs: None,
+        travel_time_variances: None,
     }
 }
 
@@ -25,6 +27,8 @@ fn wrong_matrix(profile: Option<&str>, timestamp: Option<String>) -> Matrix {
         travel_times: vec![1; 4],
         distances: vec![2; 3],
         error_codes: None,
+        toll_costs: None,
+        travel_time_variances: None,
     }
 }
 
@@ -32,7 +36,11 @@ fn create_problem(profiles: &[&str]) -> Problem {
     Problem {
         fleet: Fleet {
             vehicles: vec![],
-            profiles: profiles.iter().map(|p| MatrixProfile { name: p.to_string(), speed: None }).collect(),
+            drivers: None,
+            profiles: profiles
+                .iter()
+                .map(|p| MatrixProfile { name: p.to_string(), speed: None, service_level: None })
+                .collect(),
         },
         ..create_empty_problem()
     }
@@ -162,7 +170,7 @@ fn can_create_transport_costs_positive_cases_impl(
             actor: Arc::new(Actor {
                 vehicle: Arc::new(Vehicle { profile: CoreProfile::new(profile_idx, None), ..test_vehicle("v1") }),
                 driver: Arc::new(test_driver()),
-                detail: ActorDetail { start: None, end: None, time: TimeWindow::new(0., 1.) },
+                detail: ActorDetail { start: None, end: None, time: TimeWindow::new(0., 1.), links: vec![] },
             }),
             tour: Default::default(),
         };
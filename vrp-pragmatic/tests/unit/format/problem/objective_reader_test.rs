@@ -14,13 +14,27 @@ fn create_problem_props() -> ProblemProperties {
         has_multi_dimen_capacity: false,
         has_breaks: false,
         has_skills: false,
+        has_preferred_skills: false,
         has_unreachable_locations: false,
         has_dispatch: false,
         has_reloads: false,
         has_order: false,
         has_group: false,
         has_compatibility: false,
+        has_compartments: false,
+        has_ride_time: false,
         has_tour_size_limits: false,
+        has_backhauls: false,
+        has_driving_hours: false,
+        has_linked_shifts: false,
+        has_periodic_jobs: false,
+        has_batteries: false,
+        has_transfers: false,
+        has_sync_jobs: false,
+        has_depots: false,
+        has_service_durations: false,
+        has_load_costs: false,
+        utc_offset: 0,
         max_job_value: None,
         max_area_value: None,
     }
@@ -39,7 +53,7 @@ fn can_define_proper_place_for_value_objective_by_default() {
     let mut constraint = ConstraintPipeline::default();
     let props = ProblemProperties { max_job_value: Some(1.), ..create_problem_props() };
 
-    let objective_cost = create_objective(&problem, &mut constraint, &props);
+    let objective_cost = create_objective(&problem, &mut constraint, &props, &[]);
     let objectives = objective_cost.objectives().collect::<Vec<_>>();
 
     assert_eq!(objectives[0].fitness(&create_solution_with_state_value(TOTAL_VALUE_KEY, 1234.)), 1234.);
@@ -51,7 +65,7 @@ fn can_define_proper_place_for_order_objective_by_default() {
     let mut constraint = ConstraintPipeline::default();
     let props = ProblemProperties { has_order: true, ..create_problem_props() };
 
-    let objective_cost = create_objective(&problem, &mut constraint, &props);
+    let objective_cost = create_objective(&problem, &mut constraint, &props, &[]);
     let objectives = objective_cost.objectives().collect::<Vec<_>>();
 
     assert_eq!(objectives[1].fitness(&create_solution_with_state_value(TOUR_ORDER_KEY, 1234_usize)), 1234.);
@@ -67,7 +81,7 @@ fn can_define_proper_places_for_mixed_priority_and_order_objectives_by_default()
 
     let props = ProblemProperties { max_job_value: Some(1.), has_order: true, ..create_problem_props() };
 
-    let objective_cost = create_objective(&problem, &mut constraint, &props);
+    let objective_cost = create_objective(&problem, &mut constraint, &props, &[]);
     let objectives = objective_cost.objectives().collect::<Vec<_>>();
 
     assert_eq!(objectives[0].fitness(&insertion_ctx), 123.);
@@ -78,6 +78,8 @@ fn can_read_complex_problem() {
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 100.0,
                             tag: Some("my_delivery".to_string()),
+                            lateness: None,
+                            durations: None,
                         }],
                         demand: Some(vec![0, 1]),
                         order: None,
@@ -95,6 +97,8 @@ fn can_read_complex_problem() {
                             location: (52.48300, 13.4420).to_loc(),
                             duration: 110.0,
                             tag: None,
+                            lateness: None,
+                            durations: None,
                         }],
                         demand: Some(vec![2]),
                         order: None,
@@ -108,6 +112,8 @@ fn can_read_complex_problem() {
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 120.0,
                             tag: None,
+                            lateness: None,
+                            durations: None,
                         }],
                         demand: Some(vec![2]),
                         order: None,
@@ -124,6 +130,8 @@ fn can_read_complex_problem() {
                             location: (52.48321, 13.4438).to_loc(),
                             duration: 90.0,
                             tag: None,
+                            lateness: None,
+                            durations: None,
                         }],
                         demand: Some(vec![3]),
                         order: None,
@@ -139,17 +147,17 @@ fn can_read_complex_problem() {
                 type_id: "my_vehicle".to_string(),
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 profile: create_default_vehicle_profile(),
-                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2. },
+                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2., full_load_distance: None },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
                         earliest: "1970-01-01T00:00:00Z".to_string(),
                         latest: None,
-                        location: (52.4862, 13.45148).to_loc(),
+                        location: Some((52.4862, 13.45148).to_loc()),
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: "1970-01-01T00:01:40Z".to_string(),
-                        location: (52.4862, 13.45148).to_loc(),
+                        location: Some((52.4862, 13.45148).to_loc()),
                     }),
                     dispatch: None,
                     breaks: Some(vec![VehicleBreak::Optional {
@@ -165,6 +173,7 @@ fn can_read_complex_problem() {
                         policy: None,
                     }]),
                     reloads: None,
+                    charging_stations: None,
                 }],
                 capacity: vec![10, 1],
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
@@ -173,8 +182,14 @@ fn can_read_complex_problem() {
                     shift_time: Some(100.),
                     tour_size: Some(3),
                     areas: None,
+                    driving_hours: None,
                 }),
+                compartments: None,
+                battery: None,
+                emissions: None,
+                backhauls: None,
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         objectives: None,
@@ -185,6 +200,8 @@ fn can_read_complex_problem() {
         travel_times: vec![1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
         distances: vec![2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2],
         error_codes: Option::None,
+        toll_costs: None,
+        travel_time_variances: None,
     };
 
     let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
@@ -297,11 +314,12 @@ fn can_create_approximation_matrices() {
         },
         fleet: Fleet {
             vehicles: vec![],
+            drivers: None,
             profiles: vec![
-                MatrixProfile { name: "car1".to_string(), speed: Some(8.) },
-                MatrixProfile { name: "car2".to_string(), speed: Some(10.) },
-                MatrixProfile { name: "car3".to_string(), speed: Some(5.) },
-                MatrixProfile { name: "car4".to_string(), speed: None },
+                MatrixProfile { name: "car1".to_string(), speed: Some(8.), service_level: None },
+                MatrixProfile { name: "car2".to_string(), speed: Some(10.), service_level: None },
+                MatrixProfile { name: "car3".to_string(), speed: Some(5.), service_level: None },
+                MatrixProfile { name: "car4".to_string(), speed: None, service_level: None },
             ],
         },
         ..create_empty_problem()
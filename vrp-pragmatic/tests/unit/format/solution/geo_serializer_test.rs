@@ -18,6 +18,7 @@ fn can_create_geo_json_from_solution() {
         },
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("my_vehicle")],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -77,6 +78,9 @@ fn can_create_geo_json_for_cluster_geometry() {
                 time: Some(Interval { start: format_time(0.), end: format_time(1.) }),
                 job_tag: None,
                 commute: Some(Commute { forward: None, backward: None }),
+                lateness: None,
+                compartment: None,
+                visit_index: None,
             },
             Activity {
                 job_id: "job2".to_string(),
@@ -96,8 +100,13 @@ fn can_create_geo_json_for_cluster_geometry() {
                         time: Interval { start: format_time(3.), end: format_time(4.) },
                     }),
                 }),
+                lateness: None,
+                compartment: None,
+                visit_index: None,
             },
         ],
+        charge: None,
+        on_time_probability: None,
     };
 
     let features = get_cluster_geometry(0, 0, &stop).unwrap();
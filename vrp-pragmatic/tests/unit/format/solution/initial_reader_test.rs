@@ -27,6 +27,7 @@ fn create_basic_problem(breaks: Option<Vec<VehicleBreak>>) -> Problem {
                 shifts: vec![VehicleShift { breaks, ..create_default_vehicle_shift() }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -84,12 +85,15 @@ fn can_read_basic_init_solution() {
             cost: 32.,
             distance: 8,
             duration: 14,
+            emissions: None,
+            tolls: None,
             times: Timing { driving: 8, serving: 4, break_time: 2, ..Timing::default() },
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
             type_id: "my_vehicle".to_string(),
             shift_index: 0,
+            driver_id: None,
             stops: vec![
                 create_stop_with_activity(
                     "departure",
@@ -136,6 +140,9 @@ fn can_read_basic_init_solution() {
                             }),
                             job_tag: Some("p2".to_owned()),
                             commute: None,
+                            lateness: None,
+                            compartment: None,
+                            visit_index: None,
                         },
                         Activity {
                             job_id: "break".to_string(),
@@ -147,8 +154,13 @@ fn can_read_basic_init_solution() {
                             }),
                             job_tag: None,
                             commute: None,
+                            lateness: None,
+                            compartment: None,
+                            visit_index: None,
                         },
                     ],
+                    charge: None,
+                    on_time_probability: None,
                 }),
                 create_stop_with_activity_with_tag(
                     "job2",
@@ -172,6 +184,8 @@ fn can_read_basic_init_solution() {
                 cost: 32.,
                 distance: 8,
                 duration: 14,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 8, serving: 4, break_time: 2, ..Timing::default() },
             },
         }],
@@ -237,7 +251,12 @@ fn can_handle_commute_error_in_init_solution() {
                         }),
                         job_tag: None,
                         commute: Some(Commute { forward: None, backward: None }),
+                        lateness: None,
+                        compartment: None,
+                        visit_index: None,
                     }],
+                    charge: None,
+                    on_time_probability: None,
                 }),
             ],
             ..create_empty_tour()
@@ -249,3 +268,102 @@ fn can_handle_commute_error_in_init_solution() {
 
     assert_eq!(result_solution, Err("commute property in initial solution is not supported".to_owned()));
 }
+
+#[test]
+fn can_skip_completed_and_removed_activities_in_init_solution() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_pickup_delivery_job("job2", (2., 0.), (3., 0.)),
+                create_delivery_job("job4", (4., 0.)),
+            ],
+            tours: Some(vec![TourState {
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+                location: (1., 0.).to_loc(),
+                time: format_time(2.),
+                load: None,
+                completed: Some(vec![create_completed_task("job1", None)]),
+            }]),
+            ..create_empty_plan()
+        },
+        ..create_basic_problem(None)
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    2,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    1,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+                create_stop_with_activity(
+                    "job3",
+                    "delivery",
+                    (5., 0.),
+                    0,
+                    ("1970-01-01T00:00:06Z", "1970-01-01T00:00:07Z"),
+                    5,
+                ),
+                create_stop_with_activity_with_tag(
+                    "job2",
+                    "pickup",
+                    (2., 0.),
+                    1,
+                    ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
+                    8,
+                    "p1",
+                ),
+                create_stop_with_activity_with_tag(
+                    "job2",
+                    "delivery",
+                    (3., 0.),
+                    0,
+                    ("1970-01-01T00:00:12Z", "1970-01-01T00:00:13Z"),
+                    9,
+                    "d1",
+                ),
+                create_stop_with_activity(
+                    "arrival",
+                    "arrival",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:00:16Z", "1970-01-01T00:00:16Z"),
+                    12,
+                ),
+            ],
+            ..create_empty_tour()
+        }],
+        unassigned: create_unassigned_jobs(&["job3"]),
+        ..create_empty_solution()
+    };
+
+    let result_solution =
+        get_init_solution(problem, &solution).unwrap_or_else(|err| panic!("cannot get solution: {}", err));
+
+    assert_eq!(result_solution.tours.len(), 1);
+    let stops = &result_solution.tours[0].stops;
+    assert_eq!(
+        stops.iter().flat_map(|stop| stop.activities().iter().map(|a| a.job_id.as_str())).collect::<Vec<_>>(),
+        vec!["departure", "job2", "job2", "arrival"]
+    );
+    assert_eq!(stops[0].schedule().departure, "1970-01-01T00:00:02Z");
+    assert_eq!(
+        result_solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>(),
+        vec!["job4"]
+    );
+}
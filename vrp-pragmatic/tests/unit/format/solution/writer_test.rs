@@ -39,6 +39,7 @@ fn can_create_solution() {
         },
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("my_vehicle")],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -54,12 +55,15 @@ fn can_create_solution() {
                 cost: 52.,
                 distance: 20,
                 duration: 22,
+                emissions: None,
+                tolls: None,
                 times: Timing { driving: 20, serving: 2, ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![
                     create_stop_with_activity(
                         "departure",
@@ -98,6 +102,8 @@ fn can_create_solution() {
                     cost: 52.,
                     distance: 20,
                     duration: 22,
+                    emissions: None,
+                    tolls: None,
                     times: Timing { driving: 20, serving: 2, ..Timing::default() },
                 },
             }],
@@ -115,6 +121,7 @@ fn can_merge_activities_with_same_location_in_one_stop() {
         },
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("my_vehicle")],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
         },
         ..create_empty_problem()
@@ -129,6 +136,8 @@ fn can_merge_activities_with_same_location_in_one_stop() {
             cost: 32.,
             distance: 10,
             duration: 12,
+            emissions: None,
+            tolls: None,
             times: Timing { driving: 10, serving: 2, ..Timing::default() },
         }
     );
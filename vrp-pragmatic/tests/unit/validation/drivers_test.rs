@@ -0,0 +1,59 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_problem_with_drivers(drivers: Vec<DriverType>) -> Problem {
+    Problem {
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            drivers: Some(drivers),
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_detect_duplicated_driver_ids() {
+    let problem = create_problem_with_drivers(vec![
+        create_default_driver("driver1"),
+        DriverType { driver_ids: vec!["driver1_1".to_string()], ..create_default_driver("driver2") },
+    ]);
+
+    let result = check_e1401_no_driver_types_with_duplicate_ids(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), Some("E1401".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_driver_shift_time, (shifts, expected), {
+    can_detect_invalid_driver_shift_time_impl(shifts, expected);
+}}
+
+can_detect_invalid_driver_shift_time! {
+    case01: (None, None),
+    case02: (Some(vec![(0., 10.)]), None),
+    case03: (Some(vec![(0., 10.), (20., 30.)]), None),
+    case04: (Some(vec![]), Some("E1402")),
+    case05: (Some(vec![(10., 0.)]), Some("E1402")),
+    case06: (Some(vec![(0., 10.), (5., 30.)]), Some("E1402")),
+}
+
+fn can_detect_invalid_driver_shift_time_impl(shifts: Option<Vec<(f64, f64)>>, expected: Option<&str>) {
+    let problem = create_problem_with_drivers(vec![DriverType {
+        shifts: shifts.map(|shifts| {
+            shifts
+                .into_iter()
+                .map(|(earliest, latest)| DriverShift { earliest: format_time(earliest), latest: format_time(latest) })
+                .collect()
+        }),
+        ..create_default_driver("driver")
+    }]);
+
+    let result = check_e1402_driver_shift_time(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
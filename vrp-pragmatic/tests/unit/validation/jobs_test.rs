@@ -17,12 +17,13 @@ can_detect_reserved_ids! {
     case04: ("break", Some("break")),
     case05: ("reload", Some("reload")),
     case06: ("dispatch", Some("dispatch")),
+    case07: ("transfer", Some("transfer")),
 }
 
 fn can_detect_reserved_ids_impl(job_id: String, expected: Option<&str>) {
     let problem = Problem {
         plan: Plan { jobs: vec![create_delivery_job(job_id.as_str(), (1., 0.))], ..create_empty_plan() },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], drivers: None, profiles: vec![] },
         ..create_empty_problem()
     };
 
@@ -60,6 +61,22 @@ fn can_detect_negative_duration() {
     assert_result("E1106", "job1", result);
 }
 
+#[test]
+fn can_detect_negative_duration_override() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_durations("job1", (1., 0.), vec![("my_vehicle", -10.)], vec![])],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1106_negative_duration(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_result("E1106", "job1", result);
+}
+
 #[test]
 fn can_detect_negative_demand() {
     let problem = Problem {
@@ -74,3 +91,410 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+parameterized_test! {can_detect_invalid_soft_time_windows, (times, lateness, expected), {
+    can_detect_invalid_soft_time_windows_impl(times, lateness, expected);
+}}
+
+can_detect_invalid_soft_time_windows! {
+    case01: (vec![(0, 10)], vec![(10., 1.)], false),
+    case02: (vec![(0, 10), (20, 30)], vec![(10., 1.), (5., 2.)], false),
+    case03: (vec![], vec![(10., 1.)], true),
+    case04: (vec![(0, 10)], vec![(10., 1.), (5., 2.)], true),
+    case05: (vec![(0, 10)], vec![(-10., 1.)], true),
+    case06: (vec![(0, 10)], vec![(10., -1.)], true),
+}
+
+fn can_detect_invalid_soft_time_windows_impl(times: Vec<(i32, i32)>, lateness: Vec<(f64, f64)>, expected: bool) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_soft_times("job1", (1., 0.), times, lateness, 1.)],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1108_soft_time_window_correctness(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    if expected {
+        assert_result("E1108", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_max_ride_time, (job, max_ride_time, expected), {
+    can_detect_invalid_max_ride_time_impl(job, max_ride_time, expected);
+}}
+
+can_detect_invalid_max_ride_time! {
+    case01: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), Some(10.), false),
+    case02: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), None, false),
+    case03: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), Some(-1.), true),
+    case04: (create_delivery_job("job1", (1., 0.)), Some(10.), true),
+    case05: (create_pickup_job("job1", (1., 0.)), Some(10.), true),
+}
+
+fn can_detect_invalid_max_ride_time_impl(job: Job, max_ride_time: Option<f64>, expected: bool) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![Job { max_ride_time, ..job }], ..create_empty_plan() },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1109_max_ride_time_correctness(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    if expected {
+        assert_result("E1109", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_visits, (count, patterns, has_relation, expected), {
+    can_detect_invalid_visits_impl(count, patterns, has_relation, expected);
+}}
+
+can_detect_invalid_visits! {
+    case01_valid: (2, None, false, false),
+    case02_zero_count: (0, None, false, true),
+    case03_valid_pattern: (2, Some(vec![vec![DayOfWeek::Monday, DayOfWeek::Thursday]]), false, false),
+    case04_pattern_length: (2, Some(vec![vec![DayOfWeek::Monday]]), false, true),
+    case05_pattern_duplicates: (2, Some(vec![vec![DayOfWeek::Monday, DayOfWeek::Monday]]), false, true),
+    case06_empty_patterns: (2, Some(vec![]), false, true),
+    case07_in_relation: (2, None, true, true),
+}
+
+fn can_detect_invalid_visits_impl(
+    count: usize,
+    patterns: Option<Vec<Vec<DayOfWeek>>>,
+    has_relation: bool,
+    expected: bool,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                visits: Some(JobVisits { count, min_days_between: None, patterns }),
+                ..create_delivery_job("job1", (1., 0.))
+            }],
+            relations: if has_relation {
+                Some(vec![Relation {
+                    type_field: RelationType::Any,
+                    jobs: vec!["job1".to_string()],
+                    vehicle_id: "my_vehicle_1".to_string(),
+                    shift_index: None,
+                }])
+            } else {
+                None
+            },
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1110_job_visits_correctness(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if expected {
+        assert_result("E1110", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+fn create_transfer_hub(id: &str, first_echelon: Vec<&str>, second_echelon: Vec<&str>) -> TransferHub {
+    TransferHub {
+        id: id.to_string(),
+        location: (0., 0.).to_loc(),
+        duration: 0.,
+        first_echelon: first_echelon.into_iter().map(|id| id.to_string()).collect(),
+        second_echelon: second_echelon.into_iter().map(|id| id.to_string()).collect(),
+    }
+}
+
+parameterized_test! {can_detect_invalid_job_hub, (hub_id, job, has_relation, expected), {
+    can_detect_invalid_job_hub_impl(hub_id, job, has_relation, expected);
+}}
+
+can_detect_invalid_job_hub! {
+    case01_valid: ("hub1", create_delivery_job("job1", (1., 0.)), false, false),
+    case02_unknown_hub: ("hub2", create_delivery_job("job1", (1., 0.)), false, true),
+    case03_pickup: ("hub1", create_pickup_job("job1", (1., 0.)), false, true),
+    case04_pickup_delivery: ("hub1", create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), false, true),
+    case05_in_relation: ("hub1", create_delivery_job("job1", (1., 0.)), true, true),
+}
+
+fn can_detect_invalid_job_hub_impl(hub_id: &str, job: Job, has_relation: bool, expected: bool) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { hub: Some(hub_id.to_string()), ..job }],
+            relations: if has_relation {
+                Some(vec![Relation {
+                    type_field: RelationType::Any,
+                    jobs: vec!["job1".to_string()],
+                    vehicle_id: "my_vehicle_1".to_string(),
+                    shift_index: None,
+                }])
+            } else {
+                None
+            },
+            hubs: Some(vec![create_transfer_hub("hub1", vec!["truck"], vec!["bike"])]),
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1111_job_hub_correctness(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if expected {
+        assert_result("E1111", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_transfer_hubs, (hubs, bike_start, expected), {
+    can_detect_invalid_transfer_hubs_impl(hubs, bike_start, expected);
+}}
+
+can_detect_invalid_transfer_hubs! {
+    case01_valid: (vec![create_transfer_hub("hub1", vec!["truck"], vec!["bike"])], (0., 0.), None),
+    case02_duplicates: (vec![
+        create_transfer_hub("hub1", vec!["truck"], vec!["bike"]),
+        create_transfer_hub("hub1", vec!["truck"], vec!["bike"])
+    ], (0., 0.), Some("hub1")),
+    case03_empty_echelon: (vec![create_transfer_hub("hub1", vec![], vec!["bike"])], (0., 0.), Some("hub1")),
+    case04_same_types: (vec![create_transfer_hub("hub1", vec!["truck", "bike"], vec!["bike"])], (0., 0.), Some("hub1")),
+    case05_unknown_type: (vec![create_transfer_hub("hub1", vec!["van"], vec!["bike"])], (0., 0.), Some("hub1")),
+    case06_wrong_start: (vec![create_transfer_hub("hub1", vec!["truck"], vec!["bike"])], (1., 0.), Some("hub1")),
+    case07_driving_hours: (vec![create_transfer_hub("hub1", vec!["lorry"], vec!["bike"])], (0., 0.), Some("hub1")),
+}
+
+fn can_detect_invalid_transfer_hubs_impl(hubs: Vec<TransferHub>, bike_start: (f64, f64), expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], hubs: Some(hubs), ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("truck"),
+                VehicleType {
+                    shifts: vec![create_default_vehicle_shift_with_locations(bike_start, bike_start)],
+                    ..create_default_vehicle("bike")
+                },
+                create_vehicle_with_driving_hours("lorry"),
+            ],
+            drivers: None,
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1112_transfer_hubs_correctness(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    if let Some(hub_id) = expected {
+        assert_result("E1112", hub_id, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_job_sync, (job, max_offset, has_relation, vehicle, expected), {
+    can_detect_invalid_job_sync_impl(job, max_offset, has_relation, vehicle, expected);
+}}
+
+can_detect_invalid_job_sync! {
+    case01_valid: (create_sync_job(vec![Some("s1"), Some("s2")]), 0., false, create_default_vehicle_type(), false),
+    case02_single_task: (create_sync_job(vec![Some("s1")]), 0., false, create_default_vehicle_type(), true),
+    case03_no_tags: (create_sync_job(vec![None, Some("s2")]), 0., false, create_default_vehicle_type(), true),
+    case04_same_tags: (create_sync_job(vec![Some("s1"), Some("s1")]), 0., false, create_default_vehicle_type(), true),
+    case05_negative_offset: (
+        create_sync_job(vec![Some("s1"), Some("s2")]), -1., false, create_default_vehicle_type(), true
+    ),
+    case06_pickup_delivery: (
+        create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), 0., false, create_default_vehicle_type(), true
+    ),
+    case07_in_relation: (create_sync_job(vec![Some("s1"), Some("s2")]), 0., true, create_default_vehicle_type(), true),
+    case08_driving_hours: (
+        create_sync_job(vec![Some("s1"), Some("s2")]), 0., false, create_vehicle_with_driving_hours("my_vehicle"), true
+    ),
+}
+
+fn create_sync_job(tags: Vec<Option<&str>>) -> Job {
+    Job {
+        services: Some(
+            tags.into_iter()
+                .map(|tag| JobTask { demand: None, ..create_task((1., 0.), tag.map(|tag| tag.to_string())) })
+                .collect(),
+        ),
+        ..create_job("job1")
+    }
+}
+
+fn can_detect_invalid_job_sync_impl(
+    job: Job,
+    max_offset: f64,
+    has_relation: bool,
+    vehicle: VehicleType,
+    expected: bool,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { sync: Some(JobSync { max_offset }), ..job }],
+            relations: if has_relation {
+                Some(vec![Relation {
+                    type_field: RelationType::Any,
+                    jobs: vec!["job1".to_string()],
+                    vehicle_id: "my_vehicle_1".to_string(),
+                    shift_index: None,
+                }])
+            } else {
+                None
+            },
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![vehicle], drivers: None, profiles: create_default_matrix_profiles() },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1113_job_sync_correctness(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if expected {
+        assert_result("E1113", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_job_place_durations, (vehicles, drivers, expected), {
+    can_detect_invalid_job_place_durations_impl(vehicles, drivers, expected);
+}}
+
+can_detect_invalid_job_place_durations! {
+    case01_known_vehicle: (vec![("my_vehicle", 10.)], vec![], false),
+    case02_known_driver: (vec![], vec![("my_driver", 10.)], false),
+    case03_unknown_vehicle: (vec![("truck", 10.)], vec![], true),
+    case04_unknown_driver: (vec![("my_vehicle", 10.)], vec![("junior", 10.)], true),
+}
+
+fn can_detect_invalid_job_place_durations_impl(vehicles: Vec<(&str, f64)>, drivers: Vec<(&str, f64)>, expected: bool) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_durations("job1", (1., 0.), vehicles, drivers)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            drivers: Some(vec![create_default_driver("my_driver")]),
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1114_job_place_durations_correctness(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ))
+    .err();
+
+    if expected {
+        assert_result("E1114", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+fn create_depot(id: &str, location: (f64, f64), docks: usize, loading: Option<(f64, f64)>) -> Depot {
+    Depot {
+        id: id.to_string(),
+        location: location.to_loc(),
+        docks,
+        loading: loading.map(|(fixed, per_unit)| DockDuration { fixed, per_unit }),
+        unloading: None,
+    }
+}
+
+parameterized_test! {can_detect_invalid_depots, (depots, vehicle, expected), {
+    can_detect_invalid_depots_impl(depots, vehicle, expected);
+}}
+
+can_detect_invalid_depots! {
+    case01_valid: (vec![create_depot("depot1", (0., 0.), 1, Some((10., 1.)))], create_default_vehicle_type(), None),
+    case02_duplicates: (vec![
+        create_depot("depot1", (0., 0.), 1, None),
+        create_depot("depot1", (1., 0.), 1, None)
+    ], create_default_vehicle_type(), Some("depot1")),
+    case03_same_location: (vec![
+        create_depot("depot1", (0., 0.), 1, None),
+        create_depot("depot2", (0., 0.), 1, None)
+    ], create_default_vehicle_type(), Some("depot1, depot2")),
+    case04_no_docks: (vec![create_depot("depot1", (0., 0.), 0, None)], create_default_vehicle_type(), Some("depot1")),
+    case05_negative_fixed: (
+        vec![create_depot("depot1", (0., 0.), 1, Some((-1., 1.)))], create_default_vehicle_type(), Some("depot1")
+    ),
+    case06_negative_per_unit: (
+        vec![create_depot("depot1", (0., 0.), 1, Some((1., -1.)))], create_default_vehicle_type(), Some("depot1")
+    ),
+    case07_driving_hours: (
+        vec![create_depot("depot1", (0., 0.), 1, None)], create_vehicle_with_driving_hours("my_vehicle"), Some("depot1")
+    ),
+}
+
+fn can_detect_invalid_depots_impl(depots: Vec<Depot>, vehicle: VehicleType, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], depots: Some(depots), ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![vehicle], drivers: None, profiles: create_default_matrix_profiles() },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1115_depots_correctness(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if let Some(depot_ids) = expected {
+        assert_result("E1115", depot_ids, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_preferred_skills, (one_of, weight, expected), {
+    can_detect_invalid_preferred_skills_impl(one_of, weight, expected);
+}}
+
+can_detect_invalid_preferred_skills! {
+    case01_valid: (vec!["s1"], Some(10.), false),
+    case02_default_weight: (vec!["s1"], None, false),
+    case03_empty: (vec![], None, true),
+    case04_negative_weight: (vec!["s1"], Some(-1.), true),
+}
+
+fn can_detect_invalid_preferred_skills_impl(one_of: Vec<&str>, weight: Option<f64>, expected: bool) {
+    let preferred = JobPreferredSkills { one_of: one_of.into_iter().map(|skill| skill.to_string()).collect(), weight };
+    let skills = JobSkills { all_of: None, one_of: None, none_of: None, preferred: Some(preferred) };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { skills: Some(skills), ..create_delivery_job("job1", (1., 0.)) }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1116_job_preferred_skills_correctness(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ))
+    .err();
+
+    if expected {
+        assert_result("E1116", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
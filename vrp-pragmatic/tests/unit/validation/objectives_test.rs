@@ -251,3 +251,97 @@ fn can_detect_missing_area_objective() {
 
     assert_eq!(result.err().unwrap().code, "E1608".to_string());
 }
+
+parameterized_test! {can_detect_redundant_emissions_objective, (emissions, expected), {
+    can_detect_redundant_emissions_objective_impl(emissions, expected);
+}}
+
+can_detect_redundant_emissions_objective! {
+    case01: (Some(VehicleEmissions { distance: 1., full_load_distance: None }), None),
+    case02: (None, Some("E1609".to_string())),
+}
+
+fn can_detect_redundant_emissions_objective_impl(emissions: Option<VehicleEmissions>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType { emissions, ..create_default_vehicle_type() }],
+            drivers: None,
+            profiles: vec![],
+        },
+        objectives: Some(vec![vec![MinimizeEmissions], vec![MinimizeCost]]),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1609_no_vehicles_with_emissions_objective(&ctx, &objectives);
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+parameterized_test! {can_detect_missing_preference_objective, (objectives, expected), {
+    can_detect_missing_preference_objective_impl(objectives, expected);
+}}
+
+can_detect_missing_preference_objective! {
+    case01: (vec![vec![MinimizeUnassignedJobs { breaks: None }], vec![MinimizeCost]], Some("E1610".to_string())),
+    case02: (vec![vec![MinimizePreferencePenalty], vec![MinimizeCost]], None),
+}
+
+fn can_detect_missing_preference_objective_impl(objectives: Vec<Vec<Objective>>, expected: Option<String>) {
+    let skills = JobSkills {
+        all_of: None,
+        one_of: None,
+        none_of: None,
+        preferred: Some(JobPreferredSkills { one_of: vec!["driver1".to_string()], weight: None }),
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { skills: Some(skills), ..create_delivery_job("job1", (1., 0.)) }],
+            ..create_empty_plan()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1610_jobs_with_preferred_skills_but_no_objective(&ctx, &objectives);
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+parameterized_test! {can_detect_missing_reassignment_objective, (objectives, expected), {
+    can_detect_missing_reassignment_objective_impl(objectives, expected);
+}}
+
+can_detect_missing_reassignment_objective! {
+    case01: (vec![vec![MinimizeUnassignedJobs { breaks: None }], vec![MinimizeCost]], Some("E1611".to_string())),
+    case02: (vec![vec![MinimizeReassignments], vec![MinimizeCost]], None),
+}
+
+fn can_detect_missing_reassignment_objective_impl(objectives: Vec<Vec<Objective>>, expected: Option<String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.))],
+            relations: Some(vec![Relation {
+                type_field: RelationType::Preferred,
+                jobs: vec!["job1".to_string()],
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+            }]),
+            ..create_empty_plan()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1611_preferred_relations_but_no_objective(&ctx, &objectives);
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
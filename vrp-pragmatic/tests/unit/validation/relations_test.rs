@@ -52,7 +52,7 @@ fn can_detect_relation_errors_impl(
             }]),
             ..create_empty_plan()
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], drivers: None, profiles: vec![] },
         ..create_empty_problem()
     };
 
@@ -73,7 +73,8 @@ parameterized_test! {can_detect_multi_place_time_window_jobs, (relation_type, ex
 can_detect_multi_place_time_window_jobs! {
     case01: (RelationType::Strict, Some(())),
     case02: (RelationType::Sequence, Some(())),
-    case03: (RelationType::Any, Some(())),
+    case03: (RelationType::Any, None),
+    case04: (RelationType::Preferred, None),
 }
 
 fn can_detect_multi_place_time_window_jobs_impl(relation_type: RelationType, expected: Option<()>) {
@@ -98,7 +99,7 @@ fn can_detect_multi_place_time_window_jobs_impl(relation_type: RelationType, exp
             }]),
             ..create_empty_plan()
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], drivers: None, profiles: vec![] },
         ..create_empty_problem()
     };
 
@@ -114,18 +115,24 @@ fn can_detect_multi_place_time_window_jobs_impl(relation_type: RelationType, exp
     }
 }
 
-parameterized_test! {can_detect_multi_vehicle_assignment, (relations, expected), {
-    can_detect_multi_vehicle_assignment_impl(relations, expected);
+parameterized_test! {can_detect_multi_vehicle_assignment, (relations, relation_type, expected), {
+    can_detect_multi_vehicle_assignment_impl(relations, relation_type, expected);
 }}
 
 can_detect_multi_vehicle_assignment! {
-    case01: (vec![("job1", "car_1")], None),
-    case02: (vec![("job1", "car_1"), ("job1", "car_1")], None),
-    case03: (vec![("job1", "car_1"), ("job2", "car_1")], None),
-    case04: (vec![("job1", "car_1"), ("job1", "truck_1")], Some("job1")),
+    case01: (vec![("job1", "car_1")], RelationType::Any, None),
+    case02: (vec![("job1", "car_1"), ("job1", "car_1")], RelationType::Any, None),
+    case03: (vec![("job1", "car_1"), ("job2", "car_1")], RelationType::Any, None),
+    case04: (vec![("job1", "car_1"), ("job1", "truck_1")], RelationType::Any, Some("job1")),
+    case05: (vec![("job1", "car_1"), ("job1", "truck_1")], RelationType::Strict, Some("job1")),
+    case06: (vec![("job1", "car_1"), ("job1", "truck_1")], RelationType::Preferred, None),
 }
 
-fn can_detect_multi_vehicle_assignment_impl(relations: Vec<(&str, &str)>, expected: Option<&str>) {
+fn can_detect_multi_vehicle_assignment_impl(
+    relations: Vec<(&str, &str)>,
+    relation_type: RelationType,
+    expected: Option<&str>,
+) {
     let problem = Problem {
         plan: Plan {
             jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
@@ -133,7 +140,7 @@ fn can_detect_multi_vehicle_assignment_impl(relations: Vec<(&str, &str)>, expect
                 relations
                     .iter()
                     .map(|(job_id, vehicle_id)| Relation {
-                        type_field: RelationType::Any,
+                        type_field: relation_type.clone(),
                         jobs: vec![job_id.to_string()],
                         vehicle_id: vehicle_id.to_string(),
                         shift_index: None,
@@ -144,6 +151,7 @@ fn can_detect_multi_vehicle_assignment_impl(relations: Vec<(&str, &str)>, expect
         },
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("car"), create_default_vehicle("truck")],
+            drivers: None,
             profiles: vec![],
         },
         ..create_empty_problem()
@@ -190,7 +198,7 @@ fn can_detect_incomplete_multi_job_in_relation_impl(
             }]),
             ..create_empty_plan()
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("car")], profiles: vec![] },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("car")], drivers: None, profiles: vec![] },
         ..create_empty_problem()
     };
 
@@ -198,3 +206,30 @@ fn can_detect_incomplete_multi_job_in_relation_impl(
 
     assert_eq!(result.map(|err| err.code), expected);
 }
+
+parameterized_test! {can_detect_reserved_ids_in_preferred_relation, (relation_type, jobs, expected), {
+    can_detect_reserved_ids_in_preferred_relation_impl(relation_type, jobs, expected);
+}}
+
+can_detect_reserved_ids_in_preferred_relation! {
+    case01: (RelationType::Preferred, vec!["job1"], None),
+    case02: (RelationType::Preferred, vec!["departure", "job1"], Some("E1208")),
+    case03: (RelationType::Any, vec!["departure", "job1"], None),
+}
+
+fn can_detect_reserved_ids_in_preferred_relation_impl(
+    relation_type: RelationType,
+    jobs: Vec<&str>,
+    expected: Option<&str>,
+) {
+    let relations = vec![Relation {
+        type_field: relation_type,
+        jobs: jobs.into_iter().map(|job| job.to_string()).collect(),
+        vehicle_id: "vehicle_1".to_string(),
+        shift_index: None,
+    }];
+
+    let result = check_e1208_preferred_relation_has_no_reserved_ids(&relations);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
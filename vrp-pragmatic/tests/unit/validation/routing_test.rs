@@ -6,9 +6,10 @@ fn can_detect_duplicates() {
     let problem = Problem {
         fleet: Fleet {
             vehicles: vec![],
+            drivers: None,
             profiles: vec![
-                MatrixProfile { name: "my_vehicle".to_string(), speed: None },
-                MatrixProfile { name: "my_vehicle".to_string(), speed: None },
+                MatrixProfile { name: "my_vehicle".to_string(), speed: None, service_level: None },
+                MatrixProfile { name: "my_vehicle".to_string(), speed: None, service_level: None },
             ],
         },
         ..create_empty_problem()
@@ -23,7 +24,8 @@ fn can_detect_duplicates() {
 
 #[test]
 fn can_detect_empty_profiles() {
-    let problem = Problem { fleet: Fleet { vehicles: vec![], profiles: vec![] }, ..create_empty_problem() };
+    let problem =
+        Problem { fleet: Fleet { vehicles: vec![], drivers: None, profiles: vec![] }, ..create_empty_problem() };
     let coord_index = CoordIndex::new(&problem);
     let ctx = ValidationContext::new(&problem, None, &coord_index);
 
@@ -83,6 +85,8 @@ fn can_detect_index_mismatch() {
         travel_times: vec![1; 4],
         distances: vec![1; 4],
         error_codes: None,
+        toll_costs: None,
+        travel_time_variances: None,
     }];
     let coord_index = CoordIndex::new(&problem);
     let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
@@ -100,7 +104,8 @@ fn can_detect_missing_profile() {
                 VehicleType { profile: create_vehicle_profile_with_name("car"), ..create_default_vehicle_type() },
                 VehicleType { profile: create_vehicle_profile_with_name("truck"), ..create_default_vehicle_type() },
             ],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            drivers: None,
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, service_level: None }],
         },
         ..create_empty_problem()
     };
@@ -111,3 +116,76 @@ fn can_detect_missing_profile() {
 
     assert_eq!(result.err().map(|err| err.code), Some("E1505".to_string()));
 }
+
+parameterized_test! {can_detect_invalid_toll_costs, (toll_costs, expected), {
+    can_detect_invalid_toll_costs_impl(toll_costs, expected);
+}}
+
+can_detect_invalid_toll_costs! {
+    case01_no_tolls: (None, None),
+    case02_correct_tolls: (Some(vec![0., 1., 2., 0.]), None),
+    case03_wrong_size: (Some(vec![0., 1.]), Some("E1506".to_string())),
+    case04_negative_toll: (Some(vec![0., -1., 2., 0.]), Some("E1506".to_string())),
+}
+
+fn can_detect_invalid_toll_costs_impl(toll_costs: Option<Vec<f64>>, expected: Option<String>) {
+    let problem = create_empty_problem();
+    let matrices = vec![Matrix { toll_costs, ..create_matrix(vec![1; 4]) }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1506_toll_costs_are_correct(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_travel_time_variances, (variances, expected), {
+    can_detect_invalid_travel_time_variances_impl(variances, expected);
+}}
+
+can_detect_invalid_travel_time_variances! {
+    case01_no_variances: (None, None),
+    case02_correct_variances: (Some(vec![0., 1., 2., 0.]), None),
+    case03_wrong_size: (Some(vec![0., 1.]), Some("E1507".to_string())),
+    case04_negative_variance: (Some(vec![0., -1., 2., 0.]), Some("E1507".to_string())),
+}
+
+fn can_detect_invalid_travel_time_variances_impl(variances: Option<Vec<f64>>, expected: Option<String>) {
+    let problem = create_empty_problem();
+    let matrices = vec![Matrix { travel_time_variances: variances, ..create_matrix(vec![1; 4]) }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1507_travel_time_variances_are_correct(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_service_level, (service_level, expected), {
+    can_detect_invalid_service_level_impl(service_level, expected);
+}}
+
+can_detect_invalid_service_level! {
+    case01_no_level: (None, None),
+    case02_correct_level: (Some(0.9), None),
+    case03_median_level: (Some(0.5), None),
+    case04_too_low: (Some(0.3), Some("E1508".to_string())),
+    case05_too_high: (Some(1.), Some("E1508".to_string())),
+}
+
+fn can_detect_invalid_service_level_impl(service_level: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            drivers: None,
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, service_level }],
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let result = check_e1508_service_level_is_correct(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
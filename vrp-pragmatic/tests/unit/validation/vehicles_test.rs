@@ -18,6 +18,7 @@ fn can_detect_invalid_break_time() {
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: vec![],
         },
         ..create_empty_problem()
@@ -73,9 +74,11 @@ fn can_detect_invalid_area_impl(
                             .map(|area_id| AreaLimit { area_id: area_id.to_string(), job_value: 1. })
                             .collect()]
                     }),
+                    driving_hours: None,
                 }),
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: vec![],
         },
         ..create_empty_problem()
@@ -120,6 +123,7 @@ fn can_detect_invalid_dispatch_impl(dispatch: &[(f64, (f64, f64))], expected: Op
                 shifts: vec![VehicleShift { dispatch: dispatch, ..create_default_vehicle_shift() }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: vec![],
         },
         ..create_empty_problem()
@@ -147,9 +151,10 @@ fn can_detect_zero_costs_impl(costs: (f64, f64), expected: Option<String>) {
     let problem = Problem {
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: None, distance, time },
+                costs: VehicleCosts { fixed: None, distance, time, full_load_distance: None },
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: vec![],
         },
         ..create_empty_problem()
@@ -179,7 +184,7 @@ fn can_handle_rescheduling_with_required_break_impl(latest: Option<f64>, expecte
                     start: ShiftStart {
                         earliest: format_time(0.),
                         latest: latest.map(|latest| format_time(latest)),
-                        location: (0., 0.).to_loc(),
+                        location: Some((0., 0.).to_loc()),
                     },
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime(10.),
@@ -189,6 +194,7 @@ fn can_handle_rescheduling_with_required_break_impl(latest: Option<f64>, expecte
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: vec![],
         },
         ..create_empty_problem()
@@ -202,3 +208,337 @@ fn can_handle_rescheduling_with_required_break_impl(latest: Option<f64>, expecte
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_detect_invalid_compartments, (compartments, expected), {
+    can_detect_invalid_compartments_impl(compartments, expected);
+}}
+
+can_detect_invalid_compartments! {
+    case01: (vec![("c1", vec![5]), ("c2", vec![5])], None),
+    case02: (vec![], Some("E1309".to_string())),
+    case03: (vec![("c1", vec![5]), ("c1", vec![5])], Some("E1309".to_string())),
+    case04: (vec![("c1", vec![5, 1])], Some("E1309".to_string())),
+    case05: (vec![("c1", vec![-1])], Some("E1309".to_string())),
+}
+
+fn can_detect_invalid_compartments_impl(compartments: Vec<(&str, Vec<i32>)>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                compartments: Some(
+                    compartments
+                        .into_iter()
+                        .map(|(id, capacity)| VehicleCompartment { id: id.to_string(), capacity, products: None })
+                        .collect(),
+                ),
+                ..create_default_vehicle_type()
+            }],
+            drivers: None,
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1309_vehicle_compartments_are_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_driving_hours, (max_continuous_driving, split_break, expected), {
+    can_detect_invalid_driving_hours_impl(max_continuous_driving, split_break, expected);
+}}
+
+can_detect_invalid_driving_hours! {
+    case01: (16200., None, None),
+    case02: (16200., Some(vec![900., 1800.]), None),
+    case03: (0., None, Some("E1310".to_string())),
+    case04: (16200., Some(vec![900.]), Some("E1310".to_string())),
+    case05: (16200., Some(vec![900., 900.]), Some("E1310".to_string())),
+    case06: (16200., Some(vec![-900., 3600.]), Some("E1310".to_string())),
+}
+
+fn can_detect_invalid_driving_hours_impl(
+    max_continuous_driving: f64,
+    split_break: Option<Vec<f64>>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    tour_size: None,
+                    areas: None,
+                    driving_hours: Some(DrivingHoursLimit {
+                        max_continuous_driving,
+                        break_duration: 2700.,
+                        split_break,
+                        max_daily_driving: 32400.,
+                        daily_rest: 39600.,
+                        max_daily_duty: None,
+                    }),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            drivers: None,
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1310_vehicle_driving_hours_are_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+type ShiftData = (f64, bool, Option<(f64, bool)>);
+
+parameterized_test! {can_detect_invalid_linked_shifts, (shifts, has_breaks, expected), {
+    can_detect_invalid_linked_shifts_impl(shifts, has_breaks, expected);
+}}
+
+can_detect_invalid_linked_shifts! {
+    case01_single_shift: (vec![(0., true, Some((100., true)))], false, None),
+    case02_linked_at_stop: (vec![(0., true, Some((100., false))), (200., false, Some((300., true)))], false, None),
+    case03_linked_at_location: (vec![(0., true, Some((100., true))), (200., false, Some((300., true)))], false, None),
+    case04_first_linked: (vec![(0., false, Some((100., true)))], false, Some("E1311".to_string())),
+    case05_wrong_order: (
+        vec![(0., true, Some((100., false))), (50., false, Some((300., true)))], false, Some("E1311".to_string())
+    ),
+    case06_no_previous_end: (vec![(0., true, None), (200., false, Some((300., true)))], false, Some("E1311".to_string())),
+    case07_end_without_link: (
+        vec![(0., true, Some((100., false))), (200., true, Some((300., true)))], false, Some("E1311".to_string())
+    ),
+    case08_last_end_without_location: (vec![(0., true, Some((100., false)))], false, Some("E1311".to_string())),
+    case09_linked_with_breaks: (
+        vec![(0., true, Some((100., false))), (200., false, Some((300., true)))], true, Some("E1311".to_string())
+    ),
+    case10_not_linked_with_breaks: (vec![(0., true, Some((100., true)))], true, None),
+}
+
+fn can_detect_invalid_linked_shifts_impl(shifts: Vec<ShiftData>, has_breaks: bool, expected: Option<String>) {
+    let shifts_size = shifts.len();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: shifts
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, (start, has_start_location, end))| VehicleShift {
+                        start: ShiftStart {
+                            earliest: format_time(start),
+                            latest: None,
+                            location: if has_start_location { Some((0., 0.).to_loc()) } else { None },
+                        },
+                        end: end.map(|(end, has_end_location)| ShiftEnd {
+                            earliest: None,
+                            latest: format_time(end),
+                            location: if has_end_location { Some((0., 0.).to_loc()) } else { None },
+                        }),
+                        breaks: if has_breaks && idx + 1 == shifts_size {
+                            Some(vec![VehicleBreak::Required {
+                                time: VehicleRequiredBreakTime::OffsetTime(10.),
+                                duration: 2.,
+                            }])
+                        } else {
+                            None
+                        },
+                        ..create_default_vehicle_shift()
+                    })
+                    .collect(),
+                ..create_default_vehicle_type()
+            }],
+            drivers: None,
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1311_vehicle_linked_shifts_are_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_battery, (battery, rate, expected), {
+    can_detect_invalid_battery_impl(battery, rate, expected);
+}}
+
+can_detect_invalid_battery! {
+    case01: (Some((100., None, 1.)), Some(1.), None),
+    case02: (Some((100., Some(50.), 1.)), None, None),
+    case03: (Some((0., None, 1.)), None, Some("E1312".to_string())),
+    case04: (Some((100., Some(150.), 1.)), None, Some("E1312".to_string())),
+    case05: (Some((100., Some(-1.), 1.)), None, Some("E1312".to_string())),
+    case06: (Some((100., None, -1.)), None, Some("E1312".to_string())),
+    case07: (Some((100., None, 1.)), Some(0.), Some("E1312".to_string())),
+    case08: (None, Some(1.), Some("E1312".to_string())),
+    case09: (None, None, None),
+}
+
+fn can_detect_invalid_battery_impl(
+    battery: Option<(f64, Option<f64>, f64)>,
+    rate: Option<f64>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    charging_stations: rate.map(|rate| {
+                        vec![VehicleChargingStation {
+                            location: (0., 0.).to_loc(),
+                            duration: 100.,
+                            rate,
+                            times: None,
+                            tag: None,
+                        }]
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                battery: battery.map(|(capacity, initial, consumption)| VehicleBattery {
+                    capacity,
+                    initial,
+                    consumption,
+                    full_load_consumption: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            drivers: None,
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1312_vehicle_battery_is_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_load_costs, (full_load_distance, emissions, expected), {
+    can_detect_invalid_load_costs_impl(full_load_distance, emissions, expected);
+}}
+
+can_detect_invalid_load_costs! {
+    case01: (Some(2.), Some((1., Some(2.))), None),
+    case02: (None, None, None),
+    case03: (Some(-1.), None, Some("E1313".to_string())),
+    case04: (None, Some((-1., None)), Some("E1313".to_string())),
+    case05: (None, Some((1., Some(-1.))), Some("E1313".to_string())),
+}
+
+fn can_detect_invalid_load_costs_impl(
+    full_load_distance: Option<f64>,
+    emissions: Option<(f64, Option<f64>)>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { full_load_distance, ..create_default_vehicle_costs() },
+                emissions: emissions
+                    .map(|(distance, full_load_distance)| VehicleEmissions { distance, full_load_distance }),
+                ..create_default_vehicle_type()
+            }],
+            drivers: None,
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1313_vehicle_load_costs_are_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+fn create_problem_with_tour_states(tours: Vec<TourState>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_pickup_delivery_job("job2", (1., 0.), (2., 0.))],
+            tours: Some(tours),
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], drivers: None, profiles: vec![] },
+        ..create_empty_problem()
+    }
+}
+
+fn create_tour_state(
+    vehicle_id: &str,
+    shift_index: Option<usize>,
+    time: f64,
+    completed: Vec<(&str, Option<&str>)>,
+) -> TourState {
+    TourState {
+        vehicle_id: vehicle_id.to_string(),
+        shift_index,
+        location: (1., 0.).to_loc(),
+        time: format_time(time),
+        load: Some(vec![1]),
+        completed: Some(completed.into_iter().map(|(job_id, tag)| create_completed_task(job_id, tag)).collect()),
+    }
+}
+
+parameterized_test! {can_detect_invalid_tour_states, (tours, expected), {
+    can_detect_invalid_tour_states_impl(tours, expected);
+}}
+
+can_detect_invalid_tour_states! {
+    case01_valid: (vec![("my_vehicle_1", None, 10.)], None),
+    case02_unknown_vehicle: (vec![("my_vehicle_2", None, 10.)], Some("E1314".to_string())),
+    case03_unknown_shift: (vec![("my_vehicle_1", Some(1), 10.)], Some("E1314".to_string())),
+    case04_time_outside_shift: (vec![("my_vehicle_1", None, 1001.)], Some("E1314".to_string())),
+    case05_duplicate: (vec![("my_vehicle_1", None, 10.), ("my_vehicle_1", Some(0), 20.)], Some("E1314".to_string())),
+}
+
+fn can_detect_invalid_tour_states_impl(tours: Vec<(&str, Option<usize>, f64)>, expected: Option<String>) {
+    let problem = create_problem_with_tour_states(
+        tours
+            .into_iter()
+            .map(|(vehicle_id, shift_index, time)| create_tour_state(vehicle_id, shift_index, time, vec![]))
+            .collect(),
+    );
+
+    let result =
+        check_e1314_tour_states_are_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_completed_activities, (completed, expected), {
+    can_detect_invalid_completed_activities_impl(completed, expected);
+}}
+
+can_detect_invalid_completed_activities! {
+    case01_valid: (vec![("job1", None), ("job2", Some("p1")), ("job2", Some("d1"))], None),
+    case02_unknown_job: (vec![("job3", None)], Some("E1315".to_string())),
+    case03_too_many_tasks: (vec![("job1", None), ("job1", None)], Some("E1315".to_string())),
+    case04_ambiguous_task: (vec![("job2", None)], Some("E1315".to_string())),
+    case05_unknown_tag: (vec![("job2", Some("d2"))], Some("E1315".to_string())),
+}
+
+fn can_detect_invalid_completed_activities_impl(completed: Vec<(&str, Option<&str>)>, expected: Option<String>) {
+    let problem = create_problem_with_tour_states(vec![create_tour_state("my_vehicle_1", None, 10., completed)]);
+
+    let result = check_e1315_tour_states_completed_activities_are_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
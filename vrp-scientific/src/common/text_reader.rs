@@ -17,7 +17,7 @@ pub(crate) trait TextReader {
             fleet: Arc::new(fleet),
             jobs: Arc::new(jobs),
             locks: vec![],
-            constraint: Arc::new(create_constraint(activity.clone(), transport.clone())),
+            constraint: Arc::new(create_constraint(activity.clone(), transport.clone(), self.has_backhauls())),
             activity,
             transport,
             objective: Arc::new(ProblemObjective::default()),
@@ -30,6 +30,10 @@ pub(crate) trait TextReader {
     fn create_transport(&self, is_rounded: bool) -> Result<Arc<dyn TransportCost + Send + Sync>, String>;
 
     fn create_extras(&self) -> Extras;
+
+    fn has_backhauls(&self) -> bool {
+        false
+    }
 }
 
 pub(crate) fn create_fleet_with_distance_costs(
@@ -73,6 +77,7 @@ pub(crate) fn create_fleet_with_distance_costs(
                             location,
                             time: TimeInterval { earliest: None, latest: Some(time.end) },
                         }),
+                        links: vec![],
                     }],
                 })
             })
@@ -90,12 +95,14 @@ pub(crate) fn create_dimens_with_id(prefix: &str, id: &str) -> Dimensions {
 pub(crate) fn create_constraint(
     activity: Arc<SimpleActivityCost>,
     transport: Arc<dyn TransportCost + Send + Sync>,
+    has_backhauls: bool,
 ) -> ConstraintPipeline {
     let mut constraint = ConstraintPipeline::default();
     constraint.add_module(Arc::new(TransportConstraintModule::new(
         transport.clone(),
         activity.clone(),
         Arc::new(|_| (None, None)),
+        Arc::new(|_| None),
         1,
         2,
         3,
@@ -103,6 +110,10 @@ pub(crate) fn create_constraint(
     constraint.add_module(Arc::new(CapacityConstraintModule::<SingleDimLoad>::new(activity, transport, 4)));
     constraint.add_module(Arc::new(FleetUsageConstraintModule::new_minimized()));
 
+    if has_backhauls {
+        constraint.add_module(Arc::new(BackhaulModule::new::<SingleDimLoad>(Arc::new(|_| true), 5)));
+    }
+
     constraint
 }
 
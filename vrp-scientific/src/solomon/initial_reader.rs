@@ -49,6 +49,8 @@ pub fn read_init_solution<R: Read>(
                     let place = single.places.first().unwrap();
                     tour.insert_last(Activity {
                         place: vrp_core::models::solution::Place {
+                            idx: 0,
+                            time_idx: 0,
                             location: place.location.unwrap(),
                             duration: place.duration,
                             time: place.times.first().and_then(|span| span.as_time_window()).unwrap(),
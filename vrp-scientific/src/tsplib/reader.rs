@@ -4,7 +4,7 @@ mod reader_test;
 
 use crate::common::*;
 use crate::utils::CoordIndex;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::models::common::TimeWindow;
@@ -13,6 +13,8 @@ use vrp_core::models::problem::*;
 use vrp_core::models::{Extras, Problem};
 
 /// A trait to read tsplib95 problem. Please note that it is very basic implementation of the format specification.
+/// Besides `CVRP`, problem with backhauls (`VRPB`) is supported: backhaul customers are listed in `BACKHAUL_SECTION`
+/// and their demand is picked up after all linehaul customers are served.
 pub trait TsplibProblem {
     /// Reads tsplib95 problem.
     fn read_tsplib(self, is_rounded: bool) -> Result<Problem, String>;
@@ -35,6 +37,7 @@ struct TsplibReader<R: Read> {
     reader: BufReader<R>,
     dimension: Option<usize>,
     vehicle_capacity: Option<usize>,
+    has_backhauls: bool,
     coord_index: CoordIndex,
 }
 
@@ -43,6 +46,7 @@ impl<R: Read> TextReader for TsplibReader<R> {
         self.read_meta()?;
 
         let (coordinates, demands) = self.read_customer_data()?;
+        let backhauls = self.read_backhaul_data()?;
         let depot_id = self.read_depot_data()?;
         self.read_expected_line("EOF")?;
 
@@ -53,7 +57,7 @@ impl<R: Read> TextReader for TsplibReader<R> {
             |mut jobs, (id, (x, y))| {
                 let demand = demands.get(id).cloned().ok_or_else(|| format!("cannot find demand for id: '{}'", id))?;
 
-                jobs.push(self.create_job(&(*id - 1).to_string(), (*x, *y), demand));
+                jobs.push(self.create_job(&(*id - 1).to_string(), (*x, *y), demand, backhauls.contains(id)));
 
                 Ok(jobs)
             },
@@ -80,6 +84,10 @@ impl<R: Read> TextReader for TsplibReader<R> {
     fn create_extras(&self) -> Extras {
         Extras::default()
     }
+
+    fn has_backhauls(&self) -> bool {
+        self.has_backhauls
+    }
 }
 
 type ProblemData = (HashMap<i32, (i32, i32)>, HashMap<i32, i32>);
@@ -91,6 +99,7 @@ impl<R: Read> TsplibReader<R> {
             reader,
             dimension: None,
             vehicle_capacity: None,
+            has_backhauls: false,
             coord_index: CoordIndex::default(),
         }
     }
@@ -99,9 +108,11 @@ impl<R: Read> TsplibReader<R> {
         self.skip_lines(2)?;
 
         let problem_type = self.read_key_value("TYPE")?;
-        if problem_type != "CVRP" {
-            return Err(format!("expecting 'CVRP' as TYPE, got '{}'", problem_type));
-        }
+        self.has_backhauls = match problem_type.as_str() {
+            "CVRP" => false,
+            "VRPB" => true,
+            _ => return Err(format!("expecting 'CVRP' or 'VRPB' as TYPE, got '{}'", problem_type)),
+        };
 
         self.dimension = Some(
             self.read_key_value("DIMENSION")
@@ -159,6 +170,25 @@ impl<R: Read> TsplibReader<R> {
         Ok((coordinates, demands))
     }
 
+    fn read_backhaul_data(&mut self) -> Result<HashSet<i32>, String> {
+        let mut backhauls = HashSet::new();
+
+        if self.has_backhauls {
+            self.read_expected_line("BACKHAUL_SECTION")?;
+
+            loop {
+                let id = parse_int(self.read_line()?.trim(), "cannot parse backhaul id")?;
+                if id == -1 {
+                    break;
+                }
+
+                backhauls.insert(id);
+            }
+        }
+
+        Ok(backhauls)
+    }
+
     fn read_depot_data(&mut self) -> Result<i32, String> {
         self.read_expected_line("DEPOT_SECTION")?;
         let depot_id = parse_int(self.read_line()?.trim(), "cannot parse depot id")?;
@@ -201,11 +231,17 @@ impl<R: Read> TsplibReader<R> {
         skip_lines(count, &mut self.reader, &mut self.buffer)
     }
 
-    fn create_job(&mut self, id: &str, location: (i32, i32), demand: i32) -> Job {
+    fn create_job(&mut self, id: &str, location: (i32, i32), demand: i32, is_backhaul: bool) -> Job {
+        let (pickup, delivery) = if is_backhaul {
+            (SingleDimLoad::new(demand), SingleDimLoad::default())
+        } else {
+            (SingleDimLoad::default(), SingleDimLoad::new(demand))
+        };
+
         let mut dimens = create_dimens_with_id("", id);
         dimens.set_demand(Demand::<SingleDimLoad> {
-            pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
-            delivery: (SingleDimLoad::new(demand), SingleDimLoad::default()),
+            pickup: (pickup, SingleDimLoad::default()),
+            delivery: (delivery, SingleDimLoad::default()),
         });
         Job::Single(Arc::new(Single {
             places: vec![Place {
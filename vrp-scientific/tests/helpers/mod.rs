@@ -14,6 +14,7 @@ pub use self::lilim::LilimBuilder;
 
 use crate::lilim::LilimProblem;
 use crate::solomon::SolomonProblem;
+use crate::tsplib::TsplibProblem;
 use std::fs::File;
 use std::io::BufReader;
 use vrp_core::models::Problem;
@@ -43,3 +44,9 @@ pub fn create_lc101_problem() -> Problem {
         .read_lilim(false)
         .unwrap()
 }
+
+pub fn create_backhauls_problem() -> Problem {
+    BufReader::new(get_test_resource("../../examples/data/scientific/tsplib/backhauls.txt").unwrap())
+        .read_tsplib(false)
+        .unwrap()
+}
@@ -55,6 +55,10 @@ can_solve_problem_with_cheapest_insertion_heuristic! {
             vec!["mlt6", "mlt6", "mlt8", "mlt9", "mlt9", "mlt7", "mlt7", "mlt8"]
         ],
         828.937),
+    case3: (
+        create_backhauls_problem(),
+        vec![vec!["2", "5"], vec!["4", "1", "3"]],
+        269.),
 }
 
 fn can_solve_problem_with_cheapest_insertion_heuristic_impl(
@@ -3,6 +3,7 @@ use crate::tsplib::reader::TsplibReader;
 use crate::tsplib::TsplibProblem;
 use std::fs::File;
 use std::io::{BufReader, Read};
+use vrp_core::models::common::{Demand, DemandDimension, IdDimension, Load, SingleDimLoad};
 
 fn get_example_problem_string() -> String {
     let mut buffer = "".to_string();
@@ -19,10 +20,14 @@ fn get_example_problem_reader() -> BufReader<File> {
     BufReader::new(get_test_resource("../../examples/data/scientific/tsplib/example.txt").expect("cannot open file"))
 }
 
+fn get_backhaul_problem_reader() -> BufReader<File> {
+    BufReader::new(get_test_resource("../../examples/data/scientific/tsplib/backhauls.txt").expect("cannot open file"))
+}
+
 #[test]
 fn can_read_meta_errors() {
     for (from, to, error) in &[
-        ("CVRP", "ASD", "expecting 'CVRP' as TYPE, got 'ASD'"),
+        ("CVRP", "ASD", "expecting 'CVRP' or 'VRPB' as TYPE, got 'ASD'"),
         ("DIMENSION : 6", "DIMENSION : asd", "cannot parse DIMENSION: 'invalid float literal'"),
         ("EUC_2D", "ASD", "expecting 'EUC_2D' as EDGE_WEIGHT_TYPE, got 'ASD'"),
         ("CAPACITY : 30", "CAPACITY : asd", "cannot parse CAPACITY: 'invalid float literal'"),
@@ -75,3 +80,40 @@ fn can_read_problem() {
     assert_eq!(problem.jobs.size(), 5);
     assert_eq!(problem.fleet.actors.len(), 6);
 }
+
+#[test]
+fn can_read_backhaul_data() {
+    let mut reader = TsplibReader::new(get_backhaul_problem_reader());
+    reader.read_meta().expect("cannot read meta");
+    reader.read_customer_data().expect("cannot read customer data");
+
+    let backhauls = reader.read_backhaul_data().expect("cannot read backhaul data");
+
+    assert!(reader.has_backhauls);
+    assert_eq!(backhauls, vec![4, 6].into_iter().collect());
+    assert_eq!(reader.read_depot_data(), Ok(1));
+}
+
+#[test]
+fn can_read_problem_with_backhauls() {
+    let reader = get_backhaul_problem_reader();
+
+    let problem = reader.read_tsplib(false).expect("cannot read problem");
+
+    let mut backhauls = problem
+        .jobs
+        .all()
+        .filter_map(|job| {
+            let single = job.to_single();
+            let demand: &Demand<SingleDimLoad> = single.dimens.get_demand().unwrap();
+            if demand.pickup.0.is_not_empty() {
+                single.dimens.get_id().cloned()
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    backhauls.sort();
+    assert_eq!(problem.jobs.size(), 5);
+    assert_eq!(backhauls, vec!["3".to_string(), "5".to_string()]);
+}